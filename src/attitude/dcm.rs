@@ -33,6 +33,12 @@ impl<T: Float> DirectionCosineMatrix<T> {
     pub fn as_matrix(&self) -> &Matrix<T, 3, 3> {
         &self.data
     }
+
+    /// The inverse rotation
+    pub fn transpose(&self) -> Self {
+        let m = &self.data.data;
+        Self { data: Matrix { data: core::array::from_fn(|i| core::array::from_fn(|j| m[j][i])) } }
+    }
     
     pub fn rotate_x(angle: T) -> Self {
         let (c, s) = (angle.cos(), angle.sin());
@@ -25,6 +25,44 @@ impl<T: Float> Rotation<T> {
     pub fn compose(&self, rhs: &Self) -> Self {
         Self::from_quaternion(self.quat * rhs.quat)
     }
+
+    /// Exponential map from a rotation vector (axis times angle) to SO(3).
+    /// The result has unit norm to rounding, so no renormalization is needed.
+    pub fn exp(rotation_vector: Vector<T, 3>) -> Self {
+        let half = T::from(0.5).unwrap();
+        let angle = rotation_vector.norm();
+        // sin(angle / 2) / angle, by its series near zero
+        let sinc = if angle < T::from(1e-4).unwrap() {
+            half - angle * angle / T::from(48.0).unwrap()
+        } else {
+            (angle * half).sin() / angle
+        };
+        let v = rotation_vector * sinc;
+        Self::from_quaternion(Quaternion::new((angle * half).cos(), v[0], v[1], v[2]))
+    }
+
+    /// Logarithm map, the rotation vector with angle in `[0, pi]`.
+    pub fn log(&self) -> Vector<T, 3> {
+        let [w, i, j, k] = self.quat.data.data;
+        // q and -q are the same rotation; take the short way round
+        let sign = if w < T::zero() { -T::one() } else { T::one() };
+        let v = Vector::new([i, j, k]) * sign;
+        let sin_half = v.norm();
+        if sin_half == T::zero() {
+            return v;
+        }
+        let angle = T::from(2.0).unwrap() * sin_half.atan2(w * sign);
+        v * (angle / sin_half)
+    }
+
+    /// Rotates a body-frame vector into the inertial frame, `q v q*`.
+    pub fn rotate(&self, v: &Vector<T, 3>) -> Vector<T, 3> {
+        let p = Quaternion::new(T::zero(), v[0], v[1], v[2]);
+        let q = self.quat;
+        let conjugate = Quaternion::new(q.w(), -q.i(), -q.j(), -q.k());
+        let r = q * p * conjugate;
+        Vector::new([r.i(), r.j(), r.k()])
+    }
 }
 
 impl<T: Float> TryFrom<&DirectionCosineMatrix<T>> for Rotation<T> {
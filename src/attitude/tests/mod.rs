@@ -2,5 +2,6 @@
 mod euler_tests;
 mod quaternion_tests;
 mod dcm_tests;
+mod rotation_tests;
 pub mod test_utils;
 pub use test_utils::*;
\ No newline at end of file
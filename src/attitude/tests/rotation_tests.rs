@@ -0,0 +1,38 @@
+mod tests {
+    use crate::attitude::{DirectionCosineMatrix, Rotation};
+    use crate::math::Vector;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_rotation_exp_log_roundtrip() {
+        for v in [[0.3, -0.2, 0.9], [1e-6, 2e-6, -1e-6], [0.0, 0.0, 0.0], [2.0, 1.0, -1.5]] {
+            let v = Vector::new(v);
+            let r = Rotation::exp(v);
+            assert_relative_eq!(r.quaternion().norm(), 1.0, epsilon = 1e-15);
+            let back = r.log();
+            for i in 0..3 {
+                assert_relative_eq!(back[i], v[i], epsilon = 1e-12);
+            }
+        }
+        // Angles beyond pi come back the short way round
+        let r = Rotation::exp(Vector::new([0.0, 0.0, 4.0]));
+        assert_relative_eq!(r.log()[2], 4.0 - 2.0 * core::f64::consts::PI, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_rotation_rotate_matches_dcm() {
+        // The DCM maps inertial to body axes, `rotate` maps body to inertial
+        let r = Rotation::exp(Vector::new([0.4, -0.7, 0.2]));
+        let v = Vector::new([1.0, 2.0, 3.0]);
+        let dcm = DirectionCosineMatrix::from(r.quaternion());
+        let expected = dcm.as_matrix().transpose() * v;
+        let rotated = r.rotate(&v);
+        for i in 0..3 {
+            assert_relative_eq!(rotated[i], expected[i], epsilon = 1e-12);
+        }
+        // Quarter turn about z takes x to y
+        let quarter = Rotation::exp(Vector::new([0.0, 0.0, core::f64::consts::FRAC_PI_2]));
+        let y = quarter.rotate(&Vector::new([1.0, 0.0, 0.0]));
+        assert_relative_eq!(y[1], 1.0, epsilon = 1e-15);
+    }
+}
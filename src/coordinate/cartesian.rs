@@ -1,6 +1,7 @@
+use super::covariance::transform_covariance;
 use super::cylindrical::Cylindrical;
 use super::spherical::Spherical;
-use crate::math::Vector;
+use crate::math::{Matrix, Vector};
 use num_traits::Float;
 use core::marker::PhantomData; // Reference frame tracking.
 
@@ -17,6 +18,50 @@ impl<T: Float, ReferenceFrame> Cartesian<T, ReferenceFrame>{
     pub fn x(&self) -> T{ self.data.data[0] }
     pub fn y(&self) -> T{ self.data.data[1] }
     pub fn z(&self) -> T{ self.data.data[2] }
+
+    pub fn zero() -> Self {
+        Self::new(T::zero(), T::zero(), T::zero())
+    }
+    pub fn from_vector(data: Vector<T, 3>) -> Self {
+        Self { data, _reference_frame: PhantomData }
+    }
+}
+
+// Geometry, only between points expressed in the same frame
+impl<T: Float, ReferenceFrame> Cartesian<T, ReferenceFrame> {
+    pub fn dot(&self, rhs: &Self) -> T {
+        self.data.dot(&rhs.data)
+    }
+    pub fn cross(&self, rhs: &Self) -> Self {
+        Self::from_vector(self.data.cross(rhs.data))
+    }
+    pub fn norm(&self) -> T {
+        self.data.norm()
+    }
+    /// Euclidean distance between the two points.
+    pub fn distance_to(&self, rhs: &Self) -> T {
+        (self.data - rhs.data).norm()
+    }
+    /// Unit vector in the direction of `self` (non-finite for the zero vector).
+    pub fn unit(&self) -> Self {
+        Self::from_vector(self.data / self.norm())
+    }
+    /// Component of `self` parallel to `rhs` (zero if `rhs` is the zero vector).
+    pub fn project_onto(&self, rhs: &Self) -> Self {
+        let denom = rhs.dot(rhs);
+        if denom == T::zero() {
+            return Self::zero();
+        }
+        Self::from_vector(rhs.data * (self.dot(rhs) / denom))
+    }
+    /// Component of `self` perpendicular to `rhs`.
+    pub fn reject_from(&self, rhs: &Self) -> Self {
+        Self::from_vector(self.data - self.project_onto(rhs).data)
+    }
+    /// Angle between the two vectors in `[0, pi]` (zero if either is the zero vector).
+    pub fn angle_between(&self, rhs: &Self) -> T {
+        self.data.angle(&rhs.data)
+    }
 }
 
 
@@ -172,25 +217,76 @@ where
 }
 
 // Conversions
-impl<T: Float, ReferenceFrame> From<&Spherical<T>> for Cartesian<T, ReferenceFrame> {
-    fn from(p: &Spherical<T>) -> Self {
+impl<T: Float, ReferenceFrame> From<&Spherical<T, ReferenceFrame>> for Cartesian<T, ReferenceFrame> {
+    fn from(p: &Spherical<T, ReferenceFrame>) -> Self {
         let r = p.r();
-        let phi = p.phi();     // azimuth
-        let theta = p.theta(); // inclination
-        let sin_theta = theta.sin();
-        let x = r * sin_theta * phi.cos();
-        let y = r * sin_theta * phi.sin();
-        let z = r * theta.cos();
+        let (sin_az, cos_az) = p.azimuth().sin_cos();
+        let (sin_inc, cos_inc) = p.inclination().sin_cos();
+        let x = r * sin_inc * cos_az;
+        let y = r * sin_inc * sin_az;
+        let z = r * cos_inc;
         Cartesian::new(x, y, z)
     }
 }
 
-impl<T: Float, ReferenceFrame> From<&Cylindrical<T>> for Cartesian<T, ReferenceFrame>{
-    fn from(c: &Cylindrical<T>) -> Self {
-        let x = c.r()*c.theta().cos();
-        let y = c.r()*c.theta().sin();
+impl<T: Float, ReferenceFrame> From<&Cylindrical<T, ReferenceFrame>> for Cartesian<T, ReferenceFrame>{
+    fn from(c: &Cylindrical<T, ReferenceFrame>) -> Self {
+        let x = c.r()*c.azimuth().cos();
+        let y = c.r()*c.azimuth().sin();
         let z = c.z(); 
         Cartesian::new(x, y, z)
     }
 }
 
+// Jacobians and rates
+impl<T: Float + Default, RF> Cartesian<T, RF> {
+    /// Jacobian of spherical `[r, azimuth, inclination]` with respect to `[x, y, z]`.
+    /// Not defined on the z-axis.
+    pub fn jacobian_to_spherical(&self) -> Matrix<T, 3, 3> {
+        let (x, y, z) = (self.x(), self.y(), self.z());
+        let s2 = x * x + y * y;
+        let s = s2.sqrt();
+        let rho2 = s2 + z * z;
+        let rho = rho2.sqrt();
+        Matrix::new([
+            [x / rho, y / rho, z / rho],
+            [-y / s2, x / s2, T::zero()],
+            [x * z / (rho2 * s), y * z / (rho2 * s), -s / rho2],
+        ])
+    }
+
+    /// Jacobian of cylindrical `[r, azimuth, z]` with respect to `[x, y, z]`.
+    /// Not defined on the z-axis.
+    pub fn jacobian_to_cylindrical(&self) -> Matrix<T, 3, 3> {
+        let (x, y) = (self.x(), self.y());
+        let s2 = x * x + y * y;
+        let s = s2.sqrt();
+        Matrix::new([
+            [x / s, y / s, T::zero()],
+            [-y / s2, x / s2, T::zero()],
+            [T::zero(), T::zero(), T::one()],
+        ])
+    }
+
+    /// Spherical rates `[r_dot, azimuth_dot, inclination_dot]` of a point at `self`
+    /// moving with `velocity`.
+    pub fn velocity_to_spherical(&self, velocity: &Cartesian<T, RF>) -> Vector<T, 3> {
+        self.jacobian_to_spherical() * velocity.data
+    }
+
+    /// Cylindrical rates `[r_dot, azimuth_dot, z_dot]` of a point at `self`
+    /// moving with `velocity`.
+    pub fn velocity_to_cylindrical(&self, velocity: &Cartesian<T, RF>) -> Vector<T, 3> {
+        self.jacobian_to_cylindrical() * velocity.data
+    }
+
+    /// Spherical covariance from the covariance of `[x, y, z]`.
+    pub fn covariance_to_spherical(&self, covariance: &Matrix<T, 3, 3>) -> Matrix<T, 3, 3> {
+        transform_covariance(&self.jacobian_to_spherical(), covariance)
+    }
+
+    /// Cylindrical covariance from the covariance of `[x, y, z]`.
+    pub fn covariance_to_cylindrical(&self, covariance: &Matrix<T, 3, 3>) -> Matrix<T, 3, 3> {
+        transform_covariance(&self.jacobian_to_cylindrical(), covariance)
+    }
+}
@@ -0,0 +1,102 @@
+//! Propagation of covariance through coordinate conversions.
+//!
+//! [`transform_covariance`] applies the first-order (Jacobian) propagation
+//! `J P J^T` used by the `covariance_to_*` methods on the coordinate types.
+//! [`UnscentedTransform`] propagates a mean and covariance through the exact
+//! nonlinear conversion instead, which holds up better for large angular
+//! uncertainties at short range.
+
+use num_traits::Float;
+
+use crate::math::{Matrix, Vector};
+
+/// First-order covariance propagation `J P J^T`.
+pub fn transform_covariance<T, const M: usize, const N: usize>(
+    jacobian: &Matrix<T, M, N>,
+    covariance: &Matrix<T, N, N>,
+) -> Matrix<T, M, M>
+where
+    T: Float + Default,
+{
+    *jacobian * *covariance * jacobian.transpose()
+}
+
+/// Scaled unscented transform (Wan & van der Merwe, 2000).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnscentedTransform<T: Float> {
+    /// Spread of the sigma points around the mean
+    pub alpha: T,
+    /// Prior knowledge of the distribution (2 is optimal for Gaussians)
+    pub beta: T,
+    /// Secondary scaling parameter
+    pub kappa: T,
+}
+
+impl<T: Float> Default for UnscentedTransform<T> {
+    fn default() -> Self {
+        Self::new(T::one(), T::from(2.0).unwrap(), T::zero())
+    }
+}
+
+impl<T: Float> UnscentedTransform<T> {
+    pub fn new(alpha: T, beta: T, kappa: T) -> Self {
+        Self { alpha, beta, kappa }
+    }
+
+    /// Propagates `mean` and `covariance` through `f`, returning the transformed
+    /// mean and covariance. Returns `None` if `covariance` is not positive definite.
+    pub fn propagate<F, const N: usize, const M: usize>(
+        &self,
+        mean: &Vector<T, N>,
+        covariance: &Matrix<T, N, N>,
+        f: F,
+    ) -> Option<(Vector<T, M>, Matrix<T, M, M>)>
+    where
+        T: Default,
+        F: Fn(&Vector<T, N>) -> Vector<T, M>,
+    {
+        let one = T::one();
+        let two = one + one;
+        let n = T::from(N).unwrap();
+        let lambda = self.alpha * self.alpha * (n + self.kappa) - n;
+        let sqrt_cov = (*covariance * (n + lambda)).cholesky()?;
+
+        let w_mean0 = lambda / (n + lambda);
+        let w_cov0 = w_mean0 + (one - self.alpha * self.alpha + self.beta);
+        let w = one / (two * (n + lambda));
+
+        // Sigma points mean +/- the columns of the scaled square root
+        let center = f(mean);
+        let mut plus = [Vector::<T, M>::default(); N];
+        let mut minus = [Vector::<T, M>::default(); N];
+        for i in 0..N {
+            let mut offset = Vector::<T, N>::default();
+            for r in 0..N {
+                offset[r] = sqrt_cov[(r, i)];
+            }
+            plus[i] = f(&(*mean + offset));
+            minus[i] = f(&(*mean - offset));
+        }
+
+        let mut y_mean = center * w_mean0;
+        for i in 0..N {
+            y_mean = y_mean + (plus[i] + minus[i]) * w;
+        }
+
+        let mut y_cov = outer(&(center - y_mean)) * w_cov0;
+        for i in 0..N {
+            y_cov = y_cov + (outer(&(plus[i] - y_mean)) + outer(&(minus[i] - y_mean))) * w;
+        }
+        Some((y_mean, y_cov))
+    }
+}
+
+fn outer<T: Float + Default, const M: usize>(v: &Vector<T, M>) -> Matrix<T, M, M> {
+    let mut out = Matrix::<T, M, M>::default();
+    for r in 0..M {
+        for c in 0..M {
+            out[(r, c)] = v[r] * v[c];
+        }
+    }
+    out
+}
@@ -1,39 +1,93 @@
 use num_traits::Float;
+use core::marker::PhantomData;
 
 use super::spherical::Spherical;
 use super::cartesian::Cartesian;
-use crate::math::Vector;
-use crate::reference_frame::ReferenceFrame;
+use super::covariance::transform_covariance;
+use crate::math::{Matrix, Vector};
 
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct Cylindrical<T: Float> {
-    pub data: Vector<T, 3>, // [r, theta, z]
+/// Cylindrical coordinates `[r, azimuth, z]` in a reference frame.
+///
+/// `r` is the distance from the z-axis and the azimuth is measured in the
+/// xy-plane from +x towards +y, in radians.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Cylindrical<T: Float, ReferenceFrame> {
+    pub data: Vector<T, 3>, // [r, azimuth, z]
+    pub _reference_frame: PhantomData<ReferenceFrame>
 }
-impl<T: Float> Cylindrical<T> {
-    pub fn new(r: T, theta: T, z: T) -> Self {
-        Self { data: Vector { data: [r, theta, z] } }
+impl<T: Float, ReferenceFrame> Cylindrical<T, ReferenceFrame> {
+    pub fn new(r: T, azimuth: T, z: T) -> Self {
+        Self { data: Vector { data: [r, azimuth, z] }, _reference_frame: PhantomData }
     }
+    /// Distance from the z-axis.
     pub fn r(&self) -> T { self.data.data[0] }
-    pub fn theta(&self) -> T { self.data.data[1] }
+    /// Angle in the xy-plane from +x towards +y.
+    pub fn azimuth(&self) -> T { self.data.data[1] }
     pub fn z(&self) -> T { self.data.data[2] }
 }
 
-impl<T:Float, RF: ReferenceFrame> From<&Cartesian<T, RF>> for Cylindrical<T> {
+impl<T: Float, RF> From<&Cartesian<T, RF>> for Cylindrical<T, RF> {
     fn from(cart: &Cartesian<T, RF>) -> Self {
         let x = cart.x();
         let y = cart.y();
         let z = cart.z();
         let r = (x * x + y * y).sqrt();
-        let theta = y.atan2(x);
-        Cylindrical::new(r, theta, z)
+        let azimuth = y.atan2(x);
+        Cylindrical::new(r, azimuth, z)
     }
 }
 
-impl<T:Float> From<&Spherical<T>> for Cylindrical<T>{
-    fn from(s: &Spherical<T>) -> Self{
-        let r = s.r() * s.theta().sin();
-        let z = s.r() * s.theta().cos();
-        let theta = s.phi();
-        return Cylindrical::new(r, theta, z)
+impl<T: Float, RF> From<&Spherical<T, RF>> for Cylindrical<T, RF> {
+    fn from(s: &Spherical<T, RF>) -> Self {
+        let r = s.r() * s.inclination().sin();
+        let z = s.r() * s.inclination().cos();
+        Cylindrical::new(r, s.azimuth(), z)
     }
-}
\ No newline at end of file
+}
+
+// Jacobians and rates
+impl<T: Float + Default, RF> Cylindrical<T, RF> {
+    /// Jacobian of `[x, y, z]` with respect to `[r, azimuth, z]`.
+    pub fn jacobian_to_cartesian(&self) -> Matrix<T, 3, 3> {
+        let r = self.r();
+        let (sin_az, cos_az) = self.azimuth().sin_cos();
+        Matrix::new([
+            [cos_az, -r * sin_az, T::zero()],
+            [sin_az, r * cos_az, T::zero()],
+            [T::zero(), T::zero(), T::one()],
+        ])
+    }
+
+    /// Jacobian of spherical `[r, azimuth, inclination]` with respect to `[r, azimuth, z]`.
+    /// Not defined at the origin.
+    pub fn jacobian_to_spherical(&self) -> Matrix<T, 3, 3> {
+        let (r, z) = (self.r(), self.z());
+        let rho2 = r * r + z * z;
+        let rho = rho2.sqrt();
+        Matrix::new([
+            [r / rho, T::zero(), z / rho],
+            [T::zero(), T::one(), T::zero()],
+            [z / rho2, T::zero(), -r / rho2],
+        ])
+    }
+
+    /// Cartesian velocity from the cylindrical rates `[r_dot, azimuth_dot, z_dot]`.
+    pub fn velocity_to_cartesian(&self, rates: &Vector<T, 3>) -> Cartesian<T, RF> {
+        Cartesian::from_vector(self.jacobian_to_cartesian() * *rates)
+    }
+
+    /// Spherical rates `[r_dot, azimuth_dot, inclination_dot]` from the cylindrical rates.
+    pub fn velocity_to_spherical(&self, rates: &Vector<T, 3>) -> Vector<T, 3> {
+        self.jacobian_to_spherical() * *rates
+    }
+
+    /// Cartesian covariance from the covariance of `[r, azimuth, z]`.
+    pub fn covariance_to_cartesian(&self, covariance: &Matrix<T, 3, 3>) -> Matrix<T, 3, 3> {
+        transform_covariance(&self.jacobian_to_cartesian(), covariance)
+    }
+
+    /// Spherical covariance from the covariance of `[r, azimuth, z]`.
+    pub fn covariance_to_spherical(&self, covariance: &Matrix<T, 3, 3>) -> Matrix<T, 3, 3> {
+        transform_covariance(&self.jacobian_to_spherical(), covariance)
+    }
+}
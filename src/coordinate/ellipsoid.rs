@@ -0,0 +1,50 @@
+use num_traits::Float;
+
+/// Reference ellipsoid of revolution, described by its equatorial radius `a`
+/// and flattening `f`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ellipsoid<T: Float> {
+    a: T,
+    f: T,
+}
+
+impl<T: Float> Ellipsoid<T> {
+    pub fn new(a: T, f: T) -> Self {
+        Self { a, f }
+    }
+
+    /// WGS-84 ellipsoid (meters).
+    pub fn wgs84() -> Self {
+        Self::new(
+            T::from(6_378_137.0).unwrap(),
+            T::one() / T::from(298.257_223_563).unwrap(),
+        )
+    }
+
+    /// GRS-80 ellipsoid (meters).
+    pub fn grs80() -> Self {
+        Self::new(
+            T::from(6_378_137.0).unwrap(),
+            T::one() / T::from(298.257_222_101).unwrap(),
+        )
+    }
+
+    /// A sphere of radius `r`, i.e. an ellipsoid with zero flattening.
+    pub fn sphere(r: T) -> Self {
+        Self::new(r, T::zero())
+    }
+
+    /// Equatorial (semi-major) radius.
+    pub fn a(&self) -> T { self.a }
+    /// Flattening.
+    pub fn f(&self) -> T { self.f }
+    /// Polar (semi-minor) radius.
+    pub fn b(&self) -> T { self.a * (T::one() - self.f) }
+    /// First eccentricity squared.
+    pub fn e2(&self) -> T { self.f * (T::from(2.0).unwrap() - self.f) }
+    /// Second eccentricity squared.
+    pub fn ep2(&self) -> T {
+        let e2 = self.e2();
+        e2 / (T::one() - e2)
+    }
+}
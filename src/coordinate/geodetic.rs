@@ -0,0 +1,114 @@
+use num_traits::Float;
+
+use super::cartesian::Cartesian;
+use super::ellipsoid::Ellipsoid;
+use super::covariance::transform_covariance;
+use crate::math::{Matrix, Vector};
+
+const MAX_ITERATIONS: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Geodetic<T: Float> {
+    /// latitude: geodetic latitude (radians)
+    /// longitude: longitude (radians, east positive)
+    /// height: height above the ellipsoid
+    pub data: Vector<T, 3>, // [latitude, longitude, height]
+}
+
+impl<T: Float> Geodetic<T> {
+    pub fn new(latitude: T, longitude: T, height: T) -> Self {
+        Self { data: Vector { data: [latitude, longitude, height] } }
+    }
+    pub fn latitude(&self) -> T { self.data.data[0] }
+    pub fn longitude(&self) -> T { self.data.data[1] }
+    pub fn height(&self) -> T { self.data.data[2] }
+
+    /// Body-fixed Cartesian position on `ellipsoid`.
+    pub fn to_cartesian<RF>(&self, ellipsoid: &Ellipsoid<T>) -> Cartesian<T, RF> {
+        let (sin_lat, cos_lat) = self.latitude().sin_cos();
+        let (sin_lon, cos_lon) = self.longitude().sin_cos();
+        let e2 = ellipsoid.e2();
+        let n = ellipsoid.a() / (T::one() - e2 * sin_lat * sin_lat).sqrt();
+        let h = self.height();
+        Cartesian::new(
+            (n + h) * cos_lat * cos_lon,
+            (n + h) * cos_lat * sin_lon,
+            (n * (T::one() - e2) + h) * sin_lat,
+        )
+    }
+
+    /// Geodetic coordinates of a body-fixed Cartesian position on `ellipsoid`.
+    pub fn from_cartesian<RF>(cart: &Cartesian<T, RF>, ellipsoid: &Ellipsoid<T>) -> Self {
+        let (x, y, z) = (cart.x(), cart.y(), cart.z());
+        let a = ellipsoid.a();
+        let e2 = ellipsoid.e2();
+        let p = (x * x + y * y).sqrt();
+        let tol = T::epsilon() * T::from(4.0).unwrap();
+
+        // Fixed-point iteration on latitude, h recovered without dividing by cos(lat)
+        let mut lat = z.atan2(p * (T::one() - e2));
+        for _ in 0..MAX_ITERATIONS {
+            let sin_lat = lat.sin();
+            let n = a / (T::one() - e2 * sin_lat * sin_lat).sqrt();
+            let next = (z + e2 * n * sin_lat).atan2(p);
+            let done = (next - lat).abs() <= tol;
+            lat = next;
+            if done {
+                break;
+            }
+        }
+        let (sin_lat, cos_lat) = lat.sin_cos();
+        let height = p * cos_lat + z * sin_lat - a * (T::one() - e2 * sin_lat * sin_lat).sqrt();
+        let longitude = if p == T::zero() { T::zero() } else { y.atan2(x) };
+        Self::new(lat, longitude, height)
+    }
+}
+
+// Jacobians and covariance
+impl<T: Float + Default> Geodetic<T> {
+    /// Jacobian of body-fixed `[x, y, z]` with respect to `[latitude, longitude, height]`.
+    pub fn jacobian_to_cartesian(&self, ellipsoid: &Ellipsoid<T>) -> Matrix<T, 3, 3> {
+        let (sin_lat, cos_lat) = self.latitude().sin_cos();
+        let (sin_lon, cos_lon) = self.longitude().sin_cos();
+        let (m, n) = radii_of_curvature(ellipsoid, sin_lat);
+        let (mh, nh) = (m + self.height(), n + self.height());
+        Matrix::new([
+            [-mh * sin_lat * cos_lon, -nh * cos_lat * sin_lon, cos_lat * cos_lon],
+            [-mh * sin_lat * sin_lon, nh * cos_lat * cos_lon, cos_lat * sin_lon],
+            [mh * cos_lat, T::zero(), sin_lat],
+        ])
+    }
+
+    /// Jacobian of `[latitude, longitude, height]` with respect to body-fixed
+    /// `[x, y, z]`, evaluated at this point. Not defined at the poles.
+    pub fn jacobian_from_cartesian(&self, ellipsoid: &Ellipsoid<T>) -> Matrix<T, 3, 3> {
+        let (sin_lat, cos_lat) = self.latitude().sin_cos();
+        let (sin_lon, cos_lon) = self.longitude().sin_cos();
+        let (m, n) = radii_of_curvature(ellipsoid, sin_lat);
+        let (mh, nh) = (m + self.height(), n + self.height());
+        // The columns of the forward Jacobian are orthogonal
+        Matrix::new([
+            [-sin_lat * cos_lon / mh, -sin_lat * sin_lon / mh, cos_lat / mh],
+            [-sin_lon / (nh * cos_lat), cos_lon / (nh * cos_lat), T::zero()],
+            [cos_lat * cos_lon, cos_lat * sin_lon, sin_lat],
+        ])
+    }
+
+    /// Body-fixed Cartesian covariance from the covariance of `[latitude, longitude, height]`.
+    pub fn covariance_to_cartesian(&self, ellipsoid: &Ellipsoid<T>, covariance: &Matrix<T, 3, 3>) -> Matrix<T, 3, 3> {
+        transform_covariance(&self.jacobian_to_cartesian(ellipsoid), covariance)
+    }
+
+    /// Covariance of `[latitude, longitude, height]` from a body-fixed Cartesian covariance.
+    pub fn covariance_from_cartesian(&self, ellipsoid: &Ellipsoid<T>, covariance: &Matrix<T, 3, 3>) -> Matrix<T, 3, 3> {
+        transform_covariance(&self.jacobian_from_cartesian(ellipsoid), covariance)
+    }
+}
+
+/// Meridional and prime-vertical radii of curvature `(M, N)`.
+fn radii_of_curvature<T: Float>(ellipsoid: &Ellipsoid<T>, sin_lat: T) -> (T, T) {
+    let e2 = ellipsoid.e2();
+    let w2 = T::one() - e2 * sin_lat * sin_lat;
+    let n = ellipsoid.a() / w2.sqrt();
+    (n * (T::one() - e2) / w2, n)
+}
@@ -1,9 +1,18 @@
 pub mod cartesian;
 pub mod spherical;
 pub mod cylindrical;
+pub mod covariance;
+pub mod ellipsoid;
+pub mod geodesic;
+pub mod geodetic;
+pub mod projection;
 pub use cartesian::Cartesian;
 pub use cylindrical::Cylindrical;
 pub use spherical::Spherical;
+pub use ellipsoid::Ellipsoid;
+pub use geodesic::Geodesic;
+pub use geodetic::Geodetic;
+pub use covariance::{transform_covariance, UnscentedTransform};
 
 pub mod coordinate{
     use num_traits::Float;
@@ -11,8 +20,8 @@ pub mod coordinate{
 
     pub enum Coordinate<T: Float, F: ReferenceFrame>{
         CartesianValue(Cartesian<T, F>),
-        CylindricalValue(Cylindrical<T>),
-        SphericalValue(Spherical<T>)
+        CylindricalValue(Cylindrical<T, F>),
+        SphericalValue(Spherical<T, F>)
     }
 }
 
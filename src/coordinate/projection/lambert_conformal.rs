@@ -0,0 +1,118 @@
+//! Ellipsoidal Lambert conformal conic projection with one or two standard
+//! parallels (Snyder, 1987, §15).
+
+use num_traits::Float;
+
+use super::{conformal_t, conformal_t_inverse, parallel_m, ProjectedPoint, ProjectionError};
+use crate::coordinate::Ellipsoid;
+use crate::utils::wrap_pi;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LambertConformalConic<T: Float> {
+    ellipsoid: Ellipsoid<T>,
+    central_meridian: T,
+    false_easting: T,
+    false_northing: T,
+    /// Cone constant
+    n: T,
+    /// `a F k0`
+    big_f: T,
+    /// Radius of the latitude of origin
+    rho0: T,
+}
+
+impl<T: Float> LambertConformalConic<T> {
+    /// Two standard parallels (2SP).
+    pub fn new(
+        ellipsoid: Ellipsoid<T>,
+        central_meridian: T,
+        latitude_of_origin: T,
+        standard_parallel_1: T,
+        standard_parallel_2: T,
+        false_easting: T,
+        false_northing: T,
+    ) -> Self {
+        let e = ellipsoid.e2().sqrt();
+        let m1 = parallel_m(e, standard_parallel_1);
+        let m2 = parallel_m(e, standard_parallel_2);
+        let t1 = conformal_t(e, standard_parallel_1);
+        let t2 = conformal_t(e, standard_parallel_2);
+        let n = if (standard_parallel_1 - standard_parallel_2).abs() > T::epsilon() {
+            (m1.ln() - m2.ln()) / (t1.ln() - t2.ln())
+        } else {
+            standard_parallel_1.sin()
+        };
+        Self::from_cone(ellipsoid, central_meridian, latitude_of_origin, n, m1 / (n * t1.powf(n)), false_easting, false_northing)
+    }
+
+    /// One standard parallel (1SP) with scale factor `scale` along it.
+    pub fn with_scale(
+        ellipsoid: Ellipsoid<T>,
+        central_meridian: T,
+        latitude_of_origin: T,
+        scale: T,
+        false_easting: T,
+        false_northing: T,
+    ) -> Self {
+        let e = ellipsoid.e2().sqrt();
+        let n = latitude_of_origin.sin();
+        let m0 = parallel_m(e, latitude_of_origin);
+        let t0 = conformal_t(e, latitude_of_origin);
+        Self::from_cone(ellipsoid, central_meridian, latitude_of_origin, n, scale * m0 / (n * t0.powf(n)), false_easting, false_northing)
+    }
+
+    fn from_cone(
+        ellipsoid: Ellipsoid<T>,
+        central_meridian: T,
+        latitude_of_origin: T,
+        n: T,
+        f: T,
+        false_easting: T,
+        false_northing: T,
+    ) -> Self {
+        let e = ellipsoid.e2().sqrt();
+        let big_f = ellipsoid.a() * f;
+        let rho0 = big_f * conformal_t(e, latitude_of_origin).powf(n);
+        Self { ellipsoid, central_meridian, false_easting, false_northing, n, big_f, rho0 }
+    }
+
+    pub fn central_meridian(&self) -> T { self.central_meridian }
+    /// Cone constant `n`.
+    pub fn cone_constant(&self) -> T { self.n }
+
+    pub fn forward(&self, latitude: T, longitude: T) -> Result<ProjectedPoint<T>, ProjectionError> {
+        let half_pi = T::from(core::f64::consts::FRAC_PI_2).unwrap();
+        // The pole opposite the cone apex maps to infinity
+        if latitude * self.n.signum() <= -half_pi {
+            return Err(ProjectionError::Singular);
+        }
+        let e = self.ellipsoid.e2().sqrt();
+        let rho = self.big_f * conformal_t(e, latitude).powf(self.n);
+        let theta = self.n * wrap_pi(longitude - self.central_meridian);
+        let m = parallel_m(e, latitude);
+        let scale = if m > T::epsilon() {
+            rho * self.n / (self.ellipsoid.a() * m)
+        } else {
+            T::zero()
+        };
+        Ok(ProjectedPoint {
+            easting: self.false_easting + rho * theta.sin(),
+            northing: self.false_northing + self.rho0 - rho * theta.cos(),
+            convergence: theta,
+            scale,
+        })
+    }
+
+    /// Returns `(latitude, longitude)` of a projected point.
+    pub fn inverse(&self, easting: T, northing: T) -> (T, T) {
+        let e = self.ellipsoid.e2().sqrt();
+        let sign = self.n.signum();
+        let dx = easting - self.false_easting;
+        let dy = self.rho0 - (northing - self.false_northing);
+        let rho = sign * (dx * dx + dy * dy).sqrt();
+        let theta = (sign * dx).atan2(sign * dy);
+        let t = (rho / self.big_f).powf(T::one() / self.n);
+        let latitude = conformal_t_inverse(e, t);
+        (latitude, wrap_pi(theta / self.n + self.central_meridian))
+    }
+}
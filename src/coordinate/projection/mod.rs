@@ -0,0 +1,70 @@
+//! Conformal map projections of the ellipsoid.
+//!
+//! All projections take geodetic latitude/longitude in radians and produce
+//! easting/northing in the units of the ellipsoid, together with the grid
+//! convergence (angle from true north to grid north, radians) and point scale.
+
+pub mod transverse_mercator;
+pub mod utm;
+pub mod lambert_conformal;
+pub mod polar_stereographic;
+pub use transverse_mercator::TransverseMercator;
+pub use utm::{Hemisphere, Ups, UpsCoordinate, Utm, UtmCoordinate};
+pub use lambert_conformal::LambertConformalConic;
+pub use polar_stereographic::PolarStereographic;
+
+use num_traits::Float;
+
+const MAX_ITERATIONS: usize = 20;
+
+/// Easting/northing of a projected point with its local distortion.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProjectedPoint<T: Float> {
+    pub easting: T,
+    pub northing: T,
+    /// Meridian convergence (radians)
+    pub convergence: T,
+    /// Point scale factor
+    pub scale: T,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProjectionError {
+    /// Latitude outside the domain of the projection or grid system
+    LatitudeOutOfRange,
+    /// UTM zone outside `1..=60`
+    InvalidZone,
+    /// Point cannot be represented (e.g. the opposite pole of a polar projection)
+    Singular,
+}
+
+/// Isometric-latitude function `t` used by the conformal projections (Snyder 15-9).
+pub(crate) fn conformal_t<T: Float>(e: T, lat: T) -> T {
+    let two = T::one() + T::one();
+    let quarter_pi = T::from(core::f64::consts::FRAC_PI_4).unwrap();
+    let es = e * lat.sin();
+    (quarter_pi - lat / two).tan() / ((T::one() - es) / (T::one() + es)).powf(e / two)
+}
+
+/// Inverse of [`conformal_t`] by fixed-point iteration (Snyder 7-9).
+pub(crate) fn conformal_t_inverse<T: Float>(e: T, t: T) -> T {
+    let two = T::one() + T::one();
+    let half_pi = T::from(core::f64::consts::FRAC_PI_2).unwrap();
+    let mut lat = half_pi - two * t.atan();
+    for _ in 0..MAX_ITERATIONS {
+        let es = e * lat.sin();
+        let next = half_pi - two * (t * ((T::one() - es) / (T::one() + es)).powf(e / two)).atan();
+        let done = (next - lat).abs() <= T::epsilon() * two;
+        lat = next;
+        if done {
+            break;
+        }
+    }
+    lat
+}
+
+/// Radius-of-parallel ratio `m = cos(lat) / sqrt(1 - e^2 sin^2(lat))`.
+pub(crate) fn parallel_m<T: Float>(e: T, lat: T) -> T {
+    let es = e * lat.sin();
+    lat.cos() / (T::one() - es * es).sqrt()
+}
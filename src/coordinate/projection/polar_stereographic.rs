@@ -0,0 +1,114 @@
+//! Ellipsoidal polar stereographic projection (Snyder, 1987, §21).
+
+use num_traits::Float;
+
+use super::{conformal_t, conformal_t_inverse, parallel_m, ProjectedPoint, ProjectionError};
+use super::utm::Hemisphere;
+use crate::coordinate::Ellipsoid;
+use crate::utils::wrap_pi;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PolarStereographic<T: Float> {
+    ellipsoid: Ellipsoid<T>,
+    hemisphere: Hemisphere,
+    central_meridian: T,
+    scale: T,
+    false_easting: T,
+    false_northing: T,
+}
+
+impl<T: Float> PolarStereographic<T> {
+    /// Polar stereographic with scale factor `scale` at the pole.
+    pub fn new(
+        ellipsoid: Ellipsoid<T>,
+        hemisphere: Hemisphere,
+        central_meridian: T,
+        scale: T,
+        false_easting: T,
+        false_northing: T,
+    ) -> Self {
+        Self { ellipsoid, hemisphere, central_meridian, scale, false_easting, false_northing }
+    }
+
+    /// Polar stereographic that is true to scale along `standard_parallel`
+    /// (the latitude's sign is ignored; the hemisphere selects the pole).
+    pub fn with_standard_parallel(
+        ellipsoid: Ellipsoid<T>,
+        hemisphere: Hemisphere,
+        central_meridian: T,
+        standard_parallel: T,
+        false_easting: T,
+        false_northing: T,
+    ) -> Self {
+        let e = ellipsoid.e2().sqrt();
+        let lat_c = standard_parallel.abs();
+        let two = T::one() + T::one();
+        let scale = parallel_m(e, lat_c) / (two * conformal_t(e, lat_c)) * pole_factor(e);
+        Self::new(ellipsoid, hemisphere, central_meridian, scale, false_easting, false_northing)
+    }
+
+    pub fn hemisphere(&self) -> Hemisphere { self.hemisphere }
+    pub fn central_meridian(&self) -> T { self.central_meridian }
+    pub fn scale(&self) -> T { self.scale }
+
+    pub fn forward(&self, latitude: T, longitude: T) -> Result<ProjectedPoint<T>, ProjectionError> {
+        let two = T::one() + T::one();
+        let half_pi = T::from(core::f64::consts::FRAC_PI_2).unwrap();
+        let sign = self.sign();
+        // Work in the north-polar aspect
+        let lat = latitude * sign;
+        if lat <= -half_pi {
+            return Err(ProjectionError::Singular);
+        }
+        let e = self.ellipsoid.e2().sqrt();
+        let a = self.ellipsoid.a();
+        let t = conformal_t(e, lat);
+        let rho = two * a * self.scale * t / pole_factor(e);
+        let dlon = wrap_pi(longitude - self.central_meridian);
+        let (sin_l, cos_l) = dlon.sin_cos();
+
+        let m = parallel_m(e, lat);
+        let scale = if m > T::epsilon() {
+            rho / (a * m)
+        } else {
+            self.scale
+        };
+        Ok(ProjectedPoint {
+            easting: self.false_easting + rho * sin_l,
+            northing: self.false_northing - sign * rho * cos_l,
+            convergence: sign * dlon,
+            scale,
+        })
+    }
+
+    /// Returns `(latitude, longitude)` of a projected point.
+    pub fn inverse(&self, easting: T, northing: T) -> (T, T) {
+        let two = T::one() + T::one();
+        let sign = self.sign();
+        let e = self.ellipsoid.e2().sqrt();
+        let dx = easting - self.false_easting;
+        let dy = northing - self.false_northing;
+        let rho = (dx * dx + dy * dy).sqrt();
+        let t = rho * pole_factor(e) / (two * self.ellipsoid.a() * self.scale);
+        let lat = conformal_t_inverse(e, t);
+        let longitude = if rho == T::zero() {
+            self.central_meridian
+        } else {
+            wrap_pi(self.central_meridian + dx.atan2(-sign * dy))
+        };
+        (sign * lat, longitude)
+    }
+
+    fn sign(&self) -> T {
+        match self.hemisphere {
+            Hemisphere::North => T::one(),
+            Hemisphere::South => -T::one(),
+        }
+    }
+}
+
+/// `sqrt((1 + e)^(1 + e) (1 - e)^(1 - e))`
+fn pole_factor<T: Float>(e: T) -> T {
+    let one = T::one();
+    ((one + e).powf(one + e) * (one - e).powf(one - e)).sqrt()
+}
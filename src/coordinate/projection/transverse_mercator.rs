@@ -0,0 +1,197 @@
+//! Transverse Mercator projection using Krüger's series to sixth order in the
+//! third flattening (Karney, 2011), accurate to a few nanometers within
+//! ~3900 km of the central meridian.
+
+use num_traits::Float;
+
+use super::ProjectedPoint;
+use crate::coordinate::Ellipsoid;
+use crate::utils::wrap_pi;
+
+const ORDER: usize = 6;
+const MAX_ITERATIONS: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TransverseMercator<T: Float> {
+    ellipsoid: Ellipsoid<T>,
+    central_meridian: T,
+    latitude_of_origin: T,
+    scale: T,
+    false_easting: T,
+    false_northing: T,
+    /// Rectifying radius `A`
+    radius: T,
+    alpha: [T; ORDER],
+    beta: [T; ORDER],
+    /// Northing of the latitude of origin on the central meridian (unscaled)
+    origin_northing: T,
+}
+
+impl<T: Float> TransverseMercator<T> {
+    pub fn new(
+        ellipsoid: Ellipsoid<T>,
+        central_meridian: T,
+        latitude_of_origin: T,
+        scale: T,
+        false_easting: T,
+        false_northing: T,
+    ) -> Self {
+        let k = |x: f64| T::from(x).unwrap();
+        let f = ellipsoid.f();
+        let n = f / (k(2.0) - f);
+        let n2 = n * n;
+        let n3 = n2 * n;
+        let n4 = n3 * n;
+        let n5 = n4 * n;
+        let n6 = n5 * n;
+
+        let radius = ellipsoid.a() / (T::one() + n) * (T::one() + n2 / k(4.0) + n4 / k(64.0) + n6 / k(256.0));
+        let alpha = [
+            n / k(2.0) - k(2.0) / k(3.0) * n2 + k(5.0) / k(16.0) * n3 + k(41.0) / k(180.0) * n4
+                - k(127.0) / k(288.0) * n5 + k(7891.0) / k(37800.0) * n6,
+            k(13.0) / k(48.0) * n2 - k(3.0) / k(5.0) * n3 + k(557.0) / k(1440.0) * n4
+                + k(281.0) / k(630.0) * n5 - k(1983433.0) / k(1935360.0) * n6,
+            k(61.0) / k(240.0) * n3 - k(103.0) / k(140.0) * n4 + k(15061.0) / k(26880.0) * n5
+                + k(167603.0) / k(181440.0) * n6,
+            k(49561.0) / k(161280.0) * n4 - k(179.0) / k(168.0) * n5 + k(6601661.0) / k(7257600.0) * n6,
+            k(34729.0) / k(80640.0) * n5 - k(3418889.0) / k(1995840.0) * n6,
+            k(212378941.0) / k(319334400.0) * n6,
+        ];
+        let beta = [
+            n / k(2.0) - k(2.0) / k(3.0) * n2 + k(37.0) / k(96.0) * n3 - n4 / k(360.0)
+                - k(81.0) / k(512.0) * n5 + k(96199.0) / k(604800.0) * n6,
+            n2 / k(48.0) + n3 / k(15.0) - k(437.0) / k(1440.0) * n4 + k(46.0) / k(105.0) * n5
+                - k(1118711.0) / k(3870720.0) * n6,
+            k(17.0) / k(480.0) * n3 - k(37.0) / k(840.0) * n4 - k(209.0) / k(4480.0) * n5
+                + k(5569.0) / k(90720.0) * n6,
+            k(4397.0) / k(161280.0) * n4 - k(11.0) / k(504.0) * n5 - k(830251.0) / k(7257600.0) * n6,
+            k(4583.0) / k(161280.0) * n5 - k(108847.0) / k(3991680.0) * n6,
+            k(20648693.0) / k(638668800.0) * n6,
+        ];
+
+        let mut tm = Self {
+            ellipsoid,
+            central_meridian,
+            latitude_of_origin,
+            scale,
+            false_easting,
+            false_northing,
+            radius,
+            alpha,
+            beta,
+            origin_northing: T::zero(),
+        };
+        let (xi, _) = tm.gauss_krueger(latitude_of_origin, T::zero());
+        tm.origin_northing = radius * xi;
+        tm
+    }
+
+    /// Transverse Mercator with the equator as latitude of origin and no false offsets.
+    pub fn with_central_meridian(ellipsoid: Ellipsoid<T>, central_meridian: T, scale: T) -> Self {
+        Self::new(ellipsoid, central_meridian, T::zero(), scale, T::zero(), T::zero())
+    }
+
+    pub fn central_meridian(&self) -> T { self.central_meridian }
+    pub fn latitude_of_origin(&self) -> T { self.latitude_of_origin }
+    pub fn scale(&self) -> T { self.scale }
+    pub fn false_easting(&self) -> T { self.false_easting }
+    pub fn false_northing(&self) -> T { self.false_northing }
+
+    pub fn forward(&self, latitude: T, longitude: T) -> ProjectedPoint<T> {
+        let one = T::one();
+        let lambda = wrap_pi(longitude - self.central_meridian);
+        let (xi, eta) = self.gauss_krueger(latitude, lambda);
+
+        // Convergence and scale from the derivative of the series
+        let (xi_p, eta_p, tau_p) = self.conformal_sphere(latitude, lambda);
+        let mut p = one;
+        let mut q = T::zero();
+        for (j, a) in self.alpha.iter().enumerate() {
+            let m = T::from(2 * (j + 1)).unwrap();
+            p = p + m * *a * (m * xi_p).cos() * (m * eta_p).cosh();
+            q = q + m * *a * (m * xi_p).sin() * (m * eta_p).sinh();
+        }
+        let (sin_l, cos_l) = lambda.sin_cos();
+        let gamma = (tau_p * sin_l).atan2((one + tau_p * tau_p).sqrt() * cos_l) + q.atan2(p);
+
+        let e2 = self.ellipsoid.e2();
+        let tau = latitude.tan();
+        let sin_lat = latitude.sin();
+        let k_sphere = (one - e2 * sin_lat * sin_lat).sqrt() * (one + tau * tau).sqrt()
+            / (tau_p * tau_p + cos_l * cos_l).sqrt();
+        let k_series = self.radius / self.ellipsoid.a() * (p * p + q * q).sqrt();
+
+        ProjectedPoint {
+            easting: self.false_easting + self.scale * self.radius * eta,
+            northing: self.false_northing + self.scale * (self.radius * xi - self.origin_northing),
+            convergence: gamma,
+            scale: self.scale * k_sphere * k_series,
+        }
+    }
+
+    /// Returns `(latitude, longitude)` of a projected point.
+    pub fn inverse(&self, easting: T, northing: T) -> (T, T) {
+        let one = T::one();
+        let k_a = self.scale * self.radius;
+        let xi = (northing - self.false_northing + self.scale * self.origin_northing) / k_a;
+        let eta = (easting - self.false_easting) / k_a;
+
+        let mut xi_p = xi;
+        let mut eta_p = eta;
+        for (j, b) in self.beta.iter().enumerate() {
+            let m = T::from(2 * (j + 1)).unwrap();
+            xi_p = xi_p - *b * (m * xi).sin() * (m * eta).cosh();
+            eta_p = eta_p - *b * (m * xi).cos() * (m * eta).sinh();
+        }
+        let sinh_eta = eta_p.sinh();
+        let (sin_xi, cos_xi) = xi_p.sin_cos();
+        let tau_p = sin_xi / (sinh_eta * sinh_eta + cos_xi * cos_xi).sqrt();
+
+        let e = self.ellipsoid.e2().sqrt();
+        let e2 = self.ellipsoid.e2();
+        let mut tau = tau_p;
+        for _ in 0..MAX_ITERATIONS {
+            let tau_i_p = conformal_tau(e, tau);
+            let d_tau = (tau_p - tau_i_p) / (one + tau_i_p * tau_i_p).sqrt()
+                * (one + (one - e2) * tau * tau)
+                / ((one - e2) * (one + tau * tau).sqrt());
+            tau = tau + d_tau;
+            if d_tau.abs() <= T::epsilon() * (one + tau.abs()) {
+                break;
+            }
+        }
+        let latitude = tau.atan();
+        let longitude = wrap_pi(self.central_meridian + sinh_eta.atan2(cos_xi));
+        (latitude, longitude)
+    }
+
+    /// Conformal-sphere coordinates `(xi', eta', tau')` of a point.
+    fn conformal_sphere(&self, latitude: T, lambda: T) -> (T, T, T) {
+        let e = self.ellipsoid.e2().sqrt();
+        let tau_p = conformal_tau(e, latitude.tan());
+        let (sin_l, cos_l) = lambda.sin_cos();
+        let xi_p = tau_p.atan2(cos_l);
+        let eta_p = (sin_l / (tau_p * tau_p + cos_l * cos_l).sqrt()).asinh();
+        (xi_p, eta_p, tau_p)
+    }
+
+    /// Normalized Gauss-Krüger coordinates `(xi, eta)`.
+    fn gauss_krueger(&self, latitude: T, lambda: T) -> (T, T) {
+        let (xi_p, eta_p, _) = self.conformal_sphere(latitude, lambda);
+        let mut xi = xi_p;
+        let mut eta = eta_p;
+        for (j, a) in self.alpha.iter().enumerate() {
+            let m = T::from(2 * (j + 1)).unwrap();
+            xi = xi + *a * (m * xi_p).sin() * (m * eta_p).cosh();
+            eta = eta + *a * (m * xi_p).cos() * (m * eta_p).sinh();
+        }
+        (xi, eta)
+    }
+}
+
+/// Tangent of the conformal latitude from the tangent of the geodetic latitude.
+fn conformal_tau<T: Float>(e: T, tau: T) -> T {
+    let one = T::one();
+    let sigma = (e * (e * tau / (one + tau * tau).sqrt()).atanh()).sinh();
+    tau * (one + sigma * sigma).sqrt() - sigma * (one + tau * tau).sqrt()
+}
@@ -0,0 +1,188 @@
+//! Universal Transverse Mercator (UTM) and Universal Polar Stereographic (UPS)
+//! grid systems.
+
+use num_traits::Float;
+
+use super::{PolarStereographic, ProjectedPoint, ProjectionError, TransverseMercator};
+use crate::coordinate::Ellipsoid;
+
+const UTM_SCALE: f64 = 0.9996;
+const UTM_FALSE_EASTING: f64 = 500_000.0;
+const UTM_FALSE_NORTHING_SOUTH: f64 = 10_000_000.0;
+const UPS_SCALE: f64 = 0.994;
+const UPS_FALSE_EASTING_NORTHING: f64 = 2_000_000.0;
+const UTM_MIN_LATITUDE: f64 = -80.0;
+const UTM_MAX_LATITUDE: f64 = 84.0;
+const LATITUDE_BANDS: &[u8; 20] = b"CDEFGHJKLMNPQRSTUVWX";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hemisphere {
+    North,
+    South,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UtmCoordinate<T: Float> {
+    pub zone: u8,
+    pub hemisphere: Hemisphere,
+    pub easting: T,
+    pub northing: T,
+    pub convergence: T,
+    pub scale: T,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UpsCoordinate<T: Float> {
+    pub hemisphere: Hemisphere,
+    pub easting: T,
+    pub northing: T,
+    pub convergence: T,
+    pub scale: T,
+}
+
+/// UTM grid on a reference ellipsoid.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Utm<T: Float> {
+    ellipsoid: Ellipsoid<T>,
+}
+
+impl<T: Float> Utm<T> {
+    pub fn new(ellipsoid: Ellipsoid<T>) -> Self {
+        Self { ellipsoid }
+    }
+
+    pub fn wgs84() -> Self {
+        Self::new(Ellipsoid::wgs84())
+    }
+
+    /// Standard zone for a point, including the Norway and Svalbard exceptions.
+    pub fn zone(latitude: T, longitude: T) -> u8 {
+        let lat = latitude.to_degrees().to_f64().unwrap();
+        let lon = crate::utils::wrap_pi(longitude).to_degrees().to_f64().unwrap();
+        let mut zone = (((lon + 180.0) / 6.0).floor() as i32 + 1).clamp(1, 60) as u8;
+        if (56.0..64.0).contains(&lat) && (3.0..12.0).contains(&lon) {
+            zone = 32;
+        }
+        if (72.0..=84.0).contains(&lat) && (0.0..42.0).contains(&lon) {
+            zone = match lon {
+                l if l < 9.0 => 31,
+                l if l < 21.0 => 33,
+                l if l < 33.0 => 35,
+                _ => 37,
+            };
+        }
+        zone
+    }
+
+    /// MGRS latitude band letter, or `None` outside the UTM latitude limits.
+    pub fn latitude_band(latitude: T) -> Option<char> {
+        let lat = latitude.to_degrees().to_f64().unwrap();
+        if !(UTM_MIN_LATITUDE..=UTM_MAX_LATITUDE).contains(&lat) {
+            return None;
+        }
+        let index = (((lat + 80.0) / 8.0).floor() as usize).min(LATITUDE_BANDS.len() - 1);
+        Some(LATITUDE_BANDS[index] as char)
+    }
+
+    /// Central meridian of `zone` (radians).
+    pub fn central_meridian(zone: u8) -> T {
+        T::from(6.0 * zone as f64 - 183.0).unwrap().to_radians()
+    }
+
+    /// Projects a point into its standard zone.
+    pub fn forward(&self, latitude: T, longitude: T) -> Result<UtmCoordinate<T>, ProjectionError> {
+        let hemisphere = if latitude < T::zero() { Hemisphere::South } else { Hemisphere::North };
+        self.forward_in_zone(latitude, longitude, Self::zone(latitude, longitude), hemisphere)
+    }
+
+    /// Projects a point into a given zone and hemisphere, e.g. to continue a
+    /// track across a zone boundary.
+    pub fn forward_in_zone(
+        &self,
+        latitude: T,
+        longitude: T,
+        zone: u8,
+        hemisphere: Hemisphere,
+    ) -> Result<UtmCoordinate<T>, ProjectionError> {
+        let lat = latitude.to_degrees().to_f64().unwrap();
+        if !(UTM_MIN_LATITUDE..=UTM_MAX_LATITUDE).contains(&lat) {
+            return Err(ProjectionError::LatitudeOutOfRange);
+        }
+        let p = self.projection(zone, hemisphere)?.forward(latitude, longitude);
+        Ok(UtmCoordinate {
+            zone,
+            hemisphere,
+            easting: p.easting,
+            northing: p.northing,
+            convergence: p.convergence,
+            scale: p.scale,
+        })
+    }
+
+    /// Returns `(latitude, longitude)` of a UTM coordinate.
+    pub fn inverse(&self, coord: &UtmCoordinate<T>) -> Result<(T, T), ProjectionError> {
+        Ok(self.projection(coord.zone, coord.hemisphere)?.inverse(coord.easting, coord.northing))
+    }
+
+    fn projection(&self, zone: u8, hemisphere: Hemisphere) -> Result<TransverseMercator<T>, ProjectionError> {
+        if !(1..=60).contains(&zone) {
+            return Err(ProjectionError::InvalidZone);
+        }
+        let false_northing = match hemisphere {
+            Hemisphere::North => T::zero(),
+            Hemisphere::South => T::from(UTM_FALSE_NORTHING_SOUTH).unwrap(),
+        };
+        Ok(TransverseMercator::new(
+            self.ellipsoid,
+            Self::central_meridian(zone),
+            T::zero(),
+            T::from(UTM_SCALE).unwrap(),
+            T::from(UTM_FALSE_EASTING).unwrap(),
+            false_northing,
+        ))
+    }
+}
+
+/// UPS grid on a reference ellipsoid, used poleward of the UTM limits.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ups<T: Float> {
+    ellipsoid: Ellipsoid<T>,
+}
+
+impl<T: Float> Ups<T> {
+    pub fn new(ellipsoid: Ellipsoid<T>) -> Self {
+        Self { ellipsoid }
+    }
+
+    pub fn wgs84() -> Self {
+        Self::new(Ellipsoid::wgs84())
+    }
+
+    /// Projects a point poleward of the UTM limits, i.e. at or north of 84N or
+    /// at or south of 80S.
+    pub fn forward(&self, latitude: T, longitude: T) -> Result<UpsCoordinate<T>, ProjectionError> {
+        let lat = latitude.to_degrees().to_f64().unwrap();
+        if lat > UTM_MIN_LATITUDE && lat < UTM_MAX_LATITUDE {
+            return Err(ProjectionError::LatitudeOutOfRange);
+        }
+        let hemisphere = if latitude < T::zero() { Hemisphere::South } else { Hemisphere::North };
+        let p: ProjectedPoint<T> = self.projection(hemisphere).forward(latitude, longitude)?;
+        Ok(UpsCoordinate {
+            hemisphere,
+            easting: p.easting,
+            northing: p.northing,
+            convergence: p.convergence,
+            scale: p.scale,
+        })
+    }
+
+    /// Returns `(latitude, longitude)` of a UPS coordinate.
+    pub fn inverse(&self, coord: &UpsCoordinate<T>) -> (T, T) {
+        self.projection(coord.hemisphere).inverse(coord.easting, coord.northing)
+    }
+
+    fn projection(&self, hemisphere: Hemisphere) -> PolarStereographic<T> {
+        let offset = T::from(UPS_FALSE_EASTING_NORTHING).unwrap();
+        PolarStereographic::new(self.ellipsoid, hemisphere, T::zero(), T::from(UPS_SCALE).unwrap(), offset, offset)
+    }
+}
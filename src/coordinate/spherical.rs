@@ -1,50 +1,188 @@
-use num_traits::{Float, Pow};
+use num_traits::Float;
+use core::marker::PhantomData;
 
 use super::cartesian::Cartesian;
+use super::covariance::{transform_covariance, UnscentedTransform};
 use super::cylindrical::Cylindrical;
-use crate::{reference_frame::ReferenceFrame, math::Vector};
-
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct Spherical<T: Float> {
-    /// r: radial distance
-    /// phi: azimuthal angle (radians, from +x in xy-plane)
-    /// theta: inclination angle (radians (z-axis))
-    pub data: Vector<T, 3>, // [r, phi, theta]
+use crate::math::{Matrix, Vector};
+use crate::utils::wrap_pi;
+
+/// Spherical coordinates `[r, azimuth, inclination]` in a reference frame.
+///
+/// The azimuth is measured in the xy-plane from +x towards +y, the inclination
+/// from the +z axis; both are in radians.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Spherical<T: Float, ReferenceFrame> {
+    pub data: Vector<T, 3>, // [r, azimuth, inclination]
+    pub _reference_frame: PhantomData<ReferenceFrame>
 }
 
-impl<T: Float> Spherical<T>{
-    pub fn new(r: T, phi: T, theta: T) -> Self {
-        Self { data: Vector { data: [r, phi, theta] } }
+impl<T: Float, ReferenceFrame> Spherical<T, ReferenceFrame> {
+    pub fn new(r: T, azimuth: T, inclination: T) -> Self {
+        Self { data: Vector { data: [r, azimuth, inclination] }, _reference_frame: PhantomData }
+    }
+
+    /// Builds a point from its elevation above the xy-plane instead of its inclination.
+    pub fn from_elevation(r: T, azimuth: T, elevation: T) -> Self {
+        Self::new(r, azimuth, half_pi::<T>() - elevation)
     }
+
+    /// Radial distance.
     pub fn r(&self) -> T { self.data.data[0] }
-    pub fn phi(&self) -> T { self.data.data[1] }
-    pub fn theta(&self) -> T { self.data.data[2] }
+    /// Angle in the xy-plane from +x towards +y.
+    pub fn azimuth(&self) -> T { self.data.data[1] }
+    /// Angle from the +z axis.
+    pub fn inclination(&self) -> T { self.data.data[2] }
+    /// Angle above the xy-plane, `pi/2 - inclination`.
+    pub fn elevation(&self) -> T { half_pi::<T>() - self.inclination() }
 }
 
-impl<T: Float, RF: ReferenceFrame> From<&Cartesian<T, RF>> for Spherical<T> {
+impl<T: Float, RF> From<&Cartesian<T, RF>> for Spherical<T, RF> {
     fn from(cart: &Cartesian<T, RF>) -> Self {
         let x = cart.x();
         let y = cart.y();
         let z = cart.z();
         let rho = (x * x + y * y + z * z).sqrt();
-        let theta = y.atan2(x); // azimuth angle in xy-plane
-        let phi = if rho == T::zero() { T::zero() } else { (z / rho).acos() }; // inclination from z-axis
-        Spherical::new(rho, theta, phi)
+        let azimuth = y.atan2(x);
+        let inclination = if rho == T::zero() { T::zero() } else { (z / rho).acos() };
+        Spherical::new(rho, azimuth, inclination)
     }
 }
 
-impl<T: Float> From<&Cylindrical<T>> for Spherical<T> {
-    fn from(c: &Cylindrical<T>) -> Self {
+impl<T: Float, RF> From<&Cylindrical<T, RF>> for Spherical<T, RF> {
+    fn from(c: &Cylindrical<T, RF>) -> Self {
         let r_cyl = c.r();
-        let theta = c.theta(); // azimuthal angle in x-y plane
         let z = c.z();
 
         let rho = (r_cyl * r_cyl + z * z).sqrt(); // spherical radius
-        let phi = if rho != T::zero() {
-            (z / rho).acos() // inclination from z-axis
+        let inclination = if rho != T::zero() {
+            (z / rho).acos()
         } else {
             T::zero()
         };
-        Spherical::new(rho, theta, phi)
+        Spherical::new(rho, c.azimuth(), inclination)
+    }
+}
+
+// Jacobians and rates
+impl<T: Float + Default, RF> Spherical<T, RF> {
+    /// Jacobian of `[x, y, z]` with respect to `[r, azimuth, inclination]`.
+    pub fn jacobian_to_cartesian(&self) -> Matrix<T, 3, 3> {
+        let r = self.r();
+        let (sin_az, cos_az) = self.azimuth().sin_cos();
+        let (sin_inc, cos_inc) = self.inclination().sin_cos();
+        Matrix::new([
+            [sin_inc * cos_az, -r * sin_inc * sin_az, r * cos_inc * cos_az],
+            [sin_inc * sin_az, r * sin_inc * cos_az, r * cos_inc * sin_az],
+            [cos_inc, T::zero(), -r * sin_inc],
+        ])
+    }
+
+    /// Jacobian of cylindrical `[r, azimuth, z]` with respect to `[r, azimuth, inclination]`.
+    pub fn jacobian_to_cylindrical(&self) -> Matrix<T, 3, 3> {
+        let r = self.r();
+        let (sin_inc, cos_inc) = self.inclination().sin_cos();
+        Matrix::new([
+            [sin_inc, T::zero(), r * cos_inc],
+            [T::zero(), T::one(), T::zero()],
+            [cos_inc, T::zero(), -r * sin_inc],
+        ])
     }
-}
\ No newline at end of file
+
+    /// Cartesian velocity from the spherical rates `[r_dot, azimuth_dot, inclination_dot]`.
+    pub fn velocity_to_cartesian(&self, rates: &Vector<T, 3>) -> Cartesian<T, RF> {
+        Cartesian::from_vector(self.jacobian_to_cartesian() * *rates)
+    }
+
+    /// Cylindrical rates `[r_dot, azimuth_dot, z_dot]` from the spherical rates.
+    pub fn velocity_to_cylindrical(&self, rates: &Vector<T, 3>) -> Vector<T, 3> {
+        self.jacobian_to_cylindrical() * *rates
+    }
+
+    /// Cartesian covariance from the covariance of `[r, azimuth, inclination]`.
+    pub fn covariance_to_cartesian(&self, covariance: &Matrix<T, 3, 3>) -> Matrix<T, 3, 3> {
+        transform_covariance(&self.jacobian_to_cartesian(), covariance)
+    }
+
+    /// Cylindrical covariance from the covariance of `[r, azimuth, inclination]`.
+    pub fn covariance_to_cylindrical(&self, covariance: &Matrix<T, 3, 3>) -> Matrix<T, 3, 3> {
+        transform_covariance(&self.jacobian_to_cylindrical(), covariance)
+    }
+
+    /// Cartesian mean and covariance through the unscented transform. Returns
+    /// `None` if `covariance` is not positive definite.
+    pub fn unscented_to_cartesian(
+        &self,
+        covariance: &Matrix<T, 3, 3>,
+        ut: &UnscentedTransform<T>,
+    ) -> Option<(Cartesian<T, RF>, Matrix<T, 3, 3>)> {
+        let (mean, cov) = ut.propagate(&self.data, covariance, |v| {
+            Cartesian::<T, RF>::from(&Spherical::new(v[0], v[1], v[2])).data
+        })?;
+        Some((Cartesian::from_vector(mean), cov))
+    }
+}
+
+fn half_pi<T: Float>() -> T {
+    T::from(core::f64::consts::FRAC_PI_2).unwrap()
+}
+
+// Great-circle navigation, treating `self.r()` as the sphere radius.
+impl<T: Float, RF> Spherical<T, RF> {
+    /// Central angle to `other` using the haversine formula.
+    pub fn central_angle(&self, other: &Self) -> T {
+        let two = T::one() + T::one();
+        let (lat1, lat2) = (self.elevation(), other.elevation());
+        let half_dlat = (lat2 - lat1) / two;
+        let half_dlon = (other.azimuth() - self.azimuth()) / two;
+        let h = half_dlat.sin().powi(2) + lat1.cos() * lat2.cos() * half_dlon.sin().powi(2);
+        two * h.sqrt().min(T::one()).asin()
+    }
+
+    /// Great-circle distance to `other` on a sphere of radius `self.r()`.
+    pub fn haversine_distance(&self, other: &Self) -> T {
+        self.r() * self.central_angle(other)
+    }
+
+    /// Initial bearing (clockwise from north, in `(-pi, pi]`) of the great circle to `other`.
+    pub fn initial_bearing(&self, other: &Self) -> T {
+        let (lat1, lat2) = (self.elevation(), other.elevation());
+        let dlon = other.azimuth() - self.azimuth();
+        (dlon.sin() * lat2.cos()).atan2(lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * dlon.cos())
+    }
+
+    /// Bearing of the great circle on arrival at `other`.
+    pub fn final_bearing(&self, other: &Self) -> T {
+        let pi = T::from(core::f64::consts::PI).unwrap();
+        wrap_pi(other.initial_bearing(self) + pi)
+    }
+
+    /// Point at `fraction` (0 = `self`, 1 = `other`) along the great circle to `other`.
+    pub fn intermediate(&self, other: &Self, fraction: T) -> Self {
+        let delta = self.central_angle(other);
+        if delta == T::zero() {
+            return Self::new(self.r(), self.azimuth(), self.inclination());
+        }
+        let a = ((T::one() - fraction) * delta).sin() / delta.sin();
+        let b = (fraction * delta).sin() / delta.sin();
+        let (lat1, lat2) = (self.elevation(), other.elevation());
+        let x = a * lat1.cos() * self.azimuth().cos() + b * lat2.cos() * other.azimuth().cos();
+        let y = a * lat1.cos() * self.azimuth().sin() + b * lat2.cos() * other.azimuth().sin();
+        let z = a * lat1.sin() + b * lat2.sin();
+        let inclination = (x * x + y * y).sqrt().atan2(z);
+        Spherical::new(self.r(), y.atan2(x), inclination)
+    }
+
+    /// Point reached after travelling `distance` along `bearing` on a sphere of radius `self.r()`.
+    pub fn destination(&self, bearing: T, distance: T) -> Self {
+        let delta = distance / self.r();
+        let lat1 = self.elevation();
+        let lat2 = (lat1.sin() * delta.cos() + lat1.cos() * delta.sin() * bearing.cos())
+            .max(-T::one())
+            .min(T::one())
+            .asin();
+        let dlon = (bearing.sin() * delta.sin() * lat1.cos())
+            .atan2(delta.cos() - lat1.sin() * lat2.sin());
+        Spherical::from_elevation(self.r(), wrap_pi(self.azimuth() + dlon), lat2)
+    }
+}
@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod tests {
+    use crate::coordinate::{transform_covariance, Cartesian, Ellipsoid, Geodetic, Spherical, UnscentedTransform};
+    use crate::math::{Matrix, Vector};
+    use crate::reference_frame::Body;
+    use crate::matrix;
+    use approx::assert_relative_eq;
+
+    type Frame = Body<f64>;
+
+    fn assert_matrix_eq(a: &Matrix<f64, 3, 3>, b: &Matrix<f64, 3, 3>, epsilon: f64) {
+        for r in 0..3 {
+            for c in 0..3 {
+                assert_relative_eq!(a[(r, c)], b[(r, c)], epsilon = epsilon, max_relative = epsilon);
+            }
+        }
+    }
+
+    #[test]
+    fn test_unscented_is_exact_for_linear_maps() {
+        let a = matrix![1.0, 2.0, 0.0; -1.0, 0.5, 3.0; 0.0, 0.0, 2.0];
+        let p = matrix![2.0, 0.3, 0.0; 0.3, 1.0, -0.2; 0.0, -0.2, 0.5];
+        let mean = Vector::new([1.0, -2.0, 0.5]);
+        let (y, py) = UnscentedTransform::default()
+            .propagate(&mean, &p, |v| a * *v + Vector::new([1.0, 1.0, 1.0]))
+            .unwrap();
+        let expected = a * mean + Vector::new([1.0, 1.0, 1.0]);
+        for i in 0..3 {
+            assert_relative_eq!(y[i], expected[i], epsilon = 1e-12);
+        }
+        assert_matrix_eq(&py, &transform_covariance(&a, &p), 1e-12);
+    }
+
+    #[test]
+    fn test_range_azimuth_elevation_to_cartesian() {
+        // 10 km range, 1 m range noise, 1 mrad angle noise
+        let meas: Spherical<f64, Frame> = Spherical::from_elevation(10_000.0, 0.3, 0.2);
+        let cov = Matrix::diag(&[1.0, 1e-6, 1e-6]);
+
+        let linear = meas.covariance_to_cartesian(&cov);
+        let (mean, unscented) = meas.unscented_to_cartesian(&cov, &UnscentedTransform::default()).unwrap();
+        assert_matrix_eq(&linear, &unscented, 1e-3);
+
+        // The variance along the line of sight is the range variance
+        let cart: Cartesian<f64, Frame> = (&meas).into();
+        let los = cart.data / cart.data.norm();
+        assert_relative_eq!(los.dot(&(linear * los)), 1.0, epsilon = 1e-9);
+        assert_relative_eq!(mean.data.norm(), 10_000.0, epsilon = 1e-2);
+
+        // Round trip through the inverse Jacobian recovers the measurement covariance
+        assert_matrix_eq(&cart.covariance_to_spherical(&linear), &cov, 1e-9);
+    }
+
+    #[test]
+    fn test_unscented_captures_nonlinearity() {
+        // Large azimuth uncertainty at short range: the mean is pulled inward
+        let meas: Spherical<f64, Frame> = Spherical::from_elevation(100.0, 0.0, 0.0);
+        let cov = Matrix::diag(&[0.01, 0.25, 1e-8]);
+        let (mean, _) = meas.unscented_to_cartesian(&cov, &UnscentedTransform::default()).unwrap();
+        assert!(mean.x() < 100.0 * 0.9);
+        assert!(meas.unscented_to_cartesian(&Matrix::zeros(), &UnscentedTransform::default()).is_none());
+    }
+
+    #[test]
+    fn test_geodetic_jacobians() {
+        let wgs84 = Ellipsoid::wgs84();
+        let geo = Geodetic::new(0.7, -1.2, 350.0);
+        let jac = geo.jacobian_to_cartesian(&wgs84);
+        assert_matrix_eq(&(geo.jacobian_from_cartesian(&wgs84) * jac), &Matrix::identity(), 1e-12);
+
+        // Central differences of the forward conversion
+        let steps = [1e-7, 1e-7, 1e-2];
+        for (j, h) in steps.iter().enumerate() {
+            let mut plus = geo;
+            let mut minus = geo;
+            plus.data[j] += h;
+            minus.data[j] -= h;
+            let cp: Cartesian<f64, Frame> = plus.to_cartesian(&wgs84);
+            let cm: Cartesian<f64, Frame> = minus.to_cartesian(&wgs84);
+            for i in 0..3 {
+                assert_relative_eq!((cp.data[i] - cm.data[i]) / (2.0 * h), jac[(i, j)], epsilon = 1e-6, max_relative = 1e-8);
+            }
+        }
+
+        let cov = Matrix::diag(&[1e-14, 1e-14, 4.0]);
+        let cart_cov = geo.covariance_to_cartesian(&wgs84, &cov);
+        assert_matrix_eq(&geo.covariance_from_cartesian(&wgs84, &cart_cov), &cov, 1e-9);
+    }
+}
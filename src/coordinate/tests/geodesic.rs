@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod tests {
+    use crate::coordinate::{Geodesic, Spherical};
+    use crate::reference_frame::Body;
+    use crate::utils::wrap_pi;
+    use approx::assert_relative_eq;
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    fn dms(d: f64, m: f64, s: f64) -> f64 {
+        (d.signum() * (d.abs() + m / 60.0 + s / 3600.0)).to_radians()
+    }
+
+    #[test]
+    fn test_vincenty_flinders_peak_buninyong() {
+        // Vincenty (1975) reference line
+        let geod = Geodesic::wgs84();
+        let (lat1, lon1) = (dms(-37.0, 57.0, 3.72030), dms(144.0, 25.0, 29.52440));
+        let (lat2, lon2) = (dms(-37.0, 39.0, 10.15610), dms(143.0, 55.0, 35.38390));
+
+        let inv = geod.inverse(lat1, lon1, lat2, lon2);
+        assert_relative_eq!(inv.distance, 54_972.271, epsilon = 1e-3);
+        assert_relative_eq!(wrap_pi(inv.initial_azimuth), wrap_pi(dms(306.0, 52.0, 5.37)), epsilon = 1e-6);
+        assert_relative_eq!(wrap_pi(inv.final_azimuth), wrap_pi(dms(307.0, 10.0, 25.07)), epsilon = 1e-6);
+
+        let dir = geod.direct(lat1, lon1, inv.initial_azimuth, inv.distance);
+        assert_relative_eq!(dir.latitude, lat2, epsilon = 1e-10);
+        assert_relative_eq!(dir.longitude, lon2, epsilon = 1e-10);
+        assert_relative_eq!(dir.final_azimuth, inv.final_azimuth, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_nearly_antipodal_fallback() {
+        // Karney (2013), example of a nearly antipodal inverse problem, solved
+        // by the azimuth bisection fallback
+        let geod = Geodesic::wgs84();
+        let (lat1, lat2, lon2) = ((-30.0_f64).to_radians(), 29.9_f64.to_radians(), 179.8_f64.to_radians());
+
+        let inv = geod.inverse(lat1, 0.0, lat2, lon2);
+        assert_relative_eq!(inv.distance, 19_989_832.827_6, epsilon = 1e-2);
+        assert_relative_eq!(inv.initial_azimuth, 161.890_524_7_f64.to_radians(), epsilon = 1e-8);
+        assert_relative_eq!(inv.final_azimuth, 18.090_737_2_f64.to_radians(), epsilon = 1e-8);
+
+        let dir = geod.direct(lat1, 0.0, inv.initial_azimuth, inv.distance);
+        assert_relative_eq!(dir.latitude, lat2, epsilon = 1e-9);
+        assert_relative_eq!(dir.longitude, lon2, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_exactly_antipodal_equator() {
+        let geod = Geodesic::wgs84();
+        let inv = geod.inverse(0.0, 0.0, 0.0, PI);
+        // Shortest path runs over a pole: half a meridian ellipse
+        assert_relative_eq!(inv.distance, 20_003_931.458_6, epsilon = 1e-2);
+        assert_relative_eq!(inv.initial_azimuth.abs().min(PI - inv.initial_azimuth.abs()), 0.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_waypoints_lie_on_geodesic() {
+        let geod = Geodesic::wgs84();
+        let (lat1, lon1) = (40.6413_f64.to_radians(), (-73.7781_f64).to_radians());
+        let (lat2, lon2) = (51.4700_f64.to_radians(), (-0.4543_f64).to_radians());
+        let total = geod.inverse(lat1, lon1, lat2, lon2).distance;
+
+        let points: [(f64, f64); 5] = geod.waypoints(lat1, lon1, lat2, lon2);
+        assert_eq!(points[0], (lat1, lon1));
+        for (i, (lat, lon)) in points.iter().enumerate() {
+            let s = geod.inverse(lat1, lon1, *lat, *lon).distance;
+            assert_relative_eq!(s, total * i as f64 / 4.0, epsilon = 1e-3);
+        }
+
+        let (lat_mid, lon_mid) = geod.waypoint(lat1, lon1, lat2, lon2, 0.5);
+        assert_relative_eq!(lat_mid, points[2].0, epsilon = 1e-12);
+        assert_relative_eq!(lon_mid, points[2].1, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_haversine_helpers() {
+        let r = 6_371_000.0_f64;
+        let sph = |lat: f64, lon: f64| Spherical::<f64, Body<f64>>::from_elevation(r, lon.to_radians(), lat.to_radians());
+
+        // Quarter of a great circle along the equator
+        let a = sph(0.0, 0.0);
+        let b = sph(0.0, 90.0);
+        assert_relative_eq!(a.haversine_distance(&b), r * FRAC_PI_2, epsilon = 1e-6);
+        assert_relative_eq!(a.initial_bearing(&b), FRAC_PI_2, epsilon = 1e-12);
+        assert_relative_eq!(a.final_bearing(&b), FRAC_PI_2, epsilon = 1e-12);
+
+        // Destination and intermediate points are consistent with the distance
+        let c = sph(50.0, -5.0);
+        let d = sph(58.0, -3.0);
+        let mid = c.intermediate(&d, 0.5);
+        assert_relative_eq!(c.haversine_distance(&mid), 0.5 * c.haversine_distance(&d), epsilon = 1e-6);
+        let dest = c.destination(c.initial_bearing(&d), c.haversine_distance(&d));
+        assert_relative_eq!(dest.azimuth(), d.azimuth(), epsilon = 1e-12);
+        assert_relative_eq!(dest.inclination(), d.inclination(), epsilon = 1e-12);
+    }
+}
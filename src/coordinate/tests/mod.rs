@@ -1,9 +1,19 @@
 use std::f64::consts::{PI, FRAC_PI_2, FRAC_PI_4};
 
+mod geodesic;
+mod projection;
+mod covariance;
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::coordinate::*;
+    use crate::reference_frame::ReferenceFrame;
+    use crate::math::Matrix;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Default)]
+    struct TestFrame;
+    impl ReferenceFrame for TestFrame {}
 
     fn approx_eq(a: f64, b: f64) -> bool {
         (a - b).abs() < 1e-10
@@ -14,15 +24,15 @@ mod tests {
 
     #[test]
     fn test_cartesian_cylindrical_roundtrip() {
-        let originals = [
+        let originals: [Cartesian<f64, TestFrame>; 4] = [
             Cartesian::new(3.0_f64, 4.0, 1.0),
             Cartesian::new(0.0, 0.0, 1.0),
             Cartesian::new(-3.0,-4.0, 1.0),
             Cartesian::new(2.0, -2.0, 1.0),
         ];
         for cart in &originals {
-            let cyl: Cylindrical<f64> = cart.into();
-            let cart2: Cartesian<f64> = (&cyl).into();
+            let cyl: Cylindrical<f64, TestFrame> = cart.into();
+            let cart2: Cartesian<f64, TestFrame> = (&cyl).into();
             assert!(approx_eq(cart.x(), cart2.x()));
             assert!(approx_eq(cart.y(), cart2.y()));
             assert!(approx_eq(cart.z(), cart2.z()));
@@ -30,7 +40,7 @@ mod tests {
     }
     #[test]
     fn test_cartesian_spherical_roundtrip() {
-        let originals = [
+        let originals: [Cartesian<f64, TestFrame>; 5] = [
             Cartesian::new(1.0, 0.0, 0.0),
             Cartesian::new(0.0, 1.0, 0.0),
             Cartesian::new(0.0, 0.0, 1.0),
@@ -39,8 +49,8 @@ mod tests {
         ];
 
         for cart in &originals {
-            let sph: Spherical<f64> = cart.into();
-            let cart2: Cartesian<f64> = (&sph).into();
+            let sph: Spherical<f64, TestFrame> = cart.into();
+            let cart2: Cartesian<f64, TestFrame> = (&sph).into();
 
             assert!(approx_eq(cart.x(), cart2.x()), "x: {} vs {}", cart.x(), cart2.x());
             assert!(approx_eq(cart.y(), cart2.y()), "y: {} vs {}", cart.y(), cart2.y());
@@ -57,10 +67,10 @@ mod tests {
             Cylindrical::new(3.0, 2.0, 1.0)
         ];
         for cyl in &originals {
-            let cart: Cartesian<f64> = cyl.into();
-            let cyl2: Cylindrical<f64> = (&cart).into();
+            let cart: Cartesian<f64, TestFrame> = cyl.into();
+            let cyl2: Cylindrical<f64, TestFrame> = (&cart).into();
             assert!(approx_eq(cyl.r(), cyl2.r()));
-            assert!(approx_eq(cyl.theta(), cyl2.theta()));
+            assert!(approx_eq(cyl.azimuth(), cyl2.azimuth()));
             assert!(approx_eq(cyl.z(), cyl2.z()));
         }
     }
@@ -74,10 +84,10 @@ mod tests {
             Cylindrical::new(4.0, -FRAC_PI_2, 2.0),
         ];
         for cyl in &originals {
-            let sph: Spherical<f64> = cyl.into();
-            let cyl2: Cylindrical<f64> = (&sph).into();
+            let sph: Spherical<f64, TestFrame> = cyl.into();
+            let cyl2: Cylindrical<f64, TestFrame> = (&sph).into();
             assert!(approx_eq(cyl.r(), cyl2.r()));
-            assert!(approx_eq(cyl.theta(), cyl2.theta()));
+            assert!(approx_eq(cyl.azimuth(), cyl2.azimuth()));
             assert!(approx_eq(cyl.z(), cyl2.z()));
         }
     }
@@ -91,11 +101,11 @@ mod tests {
             Spherical::new(5.0, -FRAC_PI_2, FRAC_PI_4),
         ];
         for sph in &originals {
-            let cyl: Cylindrical<f64> = sph.into();
-            let sph2: Spherical<f64> = (&cyl).into();
+            let cyl: Cylindrical<f64, TestFrame> = sph.into();
+            let sph2: Spherical<f64, TestFrame> = (&cyl).into();
             assert!(approx_eq(sph.r(), sph2.r()));
-            assert!(approx_eq(sph.theta(), sph2.theta()));
-            assert!(approx_eq(sph.phi(), sph2.phi()));
+            assert!(approx_eq(sph.inclination(), sph2.inclination()));
+            assert!(approx_eq(sph.azimuth(), sph2.azimuth()));
         }
     }
     #[test]
@@ -109,17 +119,107 @@ mod tests {
             Spherical::new(1.0, 0.0, PI),               // -z pole
         ];
         for sph in &originals {
-            let cart: Cartesian<f64> = sph.into();
-            let sph2: Spherical<f64> = (&cart).into();
+            let cart: Cartesian<f64, TestFrame> = sph.into();
+            let sph2: Spherical<f64, TestFrame> = (&cart).into();
 
             assert!(approx_eq(sph.r(), sph2.r()));
-            if sph.phi().abs() < 1e-6 || (sph.phi() - PI).abs() < 1e-6 {
-                // At poles, azimuth is undefined, skip phi/theta check (not ideal)
-                println!("Hi");
-            } else {
-                assert!(approx_eq_angle(sph.theta(), sph2.theta()));
-                assert!(approx_eq(sph.phi(), sph2.phi()));
+            assert!(approx_eq(sph.inclination(), sph2.inclination()));
+            // At the poles the azimuth is undefined
+            if sph.inclination().abs() > 1e-6 && (sph.inclination() - PI).abs() > 1e-6 {
+                assert!(approx_eq_angle(sph.azimuth(), sph2.azimuth()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_spherical_accessors() {
+        let sph: Spherical<f64, TestFrame> = Spherical::from_elevation(2.0, FRAC_PI_4, FRAC_PI_4 / 2.0);
+        assert!(approx_eq(sph.elevation(), FRAC_PI_4 / 2.0));
+        assert!(approx_eq(sph.inclination(), FRAC_PI_2 - FRAC_PI_4 / 2.0));
+
+        // Azimuth and inclination land in the documented slots
+        let cart: Cartesian<f64, TestFrame> = Cartesian::new(0.0, 1.0, 1.0);
+        let sph: Spherical<f64, TestFrame> = (&cart).into();
+        assert!(approx_eq(sph.azimuth(), FRAC_PI_2));
+        assert!(approx_eq(sph.inclination(), FRAC_PI_4));
+        assert_eq!(sph.data.data[1], sph.azimuth());
+        assert_eq!(sph.data.data[2], sph.inclination());
+    }
+
+    #[test]
+    fn test_jacobians_are_inverse() {
+        let cart: Cartesian<f64, TestFrame> = Cartesian::new(1.0, -2.0, 0.5);
+        let sph: Spherical<f64, TestFrame> = (&cart).into();
+        let cyl: Cylindrical<f64, TestFrame> = (&cart).into();
+        let identity = Matrix::<f64, 3, 3>::identity();
+
+        let pairs = [
+            (sph.jacobian_to_cartesian() * cart.jacobian_to_spherical(), "spherical"),
+            (cyl.jacobian_to_cartesian() * cart.jacobian_to_cylindrical(), "cylindrical"),
+            (sph.jacobian_to_cylindrical() * cyl.jacobian_to_spherical(), "spherical/cylindrical"),
+        ];
+        for (product, name) in &pairs {
+            for r in 0..3 {
+                for c in 0..3 {
+                    assert!(approx_eq(product[(r, c)], identity[(r, c)]), "{} ({}, {})", name, r, c);
+                }
             }
         }
     }
+
+    #[test]
+    fn test_velocity_conversions() {
+        // Numerical derivative of the position along a straight line
+        let dt = 1e-6;
+        let p0: Cartesian<f64, TestFrame> = Cartesian::new(3.0, 4.0, -2.0);
+        let v: Cartesian<f64, TestFrame> = Cartesian::new(-1.0, 0.5, 2.0);
+        let p1 = p0 + v * dt;
+
+        let (s0, s1): (Spherical<f64, TestFrame>, Spherical<f64, TestFrame>) = ((&p0).into(), (&p1).into());
+        let sph_rates = p0.velocity_to_spherical(&v);
+        for i in 0..3 {
+            assert!(((s1.data[i] - s0.data[i]) / dt - sph_rates[i]).abs() < 1e-5);
+        }
+
+        let (c0, c1): (Cylindrical<f64, TestFrame>, Cylindrical<f64, TestFrame>) = ((&p0).into(), (&p1).into());
+        let cyl_rates = p0.velocity_to_cylindrical(&v);
+        for i in 0..3 {
+            assert!(((c1.data[i] - c0.data[i]) / dt - cyl_rates[i]).abs() < 1e-5);
+        }
+
+        // Back to Cartesian through every path
+        let via_sph = s0.velocity_to_cartesian(&sph_rates);
+        let via_cyl = c0.velocity_to_cartesian(&cyl_rates);
+        let via_both = c0.velocity_to_cartesian(&s0.velocity_to_cylindrical(&sph_rates));
+        for out in [via_sph, via_cyl, via_both] {
+            assert!(approx_eq(out.x(), v.x()) && approx_eq(out.y(), v.y()) && approx_eq(out.z(), v.z()));
+        }
+        let back = c0.velocity_to_spherical(&cyl_rates);
+        for i in 0..3 {
+            assert!(approx_eq(back[i], sph_rates[i]));
+        }
+    }
+
+    #[test]
+    fn test_cartesian_geometry() {
+        let a: Cartesian<f64, TestFrame> = Cartesian::new(1.0, 0.0, 0.0);
+        let b: Cartesian<f64, TestFrame> = Cartesian::new(1.0, 1.0, 0.0);
+
+        assert!(approx_eq(a.dot(&b), 1.0));
+        assert_eq!(a.cross(&b), Cartesian::new(0.0, 0.0, 1.0));
+        assert!(approx_eq(b.norm(), 2.0_f64.sqrt()));
+        assert!(approx_eq(a.distance_to(&b), 1.0));
+        assert!(approx_eq(b.unit().norm(), 1.0));
+        assert!(approx_eq(a.angle_between(&b), FRAC_PI_4));
+
+        let p = b.project_onto(&a);
+        let q = b.reject_from(&a);
+        assert_eq!(p, Cartesian::new(1.0, 0.0, 0.0));
+        assert_eq!(q, Cartesian::new(0.0, 1.0, 0.0));
+        assert_eq!(p + q, b);
+        assert_eq!(b.project_onto(&Cartesian::zero()), Cartesian::zero());
+
+        let v = Cartesian::<f64, TestFrame>::from_vector(b.data);
+        assert_eq!(v, b);
+    }
 }
@@ -0,0 +1,144 @@
+#[cfg(test)]
+mod tests {
+    use crate::coordinate::projection::*;
+    use crate::coordinate::{Cartesian, Ellipsoid, Geodetic};
+    use crate::reference_frame::Body;
+    use approx::assert_relative_eq;
+    use std::f64::consts::FRAC_PI_2;
+
+    fn deg(x: f64) -> f64 {
+        x.to_radians()
+    }
+
+    #[test]
+    fn test_geodetic_cartesian_roundtrip() {
+        let wgs84 = Ellipsoid::wgs84();
+        let points = [
+            Geodetic::new(deg(45.0), deg(7.0), 1_000.0),
+            Geodetic::new(deg(-33.9), deg(151.2), 50.0),
+            Geodetic::new(deg(89.9), deg(-120.0), 400_000.0),
+            Geodetic::new(0.0, 0.0, 0.0),
+        ];
+        for geo in &points {
+            let cart: Cartesian<f64, Body<f64>> = geo.to_cartesian(&wgs84);
+            let back = Geodetic::from_cartesian(&cart, &wgs84);
+            assert_relative_eq!(back.latitude(), geo.latitude(), epsilon = 1e-12);
+            assert_relative_eq!(back.longitude(), geo.longitude(), epsilon = 1e-12);
+            assert_relative_eq!(back.height(), geo.height(), epsilon = 1e-6);
+        }
+
+        let pole: Cartesian<f64, Body<f64>> = Cartesian::new(0.0, 0.0, wgs84.b() + 10.0);
+        let geo = Geodetic::from_cartesian(&pole, &wgs84);
+        assert_relative_eq!(geo.latitude(), FRAC_PI_2, epsilon = 1e-12);
+        assert_relative_eq!(geo.height(), 10.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_utm_reference_points() {
+        let utm = Utm::wgs84();
+
+        // Equator at the edge of zone 31
+        let p = utm.forward(0.0, 0.0).unwrap();
+        assert_eq!(p.zone, 31);
+        assert_eq!(p.hemisphere, Hemisphere::North);
+        assert_relative_eq!(p.easting, 166_021.443_1, epsilon = 1e-3);
+        assert_relative_eq!(p.northing, 0.0, epsilon = 1e-6);
+
+        // On the central meridian the scale is exactly k0
+        let p = utm.forward(deg(-30.0), deg(177.0)).unwrap();
+        assert_eq!(p.zone, 60);
+        assert_eq!(p.hemisphere, Hemisphere::South);
+        assert_relative_eq!(p.easting, 500_000.0, epsilon = 1e-6);
+        assert_relative_eq!(p.scale, 0.9996, epsilon = 1e-12);
+        assert_relative_eq!(p.convergence, 0.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_utm_zone_exceptions() {
+        assert_eq!(Utm::zone(deg(60.0), deg(5.0)), 32);
+        assert_eq!(Utm::zone(deg(78.0), deg(15.0)), 33);
+        assert_eq!(Utm::zone(deg(78.0), deg(40.0)), 37);
+        assert_eq!(Utm::zone(deg(40.0), deg(-179.0)), 1);
+        assert_eq!(Utm::latitude_band(deg(84.0)), Some('X'));
+        assert_eq!(Utm::latitude_band(deg(-80.0)), Some('C'));
+        assert_eq!(Utm::latitude_band(deg(85.0)), None);
+        assert_eq!(Utm::wgs84().forward(deg(85.0), 0.0), Err(ProjectionError::LatitudeOutOfRange));
+    }
+
+    #[test]
+    fn test_utm_roundtrip() {
+        let utm = Utm::wgs84();
+        for &(lat, lon) in &[(47.37, 8.54), (-33.86, 151.21), (64.1, -21.9), (-79.9, -70.0), (83.9, 10.0)] {
+            let p = utm.forward(deg(lat), deg(lon)).unwrap();
+            let (lat2, lon2) = utm.inverse(&p).unwrap();
+            assert_relative_eq!(lat2, deg(lat), epsilon = 1e-12);
+            assert_relative_eq!(lon2, deg(lon), epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_transverse_mercator_far_from_meridian() {
+        let tm = TransverseMercator::with_central_meridian(Ellipsoid::wgs84(), 0.0, 1.0);
+        let p = tm.forward(deg(30.0), deg(25.0));
+        let (lat, lon) = tm.inverse(p.easting, p.northing);
+        assert_relative_eq!(lat, deg(30.0), epsilon = 1e-12);
+        assert_relative_eq!(lon, deg(25.0), epsilon = 1e-12);
+        assert!(p.scale > 1.0);
+    }
+
+    #[test]
+    fn test_ups_poles() {
+        let ups = Ups::wgs84();
+        let north = ups.forward(FRAC_PI_2, 0.0).unwrap();
+        assert_relative_eq!(north.easting, 2_000_000.0, epsilon = 1e-6);
+        assert_relative_eq!(north.northing, 2_000_000.0, epsilon = 1e-6);
+        assert_relative_eq!(north.scale, 0.994, epsilon = 1e-12);
+
+        for &(lat, lon) in &[(85.0, 30.0), (-87.5, -135.0), (-84.0, 100.0)] {
+            let p = ups.forward(deg(lat), deg(lon)).unwrap();
+            let (lat2, lon2) = ups.inverse(&p);
+            assert_relative_eq!(lat2, deg(lat), epsilon = 1e-12);
+            assert_relative_eq!(lon2, deg(lon), epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_ups_latitude_limits() {
+        let ups = Ups::wgs84();
+        assert!(ups.forward(deg(84.0), 0.0).is_ok());
+        assert!(ups.forward(deg(-80.0), 0.0).is_ok());
+        for &lat in &[-10.0, 0.0, 83.9, -79.9] {
+            assert_eq!(ups.forward(deg(lat), 0.0), Err(ProjectionError::LatitudeOutOfRange));
+        }
+    }
+
+    #[test]
+    fn test_polar_stereographic_snyder() {
+        // Snyder (1987), numerical example for the ellipsoidal polar stereographic
+        let international = Ellipsoid::new(6_378_388.0, 1.0 / 297.0);
+        let ps = PolarStereographic::with_standard_parallel(international, Hemisphere::South, deg(-100.0), deg(-71.0), 0.0, 0.0);
+        let p = ps.forward(deg(-75.0), deg(150.0)).unwrap();
+        assert_relative_eq!(p.easting, -1_540_033.6, epsilon = 1.0);
+        assert_relative_eq!(p.northing, -560_526.4, epsilon = 1.0);
+        let (lat, lon) = ps.inverse(p.easting, p.northing);
+        assert_relative_eq!(lat, deg(-75.0), epsilon = 1e-12);
+        assert_relative_eq!(lon, deg(150.0), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_lambert_conformal_snyder() {
+        // Snyder (1987), numerical example for the ellipsoidal Lambert conformal conic
+        let clarke1866 = Ellipsoid::new(6_378_206.4, 1.0 / 294.978_698_214);
+        let lcc = LambertConformalConic::new(clarke1866, deg(-96.0), deg(23.0), deg(33.0), deg(45.0), 0.0, 0.0);
+        let p = lcc.forward(deg(35.0), deg(-75.0)).unwrap();
+        assert_relative_eq!(p.easting, 1_894_410.9, epsilon = 1.0);
+        assert_relative_eq!(p.northing, 1_564_649.5, epsilon = 1.0);
+        let (lat, lon) = lcc.inverse(p.easting, p.northing);
+        assert_relative_eq!(lat, deg(35.0), epsilon = 1e-12);
+        assert_relative_eq!(lon, deg(-75.0), epsilon = 1e-12);
+
+        // True to scale on both standard parallels
+        assert_relative_eq!(lcc.forward(deg(33.0), deg(-96.0)).unwrap().scale, 1.0, epsilon = 1e-12);
+        assert_relative_eq!(lcc.forward(deg(45.0), deg(-90.0)).unwrap().scale, 1.0, epsilon = 1e-12);
+    }
+}
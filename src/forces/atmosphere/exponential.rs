@@ -0,0 +1,102 @@
+use std::vec::Vec;
+
+use super::{height, Atmosphere};
+use crate::coordinate::Cartesian;
+use crate::time::Epoch;
+
+/// A layer of an exponential atmosphere starting at `base` height (m), with
+/// density `density` (kg/m^3) there and scale height `scale_height` (m).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExponentialLayer {
+    pub base: f64,
+    pub density: f64,
+    pub scale_height: f64,
+}
+
+/// Vallado, Fundamentals of Astrodynamics and Applications, table 8-4:
+/// base height (km), density (kg/m^3) and scale height (km).
+const VALLADO: [(f64, f64, f64); 28] = [
+    (0.0, 1.225, 7.249),
+    (25.0, 3.899e-2, 6.349),
+    (30.0, 1.774e-2, 6.682),
+    (40.0, 3.972e-3, 7.554),
+    (50.0, 1.057e-3, 8.382),
+    (60.0, 3.206e-4, 7.714),
+    (70.0, 8.770e-5, 6.549),
+    (80.0, 1.905e-5, 5.799),
+    (90.0, 3.396e-6, 5.382),
+    (100.0, 5.297e-7, 5.877),
+    (110.0, 9.661e-8, 7.263),
+    (120.0, 2.438e-8, 9.473),
+    (130.0, 8.484e-9, 12.636),
+    (140.0, 3.845e-9, 16.149),
+    (150.0, 2.070e-9, 22.523),
+    (180.0, 5.464e-10, 29.740),
+    (200.0, 2.789e-10, 37.105),
+    (250.0, 7.248e-11, 45.546),
+    (300.0, 2.418e-11, 53.628),
+    (350.0, 9.518e-12, 53.298),
+    (400.0, 3.725e-12, 58.515),
+    (450.0, 1.585e-12, 60.828),
+    (500.0, 6.967e-13, 63.822),
+    (600.0, 1.454e-13, 71.835),
+    (700.0, 3.614e-14, 88.667),
+    (800.0, 1.170e-14, 124.64),
+    (900.0, 5.245e-15, 181.05),
+    (1000.0, 3.019e-15, 268.00),
+];
+
+/// Piecewise exponential atmosphere. Below the first layer its density is
+/// extrapolated; above the last its scale height continues.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Exponential {
+    layers: Vec<ExponentialLayer>,
+}
+
+impl Exponential {
+    /// Layers sorted by increasing base height, or `None` if empty or
+    /// unsorted.
+    pub fn new(layers: Vec<ExponentialLayer>) -> Option<Self> {
+        if layers.is_empty() || layers.windows(2).any(|w| w[1].base <= w[0].base) {
+            return None;
+        }
+        Some(Self { layers })
+    }
+
+    /// A single layer with reference density `density` at `base` height.
+    pub fn single(base: f64, density: f64, scale_height: f64) -> Self {
+        Self { layers: vec![ExponentialLayer { base, density, scale_height }] }
+    }
+
+    /// The table of Vallado from 0 to 1000 km.
+    pub fn vallado() -> Self {
+        let layers = VALLADO
+            .iter()
+            .map(|&(base, density, scale_height)| ExponentialLayer { base: base * 1e3, density, scale_height: scale_height * 1e3 })
+            .collect();
+        Self { layers }
+    }
+
+    pub fn layers(&self) -> &[ExponentialLayer] {
+        &self.layers
+    }
+
+    /// Density (kg/m^3) at geodetic height `h` (m).
+    pub fn density_at_height(&self, h: f64) -> f64 {
+        let index = self.layers.partition_point(|layer| layer.base <= h).saturating_sub(1);
+        let layer = &self.layers[index];
+        layer.density * ((layer.base - h) / layer.scale_height).exp()
+    }
+}
+
+impl Default for Exponential {
+    fn default() -> Self {
+        Self::vallado()
+    }
+}
+
+impl Atmosphere for Exponential {
+    fn density<F>(&self, position: &Cartesian<f64, F>, _epoch: &Epoch) -> f64 {
+        self.density_at_height(height(position))
+    }
+}
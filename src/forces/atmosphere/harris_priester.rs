@@ -0,0 +1,131 @@
+use super::{height, log_interpolate, Atmosphere};
+use crate::coordinate::Cartesian;
+use crate::reference_frame::earth_orientation::gmst_1982;
+use crate::time::{Epoch, TimeScale};
+
+/// Montenbruck and Gill, Satellite Orbits, table 3.8 (mean solar activity):
+/// height (km) with the minimum and maximum density (g/km^3).
+const TABLE: [(f64, f64, f64); 50] = [
+    (100.0, 497_400.0, 497_400.0),
+    (120.0, 24_900.0, 24_900.0),
+    (130.0, 8_377.0, 8_710.0),
+    (140.0, 3_899.0, 4_059.0),
+    (150.0, 2_122.0, 2_215.0),
+    (160.0, 1_263.0, 1_344.0),
+    (170.0, 800.8, 875.8),
+    (180.0, 528.3, 601.0),
+    (190.0, 361.7, 429.7),
+    (200.0, 255.7, 316.2),
+    (210.0, 183.9, 239.6),
+    (220.0, 134.1, 185.3),
+    (230.0, 99.49, 145.5),
+    (240.0, 74.88, 115.7),
+    (250.0, 57.09, 93.08),
+    (260.0, 44.03, 75.55),
+    (270.0, 34.30, 61.82),
+    (280.0, 26.97, 50.95),
+    (290.0, 21.39, 42.26),
+    (300.0, 17.08, 35.26),
+    (320.0, 10.99, 25.11),
+    (340.0, 7.214, 18.19),
+    (360.0, 4.824, 13.37),
+    (380.0, 3.274, 9.955),
+    (400.0, 2.249, 7.492),
+    (420.0, 1.558, 5.684),
+    (440.0, 1.091, 4.355),
+    (460.0, 0.770_1, 3.362),
+    (480.0, 0.547_4, 2.612),
+    (500.0, 0.391_6, 2.042),
+    (520.0, 0.281_9, 1.605),
+    (540.0, 0.204_2, 1.267),
+    (560.0, 0.148_8, 1.005),
+    (580.0, 0.109_2, 0.799_7),
+    (600.0, 0.080_70, 0.639_0),
+    (620.0, 0.060_12, 0.512_3),
+    (640.0, 0.045_19, 0.412_1),
+    (660.0, 0.034_30, 0.332_5),
+    (680.0, 0.026_32, 0.269_1),
+    (700.0, 0.020_43, 0.218_5),
+    (720.0, 0.016_07, 0.177_9),
+    (740.0, 0.012_81, 0.145_2),
+    (760.0, 0.010_36, 0.119_0),
+    (780.0, 0.008_496, 0.097_76),
+    (800.0, 0.007_069, 0.080_59),
+    (840.0, 0.004_680, 0.057_41),
+    (880.0, 0.003_200, 0.042_10),
+    (920.0, 0.002_210, 0.031_30),
+    (960.0, 0.001_560, 0.023_60),
+    (1000.0, 0.001_150, 0.018_10),
+];
+
+/// Lag of the apex of the diurnal bulge behind the Sun's right ascension.
+const LAG: f64 = 30.0 * core::f64::consts::PI / 180.0;
+
+/// The Harris-Priester atmosphere: densities between a night-time minimum
+/// and a day-time maximum weighted by `cos^n(psi / 2)`, where `psi` is the
+/// angle from the apex of the diurnal bulge. Zero outside 100 to 1000 km.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HarrisPriester {
+    /// The exponent `n`, from 2 for low-inclination orbits to 6 for polar
+    /// ones
+    pub exponent: f64,
+}
+
+impl HarrisPriester {
+    pub fn new(exponent: f64) -> Self {
+        Self { exponent }
+    }
+
+    /// Minimum and maximum density (kg/m^3) at geodetic height `h` (m), or
+    /// `None` outside the table.
+    pub fn density_bounds(&self, h: f64) -> Option<(f64, f64)> {
+        let km = h / 1e3;
+        let (first, last) = (TABLE[0].0, TABLE[TABLE.len() - 1].0);
+        if km.is_nan() || km < first || km > last {
+            return None;
+        }
+        let upper = TABLE.partition_point(|row| row.0 <= km).clamp(1, TABLE.len() - 1);
+        let (a, b) = (TABLE[upper - 1], TABLE[upper]);
+        let min = log_interpolate(km, (a.0, a.1), (b.0, b.1));
+        let max = log_interpolate(km, (a.0, a.2), (b.0, b.2));
+        Some((min * 1e-12, max * 1e-12))
+    }
+
+    /// Earth-fixed unit vector to the apex of the diurnal bulge at `epoch`,
+    /// from the low-precision solar coordinates of the Astronomical Almanac
+    /// and Greenwich mean sidereal time.
+    pub fn bulge_apex(epoch: &Epoch) -> [f64; 3] {
+        let degrees = core::f64::consts::PI / 180.0;
+        let n = epoch.to_scale(TimeScale::TT).days_since_j2000();
+        let mean_longitude = 280.460 + 0.985_647_4 * n;
+        let anomaly = (357.528 + 0.985_600_3 * n) * degrees;
+        let longitude = (mean_longitude + 1.915 * anomaly.sin() + 0.020 * (2.0 * anomaly).sin()) * degrees;
+        let obliquity = (23.439 - 4e-7 * n) * degrees;
+        let right_ascension = (obliquity.cos() * longitude.sin()).atan2(longitude.cos());
+        let declination = (obliquity.sin() * longitude.sin()).asin();
+
+        let hour_angle = right_ascension + LAG - gmst_1982(&epoch.to_scale(TimeScale::UT1));
+        let (sin_dec, cos_dec) = declination.sin_cos();
+        [cos_dec * hour_angle.cos(), cos_dec * hour_angle.sin(), sin_dec]
+    }
+}
+
+impl Default for HarrisPriester {
+    fn default() -> Self {
+        Self::new(4.0)
+    }
+}
+
+impl Atmosphere for HarrisPriester {
+    fn density<F>(&self, position: &Cartesian<f64, F>, epoch: &Epoch) -> f64 {
+        let Some((min, max)) = self.density_bounds(height(position)) else {
+            return 0.0;
+        };
+        let apex = Self::bulge_apex(epoch);
+        let r = position.data.data;
+        let cos_psi = (r[0] * apex[0] + r[1] * apex[1] + r[2] * apex[2]) / position.norm();
+        // cos^2(psi / 2)
+        let cos2 = (0.5 + 0.5 * cos_psi).max(0.0);
+        min + (max - min) * cos2.powf(self.exponent / 2.0)
+    }
+}
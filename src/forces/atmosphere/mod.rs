@@ -0,0 +1,51 @@
+//! Atmospheric density models for drag.
+//!
+//! Models implement [`Atmosphere`], giving the density in kg/m^3 at a
+//! position in the ITRF (m) and an epoch. Heights are geodetic above the
+//! WGS84 ellipsoid.
+//!
+//! - [`Exponential`]: piecewise exponential profile, by default the table of
+//!   Vallado from 0 to 1000 km;
+//! - [`UsStandard1976`]: the U.S. Standard Atmosphere 1976, computed exactly
+//!   below 86 km and interpolated in its tabulated densities above, up to
+//!   1000 km;
+//! - [`HarrisPriester`]: the Harris-Priester model for mean solar activity,
+//!   with the diurnal bulge lagging the Sun by 30 degrees, from 100 to
+//!   1000 km.
+//!
+//! None of these depend on solar or geomagnetic activity. Thermospheric
+//! models which do take their F10.7 and Ap inputs from a
+//! [`SpaceWeatherProvider`]: constant [`SpaceWeather`] values or a
+//! [`SpaceWeatherTable`] read from a CelesTrak space weather file.
+//!
+//! NRLMSISE-00 is not implemented yet; it is planned as a separate item that
+//! embeds the published coefficients and reads [`SpaceWeather::ap_history`]
+//! in its NRLMSISE-00 layout.
+
+mod exponential;
+mod harris_priester;
+mod space_weather;
+mod us_standard;
+pub use exponential::{Exponential, ExponentialLayer};
+pub use harris_priester::HarrisPriester;
+pub use space_weather::{SpaceWeather, SpaceWeatherError, SpaceWeatherProvider, SpaceWeatherRecord, SpaceWeatherTable};
+pub use us_standard::UsStandard1976;
+
+use crate::coordinate::{Cartesian, Ellipsoid, Geodetic};
+use crate::time::Epoch;
+
+/// Atmospheric density as a function of position and time.
+pub trait Atmosphere {
+    /// Density (kg/m^3) at an ITRF `position` (m) at `epoch`.
+    fn density<F>(&self, position: &Cartesian<f64, F>, epoch: &Epoch) -> f64;
+}
+
+/// Geodetic height (m) above the WGS84 ellipsoid.
+fn height<F>(position: &Cartesian<f64, F>) -> f64 {
+    Geodetic::from_cartesian(position, &Ellipsoid::wgs84()).height()
+}
+
+/// Exponential interpolation between `(h0, rho0)` and `(h1, rho1)`.
+fn log_interpolate(h: f64, (h0, rho0): (f64, f64), (h1, rho1): (f64, f64)) -> f64 {
+    rho0 * (rho1 / rho0).powf((h - h0) / (h1 - h0))
+}
@@ -0,0 +1,179 @@
+//! Solar and geomagnetic activity indices.
+
+use std::vec::Vec;
+
+use crate::time::{Calendar, Epoch, TimeScale};
+
+/// Activity indices driving thermospheric density models.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpaceWeather {
+    /// Observed 10.7 cm solar flux of the previous day (sfu)
+    pub f107: f64,
+    /// 81-day average of F10.7 centred on the day (sfu)
+    pub f107_average: f64,
+    /// Daily planetary geomagnetic index Ap
+    pub ap: f64,
+    /// 3-hourly ap in the NRLMSISE-00 layout: daily Ap, the current value,
+    /// the values 3, 6 and 9 hours before, and the averages of the eight
+    /// values 12 to 33 and 36 to 57 hours before
+    pub ap_history: [f64; 7],
+}
+
+impl SpaceWeather {
+    /// Constant activity: the same flux on every day and the same ap at
+    /// every time.
+    pub fn new(f107: f64, f107_average: f64, ap: f64) -> Self {
+        Self { f107, f107_average, ap, ap_history: [ap; 7] }
+    }
+}
+
+/// Source of space weather indices.
+pub trait SpaceWeatherProvider {
+    /// Indices at `epoch`.
+    fn space_weather(&self, epoch: &Epoch) -> SpaceWeather;
+}
+
+impl SpaceWeatherProvider for SpaceWeather {
+    fn space_weather(&self, _epoch: &Epoch) -> SpaceWeather {
+        *self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpaceWeatherError {
+    /// The file could not be read
+    #[cfg(feature = "std")]
+    Io(std::io::ErrorKind),
+    /// A malformed field on the given (1-based) line
+    Parse { line: usize },
+    /// A required CSV column is absent from the header
+    MissingColumn,
+    /// No usable records
+    Empty,
+    /// Record `index` does not follow its predecessor by exactly one day
+    NotConsecutive { index: usize },
+}
+
+/// Daily indices at UTC day `mjd`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpaceWeatherRecord {
+    pub mjd: i64,
+    /// Eight 3-hourly ap values, from 0h UTC
+    pub ap: [f64; 8],
+    /// Daily Ap
+    pub ap_daily: f64,
+    /// Observed F10.7 (sfu)
+    pub f107: f64,
+    /// 81-day centred average of the observed F10.7 (sfu)
+    pub f107_average: f64,
+}
+
+/// Daily space weather records. Outside the table the first or last record
+/// is used; [`SpaceWeatherTable::at`] reports that case instead.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SpaceWeatherTable {
+    records: Vec<SpaceWeatherRecord>,
+}
+
+impl SpaceWeatherTable {
+    /// Records must be on consecutive days.
+    pub fn new(records: Vec<SpaceWeatherRecord>) -> Result<Self, SpaceWeatherError> {
+        if records.is_empty() {
+            return Err(SpaceWeatherError::Empty);
+        }
+        if let Some(index) = records.windows(2).position(|w| w[1].mjd != w[0].mjd + 1) {
+            return Err(SpaceWeatherError::NotConsecutive { index: index + 1 });
+        }
+        Ok(Self { records })
+    }
+
+    pub fn records(&self) -> &[SpaceWeatherRecord] {
+        &self.records
+    }
+
+    /// Reads a CelesTrak space weather file (`SW-All.csv` or
+    /// `SW-Last5Years.csv`).
+    #[cfg(feature = "std")]
+    pub fn load_celestrak_csv(path: impl AsRef<std::path::Path>) -> Result<Self, SpaceWeatherError> {
+        let text = std::fs::read_to_string(path.as_ref()).map_err(|e| SpaceWeatherError::Io(e.kind()))?;
+        Self::parse_celestrak_csv(&text)
+    }
+
+    /// Parses the CelesTrak CSV format with the columns `DATE`, `AP1` to
+    /// `AP8`, `AP_AVG`, `F10.7_OBS` and `F10.7_OBS_CENTER81`. Rows without
+    /// observed flux are skipped, and missing 3-hourly values (monthly
+    /// predictions) take the daily Ap.
+    pub fn parse_celestrak_csv(text: &str) -> Result<Self, SpaceWeatherError> {
+        let mut lines = text.lines().enumerate().filter(|(_, line)| !line.trim().is_empty());
+        let (_, header) = lines.next().ok_or(SpaceWeatherError::Empty)?;
+        let names: Vec<&str> = header.split(',').map(str::trim).collect();
+        let find = |name: &str| names.iter().position(|n| n.eq_ignore_ascii_case(name)).ok_or(SpaceWeatherError::MissingColumn);
+        let (date, ap_daily, f107, f107_average) = (find("DATE")?, find("AP_AVG")?, find("F10.7_OBS")?, find("F10.7_OBS_CENTER81")?);
+        let mut ap_columns = [0; 8];
+        for (i, column) in ap_columns.iter_mut().enumerate() {
+            *column = find(&format!("AP{}", i + 1))?;
+        }
+
+        let mut records = Vec::new();
+        for (index, line) in lines {
+            let error = SpaceWeatherError::Parse { line: index + 1 };
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            let field = |column: usize| fields.get(column).copied().unwrap_or("");
+            let number = |column: usize| field(column).parse::<f64>().map_err(|_| error);
+            if field(f107).is_empty() || field(f107_average).is_empty() {
+                continue;
+            }
+            let mut day = field(date).splitn(3, '-').map(|part| part.parse::<i64>().map_err(|_| error));
+            let (year, month, day) = (day.next().ok_or(error)??, day.next().ok_or(error)??, day.next().ok_or(error)??);
+            let calendar = Calendar::date(year as i32, month as u8, day as u8);
+            let mjd = Epoch::from_calendar(calendar, TimeScale::UTC).map_err(|_| error)?.day();
+            let daily = number(ap_daily)?;
+            let mut ap = [daily; 8];
+            for (value, &column) in ap.iter_mut().zip(&ap_columns) {
+                if !field(column).is_empty() {
+                    *value = number(column)?;
+                }
+            }
+            records.push(SpaceWeatherRecord { mjd, ap, ap_daily: daily, f107: number(f107)?, f107_average: number(f107_average)? });
+        }
+        Self::new(records)
+    }
+
+    /// Indices at `epoch`, or `None` if it or the preceding days it needs
+    /// are outside the table.
+    pub fn at(&self, epoch: &Epoch) -> Option<SpaceWeather> {
+        let mjd = epoch.to_scale(TimeScale::UTC).day();
+        let (first, last) = (self.records.first()?.mjd, self.records.last()?.mjd);
+        if mjd - 3 < first || mjd > last {
+            return None;
+        }
+        Some(self.space_weather(epoch))
+    }
+
+    fn record(&self, mjd: i64) -> &SpaceWeatherRecord {
+        let first = self.records[0].mjd;
+        let index = (mjd - first).clamp(0, self.records.len() as i64 - 1);
+        &self.records[index as usize]
+    }
+}
+
+impl SpaceWeatherProvider for SpaceWeatherTable {
+    fn space_weather(&self, epoch: &Epoch) -> SpaceWeather {
+        let utc = epoch.to_scale(TimeScale::UTC);
+        let mjd = utc.day();
+        let today = self.record(mjd);
+        // 3-hour intervals counted back from the current one
+        let now = mjd * 8 + ((utc.seconds_of_day() / 10_800.0).floor() as i64).clamp(0, 7);
+        let ap = |back: i64| {
+            let slot = now - back;
+            self.record(slot.div_euclid(8)).ap[slot.rem_euclid(8) as usize]
+        };
+        let average = |from: i64| (from..from + 8).map(ap).sum::<f64>() / 8.0;
+        SpaceWeather {
+            f107: self.record(mjd - 1).f107,
+            f107_average: today.f107_average,
+            ap: today.ap_daily,
+            ap_history: [today.ap_daily, ap(0), ap(1), ap(2), ap(3), average(4), average(12)],
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use super::{height, log_interpolate, Atmosphere};
+use crate::coordinate::Cartesian;
+use crate::time::Epoch;
+
+/// Standard gravity (m/s^2), gas constant (J/(mol K)) and sea-level mean
+/// molar mass (kg/mol) of the 1976 standard.
+const G0: f64 = 9.806_65;
+const GAS_CONSTANT: f64 = 8.314_32;
+const MOLAR_MASS: f64 = 0.028_964_4;
+/// Effective Earth radius (m) for geopotential heights.
+const R0: f64 = 6_356_766.0;
+
+/// Base geopotential height (m) and molecular-scale temperature gradient
+/// (K/m) of the layers below 86 km geometric height.
+const LAYERS: [(f64, f64); 7] = [
+    (0.0, -6.5e-3),
+    (11_000.0, 0.0),
+    (20_000.0, 1.0e-3),
+    (32_000.0, 2.8e-3),
+    (47_000.0, 0.0),
+    (51_000.0, -2.8e-3),
+    (71_000.0, -2.0e-3),
+];
+
+/// Geometric height (m) where the tabulated upper atmosphere starts.
+const UPPER: f64 = 86_000.0;
+
+/// Tabulated densities of the upper atmosphere: geometric height (km) and
+/// density (kg/m^3).
+const DENSITIES: [(f64, f64); 22] = [
+    (86.0, 6.958e-6),
+    (90.0, 3.416e-6),
+    (100.0, 5.604e-7),
+    (110.0, 9.708e-8),
+    (120.0, 2.222e-8),
+    (130.0, 8.152e-9),
+    (140.0, 3.831e-9),
+    (150.0, 2.076e-9),
+    (160.0, 1.233e-9),
+    (180.0, 5.194e-10),
+    (200.0, 2.541e-10),
+    (250.0, 6.073e-11),
+    (300.0, 1.916e-11),
+    (350.0, 7.014e-12),
+    (400.0, 2.803e-12),
+    (450.0, 1.184e-12),
+    (500.0, 5.215e-13),
+    (600.0, 1.137e-13),
+    (700.0, 3.070e-14),
+    (800.0, 1.136e-14),
+    (900.0, 5.759e-15),
+    (1000.0, 3.561e-15),
+];
+
+/// The U.S. Standard Atmosphere 1976.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct UsStandard1976;
+
+impl UsStandard1976 {
+    /// Molecular-scale temperature (K) and pressure (Pa) at geometric height
+    /// `h` (m), for heights below 86 km.
+    pub fn temperature_and_pressure(&self, h: f64) -> Option<(f64, f64)> {
+        if h.is_nan() || h > UPPER {
+            return None;
+        }
+        let geopotential = R0 * h / (R0 + h);
+        let (mut temperature, mut pressure) = (288.15, 101_325.0);
+        for (i, &(base, gradient)) in LAYERS.iter().enumerate() {
+            let top = LAYERS.get(i + 1).map_or(f64::INFINITY, |layer| layer.0);
+            let dh = geopotential.min(top) - base;
+            let (next, ratio) = if gradient == 0.0 {
+                (temperature, (-G0 * MOLAR_MASS * dh / (GAS_CONSTANT * temperature)).exp())
+            } else {
+                let next = temperature + gradient * dh;
+                (next, (temperature / next).powf(G0 * MOLAR_MASS / (GAS_CONSTANT * gradient)))
+            };
+            pressure *= ratio;
+            temperature = next;
+            if geopotential <= top {
+                break;
+            }
+        }
+        Some((temperature, pressure))
+    }
+
+    /// Density (kg/m^3) at geometric height `h` (m).
+    pub fn density_at_height(&self, h: f64) -> f64 {
+        if let Some((temperature, pressure)) = self.temperature_and_pressure(h) {
+            return pressure * MOLAR_MASS / (GAS_CONSTANT * temperature);
+        }
+        let km = h / 1e3;
+        let upper = DENSITIES.partition_point(|&(node, _)| node <= km).clamp(1, DENSITIES.len() - 1);
+        log_interpolate(km, DENSITIES[upper - 1], DENSITIES[upper])
+    }
+}
+
+impl Atmosphere for UsStandard1976 {
+    fn density<F>(&self, position: &Cartesian<f64, F>, _epoch: &Epoch) -> f64 {
+        self.density_at_height(height(position))
+    }
+}
@@ -0,0 +1,122 @@
+//! Gravity field coefficient files.
+
+use num_traits::Float;
+use std::vec::Vec;
+
+use super::spherical_harmonics::index;
+use super::{GravityError, SphericalHarmonics};
+
+/// A parsed coefficient `(n, m, C_nm, S_nm)`.
+type Term = (usize, usize, f64, f64);
+
+/// Parses a number, also accepting Fortran `D` exponents.
+fn number(text: &str) -> Option<f64> {
+    text.replace(['D', 'd'], "E").parse().ok()
+}
+
+/// Full normalization factor `N_nm = sqrt((2 - delta_m0) (2n + 1) (n - m)! / (n + m)!)`.
+fn normalization(n: usize, m: usize) -> f64 {
+    let ratio: f64 = (n - m + 1..=n + m).map(|i| 1.0 / i as f64).product();
+    let delta = if m == 0 { 1.0 } else { 2.0 };
+    (delta * (2 * n + 1) as f64 * ratio).sqrt()
+}
+
+impl<T: Float> SphericalHarmonics<T> {
+    /// Parses fully normalized coefficients with one `n m C S` row per line
+    /// (further columns, such as the standard deviations, are ignored), as in
+    /// the NGA EGM96 (`egm96_to360.ascii`) and EGM2008
+    /// (`EGM2008_to2190_TideFree`) tables. Terms beyond `max_degree` are
+    /// skipped, missing ones are zero and `C_00` defaults to one.
+    pub fn parse_table(text: &str, mu: T, radius: T, max_degree: usize) -> Result<Self, GravityError> {
+        let mut terms = Vec::new();
+        for (index, line) in text.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            if let Some(term) = parse_term(line.split_whitespace(), max_degree, index + 1)? {
+                terms.push(term);
+            }
+        }
+        Self::from_terms(mu, radius, &terms)
+    }
+
+    /// Parses an ICGEM gravity field file (`.gfc`), taking `mu` and the
+    /// reference radius from the `earth_gravity_constant` and `radius` header
+    /// keywords. Unnormalized coefficients (`norm unnormalized`) are
+    /// normalized; only the static `gfc` and `gfct` terms are read.
+    pub fn parse_icgem(text: &str, max_degree: usize) -> Result<Self, GravityError> {
+        let mut lines = text.lines().enumerate();
+        let (mut mu, mut radius, mut normalized) = (None, None, true);
+        for (index, line) in lines.by_ref() {
+            let mut fields = line.split_whitespace();
+            let error = GravityError::Parse { line: index + 1 };
+            match fields.next() {
+                Some("end_of_head") => break,
+                Some("earth_gravity_constant" | "gravity_constant") => mu = Some(fields.next().and_then(number).ok_or(error)?),
+                Some("radius") => radius = Some(fields.next().and_then(number).ok_or(error)?),
+                Some("norm") => normalized = fields.next() != Some("unnormalized"),
+                _ => {}
+            }
+        }
+        let (mu, radius) = (mu.ok_or(GravityError::MissingConstant)?, radius.ok_or(GravityError::MissingConstant)?);
+
+        let mut terms = Vec::new();
+        for (index, line) in lines {
+            let mut fields = line.split_whitespace();
+            if !matches!(fields.next(), Some("gfc" | "gfct")) {
+                continue;
+            }
+            if let Some((n, m, c, s)) = parse_term(fields, max_degree, index + 1)? {
+                let scale = if normalized { 1.0 } else { 1.0 / normalization(n, m) };
+                terms.push((n, m, c * scale, s * scale));
+            }
+        }
+        Self::from_terms(T::from(mu).unwrap(), T::from(radius).unwrap(), &terms)
+    }
+
+    /// Reads a table of normalized coefficients, see
+    /// [`SphericalHarmonics::parse_table`].
+    #[cfg(feature = "std")]
+    pub fn load_table(path: impl AsRef<std::path::Path>, mu: T, radius: T, max_degree: usize) -> Result<Self, GravityError> {
+        Self::parse_table(&read(path.as_ref())?, mu, radius, max_degree)
+    }
+
+    /// Reads an ICGEM `.gfc` file, see [`SphericalHarmonics::parse_icgem`].
+    #[cfg(feature = "std")]
+    pub fn load_icgem(path: impl AsRef<std::path::Path>, max_degree: usize) -> Result<Self, GravityError> {
+        Self::parse_icgem(&read(path.as_ref())?, max_degree)
+    }
+
+    fn from_terms(mu: T, radius: T, terms: &[Term]) -> Result<Self, GravityError> {
+        let degree = terms.iter().map(|term| term.0).max().ok_or(GravityError::Empty)?;
+        let size = index(degree, degree) + 1;
+        let (mut c, mut s) = (vec![T::zero(); size], vec![T::zero(); size]);
+        c[0] = T::one();
+        for &(n, m, cnm, snm) in terms {
+            c[index(n, m)] = T::from(cnm).unwrap();
+            s[index(n, m)] = T::from(snm).unwrap();
+        }
+        Self::new(mu, radius, c, s)
+    }
+}
+
+/// The `n m C S` fields of a row, or `None` beyond `max_degree`.
+fn parse_term<'a>(mut fields: impl Iterator<Item = &'a str>, max_degree: usize, line: usize) -> Result<Option<Term>, GravityError> {
+    let error = GravityError::Parse { line };
+    let n: usize = fields.next().and_then(|f| f.parse().ok()).ok_or(error)?;
+    let m: usize = fields.next().and_then(|f| f.parse().ok()).ok_or(error)?;
+    if m > n {
+        return Err(error);
+    }
+    if n > max_degree {
+        return Ok(None);
+    }
+    let c = fields.next().and_then(number).ok_or(error)?;
+    let s = fields.next().and_then(number).ok_or(error)?;
+    Ok(Some((n, m, c, s)))
+}
+
+#[cfg(feature = "std")]
+fn read(path: &std::path::Path) -> Result<std::string::String, GravityError> {
+    std::fs::read_to_string(path).map_err(|e| GravityError::Io(e.kind()))
+}
@@ -0,0 +1,39 @@
+use super::atmosphere::Atmosphere;
+use crate::coordinate::Cartesian;
+use crate::time::Epoch;
+
+/// Nominal rotation rate of the Earth (rad/s), IERS.
+pub const EARTH_ROTATION_RATE: f64 = 7.292_115_146_706_979e-5;
+
+/// Aerodynamic drag in an atmosphere co-rotating with the Earth.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Drag<A> {
+    pub atmosphere: A,
+    /// Ballistic coefficient `m / (C_D A)` (kg/m^2)
+    pub ballistic_coefficient: f64,
+    /// Rotation rate of the atmosphere about the `z` axis (rad/s)
+    pub rotation_rate: f64,
+}
+
+impl<A: Atmosphere> Drag<A> {
+    /// Drag with the atmosphere rotating at [`EARTH_ROTATION_RATE`].
+    pub fn new(atmosphere: A, ballistic_coefficient: f64) -> Self {
+        Self { atmosphere, ballistic_coefficient, rotation_rate: EARTH_ROTATION_RATE }
+    }
+
+    /// Drag acceleration `-rho / (2 B) |v_rel| v_rel` at an ITRF `position`
+    /// (m), for an inertial `velocity` (m/s) resolved in the ITRF axes. The
+    /// velocity relative to the atmosphere is `v_rel = v - w x r`.
+    pub fn acceleration<F>(&self, position: &Cartesian<f64, F>, velocity: &Cartesian<f64, F>, epoch: &Epoch) -> Cartesian<f64, F> {
+        let rho = self.atmosphere.density(position, epoch);
+        let relative = self.relative_velocity(position, velocity);
+        let scale = -0.5 * rho / self.ballistic_coefficient * relative.norm();
+        Cartesian::from_vector(relative.data * scale)
+    }
+
+    /// Velocity relative to the co-rotating atmosphere.
+    pub fn relative_velocity<F>(&self, position: &Cartesian<f64, F>, velocity: &Cartesian<f64, F>) -> Cartesian<f64, F> {
+        let w = self.rotation_rate;
+        Cartesian::new(velocity.x() + w * position.y(), velocity.y() - w * position.x(), velocity.z())
+    }
+}
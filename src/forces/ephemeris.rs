@@ -0,0 +1,76 @@
+//! Low-precision analytic Sun and Moon ephemerides.
+//!
+//! Geocentric positions in the mean equator and equinox of J2000 (EME2000,
+//! within 23 mas of the GCRF), from the series of Montenbruck and Gill,
+//! Satellite Orbits, section 3.3.2, themselves truncated from the theories
+//! used by Meeus. The Sun is good to about 0.01% in distance and 2 arcmin in
+//! direction, the Moon to a few hundred km and several arcmin, which suffices
+//! for perturbations and shadow conditions.
+
+use crate::coordinate::Cartesian;
+use crate::time::{Epoch, TimeScale};
+
+const ARCSEC: f64 = core::f64::consts::PI / 648_000.0;
+const TWO_PI: f64 = core::f64::consts::TAU;
+/// Obliquity of the ecliptic at J2000.0.
+const OBLIQUITY: f64 = 23.439_291_11 * core::f64::consts::PI / 180.0;
+
+fn fraction(x: f64) -> f64 {
+    x - x.floor()
+}
+
+/// Equatorial coordinates of an ecliptic vector, rotating by the obliquity.
+fn from_ecliptic<F>(x: f64, y: f64, z: f64) -> Cartesian<f64, F> {
+    let (sin_e, cos_e) = OBLIQUITY.sin_cos();
+    Cartesian::new(x, cos_e * y - sin_e * z, sin_e * y + cos_e * z)
+}
+
+/// Geocentric position of the Sun (m) at `epoch`.
+pub fn sun_position<F>(epoch: &Epoch) -> Cartesian<f64, F> {
+    let t = epoch.to_scale(TimeScale::TT).julian_centuries();
+    let m = TWO_PI * fraction(0.993_126_7 + 99.997_358_3 * t);
+    let longitude = TWO_PI * fraction(0.785_944_4 + m / TWO_PI + (6_892.0 * m.sin() + 72.0 * (2.0 * m).sin()) / 1_296e3);
+    let r = 149.619e9 - 2.499e9 * m.cos() - 0.021e9 * (2.0 * m).cos();
+    from_ecliptic(r * longitude.cos(), r * longitude.sin(), 0.0)
+}
+
+/// Geocentric position of the Moon (m) at `epoch`.
+pub fn moon_position<F>(epoch: &Epoch) -> Cartesian<f64, F> {
+    let t = epoch.to_scale(TimeScale::TT).julian_centuries();
+    // Mean longitude (revolutions), mean anomalies of the Moon and the Sun,
+    // mean elongation and argument of latitude
+    let l0 = fraction(0.606_433 + 1_336.851_344 * t);
+    let l = TWO_PI * fraction(0.374_897 + 1_325.552_410 * t);
+    let lp = TWO_PI * fraction(0.993_133 + 99.997_361 * t);
+    let d = TWO_PI * fraction(0.827_361 + 1_236.853_086 * t);
+    let f = TWO_PI * fraction(0.259_086 + 1_342.227_825 * t);
+
+    let dl = 22_640.0 * l.sin() - 4_586.0 * (l - 2.0 * d).sin() + 2_370.0 * (2.0 * d).sin() + 769.0 * (2.0 * l).sin()
+        - 668.0 * lp.sin()
+        - 412.0 * (2.0 * f).sin()
+        - 212.0 * (2.0 * l - 2.0 * d).sin()
+        - 206.0 * (l + lp - 2.0 * d).sin()
+        + 192.0 * (l + 2.0 * d).sin()
+        - 165.0 * (lp - 2.0 * d).sin()
+        - 125.0 * d.sin()
+        - 110.0 * (l + lp).sin()
+        + 148.0 * (l - lp).sin()
+        - 55.0 * (2.0 * f - 2.0 * d).sin();
+    let longitude = TWO_PI * fraction(l0 + dl / 1_296e3);
+
+    let s = f + (dl + 412.0 * (2.0 * f).sin() + 541.0 * lp.sin()) * ARCSEC;
+    let h = f - 2.0 * d;
+    let n = -526.0 * h.sin() + 44.0 * (l + h).sin() - 31.0 * (h - l).sin() - 23.0 * (lp + h).sin() + 11.0 * (h - lp).sin()
+        - 25.0 * (f - 2.0 * l).sin()
+        + 21.0 * (f - l).sin();
+    let latitude = (18_520.0 * s.sin() + n) * ARCSEC;
+
+    let r = 385_000e3 - 20_905e3 * l.cos() - 3_699e3 * (2.0 * d - l).cos() - 2_956e3 * (2.0 * d).cos()
+        - 570e3 * (2.0 * l).cos()
+        + 246e3 * (2.0 * l - 2.0 * d).cos()
+        - 205e3 * (lp - 2.0 * d).cos()
+        - 171e3 * (l + 2.0 * d).cos()
+        - 152e3 * (l + lp - 2.0 * d).cos();
+    let (sin_b, cos_b) = latitude.sin_cos();
+    from_ecliptic(r * longitude.cos() * cos_b, r * longitude.sin() * cos_b, r * sin_b)
+}
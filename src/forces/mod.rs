@@ -0,0 +1,80 @@
+//! Gravitational force models for orbit propagation.
+//!
+//! All models implement [`GravityField`] and return the acceleration in the
+//! frame of the position, which for anything but a point mass must be the
+//! body-fixed frame of the attracting body (e.g. the ITRF for the Earth):
+//!
+//! - [`PointMass`]: the central `-mu r / r^3` term;
+//! - [`ZonalHarmonics`]: point mass with the closed-form `J2`, `J3` and `J4`
+//!   accelerations of an axisymmetric body;
+//! - [`SphericalHarmonics`]: a full field of degree and order `n x m` with
+//!   fully normalized coefficients, read from EGM96/EGM2008 tables or ICGEM
+//!   `.gfc` files (GGM, EIGEN, ...).
+//!
+//! [`third_body_acceleration`] adds the pull of the Sun and the Moon, placed
+//! by the analytic [`sun_position`] and [`moon_position`] in the EME2000
+//! frame. [`Drag`] gives the aerodynamic acceleration in one of the density
+//! models of the [`atmosphere`] module, and [`Cannonball`] and [`FlatPlate`]
+//! the solar radiation pressure, dimmed in eclipses by the illumination of a
+//! [`Shadow`] model. [`eclipse_intervals`] lists the passages through the
+//! Earth's shadow.
+//!
+//! [`PointMass`] and [`SphericalHarmonics`] also provide the gravity gradient
+//! `da/dr` for variational equations. Units follow `mu` and the reference
+//! radius, e.g. m and m^3/s^2 for the built-in Earth constants.
+
+pub mod atmosphere;
+mod coefficients;
+mod drag;
+mod ephemeris;
+mod point_mass;
+mod radiation_pressure;
+mod shadow;
+mod spherical_harmonics;
+mod third_body;
+mod zonal;
+pub use drag::{Drag, EARTH_ROTATION_RATE};
+pub use ephemeris::{moon_position, sun_position};
+pub use point_mass::PointMass;
+pub use radiation_pressure::{Cannonball, FlatPlate, ASTRONOMICAL_UNIT, SOLAR_PRESSURE};
+pub use shadow::{eclipse_intervals, EclipseInterval, Shadow, MOON_RADIUS, SUN_RADIUS};
+pub use spherical_harmonics::SphericalHarmonics;
+pub use third_body::{luni_solar_acceleration, third_body_acceleration, MU_MOON, MU_SUN};
+pub use zonal::{j2_acceleration, j3_acceleration, j4_acceleration, ZonalHarmonics};
+
+use num_traits::Float;
+
+use crate::coordinate::Cartesian;
+
+/// Equatorial radius of the Earth, EGM2008 (m).
+pub const EARTH_RADIUS: f64 = 6_378_136.3;
+/// Zonal harmonics of the Earth from the EGM2008 tide-free coefficients.
+pub const J2_EARTH: f64 = 1.082_626_173_852_22e-3;
+pub const J3_EARTH: f64 = -2.532_410_518_567_72e-6;
+pub const J4_EARTH: f64 = -1.619_897_599_916_97e-6;
+
+/// A gravity field evaluated in the frame of the position.
+pub trait GravityField<T: Float> {
+    /// Gravitational acceleration at `position`.
+    fn acceleration<F>(&self, position: &Cartesian<T, F>) -> Cartesian<T, F>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GravityError {
+    /// The file could not be read
+    #[cfg(feature = "std")]
+    Io(std::io::ErrorKind),
+    /// A malformed line at the given (1-based) line number
+    Parse { line: usize },
+    /// The gravitational constant or reference radius is missing from an
+    /// ICGEM header
+    MissingConstant,
+    /// Coefficient arrays not matching the degree
+    InvalidSize,
+    /// No coefficients
+    Empty,
+}
+
+#[cfg(test)]
+#[path = "tests/mod.rs"]
+mod tests;
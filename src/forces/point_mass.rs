@@ -0,0 +1,40 @@
+use num_traits::Float;
+
+use super::GravityField;
+use crate::coordinate::Cartesian;
+use crate::math::Matrix;
+
+/// Gravity of a point mass (or spherically symmetric body).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PointMass<T: Float> {
+    pub mu: T,
+}
+
+impl<T: Float> PointMass<T> {
+    pub fn new(mu: T) -> Self {
+        Self { mu }
+    }
+
+    /// Gravity gradient `da/dr = mu (3 r r^T - r^2 I) / r^5`.
+    pub fn gradient<F>(&self, position: &Cartesian<T, F>) -> Matrix<T, 3, 3> {
+        let r = position.data.data;
+        let r2 = position.dot(position);
+        let scale = self.mu / (r2 * r2 * r2.sqrt());
+        let three = T::from(3.0).unwrap();
+        let mut gradient = Matrix::new([[T::zero(); 3]; 3]);
+        for i in 0..3 {
+            for j in 0..3 {
+                let diagonal = if i == j { r2 } else { T::zero() };
+                gradient[(i, j)] = scale * (three * r[i] * r[j] - diagonal);
+            }
+        }
+        gradient
+    }
+}
+
+impl<T: Float> GravityField<T> for PointMass<T> {
+    fn acceleration<F>(&self, position: &Cartesian<T, F>) -> Cartesian<T, F> {
+        let r = position.norm();
+        Cartesian::from_vector(position.data * (-self.mu / (r * r * r)))
+    }
+}
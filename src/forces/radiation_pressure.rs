@@ -0,0 +1,77 @@
+use crate::coordinate::Cartesian;
+
+/// Astronomical unit (m), IAU 2012.
+pub const ASTRONOMICAL_UNIT: f64 = 149_597_870_700.0;
+/// Solar radiation pressure on an absorbing surface at 1 au (N/m^2), from a
+/// solar flux of 1367 W/m^2.
+pub const SOLAR_PRESSURE: f64 = 4.56e-6;
+
+/// Radiation pressure at distance `d` (m) from the Sun.
+fn pressure(d: f64) -> f64 {
+    SOLAR_PRESSURE * (ASTRONOMICAL_UNIT / d) * (ASTRONOMICAL_UNIT / d)
+}
+
+/// Solar radiation pressure on a sphere, or on an object of constant
+/// cross-section facing the Sun.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cannonball {
+    /// Radiation pressure coefficient `C_R`, 1 for a black body and 2 for a
+    /// perfect mirror facing the Sun
+    pub reflectivity: f64,
+    /// Cross-section over mass (m^2/kg)
+    pub area_to_mass: f64,
+}
+
+impl Cannonball {
+    pub fn new(reflectivity: f64, area_to_mass: f64) -> Self {
+        Self { reflectivity, area_to_mass }
+    }
+
+    /// Acceleration `P C_R A/m (au/d)^2 e` in full sunlight at `position`
+    /// (m), with the Sun at `sun` (m) and `e` the unit vector from the Sun;
+    /// scale by a [`Shadow`](super::Shadow) illumination in eclipses.
+    pub fn acceleration<F>(&self, position: &Cartesian<f64, F>, sun: &Cartesian<f64, F>) -> Cartesian<f64, F> {
+        let from_sun = position.data - sun.data;
+        let d = from_sun.norm();
+        Cartesian::from_vector(from_sun * (pressure(d) * self.reflectivity * self.area_to_mass / d))
+    }
+}
+
+/// Solar radiation pressure on a flat plate which absorbs, reflects
+/// specularly or reflects diffusely. The back of the plate is not
+/// illuminated.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlatPlate {
+    /// Plate area over mass (m^2/kg)
+    pub area_to_mass: f64,
+    /// Fraction of the incident light reflected specularly
+    pub specular: f64,
+    /// Fraction of the incident light reflected diffusely (Lambertian);
+    /// the rest is absorbed
+    pub diffuse: f64,
+}
+
+impl FlatPlate {
+    pub fn new(area_to_mass: f64, specular: f64, diffuse: f64) -> Self {
+        Self { area_to_mass, specular, diffuse }
+    }
+
+    /// Acceleration in full sunlight on a plate at `position` (m) facing
+    /// `normal`, with the Sun at `sun` (m):
+    /// `-P A/m (au/d)^2 cos(theta) [(1 - s) e + 2 (s cos(theta) + d / 3) n]`
+    /// where `e` points to the Sun, `n` is the unit normal and
+    /// `cos(theta) = n . e`.
+    pub fn acceleration<F>(&self, position: &Cartesian<f64, F>, sun: &Cartesian<f64, F>, normal: &Cartesian<f64, F>) -> Cartesian<f64, F> {
+        let to_sun = sun.data - position.data;
+        let d = to_sun.norm();
+        let e = to_sun / d;
+        let n = normal.data / normal.norm();
+        let cos_theta = n.dot(&e);
+        if cos_theta <= 0.0 {
+            return Cartesian::new(0.0, 0.0, 0.0);
+        }
+        let scale = -pressure(d) * self.area_to_mass * cos_theta;
+        let along_normal = 2.0 * (self.specular * cos_theta + self.diffuse / 3.0);
+        Cartesian::from_vector((e * (1.0 - self.specular) + n * along_normal) * scale)
+    }
+}
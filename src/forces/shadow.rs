@@ -0,0 +1,162 @@
+use std::vec::Vec;
+
+use super::ephemeris::sun_position;
+use crate::coordinate::Cartesian;
+use crate::time::{Duration, Epoch};
+
+/// Radius of the solar photosphere (m), IAU 2015 nominal.
+pub const SUN_RADIUS: f64 = 695_700e3;
+/// Mean radius of the Moon (m).
+pub const MOON_RADIUS: f64 = 1_737_400.0;
+
+/// Geometry of the shadow cast by an occulting body.
+///
+/// Positions of the object and the Sun are relative to the centre of the
+/// occulting body, in any common frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shadow {
+    /// A cylinder of the body's radius behind it: fully lit or fully dark
+    Cylindrical,
+    /// The umbra and penumbra cones of a Sun of finite size, with the lit
+    /// fraction of the solar disk in the penumbra
+    Conical,
+}
+
+impl Shadow {
+    /// Fraction of the solar disk visible from `position`, from 0 in the
+    /// umbra to 1 in full sunlight, behind a body of radius `radius`.
+    pub fn illumination<F>(&self, position: &Cartesian<f64, F>, sun: &Cartesian<f64, F>, radius: f64) -> f64 {
+        match self {
+            Shadow::Cylindrical => cylindrical(position, sun, radius),
+            Shadow::Conical => conical(position, sun, radius),
+        }
+    }
+}
+
+fn cylindrical<F>(position: &Cartesian<f64, F>, sun: &Cartesian<f64, F>, radius: f64) -> f64 {
+    let unit = sun.data / sun.norm();
+    let along = position.data.dot(&unit);
+    if along < 0.0 && (position.data - unit * along).norm() < radius {
+        0.0
+    } else {
+        1.0
+    }
+}
+
+/// Montenbruck and Gill, Satellite Orbits, section 3.4.2: overlap of the
+/// apparent disks of the Sun and the occulting body.
+fn conical<F>(position: &Cartesian<f64, F>, sun: &Cartesian<f64, F>, radius: f64) -> f64 {
+    let to_sun = sun.data - position.data;
+    let (r, d) = (position.norm(), to_sun.norm());
+    if r <= radius {
+        return 0.0;
+    }
+    // Apparent radii of the Sun and the body, and their separation
+    let a = (SUN_RADIUS / d).min(1.0).asin();
+    let b = (radius / r).asin();
+    let c = (-position.data.dot(&to_sun) / (r * d)).clamp(-1.0, 1.0).acos();
+    if c >= a + b {
+        1.0
+    } else if c <= b - a {
+        0.0
+    } else if c <= a - b {
+        // Annular: the body is entirely inside the solar disk
+        1.0 - b * b / (a * a)
+    } else {
+        let x = (c * c + a * a - b * b) / (2.0 * c);
+        let y = (a * a - x * x).max(0.0).sqrt();
+        let area = a * a * (x / a).clamp(-1.0, 1.0).acos() + b * b * ((c - x) / b).clamp(-1.0, 1.0).acos() - c * y;
+        1.0 - area / (core::f64::consts::PI * a * a)
+    }
+}
+
+/// A passage through the shadow.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EclipseInterval {
+    /// Entry into and exit from the shadow (the penumbra for the conical
+    /// model)
+    pub start: Epoch,
+    pub end: Epoch,
+    /// Entry into and exit from the umbra, if reached
+    pub umbra: Option<(Epoch, Epoch)>,
+}
+
+impl EclipseInterval {
+    pub fn duration(&self) -> Duration {
+        self.end - self.start
+    }
+}
+
+/// Eclipses by the Earth, of radius `radius` (m, e.g.
+/// [`EARTH_RADIUS`](super::EARTH_RADIUS)), of an object at the geocentric
+/// EME2000 positions (m) given by `position`, between `start` and `end`.
+///
+/// The shadow state is sampled every `step` and its changes located by
+/// bisection to a millisecond, so passages shorter than `step` may be
+/// missed. Intervals in progress at `start` or `end` are cut there. Nothing
+/// is reported for a `step` that is not positive.
+pub fn eclipse_intervals<F>(
+    start: Epoch,
+    end: Epoch,
+    step: Duration,
+    shadow: Shadow,
+    radius: f64,
+    mut position: impl FnMut(&Epoch) -> Cartesian<f64, F>,
+) -> Vec<EclipseInterval> {
+    let mut illumination = |epoch: &Epoch| shadow.illumination(&position(epoch), &sun_position::<F>(epoch), radius);
+    let shadows = intervals(start, end, step, |epoch| illumination(epoch) < 1.0);
+    let mut umbrae = intervals(start, end, step, |epoch| illumination(epoch) <= 0.0).into_iter().peekable();
+    shadows
+        .into_iter()
+        .map(|(start, end)| {
+            // Umbrae lie within a shadow interval, up to the bisection
+            // tolerance at shared boundaries
+            let mut umbra = None;
+            while let Some(&(entry, exit)) = umbrae.peek() {
+                if (entry - end).as_seconds() > 0.0 {
+                    break;
+                }
+                umbra = Some(umbra.map_or((entry, exit), |(first, _)| (first, exit)));
+                umbrae.next();
+            }
+            EclipseInterval { start, end, umbra }
+        })
+        .collect()
+}
+
+/// Intervals between `start` and `end` where `inside` holds; none unless
+/// `step` is positive.
+fn intervals(start: Epoch, end: Epoch, step: Duration, mut inside: impl FnMut(&Epoch) -> bool) -> Vec<(Epoch, Epoch)> {
+    let mut result = Vec::new();
+    if step.as_seconds() <= 0.0 || step.as_seconds().is_nan() {
+        return result;
+    }
+    let (mut previous, mut state) = (start, inside(&start));
+    let mut entry = state.then_some(start);
+    while (end - previous).as_seconds() > 0.0 {
+        let next = if (end - previous).as_seconds() > step.as_seconds() { previous + step } else { end };
+        let next_state = inside(&next);
+        if next_state != state {
+            // Bisect for the change between `previous` and `next`
+            let (mut low, mut high) = (previous, next);
+            while (high - low).as_seconds() > 1e-3 {
+                let middle = low + (high - low) * 0.5;
+                if inside(&middle) == state {
+                    low = middle;
+                } else {
+                    high = middle;
+                }
+            }
+            match entry.take() {
+                Some(entered) => result.push((entered, high)),
+                None => entry = Some(high),
+            }
+            state = next_state;
+        }
+        previous = next;
+    }
+    if let Some(entered) = entry {
+        result.push((entered, end));
+    }
+    result
+}
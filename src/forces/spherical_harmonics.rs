@@ -0,0 +1,282 @@
+//! Spherical-harmonic gravity with fully normalized coefficients.
+//!
+//! The potential `U = mu/R sum Re[(C_nm - i S_nm) Y_nm]` is expanded in the
+//! normalized solid harmonics `Y_nm = N_nm (R/r)^(n+1) P_nm(sin phi) e^(i m
+//! lambda)`, computed in Cartesian coordinates with Cunningham's recursions,
+//! which are free of the singularity at the poles. Derivatives follow from
+//! the ladder relations
+//!
+//! - `(d/dx + i d/dy) Y_nm ~ Y_(n+1, m+1)`,
+//! - `(d/dx - i d/dy) Y_nm ~ Y_(n+1, m-1)` (the conjugate of `Y_(n+1, 1)` for
+//!   `m = 0`),
+//! - `d/dz Y_nm ~ Y_(n+1, m)`,
+//!
+//! so the acceleration needs the harmonics to degree `n + 1` and the gradient
+//! to degree `n + 2`.
+
+use num_traits::Float;
+use std::vec::Vec;
+
+use super::{GravityError, GravityField};
+use crate::coordinate::Cartesian;
+use crate::math::{Matrix, Vector};
+
+fn k<T: Float>(x: f64) -> T {
+    T::from(x).unwrap()
+}
+
+/// Position of the degree `n`, order `m` term in triangular storage.
+pub(super) fn index(n: usize, m: usize) -> usize {
+    n * (n + 1) / 2 + m
+}
+
+/// Spherical-harmonic gravity field of a body, evaluated in its body-fixed
+/// frame.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SphericalHarmonics<T: Float> {
+    mu: T,
+    radius: T,
+    degree: usize,
+    order: usize,
+    c: Vec<T>,
+    s: Vec<T>,
+}
+
+impl<T: Float> SphericalHarmonics<T> {
+    /// From fully normalized coefficients stored by degree then order
+    /// (`C_nm` at `n (n + 1) / 2 + m`), complete up to some degree. The
+    /// central term `C_00` is normally one.
+    pub fn new(mu: T, radius: T, c: Vec<T>, s: Vec<T>) -> Result<Self, GravityError> {
+        if c.is_empty() {
+            return Err(GravityError::Empty);
+        }
+        if c.len() != s.len() {
+            return Err(GravityError::InvalidSize);
+        }
+        let degree = (0..).find(|&n| index(n, n) + 1 >= c.len()).unwrap();
+        if index(degree, degree) + 1 != c.len() {
+            return Err(GravityError::InvalidSize);
+        }
+        Ok(Self { mu, radius, degree, order: degree, c, s })
+    }
+
+    /// An axisymmetric field from the zonal harmonics `J2, J3, ...`.
+    pub fn zonal(mu: T, radius: T, j: &[T]) -> Self {
+        let degree = j.len() + 1;
+        let mut c = vec![T::zero(); index(degree, degree) + 1];
+        c[0] = T::one();
+        for (n, &jn) in (2..).zip(j) {
+            c[index(n, 0)] = -jn / k::<T>(2.0 * n as f64 + 1.0).sqrt();
+        }
+        let s = vec![T::zero(); c.len()];
+        Self { mu, radius, degree, order: 0, c, s }
+    }
+
+    pub fn mu(&self) -> T {
+        self.mu
+    }
+
+    pub fn radius(&self) -> T {
+        self.radius
+    }
+
+    /// Degree and order the field is evaluated to.
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    pub fn order(&self) -> usize {
+        self.order
+    }
+
+    /// Normalized coefficients `(C_nm, S_nm)`, or `None` beyond the stored
+    /// degree.
+    pub fn coefficients(&self, n: usize, m: usize) -> Option<(T, T)> {
+        let i = index(n, m);
+        if m > n || i >= self.c.len() {
+            return None;
+        }
+        Some((self.c[i], self.s[i]))
+    }
+
+    /// The field evaluated only up to `degree` and `order`, both limited to
+    /// the stored coefficients.
+    pub fn truncated(mut self, degree: usize, order: usize) -> Self {
+        let stored = (0..).find(|&n| index(n, n) + 1 >= self.c.len()).unwrap();
+        self.degree = degree.min(stored);
+        self.order = order.min(self.degree);
+        self.c.truncate(index(self.degree, self.degree) + 1);
+        self.s.truncate(self.c.len());
+        self
+    }
+
+    /// Acceleration and gravity gradient `da/dr` at `position`.
+    pub fn acceleration_and_gradient<F>(&self, position: &Cartesian<T, F>) -> (Cartesian<T, F>, Matrix<T, 3, 3>) {
+        let (acceleration, gradient) = self.evaluate(position.data, true);
+        (Cartesian::from_vector(acceleration), gradient.unwrap())
+    }
+
+    fn evaluate(&self, position: Vector<T, 3>, with_gradient: bool) -> (Vector<T, 3>, Option<Matrix<T, 3, 3>>) {
+        let extra = if with_gradient { 2 } else { 1 };
+        let harmonics = SolidHarmonics::new(position.data, self.radius, self.degree + extra, self.order + extra);
+        let term = |op: Ladder, n: usize, m: usize, conjugate: bool| {
+            let (factor, n, m, conjugate) = op.apply(n, m, conjugate);
+            let (v, w) = harmonics.get(n, m, conjugate);
+            (factor, n, m, conjugate, (k::<T>(factor) * v, k::<T>(factor) * w))
+        };
+        let second = |first: Ladder, then: Ladder, n: usize, m: usize| {
+            let (f1, n, m, conjugate, _) = term(first, n, m, false);
+            let (_, _, _, _, (v, w)) = term(then, n, m, conjugate);
+            (k::<T>(f1) * v, k::<T>(f1) * w)
+        };
+
+        let zero = (T::zero(), T::zero());
+        let (mut raise, mut lower, mut z) = (zero, zero, zero);
+        let [mut rr, mut rl, mut ll, mut rz, mut lz, mut zz] = [zero; 6];
+        for n in 0..=self.degree {
+            for m in 0..=n.min(self.order) {
+                let i = index(n, m);
+                if self.c[i] == T::zero() && self.s[i] == T::zero() {
+                    continue;
+                }
+                let coefficient = (self.c[i], -self.s[i]);
+                let accumulate = |sum: &mut (T, T), value: (T, T)| {
+                    let product = multiply(coefficient, value);
+                    *sum = (sum.0 + product.0, sum.1 + product.1);
+                };
+                accumulate(&mut raise, term(Ladder::Raise, n, m, false).4);
+                accumulate(&mut lower, term(Ladder::Lower, n, m, false).4);
+                accumulate(&mut z, term(Ladder::Z, n, m, false).4);
+                if with_gradient {
+                    accumulate(&mut rr, second(Ladder::Raise, Ladder::Raise, n, m));
+                    accumulate(&mut rl, second(Ladder::Raise, Ladder::Lower, n, m));
+                    accumulate(&mut ll, second(Ladder::Lower, Ladder::Lower, n, m));
+                    accumulate(&mut rz, second(Ladder::Raise, Ladder::Z, n, m));
+                    accumulate(&mut lz, second(Ladder::Lower, Ladder::Z, n, m));
+                    accumulate(&mut zz, second(Ladder::Z, Ladder::Z, n, m));
+                }
+            }
+        }
+
+        // d/dx = (D+ + D-) / 2, d/dy = (D+ - D-) / 2i
+        let half = k::<T>(0.5);
+        let scale = self.mu / (self.radius * self.radius);
+        let acceleration = Vector::new([
+            scale * half * (raise.0 + lower.0),
+            scale * half * (raise.1 - lower.1),
+            scale * z.0,
+        ]);
+        let gradient = with_gradient.then(|| {
+            let scale = scale / self.radius;
+            let quarter = half * half;
+            let two = k::<T>(2.0);
+            let xx = scale * quarter * (rr.0 + two * rl.0 + ll.0);
+            let yy = -scale * quarter * (rr.0 - two * rl.0 + ll.0);
+            let xy = scale * quarter * (rr.1 - ll.1);
+            let xz = scale * half * (rz.0 + lz.0);
+            let yz = scale * half * (rz.1 - lz.1);
+            Matrix::new([[xx, xy, xz], [xy, yy, yz], [xz, yz, scale * zz.0]])
+        });
+        (acceleration, gradient)
+    }
+}
+
+impl<T: Float> GravityField<T> for SphericalHarmonics<T> {
+    fn acceleration<F>(&self, position: &Cartesian<T, F>) -> Cartesian<T, F> {
+        Cartesian::from_vector(self.evaluate(position.data, false).0)
+    }
+}
+
+fn multiply<T: Float>(a: (T, T), b: (T, T)) -> (T, T) {
+    (a.0 * b.0 - a.1 * b.1, a.0 * b.1 + a.1 * b.0)
+}
+
+/// Derivative operators on the normalized solid harmonics, in units of the
+/// reference radius.
+#[derive(Debug, Clone, Copy)]
+enum Ladder {
+    /// `d/dx + i d/dy`
+    Raise,
+    /// `d/dx - i d/dy`
+    Lower,
+    /// `d/dz`
+    Z,
+}
+
+impl Ladder {
+    /// The operator applied to `Y_nm` (or its conjugate) as a multiple of a
+    /// single harmonic `(factor, n, m, conjugate)` of degree `n + 1`.
+    fn apply(self, n: usize, m: usize, conjugate: bool) -> (f64, usize, usize, bool) {
+        if conjugate {
+            // D+ conj(Y) = conj(D- Y) and conversely
+            let swapped = match self {
+                Ladder::Raise => Ladder::Lower,
+                Ladder::Lower => Ladder::Raise,
+                Ladder::Z => Ladder::Z,
+            };
+            let (factor, n, m, conjugate) = swapped.apply(n, m, false);
+            return (factor, n, m, !conjugate);
+        }
+        let (nf, mf) = (n as f64, m as f64);
+        let ratio = (2.0 * nf + 1.0) / (2.0 * nf + 3.0);
+        match self {
+            Ladder::Z => (-(ratio * (nf - mf + 1.0) * (nf + mf + 1.0)).sqrt(), n + 1, m, false),
+            Ladder::Raise => {
+                let delta = if m == 0 { 0.5 } else { 1.0 };
+                (-(delta * ratio * (nf + mf + 2.0) * (nf + mf + 1.0)).sqrt(), n + 1, m + 1, false)
+            }
+            Ladder::Lower if m == 0 => (-(0.5 * ratio * (nf + 2.0) * (nf + 1.0)).sqrt(), n + 1, 1, true),
+            Ladder::Lower => {
+                let delta = if m == 1 { 2.0 } else { 1.0 };
+                ((delta * ratio * (nf - mf + 2.0) * (nf - mf + 1.0)).sqrt(), n + 1, m - 1, false)
+            }
+        }
+    }
+}
+
+/// Normalized solid harmonics `V_nm + i W_nm` at a position, to degree
+/// `n_max` and order `m_max`.
+struct SolidHarmonics<T> {
+    v: Vec<T>,
+    w: Vec<T>,
+}
+
+impl<T: Float> SolidHarmonics<T> {
+    fn new(position: [T; 3], radius: T, n_max: usize, m_max: usize) -> Self {
+        let [x, y, z] = position;
+        let r2 = x * x + y * y + z * z;
+        let (xr, yr, zr) = (x * radius / r2, y * radius / r2, z * radius / r2);
+        let rho2 = radius * radius / r2;
+        let size = index(n_max, n_max) + 1;
+        let (mut v, mut w) = (vec![T::zero(); size], vec![T::zero(); size]);
+        v[0] = (rho2).sqrt();
+        for m in 0..=m_max.min(n_max) {
+            if m > 0 {
+                let factor = k::<T>(if m == 1 { 3.0_f64.sqrt() } else { ((2 * m + 1) as f64 / (2 * m) as f64).sqrt() });
+                let previous = index(m - 1, m - 1);
+                let (vp, wp) = (v[previous], w[previous]);
+                v[index(m, m)] = factor * (xr * vp - yr * wp);
+                w[index(m, m)] = factor * (xr * wp + yr * vp);
+            }
+            for n in m + 1..=n_max {
+                let (nf, mf) = (n as f64, m as f64);
+                let a = k::<T>(((2.0 * nf + 1.0) * (2.0 * nf - 1.0) / ((nf - mf) * (nf + mf))).sqrt());
+                let (i, i1) = (index(n, m), index(n - 1, m));
+                v[i] = a * zr * v[i1];
+                w[i] = a * zr * w[i1];
+                if n >= m + 2 {
+                    let b = ((2.0 * nf + 1.0) * (nf + mf - 1.0) * (nf - mf - 1.0) / ((2.0 * nf - 3.0) * (nf + mf) * (nf - mf))).sqrt();
+                    let (b, i2) = (k::<T>(b) * rho2, index(n - 2, m));
+                    v[i] = v[i] - b * v[i2];
+                    w[i] = w[i] - b * w[i2];
+                }
+            }
+        }
+        Self { v, w }
+    }
+
+    fn get(&self, n: usize, m: usize, conjugate: bool) -> (T, T) {
+        let i = index(n, m);
+        if conjugate { (self.v[i], -self.w[i]) } else { (self.v[i], self.w[i]) }
+    }
+}
@@ -0,0 +1,151 @@
+#[cfg(test)]
+mod tests {
+    use crate::coordinate::{Cartesian, Ellipsoid, Geodetic};
+    use crate::forces::atmosphere::*;
+    use crate::forces::{Drag, EARTH_ROTATION_RATE};
+    use crate::reference_frame::ReferenceFrame;
+    use crate::time::{Calendar, Epoch, TimeScale};
+    use approx::assert_relative_eq;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Default)]
+    struct Itrf;
+    impl ReferenceFrame for Itrf {}
+
+    fn at_height(latitude: f64, longitude: f64, h: f64) -> Cartesian<f64, Itrf> {
+        Geodetic::new(latitude.to_radians(), longitude.to_radians(), h).to_cartesian(&Ellipsoid::wgs84())
+    }
+
+    fn epoch() -> Epoch {
+        Epoch::from_calendar(Calendar::new(2024, 3, 20, 12, 0, 0.0), TimeScale::UTC).unwrap()
+    }
+
+    #[test]
+    fn test_standard_atmospheres() {
+        // U.S. Standard Atmosphere 1976 tables at geometric heights
+        let us76 = UsStandard1976;
+        for (h, rho) in [
+            (0.0, 1.225_0),
+            (11_000.0, 3.648_0e-1),
+            (20_000.0, 8.891_0e-2),
+            (32_000.0, 1.355_5e-2),
+            (50_000.0, 1.026_9e-3),
+            (80_000.0, 1.845_8e-5),
+            (86_000.0, 6.958e-6),
+            (500_000.0, 5.215e-13),
+        ] {
+            assert_relative_eq!(us76.density_at_height(h), rho, max_relative = 2e-4);
+        }
+        let (temperature, pressure) = us76.temperature_and_pressure(11_000.0).unwrap();
+        assert_relative_eq!(temperature, 216.774, max_relative = 1e-5);
+        assert_relative_eq!(pressure, 22_699.9, max_relative = 1e-4);
+        assert_eq!(us76.temperature_and_pressure(90_000.0), None);
+        // Continuous where the tabulated upper atmosphere takes over
+        assert_relative_eq!(us76.density_at_height(86_000.0 - 1e-6), us76.density_at_height(86_000.0 + 1e-6), max_relative = 1e-3);
+        assert!(us76.density_at_height(300_000.0) < us76.density_at_height(299_000.0));
+
+        let exponential = Exponential::default();
+        assert_eq!(exponential.density_at_height(0.0), 1.225);
+        assert_relative_eq!(exponential.density_at_height(400_000.0), 3.725e-12, max_relative = 1e-12);
+        assert_relative_eq!(exponential.density_at_height(420_000.0), 3.725e-12 * (-20.0 / 58.515_f64).exp(), max_relative = 1e-12);
+        // The reference atmospheres differ, but by less than 50% through the
+        // thermosphere
+        for h in [150e3, 250e3, 400e3, 700e3] {
+            let ratio = exponential.density_at_height(h) / us76.density_at_height(h);
+            assert!(ratio > 1.0 / 1.5 && ratio < 1.5, "{h}: {ratio}");
+        }
+        let single = Exponential::single(0.0, 1.225, 8_500.0);
+        assert_relative_eq!(single.density_at_height(8_500.0), 1.225 / core::f64::consts::E, max_relative = 1e-15);
+        assert_eq!(Exponential::new(vec![]), None);
+
+        // Through the Atmosphere interface with geodetic heights
+        let position = at_height(45.0, 10.0, 400e3);
+        assert_relative_eq!(us76.density(&position, &epoch()), us76.density_at_height(400e3), max_relative = 1e-9);
+    }
+
+    #[test]
+    fn test_harris_priester() {
+        let model = HarrisPriester::default();
+        let (min, max) = model.density_bounds(400e3).unwrap();
+        assert_relative_eq!(min, 2.249e-12, max_relative = 1e-12);
+        assert_relative_eq!(max, 7.492e-12, max_relative = 1e-12);
+        assert_eq!(model.density_bounds(1_100e3), None);
+        assert_eq!(model.density(&at_height(0.0, 0.0, 50e3), &epoch()), 0.0);
+
+        // At the March equinox the bulge sits on the equator, 30 degrees east
+        // of the subsolar point (near Greenwich at noon UTC)
+        let apex = HarrisPriester::bulge_apex(&epoch());
+        assert!(apex[2].abs() < 0.01);
+        let longitude = apex[1].atan2(apex[0]).to_degrees();
+        assert!((longitude - 30.0).abs() < 3.0, "{longitude}");
+
+        let densities: Vec<f64> = (0..360).map(|lon| model.density(&at_height(0.0, lon as f64, 400e3), &epoch())).collect();
+        let peak = (0..360).max_by(|&a, &b| densities[a].total_cmp(&densities[b])).unwrap();
+        assert!((peak as f64 - longitude).abs() < 1.5);
+        assert!(densities.iter().all(|&rho| rho >= min && rho <= max));
+        assert_relative_eq!(densities[peak], max, max_relative = 1e-3);
+        // Antipodal to the apex cos(psi / 2) vanishes
+        assert_relative_eq!(densities[(peak + 180) % 360], min, max_relative = 1e-3);
+    }
+
+    #[test]
+    fn test_drag() {
+        let drag = Drag::new(Exponential::default(), 50.0);
+        let position = at_height(0.0, 0.0, 400e3);
+        let r = position.norm();
+
+        // An object co-rotating with the atmosphere feels no drag
+        let corotating = Cartesian::new(0.0, EARTH_ROTATION_RATE * r, 0.0);
+        assert_eq!(drag.acceleration(&position, &corotating, &epoch()).norm(), 0.0);
+
+        let velocity = Cartesian::new(0.0, 0.0, 7_670.0);
+        let relative = drag.relative_velocity(&position, &velocity);
+        assert_relative_eq!(relative.y(), -EARTH_ROTATION_RATE * r, max_relative = 1e-15);
+        let a = drag.acceleration(&position, &velocity, &epoch());
+        let rho = drag.atmosphere.density(&position, &epoch());
+        assert_relative_eq!(a.norm(), 0.5 * rho * relative.dot(&relative) / 50.0, max_relative = 1e-12);
+        assert_relative_eq!(a.unit().dot(&relative.unit()), -1.0, max_relative = 1e-12);
+        assert!(a.norm() > 1e-6 && a.norm() < 1e-5);
+    }
+
+    #[test]
+    fn test_space_weather() {
+        let header = "DATE,BSRT,ND,KP1,KP2,KP3,KP4,KP5,KP6,KP7,KP8,KP_SUM,AP1,AP2,AP3,AP4,AP5,AP6,AP7,AP8,AP_AVG,CP,C9,ISN,\
+                      F10.7_OBS,F10.7_ADJ,F10.7_DATA_TYPE,F10.7_OBS_CENTER81,F10.7_OBS_LAST81,F10.7_ADJ_CENTER81,F10.7_ADJ_LAST81";
+        let row = |date: &str, ap: [u32; 8], f107: &str| {
+            let ap_text: Vec<String> = ap.iter().map(|a| a.to_string()).collect();
+            let average = ap.iter().sum::<u32>() as f64 / 8.0;
+            format!("{date},2600,1,0,0,0,0,0,0,0,0,0,{},{average},0.0,0,50,{f107},{f107},OBS,150.0,150.0,150.0,150.0", ap_text.join(","))
+        };
+        let text = [
+            header.to_string(),
+            row("2024-03-16", [1, 2, 3, 4, 5, 6, 7, 8], "140.0"),
+            row("2024-03-17", [9, 10, 11, 12, 13, 14, 15, 16], "141.0"),
+            row("2024-03-18", [17, 18, 19, 20, 21, 22, 23, 24], "142.0"),
+            row("2024-03-19", [25, 26, 27, 28, 29, 30, 31, 32], "143.0"),
+            row("2024-03-20", [33, 34, 35, 36, 37, 38, 39, 40], "144.0"),
+            "2024-03-21,2600,1,,,,,,,,,,,,,,,,,,,,,,,,,,,,".to_string(),
+        ]
+        .join("\n");
+        let table = SpaceWeatherTable::parse_celestrak_csv(&text).unwrap();
+        assert_eq!(table.records().len(), 5);
+
+        // 12:00 UTC is the fifth 3-hour interval of 2024-03-20
+        let weather = table.at(&epoch()).unwrap();
+        assert_eq!((weather.f107, weather.f107_average, weather.ap), (143.0, 150.0, 36.5));
+        assert_eq!(weather.ap_history[..5], [36.5, 37.0, 36.0, 35.0, 34.0]);
+        // The 3-hour intervals starting 12 to 33 hours before: 2024-03-19
+        // 03:00 to 2024-03-20 00:00
+        assert_eq!(weather.ap_history[5], (26..=33).sum::<u32>() as f64 / 8.0);
+        assert_eq!(weather.ap_history[6], (18..=25).sum::<u32>() as f64 / 8.0);
+
+        let early = Epoch::from_calendar(Calendar::date(2024, 3, 17), TimeScale::UTC).unwrap();
+        assert_eq!(table.at(&early), None);
+        assert_eq!(table.space_weather(&early).f107_average, 150.0);
+        let constant = SpaceWeather::new(150.0, 150.0, 4.0);
+        assert_eq!(constant.space_weather(&early).ap_history, [4.0; 7]);
+
+        assert_eq!(SpaceWeatherTable::parse_celestrak_csv("DATE,AP_AVG\n"), Err(SpaceWeatherError::MissingColumn));
+        let gap = [header.to_string(), row("2024-03-16", [1; 8], "140.0"), row("2024-03-18", [1; 8], "140.0")].join("\n");
+        assert_eq!(SpaceWeatherTable::parse_celestrak_csv(&gap), Err(SpaceWeatherError::NotConsecutive { index: 1 }));
+    }
+}
@@ -0,0 +1,198 @@
+#[cfg(test)]
+mod tests {
+    use crate::coordinate::Cartesian;
+    use crate::forces::*;
+    use crate::math::Matrix;
+    use crate::reference_frame::ReferenceFrame;
+    use approx::assert_relative_eq;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Default)]
+    struct BodyFixed;
+    impl ReferenceFrame for BodyFixed {}
+
+    type Position = Cartesian<f64, BodyFixed>;
+
+    const MU: f64 = 3.986_004_415e14;
+
+    /// A degree 4 field with the EGM2008 coefficients, in ICGEM format.
+    const ICGEM: &str = "\
+begin_of_head
+product_type            gravity_field
+modelname               EGM2008
+earth_gravity_constant  0.3986004415E+15
+radius                  0.63781363E+07
+max_degree              4
+norm                    fully_normalized
+key   L  M    C                      S
+end_of_head
+gfc   0  0  1.0                     0.0
+gfc   2  0 -0.484165143790815D-03   0.0
+gfc   2  1 -0.206615509074176D-09   0.138441389137979D-08
+gfc   2  2  0.243938357328313D-05  -0.140027370385934D-05
+gfc   3  0  0.957161207093473D-06   0.0
+gfc   3  1  0.203046201047864D-05   0.248200415856872D-06
+gfc   3  2  0.904787894809528D-06  -0.619005475177618D-06
+gfc   3  3  0.721321757121568D-06   0.141434926192941D-05
+gfc   4  0  0.539965866638991D-06   0.0
+gfc   4  1 -0.536157389388867D-06  -0.473567346518086D-06
+gfc   4  2  0.350501623962649D-06   0.662480026275829D-06
+gfc   4  3  0.990856766672321D-06  -0.200956723567452D-06
+gfc   4  4 -0.188519633023033D-06   0.308803882149194D-06
+";
+
+    fn positions() -> [Position; 4] {
+        [
+            Cartesian::new(6_778_000.0, 1_000.0, -2_000.0),
+            Cartesian::new(-3_000_000.0, 4_500_000.0, 4_000_000.0),
+            Cartesian::new(1.0, -2.0, 7_000_000.0),
+            Cartesian::new(20_000_000.0, -15_000_000.0, 3_000_000.0),
+        ]
+    }
+
+    fn factorial_ratio(n: usize, m: usize) -> f64 {
+        (n - m + 1..=n + m).map(|i| 1.0 / i as f64).product()
+    }
+
+    /// Acceleration from unnormalized coefficients with the recursions of
+    /// Montenbruck and Gill, Satellite Orbits, section 3.2.
+    fn montenbruck_gill(field: &SphericalHarmonics<f64>, position: &Position) -> [f64; 3] {
+        let n_max = field.degree() + 1;
+        let (mu, radius) = (field.mu(), field.radius());
+        let [x, y, z] = position.data.data;
+        let r2 = x * x + y * y + z * z;
+        let (x0, y0, z0, rho) = (radius * x / r2, radius * y / r2, radius * z / r2, radius * radius / r2);
+        let (mut v, mut w) = (vec![vec![0.0; n_max + 2]; n_max + 2], vec![vec![0.0; n_max + 2]; n_max + 2]);
+        v[0][0] = radius / r2.sqrt();
+        for m in 0..=n_max {
+            if m > 0 {
+                let f = (2 * m - 1) as f64;
+                v[m][m] = f * (x0 * v[m - 1][m - 1] - y0 * w[m - 1][m - 1]);
+                w[m][m] = f * (x0 * w[m - 1][m - 1] + y0 * v[m - 1][m - 1]);
+            }
+            for n in m + 1..=n_max {
+                let (a, b) = ((2 * n - 1) as f64 / (n - m) as f64, (n + m - 1) as f64 / (n - m) as f64);
+                let (v2, w2) = if n >= m + 2 { (v[n - 2][m], w[n - 2][m]) } else { (0.0, 0.0) };
+                v[n][m] = a * z0 * v[n - 1][m] - b * rho * v2;
+                w[n][m] = a * z0 * w[n - 1][m] - b * rho * w2;
+            }
+        }
+        let mut a = [0.0; 3];
+        for n in 0..field.degree() + 1 {
+            for m in 0..=n {
+                let (c, s) = field.coefficients(n, m).unwrap();
+                let scale = ((if m == 0 { 1.0 } else { 2.0 }) * (2 * n + 1) as f64 * factorial_ratio(n, m)).sqrt();
+                let (c, s) = (c * scale, s * scale);
+                if m == 0 {
+                    a[0] -= c * v[n + 1][1];
+                    a[1] -= c * w[n + 1][1];
+                } else {
+                    let f = ((n - m + 2) * (n - m + 1)) as f64;
+                    a[0] += 0.5 * ((-c * v[n + 1][m + 1] - s * w[n + 1][m + 1]) + f * (c * v[n + 1][m - 1] + s * w[n + 1][m - 1]));
+                    a[1] += 0.5 * ((-c * w[n + 1][m + 1] + s * v[n + 1][m + 1]) + f * (-c * w[n + 1][m - 1] + s * v[n + 1][m - 1]));
+                }
+                a[2] += (n - m + 1) as f64 * (-c * v[n + 1][m] - s * w[n + 1][m]);
+            }
+        }
+        a.map(|ai| ai * mu / (radius * radius))
+    }
+
+    /// Central differences of the acceleration.
+    fn numerical_gradient(field: &impl GravityField<f64>, position: &Position) -> Matrix<f64, 3, 3> {
+        let mut gradient = Matrix::new([[0.0; 3]; 3]);
+        let h = 1.0;
+        for j in 0..3 {
+            let (mut plus, mut minus) = (*position, *position);
+            plus.data.data[j] += h;
+            minus.data.data[j] -= h;
+            let (ap, am) = (field.acceleration(&plus), field.acceleration(&minus));
+            for i in 0..3 {
+                gradient[(i, j)] = (ap.data.data[i] - am.data.data[i]) / (2.0 * h);
+            }
+        }
+        gradient
+    }
+
+    fn assert_gradient(gradient: &Matrix<f64, 3, 3>, expected: &Matrix<f64, 3, 3>) {
+        let size = (0..3).map(|i| expected[(i, i)].abs()).fold(0.0, f64::max);
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((gradient[(i, j)] - expected[(i, j)]).abs() < 1e-6 * size, "{gradient:?} vs {expected:?}");
+                assert_relative_eq!(gradient[(i, j)], gradient[(j, i)], epsilon = 1e-12 * size);
+            }
+        }
+    }
+
+    #[test]
+    fn test_point_mass_and_zonal() {
+        let point = PointMass::new(MU);
+        let zonal = ZonalHarmonics::new(MU, EARTH_RADIUS, J2_EARTH, J3_EARTH, J4_EARTH);
+        let field = SphericalHarmonics::zonal(MU, EARTH_RADIUS, &[J2_EARTH, J3_EARTH, J4_EARTH]);
+        for position in positions() {
+            let r = position.norm();
+            let a = point.acceleration(&position);
+            assert_relative_eq!(a.norm(), MU / (r * r), max_relative = 1e-14);
+            assert_relative_eq!(a.dot(&position), -MU / r, max_relative = 1e-14);
+            assert_gradient(&point.gradient(&position), &numerical_gradient(&point, &position));
+
+            // Closed-form zonals against the normalized recursion
+            let expected = field.acceleration(&position);
+            assert!(zonal.acceleration(&position).distance_to(&expected) < 1e-13 * expected.norm());
+            for (j, closed) in [
+                (&[J2_EARTH][..], j2_acceleration(&position, MU, EARTH_RADIUS, J2_EARTH)),
+                (&[0.0, J3_EARTH][..], j3_acceleration(&position, MU, EARTH_RADIUS, J3_EARTH)),
+                (&[0.0, 0.0, J4_EARTH][..], j4_acceleration(&position, MU, EARTH_RADIUS, J4_EARTH)),
+            ] {
+                let perturbation = SphericalHarmonics::zonal(MU, EARTH_RADIUS, j).acceleration(&position).data - a.data;
+                assert!((closed.data - perturbation).norm() < 1e-8 * closed.norm(), "{closed:?} vs {perturbation:?}");
+            }
+        }
+
+        // J2 at the equator of a 7000 km orbit: 3/2 J2 mu R^2 / r^4
+        let position = Cartesian::<f64, BodyFixed>::new(7_000_000.0, 0.0, 0.0);
+        let j2 = j2_acceleration(&position, MU, EARTH_RADIUS, J2_EARTH);
+        assert_relative_eq!(j2.x(), -1.5 * J2_EARTH * MU * EARTH_RADIUS.powi(2) / 7e6_f64.powi(4), max_relative = 1e-8);
+        assert_relative_eq!(j2.x(), -0.010_967, max_relative = 1e-4);
+    }
+
+    #[test]
+    fn test_spherical_harmonics() {
+        let field = SphericalHarmonics::<f64>::parse_icgem(ICGEM, 360).unwrap();
+        assert_eq!((field.degree(), field.order(), field.mu(), field.radius()), (4, 4, MU, EARTH_RADIUS));
+        assert_eq!(field.coefficients(2, 2), Some((0.243_938_357_328_313e-5, -0.140_027_370_385_934e-5)));
+        assert_eq!(field.coefficients(5, 0), None);
+
+        for position in positions() {
+            let expected = montenbruck_gill(&field, &position);
+            let (a, gradient) = field.acceleration_and_gradient(&position);
+            assert_eq!(a, field.acceleration(&position));
+            for i in 0..3 {
+                assert_relative_eq!(a.data.data[i], expected[i], epsilon = 1e-13 * a.norm());
+            }
+            assert_gradient(&gradient, &numerical_gradient(&field, &position));
+            // Laplace's equation
+            assert!((gradient[(0, 0)] + gradient[(1, 1)] + gradient[(2, 2)]).abs() < 1e-12 * gradient[(0, 0)].abs());
+        }
+
+        // Truncation drops the tesseral and higher terms
+        let truncated = field.clone().truncated(2, 0);
+        let zonal = SphericalHarmonics::zonal(MU, EARTH_RADIUS, &[J2_EARTH]);
+        let position = positions()[1];
+        assert!(truncated.acceleration(&position).distance_to(&zonal.acceleration(&position)) < 1e-14 * MU / 4e13);
+
+        // NGA tables with Fortran exponents, and unnormalized ICGEM files
+        let table = "    2    0   -0.484165143790815D-03    0.000000000000000D+00    0.7481239490D-11    0.0000000000D+00\n\
+                         2    2    0.243938357328313D-05   -0.140027370385934D-05    0.7230231850D-11    0.7299000630D-11\n\
+                         5    0    0.1D-06 0.0\n";
+        let parsed = SphericalHarmonics::parse_table(table, MU, EARTH_RADIUS, 4).unwrap();
+        assert_eq!((parsed.degree(), parsed.coefficients(0, 0)), (2, Some((1.0, 0.0))));
+        assert_eq!(parsed.coefficients(2, 2), field.coefficients(2, 2));
+        let unnormalized = ICGEM.replace("fully_normalized", "unnormalized").replace("-0.484165143790815D-03", "-1.082626173852e-3");
+        let parsed = SphericalHarmonics::<f64>::parse_icgem(&unnormalized, 2).unwrap();
+        assert_relative_eq!(parsed.coefficients(2, 0).unwrap().0, -0.484_165_143_790_815e-3, max_relative = 1e-12);
+
+        assert_eq!(SphericalHarmonics::<f64>::parse_icgem("radius 1.0\nend_of_head\n", 4), Err(GravityError::MissingConstant));
+        assert_eq!(SphericalHarmonics::parse_table("2 x 0.1 0.0", MU, EARTH_RADIUS, 4), Err(GravityError::Parse { line: 1 }));
+        assert_eq!(SphericalHarmonics::parse_table("", MU, EARTH_RADIUS, 4), Err(GravityError::Empty));
+        assert_eq!(SphericalHarmonics::new(MU, EARTH_RADIUS, vec![1.0; 4], vec![0.0; 4]), Err(GravityError::InvalidSize));
+    }
+}
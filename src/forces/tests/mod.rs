@@ -0,0 +1,8 @@
+#[cfg(test)]
+mod forces;
+#[cfg(test)]
+mod atmosphere;
+#[cfg(test)]
+mod third_body;
+#[cfg(test)]
+mod radiation_pressure;
@@ -0,0 +1,195 @@
+#[cfg(test)]
+mod tests {
+    use crate::coordinate::Cartesian;
+    use crate::forces::*;
+    use crate::math::Vector;
+    use crate::reference_frame::ReferenceFrame;
+    use crate::orbit::MU_EARTH;
+    use crate::time::{Calendar, Duration, Epoch, TimeScale};
+    use approx::assert_relative_eq;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Default)]
+    struct Eme2000;
+    impl ReferenceFrame for Eme2000 {}
+
+    type Position = Cartesian<f64, Eme2000>;
+
+    fn sun() -> Position {
+        Cartesian::new(ASTRONOMICAL_UNIT, 0.0, 0.0)
+    }
+
+    fn assert_close(a: &Vector<f64, 3>, b: &Vector<f64, 3>) {
+        assert!((*a - *b).norm() <= 1e-12 * b.norm(), "{:?} != {:?}", a.data, b.data);
+    }
+
+    /// Lit fraction of the solar disk by counting points of a grid over it.
+    fn illumination_by_grid(a: f64, b: f64, c: f64) -> f64 {
+        let n = 1_000;
+        let (mut total, mut lit) = (0, 0);
+        for i in 0..n {
+            for j in 0..n {
+                let x = a * (2.0 * (i as f64 + 0.5) / n as f64 - 1.0);
+                let y = a * (2.0 * (j as f64 + 0.5) / n as f64 - 1.0);
+                if x * x + y * y <= a * a {
+                    total += 1;
+                    if (x - c) * (x - c) + y * y > b * b {
+                        lit += 1;
+                    }
+                }
+            }
+        }
+        lit as f64 / total as f64
+    }
+
+    #[test]
+    fn test_cylindrical_shadow() {
+        let shadow = Shadow::Cylindrical;
+        let illumination = |x: f64, y: f64, z: f64| shadow.illumination(&Position::new(x, y, z), &sun(), EARTH_RADIUS);
+        assert_eq!(illumination(-7e6, 0.0, 0.0), 0.0);
+        assert_eq!(illumination(-7e8, 6e6, 0.0), 0.0);
+        assert_eq!(illumination(-7e6, 0.0, 6.4e6), 1.0);
+        assert_eq!(illumination(7e6, 0.0, 0.0), 1.0);
+        assert_eq!(illumination(0.0, 7e6, 0.0), 1.0);
+    }
+
+    #[test]
+    fn test_conical_shadow() {
+        let shadow = Shadow::Conical;
+        let illumination = |position: &Position| shadow.illumination(position, &sun(), EARTH_RADIUS);
+        assert_eq!(illumination(&Position::new(-7e6, 0.0, 0.0)), 0.0);
+        assert_eq!(illumination(&Position::new(7e6, 0.0, 0.0)), 1.0);
+        assert_eq!(illumination(&Position::new(0.0, 0.0, -7e6)), 1.0);
+
+        // Across the shadow boundary at GEO: monotonic from dark to lit,
+        // against the overlap of the disks counted on a grid
+        let r = 42_164e3;
+        let mut previous = 0.0;
+        let mut partial = 0;
+        for i in 0..=400 {
+            let angle = (8.0 + 0.005 * i as f64).to_radians();
+            let position = Position::new(-r * angle.cos(), r * angle.sin(), 0.0);
+            let nu = illumination(&position);
+            assert!(nu >= previous && (0.0..=1.0).contains(&nu));
+            if nu > 0.0 && nu < 1.0 {
+                partial += 1;
+                if partial % 20 == 1 {
+                    let to_sun = sun().data - position.data;
+                    let a = (SUN_RADIUS / to_sun.norm()).asin();
+                    let b = (EARTH_RADIUS / r).asin();
+                    let c = (-position.data.dot(&to_sun) / (r * to_sun.norm())).acos();
+                    assert_relative_eq!(nu, illumination_by_grid(a, b, c), epsilon = 2e-3);
+                }
+            }
+            previous = nu;
+        }
+        assert!(partial > 10);
+        assert_eq!(previous, 1.0);
+
+        // Beyond the tip of the umbra the Earth transits the solar disk
+        let position = Position::new(-2e9, 0.0, 0.0);
+        let a = (SUN_RADIUS / (ASTRONOMICAL_UNIT + 2e9)).asin();
+        let b = (EARTH_RADIUS / 2e9).asin();
+        assert_relative_eq!(illumination(&position), 1.0 - b * b / (a * a), max_relative = 1e-12);
+        assert_relative_eq!(illumination(&position), illumination_by_grid(a, b, 0.0), epsilon = 2e-3);
+
+        // Shadows of the two models agree away from the penumbra
+        for position in [Position::new(-7e6, 1e6, 2e6), Position::new(-7e6, 5e6, 5e6), Position::new(1e7, -3e6, 0.0)] {
+            let cylindrical = Shadow::Cylindrical.illumination(&position, &sun(), EARTH_RADIUS);
+            assert_eq!(illumination(&position), cylindrical);
+        }
+    }
+
+    #[test]
+    fn test_cannonball() {
+        let srp = Cannonball::new(1.3, 0.02);
+        let a = srp.acceleration(&Position::new(7e6, 0.0, 0.0), &sun());
+        let d = ASTRONOMICAL_UNIT - 7e6;
+        assert_relative_eq!(a.x(), -SOLAR_PRESSURE * 1.3 * 0.02 * (ASTRONOMICAL_UNIT / d).powi(2), max_relative = 1e-12);
+        assert_eq!((a.y(), a.z()), (0.0, 0.0));
+        // Inverse square in the distance from the Sun
+        let far = srp.acceleration(&Position::new(-ASTRONOMICAL_UNIT, 0.0, 0.0), &sun());
+        assert_relative_eq!(far.x(), -0.25 * SOLAR_PRESSURE * 1.3 * 0.02, max_relative = 1e-12);
+    }
+
+    #[test]
+    fn test_flat_plate() {
+        let at = Position::new(0.0, 0.0, 0.0);
+        let facing = Position::new(1.0, 0.0, 0.0);
+        let p = SOLAR_PRESSURE * 0.01;
+
+        // An absorbing plate facing the Sun is a cannonball with C_R = 1, a
+        // mirror doubles it
+        let black = FlatPlate::new(0.01, 0.0, 0.0).acceleration(&at, &sun(), &facing);
+        assert_close(&black.data, &Cannonball::new(1.0, 0.01).acceleration(&at, &sun()).data);
+        let mirror = FlatPlate::new(0.01, 1.0, 0.0).acceleration(&at, &sun(), &facing);
+        assert_relative_eq!(mirror.x(), -2.0 * p, max_relative = 1e-12);
+        let diffuse = FlatPlate::new(0.01, 0.0, 1.0).acceleration(&at, &sun(), &facing);
+        assert_relative_eq!(diffuse.x(), -5.0 / 3.0 * p, max_relative = 1e-12);
+
+        // A tilted mirror is pushed along its normal by 2 P cos^2
+        let theta = 30_f64.to_radians();
+        let normal = Position::new(theta.cos(), theta.sin(), 0.0);
+        let tilted = FlatPlate::new(0.01, 1.0, 0.0).acceleration(&at, &sun(), &normal);
+        assert_close(&tilted.data, &(normal.data * (-2.0 * p * theta.cos().powi(2))));
+        // Absorbed light pushes away from the Sun with the projected area
+        let tilted = FlatPlate::new(0.01, 0.0, 0.0).acceleration(&at, &sun(), &normal);
+        assert_close(&tilted.data, &(facing.data * (-p * theta.cos())));
+
+        // The back is dark, and the normal need not be a unit vector
+        let back = FlatPlate::new(0.01, 0.3, 0.3).acceleration(&at, &sun(), &Position::new(-1.0, 0.5, 0.0));
+        assert_eq!(back.norm(), 0.0);
+        let scaled = FlatPlate::new(0.01, 0.3, 0.3).acceleration(&at, &sun(), &Position::new(5.0, 0.0, 0.0));
+        assert_relative_eq!(scaled.x(), -p * (0.7 + 2.0 * 0.4), max_relative = 1e-12);
+    }
+
+    #[test]
+    fn test_eclipse_intervals() {
+        // Circular equatorial orbit near the equinox, with the Sun close to
+        // the orbit plane: the cylindrical shadow lasts asin(R / r) / pi of a
+        // period
+        let start = Epoch::from_calendar(Calendar::new(2024, 3, 20, 0, 0, 0.0), TimeScale::UTC).unwrap();
+        let r = 7_000e3;
+        let n = (MU_EARTH / r.powi(3)).sqrt();
+        let period = core::f64::consts::TAU / n;
+        let position = |epoch: &Epoch| {
+            let angle = n * (*epoch - start).as_seconds();
+            Position::new(r * angle.cos(), r * angle.sin(), 0.0)
+        };
+        let end = start + Duration::from_days(1.0);
+        let step = Duration::from_seconds(60.0);
+
+        let cylindrical = eclipse_intervals(start, end, step, Shadow::Cylindrical, EARTH_RADIUS, position);
+        let conical = eclipse_intervals(start, end, step, Shadow::Conical, EARTH_RADIUS, position);
+        let passes = (86_400.0 / period).floor() as usize;
+        assert!(cylindrical.len() >= passes && cylindrical.len() <= passes + 1);
+        assert_eq!(conical.len(), cylindrical.len());
+
+        let expected = period * (EARTH_RADIUS / r).asin() / core::f64::consts::PI;
+        for (cylinder, cone) in cylindrical.iter().zip(&conical).skip(1).take(passes - 1) {
+            assert_relative_eq!(cylinder.duration().as_seconds(), expected, max_relative = 2e-3);
+            assert_eq!(cylinder.umbra, Some((cylinder.start, cylinder.end)));
+            // Penumbrae of several seconds on either side of the umbra
+            let (entry, exit) = cone.umbra.unwrap();
+            let (penumbra_in, penumbra_out) = ((entry - cone.start).as_seconds(), (cone.end - exit).as_seconds());
+            assert!(penumbra_in > 5.0 && penumbra_in < 15.0);
+            assert_relative_eq!(penumbra_in, penumbra_out, max_relative = 0.1);
+            assert!((cylinder.start - cone.start).as_seconds() > 0.0 && (cone.end - cylinder.end).as_seconds() > 0.0);
+            // Illumination at the boundaries found
+            let illumination = |epoch: &Epoch| Shadow::Conical.illumination(&position(epoch), &sun_position(epoch), EARTH_RADIUS);
+            let ms = Duration::from_seconds(2e-3);
+            assert!(illumination(&(cone.start - ms)) == 1.0 && illumination(&(cone.start + ms)) < 1.0);
+            assert!(illumination(&(entry - ms)) > 0.0 && illumination(&(entry + ms)) == 0.0);
+        }
+
+        // Orbits never eclipsed, and eclipses cut at the ends of the span
+        let polar = |epoch: &Epoch| Position::new(0.0, 0.0, 1e8 + (*epoch - start).as_seconds());
+        assert!(eclipse_intervals(start, end, step, Shadow::Conical, EARTH_RADIUS, polar).is_empty());
+        for step in [Duration::from_seconds(0.0), Duration::from_seconds(-60.0)] {
+            assert!(eclipse_intervals(start, end, step, Shadow::Conical, EARTH_RADIUS, position).is_empty());
+        }
+        let dark = cylindrical[1].start + Duration::from_seconds(60.0);
+        let cut = eclipse_intervals(dark, dark + Duration::from_seconds(120.0), step, Shadow::Cylindrical, EARTH_RADIUS, position);
+        assert_eq!(cut.len(), 1);
+        assert_eq!((cut[0].start, cut[0].end), (dark, dark + Duration::from_seconds(120.0)));
+    }
+}
@@ -0,0 +1,113 @@
+#[cfg(test)]
+mod tests {
+    use crate::coordinate::Cartesian;
+    use crate::forces::{luni_solar_acceleration, moon_position, sun_position, third_body_acceleration, MU_MOON, MU_SUN};
+    use crate::reference_frame::ReferenceFrame;
+    use crate::time::{Calendar, Duration, Epoch, TimeScale};
+    use approx::assert_relative_eq;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Default)]
+    struct Eme2000;
+    impl ReferenceFrame for Eme2000 {}
+
+    const AU: f64 = 149_597_870_700.0;
+    /// General precession in longitude (degrees per Julian century).
+    const PRECESSION: f64 = 5_029.096_6 / 3_600.0;
+
+    fn tt(year: i32, month: u8, day: u8) -> Epoch {
+        Epoch::from_calendar(Calendar::date(year, month, day), TimeScale::TT).unwrap()
+    }
+
+    /// Ecliptic longitude and latitude (degrees) of the J2000 equinox.
+    fn ecliptic(position: &Cartesian<f64, Eme2000>) -> (f64, f64) {
+        let obliquity = 23.439_291_11_f64.to_radians();
+        let (x, y, z) = (position.x(), position.y(), position.z());
+        let (y, z) = (obliquity.cos() * y + obliquity.sin() * z, -obliquity.sin() * y + obliquity.cos() * z);
+        (y.atan2(x).to_degrees().rem_euclid(360.0), (z / position.norm()).asin().to_degrees())
+    }
+
+    #[test]
+    fn test_sun_position() {
+        // Meeus, Astronomical Algorithms, example 25.a: 1992 October 13.0 TD,
+        // true longitude 199.90988 deg of date at 0.99766 au
+        let epoch = tt(1992, 10, 13);
+        let sun: Cartesian<f64, Eme2000> = sun_position(&epoch);
+        let (longitude, latitude) = ecliptic(&sun);
+        let t = epoch.julian_centuries();
+        assert_relative_eq!(longitude, 199.909_88 - PRECESSION * t, epsilon = 0.03);
+        assert_relative_eq!(latitude, 0.0, epsilon = 1e-12);
+        assert_relative_eq!(sun.norm(), 0.997_66 * AU, max_relative = 1e-4);
+    }
+
+    #[test]
+    fn test_moon_position() {
+        // Meeus, example 47.a: 1992 April 12.0 TD, longitude 133.162655 and
+        // latitude -3.229126 deg of date at 368409.7 km
+        let epoch = tt(1992, 4, 12);
+        let moon: Cartesian<f64, Eme2000> = moon_position(&epoch);
+        let (longitude, latitude) = ecliptic(&moon);
+        let t = epoch.julian_centuries();
+        assert_relative_eq!(longitude, 133.162_655 - PRECESSION * t, epsilon = 0.02);
+        assert_relative_eq!(latitude, -3.229_126, epsilon = 0.01);
+        assert_relative_eq!(moon.norm(), 368_409.7e3, epsilon = 100e3);
+    }
+
+    #[test]
+    fn test_ephemeris_ranges() {
+        // Distances stay within perigee and apogee over a year and the Sun
+        // goes around once
+        let start = tt(2024, 1, 1);
+        let mut previous = sun_position::<Eme2000>(&start);
+        let mut swept = 0.0;
+        for day in 1..=366 {
+            let epoch = start + Duration::from_days(day as f64);
+            let sun: Cartesian<f64, Eme2000> = sun_position(&epoch);
+            let moon: Cartesian<f64, Eme2000> = moon_position(&epoch);
+            assert!(sun.norm() > 0.983 * AU && sun.norm() < 1.017 * AU);
+            assert!(moon.norm() > 356_000e3 && moon.norm() < 407_000e3);
+            swept += (previous.dot(&sun) / (previous.norm() * sun.norm())).acos();
+            previous = sun;
+        }
+        assert_relative_eq!(swept, 366.0 / 365.256 * core::f64::consts::TAU, max_relative = 1e-3);
+    }
+
+    #[test]
+    fn test_battin_matches_direct_difference() {
+        let epoch = tt(2024, 6, 1);
+        let sun: Cartesian<f64, Eme2000> = sun_position(&epoch);
+        let moon: Cartesian<f64, Eme2000> = moon_position(&epoch);
+        let direct = |r: &Cartesian<f64, Eme2000>, s: &Cartesian<f64, Eme2000>, mu: f64| {
+            let d = s.data - r.data;
+            d * (mu / d.norm().powi(3)) - s.data * (mu / s.norm().powi(3))
+        };
+        for r in [Cartesian::new(42_164e3, 0.0, 0.0), Cartesian::new(-7_000e3, 3_000e3, 20_000e3), Cartesian::new(1e8, -2e8, 5e7)] {
+            for (body, mu) in [(sun, MU_SUN), (moon, MU_MOON)] {
+                let battin = third_body_acceleration(&r, &body, mu);
+                let expected = direct(&r, &body, mu);
+                assert_relative_eq!(battin.data.norm(), expected.norm(), max_relative = 1e-6);
+                assert!((battin.data - expected).norm() < 1e-6 * expected.norm());
+            }
+        }
+
+        // Tidal limit at the centre: zero, and 2 mu r / s^3 along the line
+        // to the body
+        let s = Cartesian::<f64, Eme2000>::new(384_400e3, 0.0, 0.0);
+        assert_eq!(third_body_acceleration(&Cartesian::new(0.0, 0.0, 0.0), &s, MU_MOON).norm(), 0.0);
+        let a = third_body_acceleration(&Cartesian::new(1.0, 0.0, 0.0), &s, MU_MOON);
+        assert_relative_eq!(a.x(), 2.0 * MU_MOON / 384_400e3_f64.powi(3), max_relative = 1e-8);
+    }
+
+    #[test]
+    fn test_luni_solar_at_geo() {
+        // Luni-solar perturbations at GEO are of order 1e-5 m/s^2, the Moon's
+        // about twice the Sun's
+        let epoch = tt(2024, 6, 1);
+        let r = Cartesian::<f64, Eme2000>::new(42_164e3, 0.0, 0.0);
+        let sun = third_body_acceleration(&r, &sun_position(&epoch), MU_SUN).norm();
+        let moon = third_body_acceleration(&r, &moon_position(&epoch), MU_MOON).norm();
+        assert!(sun > 1e-6 && sun < 5e-6);
+        assert!(moon > 2e-6 && moon < 1.5e-5);
+        let total = luni_solar_acceleration(&r, &epoch).norm();
+        assert!(total <= sun + moon && total >= (sun - moon).abs());
+    }
+}
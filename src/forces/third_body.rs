@@ -0,0 +1,37 @@
+use num_traits::Float;
+
+use super::ephemeris::{moon_position, sun_position};
+use crate::coordinate::Cartesian;
+use crate::time::Epoch;
+
+/// Gravitational parameters of the Sun and the Moon (m^3/s^2), DE430.
+pub const MU_SUN: f64 = 1.327_124_400_41e20;
+pub const MU_MOON: f64 = 4.902_800_066e12;
+
+/// Perturbing acceleration of a third body with gravitational parameter
+/// `mu` at `body`, on an object at `position`, both relative to the central
+/// body.
+///
+/// The direct and indirect terms `mu (d / |d|^3 - s / |s|^3)` nearly cancel
+/// when the object is much closer to the central body than the third body,
+/// so they are combined with Battin's function
+/// `F(q) = q (3 + 3q + q^2) / (1 + (1 + q)^(3/2))`:
+/// `a = -mu (r + F(q) s) / |d|^3`, with `d = r - s` and
+/// `q = r . (r - 2s) / s . s`.
+pub fn third_body_acceleration<T: Float, F>(position: &Cartesian<T, F>, body: &Cartesian<T, F>, mu: T) -> Cartesian<T, F> {
+    let (r, s) = (position.data, body.data);
+    let three = T::from(3.0).unwrap();
+    let d = r - s;
+    let q = r.dot(&(r - s * (T::one() + T::one()))) / s.dot(&s);
+    let f = q * (three + three * q + q * q) / (T::one() + (T::one() + q).powf(T::from(1.5).unwrap()));
+    let d3 = d.dot(&d) * d.norm();
+    Cartesian::from_vector((r + s * f) * (-mu / d3))
+}
+
+/// Combined Sun and Moon perturbations on an object at a geocentric EME2000
+/// `position` (m), with the low-precision ephemerides.
+pub fn luni_solar_acceleration<F>(position: &Cartesian<f64, F>, epoch: &Epoch) -> Cartesian<f64, F> {
+    let sun = third_body_acceleration(position, &sun_position(epoch), MU_SUN);
+    let moon = third_body_acceleration(position, &moon_position(epoch), MU_MOON);
+    Cartesian::from_vector(sun.data + moon.data)
+}
@@ -0,0 +1,79 @@
+use num_traits::Float;
+
+use super::{GravityField, PointMass};
+use crate::coordinate::Cartesian;
+
+fn k<T: Float>(x: f64) -> T {
+    T::from(x).unwrap()
+}
+
+/// Perturbing acceleration of the `J2` zonal harmonic for a body with
+/// gravitational parameter `mu` and reference radius `radius`.
+pub fn j2_acceleration<T: Float, F>(position: &Cartesian<T, F>, mu: T, radius: T, j2: T) -> Cartesian<T, F> {
+    let [x, y, z] = position.data.data;
+    let r2 = position.dot(position);
+    let u2 = z * z / r2;
+    let f = k::<T>(-1.5) * j2 * mu * radius * radius / (r2 * r2 * r2.sqrt());
+    let planar = f * (T::one() - k::<T>(5.0) * u2);
+    Cartesian::new(planar * x, planar * y, f * z * (k::<T>(3.0) - k::<T>(5.0) * u2))
+}
+
+/// Perturbing acceleration of the `J3` zonal harmonic.
+pub fn j3_acceleration<T: Float, F>(position: &Cartesian<T, F>, mu: T, radius: T, j3: T) -> Cartesian<T, F> {
+    let [x, y, z] = position.data.data;
+    let r2 = position.dot(position);
+    let z2 = z * z;
+    let f = k::<T>(-2.5) * j3 * mu * radius.powi(3) / (r2.powi(3) * r2.sqrt());
+    let planar = f * z * (k::<T>(3.0) - k::<T>(7.0) * z2 / r2);
+    let vertical = f * (k::<T>(6.0) * z2 - k::<T>(7.0) * z2 * z2 / r2 - k::<T>(0.6) * r2);
+    Cartesian::new(planar * x, planar * y, vertical)
+}
+
+/// Perturbing acceleration of the `J4` zonal harmonic.
+pub fn j4_acceleration<T: Float, F>(position: &Cartesian<T, F>, mu: T, radius: T, j4: T) -> Cartesian<T, F> {
+    let [x, y, z] = position.data.data;
+    let r2 = position.dot(position);
+    let u2 = z * z / r2;
+    let u4 = u2 * u2;
+    let f = k::<T>(1.875) * j4 * mu * radius.powi(4) / (r2.powi(3) * r2.sqrt());
+    let planar = f * (T::one() - k::<T>(14.0) * u2 + k::<T>(21.0) * u4);
+    Cartesian::new(planar * x, planar * y, f * z * (k::<T>(5.0) - k::<T>(70.0 / 3.0) * u2 + k::<T>(21.0) * u4))
+}
+
+/// Gravity of an axisymmetric body: point mass with the `J2`, `J3` and `J4`
+/// zonal harmonics in closed form.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ZonalHarmonics<T: Float> {
+    pub mu: T,
+    pub radius: T,
+    pub j2: T,
+    pub j3: T,
+    pub j4: T,
+}
+
+impl<T: Float> ZonalHarmonics<T> {
+    pub fn new(mu: T, radius: T, j2: T, j3: T, j4: T) -> Self {
+        Self { mu, radius, j2, j3, j4 }
+    }
+
+    /// The Earth with the EGM2008 zonal harmonics, in m and m^3/s^2.
+    pub fn earth() -> Self {
+        Self::new(k(crate::orbit::MU_EARTH), k(super::EARTH_RADIUS), k(super::J2_EARTH), k(super::J3_EARTH), k(super::J4_EARTH))
+    }
+
+    /// The zonal terms alone, without the central acceleration.
+    pub fn perturbation<F>(&self, position: &Cartesian<T, F>) -> Cartesian<T, F> {
+        let (mu, radius) = (self.mu, self.radius);
+        let j2 = j2_acceleration(position, mu, radius, self.j2).data;
+        let j3 = j3_acceleration(position, mu, radius, self.j3).data;
+        let j4 = j4_acceleration(position, mu, radius, self.j4).data;
+        Cartesian::from_vector(j2 + j3 + j4)
+    }
+}
+
+impl<T: Float> GravityField<T> for ZonalHarmonics<T> {
+    fn acceleration<F>(&self, position: &Cartesian<T, F>) -> Cartesian<T, F> {
+        let central = PointMass::new(self.mu).acceleration(position).data;
+        Cartesian::from_vector(central + self.perturbation(position).data)
+    }
+}
@@ -3,4 +3,8 @@ pub mod attitude;
 pub mod reference_frame;
 pub mod math;
 pub mod numerical_methods;
-pub mod utils;
\ No newline at end of file
+pub mod time;
+pub mod orbit;
+pub mod forces;
+pub mod utils;
+pub use math::{Matrix, Vector};
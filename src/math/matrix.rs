@@ -208,6 +208,135 @@ impl<T: Copy + core::ops::AddAssign + Default, const N: usize> Matrix<T, N, N> {
     }
 }
 
+// Cholesky decomposition
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Float + Default + Copy,
+{
+    /// Lower-triangular `L` with `L * L^T = self` for a symmetric positive-definite
+    /// matrix, or `None` if the matrix is not positive definite.
+    pub fn cholesky(&self) -> Option<Self> {
+        let mut l = Self::zeros();
+        for i in 0..N {
+            for j in 0..=i {
+                let mut sum = self.data[i][j];
+                for k in 0..j {
+                    sum = sum - l.data[i][k] * l.data[j][k];
+                }
+                if i == j {
+                    if sum <= T::zero() {
+                        return None;
+                    }
+                    l.data[i][i] = sum.sqrt();
+                } else {
+                    l.data[i][j] = sum / l.data[j][j];
+                }
+            }
+        }
+        Some(l)
+    }
+}
+
+// LU decomposition
+/// LU factorization with partial pivoting, `P A = L U`, stored compactly:
+/// `U` on and above the diagonal, the unit-diagonal `L` below it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lu<T, const N: usize> {
+    lu: Matrix<T, N, N>,
+    permutation: [usize; N],
+    swaps: usize,
+}
+
+impl<T, const N: usize> Matrix<T, N, N>
+where
+    T: Float + Default + Copy,
+{
+    /// LU factorization, or `None` if the matrix is singular.
+    pub fn lu(&self) -> Option<Lu<T, N>> {
+        let mut lu = *self;
+        let mut permutation = [0; N];
+        for (i, p) in permutation.iter_mut().enumerate() {
+            *p = i;
+        }
+        let mut swaps = 0;
+        for k in 0..N {
+            let pivot = (k..N)
+                .max_by(|&a, &b| lu.data[a][k].abs().partial_cmp(&lu.data[b][k].abs()).unwrap_or(core::cmp::Ordering::Equal))
+                .unwrap();
+            let magnitude = lu.data[pivot][k].abs();
+            if magnitude == T::zero() || !magnitude.is_finite() {
+                return None;
+            }
+            if pivot != k {
+                lu.data.swap(pivot, k);
+                permutation.swap(pivot, k);
+                swaps += 1;
+            }
+            for i in k + 1..N {
+                let factor = lu.data[i][k] / lu.data[k][k];
+                lu.data[i][k] = factor;
+                for j in k + 1..N {
+                    lu.data[i][j] = lu.data[i][j] - factor * lu.data[k][j];
+                }
+            }
+        }
+        Some(Lu { lu, permutation, swaps })
+    }
+
+    /// Solves `self * x = b`, or `None` if the matrix is singular.
+    pub fn solve(&self, b: &Vector<T, N>) -> Option<Vector<T, N>> {
+        Some(self.lu()?.solve(b))
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        Some(self.lu()?.inverse())
+    }
+}
+
+impl<T, const N: usize> Lu<T, N>
+where
+    T: Float + Default + Copy,
+{
+    /// Solves `A x = b` by forward and back substitution.
+    pub fn solve(&self, b: &Vector<T, N>) -> Vector<T, N> {
+        let m = &self.lu.data;
+        let mut x = Vector::<T, N>::default();
+        for (i, row) in m.iter().enumerate() {
+            let mut sum = b.data[self.permutation[i]];
+            for (mij, xj) in row[..i].iter().zip(&x.data[..i]) {
+                sum = sum - *mij * *xj;
+            }
+            x.data[i] = sum;
+        }
+        for (i, row) in m.iter().enumerate().rev() {
+            let mut sum = x.data[i];
+            for (mij, xj) in row[i + 1..].iter().zip(&x.data[i + 1..]) {
+                sum = sum - *mij * *xj;
+            }
+            x.data[i] = sum / row[i];
+        }
+        x
+    }
+
+    pub fn determinant(&self) -> T {
+        let sign = if self.swaps.is_multiple_of(2) { T::one() } else { -T::one() };
+        self.lu.data.iter().enumerate().fold(sign, |det, (i, row)| det * row[i])
+    }
+
+    pub fn inverse(&self) -> Matrix<T, N, N> {
+        let mut out = Matrix::<T, N, N>::zeros();
+        for j in 0..N {
+            let mut e = Vector::<T, N>::default();
+            e.data[j] = T::one();
+            let column = self.solve(&e);
+            for (row, value) in out.data.iter_mut().zip(column.data) {
+                row[j] = value;
+            }
+        }
+        out
+    }
+}
+
 // Determinant
 impl<T: Float + Copy> Matrix<T, 2, 2> {
     pub fn determinant(&self) -> T {
@@ -222,7 +351,7 @@ impl<T: Float + Copy> Matrix<T, 3, 3> {
       + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
     }
 }
-// Larger size matrices to come through LU Decomp or Laplace expansion, need to research.
+// Larger sizes go through `Matrix::lu` and `Lu::determinant`.
 
 // Behavior
 use core::ops::{Index, IndexMut};
@@ -2,7 +2,7 @@ mod matrix;
 mod vector;
 pub mod macros;
 pub use vector::Vector;
-pub use matrix::Matrix;
+pub use matrix::{Lu, Matrix};
 
 #[cfg(test)]
 #[path = "tests/mod.rs"]
@@ -84,4 +84,52 @@ mod tests {
         // ]
         assert_eq!(r.data, [-2.0, -2.0, -2.0]);
     }
+
+    #[test]
+    fn test_matrix_cholesky() {
+        let a = Matrix {
+            data: [
+                [4.0_f64, 12.0, -16.0],
+                [12.0, 37.0, -43.0],
+                [-16.0, -43.0, 98.0],
+            ],
+        };
+        let l = a.cholesky().unwrap();
+        assert_eq!(l.data, [[2.0, 0.0, 0.0], [6.0, 1.0, 0.0], [-8.0, 5.0, 3.0]]);
+        assert_eq!((l * l.transpose()).data, a.data);
+
+        let indefinite = Matrix { data: [[1.0_f64, 2.0], [2.0, 1.0]] };
+        assert!(indefinite.cholesky().is_none());
+    }
+
+    #[test]
+    fn test_matrix_lu() {
+        // Zero leading entry forces a row swap
+        let a = Matrix {
+            data: [
+                [0.0_f64, 2.0, 1.0],
+                [1.0, 1.0, 1.0],
+                [2.0, 1.0, 3.0],
+            ],
+        };
+        let lu = a.lu().unwrap();
+        assert!((lu.determinant() - a.determinant()).abs() < 1e-12);
+
+        let b = Vector { data: [3.0_f64, 3.0, 6.0] };
+        let x = a.solve(&b).unwrap();
+        for (xi, expected) in x.data.iter().zip([1.0, 1.0, 1.0]) {
+            assert!((xi - expected).abs() < 1e-12);
+        }
+
+        let product = a * a.inverse().unwrap();
+        let identity = Matrix::<f64, 3, 3>::identity();
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((product.data[i][j] - identity.data[i][j]).abs() < 1e-12);
+            }
+        }
+
+        let singular = Matrix { data: [[1.0_f64, 2.0], [2.0, 4.0]] };
+        assert!(singular.lu().is_none());
+    }
 }
@@ -0,0 +1,64 @@
+use core::ops::{Add, Div, Mul};
+use num_traits::Float;
+
+use super::float::{jet_float, Jet};
+
+/// Complex number for the complex-step derivative `f'(x) ≈ Im f(x + ih) / h`.
+///
+/// Arithmetic is fully complex. Elementary functions are evaluated to first
+/// order in the imaginary part, which is exact to rounding for the tiny steps
+/// the method uses; they are meant for real-analytic code, not as a general
+/// complex library. See [`complex_step_jacobian`](super::complex_step_jacobian).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Complex<T> {
+    pub re: T,
+    pub im: T,
+}
+
+impl<T: Float> Complex<T> {
+    pub fn new(re: T, im: T) -> Self {
+        Self { re, im }
+    }
+}
+
+impl<T: Float> Jet<T> for Complex<T> {
+    fn real(value: T) -> Self {
+        Self::new(value, T::zero())
+    }
+    fn re(&self) -> T {
+        self.re
+    }
+    fn is_real(&self) -> bool {
+        self.im == T::zero()
+    }
+    fn chain(self, value: T, first: T, _second: T) -> Self {
+        Self::new(value, first * self.im)
+    }
+}
+
+impl<T: Float> Add for Complex<T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl<T: Float> Mul for Complex<T> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(self.re * rhs.re - self.im * rhs.im, self.re * rhs.im + self.im * rhs.re)
+    }
+}
+
+impl<T: Float> Div for Complex<T> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        let denominator = rhs.re * rhs.re + rhs.im * rhs.im;
+        Self::new(
+            (self.re * rhs.re + self.im * rhs.im) / denominator,
+            (self.im * rhs.re - self.re * rhs.im) / denominator,
+        )
+    }
+}
+
+jet_float!(Complex);
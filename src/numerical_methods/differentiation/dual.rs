@@ -0,0 +1,89 @@
+use core::ops::{Add, Div, Mul};
+use num_traits::Float;
+
+use super::float::{jet_float, Jet};
+use crate::math::{Matrix, Vector};
+
+/// Dual number `re + du ε` with `ε² = 0`.
+///
+/// Evaluating `f(x + ε)` gives `f(x) + f'(x) ε` exactly, without truncation
+/// or cancellation error. `Dual<T>` implements [`Float`], so it passes through
+/// generic code such as `Vector`, `Matrix`, `Quaternion` or the coordinate
+/// conversions; see [`dual_jacobian`](super::dual_jacobian).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Dual<T> {
+    pub re: T,
+    pub du: T,
+}
+
+impl<T: Float> Dual<T> {
+    pub fn new(re: T, du: T) -> Self {
+        Self { re, du }
+    }
+
+    /// `x + ε`, the seed of the variable being differentiated.
+    pub fn variable(x: T) -> Self {
+        Self::new(x, T::one())
+    }
+
+    pub fn constant(x: T) -> Self {
+        Self::new(x, T::zero())
+    }
+}
+
+impl<T: Float> Jet<T> for Dual<T> {
+    fn real(value: T) -> Self {
+        Self::constant(value)
+    }
+    fn re(&self) -> T {
+        self.re
+    }
+    fn is_real(&self) -> bool {
+        self.du == T::zero()
+    }
+    fn chain(self, value: T, first: T, _second: T) -> Self {
+        Self::new(value, first * self.du)
+    }
+}
+
+impl<T: Float> Add for Dual<T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.re + rhs.re, self.du + rhs.du)
+    }
+}
+
+impl<T: Float> Mul for Dual<T> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(self.re * rhs.re, self.re * rhs.du + self.du * rhs.re)
+    }
+}
+
+impl<T: Float> Div for Dual<T> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        let re = self.re / rhs.re;
+        Self::new(re, (self.du - re * rhs.du) / rhs.re)
+    }
+}
+
+jet_float!(Dual);
+
+/// Exact Jacobian `df/dx` at `x` by forward-mode automatic differentiation,
+/// one evaluation of `f` per input.
+pub fn dual_jacobian<T, F, const M: usize, const N: usize>(mut f: F, x: &Vector<T, N>) -> Matrix<T, M, N>
+where
+    T: Float + Default,
+    F: FnMut(&Vector<Dual<T>, N>) -> Vector<Dual<T>, M>,
+{
+    let mut jacobian = Matrix::zeros();
+    for j in 0..N {
+        let seeded = Vector::new(core::array::from_fn(|i| if i == j { Dual::variable(x[i]) } else { Dual::constant(x[i]) }));
+        let column = f(&seeded);
+        for i in 0..M {
+            jacobian[(i, j)] = column[i].du;
+        }
+    }
+    jacobian
+}
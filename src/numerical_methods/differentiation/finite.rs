@@ -0,0 +1,126 @@
+//! Finite-difference and complex-step derivatives.
+
+use num_traits::Float;
+
+use super::Complex;
+use crate::math::{Matrix, Vector};
+
+/// Finite-difference scheme.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Difference {
+    /// `(f(x + h) - f(x)) / h`, first order, `N + 1` evaluations
+    Forward,
+    /// `(f(x + h) - f(x - h)) / 2h`, second order, `2N` evaluations
+    #[default]
+    Central,
+}
+
+/// Step `x + h` in component `j` with `h = scale * max(|x_j|, 1)`, returning
+/// the shifted point and the representable step actually taken.
+fn shifted<T: Float, const N: usize>(x: &Vector<T, N>, j: usize, scale: T) -> (Vector<T, N>, T) {
+    let mut shifted = *x;
+    shifted[j] = x[j] + scale * x[j].abs().max(T::one());
+    (shifted, shifted[j] - x[j])
+}
+
+/// Jacobian `df/dx` at `x` by finite differences.
+///
+/// Steps are scaled to `|x_j|` and balance truncation against rounding:
+/// `√ε` forward, `∛ε` central.
+pub fn jacobian<T, F, const M: usize, const N: usize>(mut f: F, x: &Vector<T, N>, difference: Difference) -> Matrix<T, M, N>
+where
+    T: Float + Default,
+    F: FnMut(&Vector<T, N>) -> Vector<T, M>,
+{
+    let mut jacobian = Matrix::zeros();
+    let fx = match difference {
+        Difference::Forward => Some(f(x)),
+        Difference::Central => None,
+    };
+    for j in 0..N {
+        let column = match fx {
+            Some(fx) => {
+                let (forward, h) = shifted(x, j, T::epsilon().sqrt());
+                (f(&forward) - fx) / h
+            }
+            None => {
+                let (forward, h) = shifted(x, j, T::epsilon().cbrt());
+                let mut backward = *x;
+                backward[j] = x[j] - h;
+                (f(&forward) - f(&backward)) / (h + h)
+            }
+        };
+        for i in 0..M {
+            jacobian[(i, j)] = column[i];
+        }
+    }
+    jacobian
+}
+
+/// Gradient of a scalar `f` at `x` by finite differences, see [`jacobian`].
+pub fn gradient<T, F, const N: usize>(mut f: F, x: &Vector<T, N>, difference: Difference) -> Vector<T, N>
+where
+    T: Float + Default,
+    F: FnMut(&Vector<T, N>) -> T,
+{
+    let jacobian = jacobian(|x: &Vector<T, N>| Vector::new([f(x)]), x, difference);
+    Vector::new(jacobian.data[0])
+}
+
+/// Hessian of a scalar `f` at `x` by central second differences with steps
+/// `ε^(1/4) max(|x_j|, 1)`; `2N² + 1` evaluations, symmetric by construction.
+pub fn hessian<T, F, const N: usize>(mut f: F, x: &Vector<T, N>) -> Matrix<T, N, N>
+where
+    T: Float + Default,
+    F: FnMut(&Vector<T, N>) -> T,
+{
+    let scale = T::epsilon().sqrt().sqrt();
+    let steps: [T; N] = core::array::from_fn(|j| shifted(x, j, scale).1);
+    let at = |f: &mut F, i: usize, si: T, j: usize, sj: T| {
+        let mut point = *x;
+        point[i] = point[i] + si * steps[i];
+        point[j] = point[j] + sj * steps[j];
+        f(&point)
+    };
+    let one = T::one();
+    let two = one + one;
+    let centre = f(x);
+    let mut hessian = Matrix::zeros();
+    for i in 0..N {
+        for j in i..N {
+            let value = if i == j {
+                // The same stencil with i == j samples f(x ± 2h)
+                (at(&mut f, i, one, i, one) - centre - centre + at(&mut f, i, -one, i, -one)) / (two * two * steps[i] * steps[i])
+            } else {
+                (at(&mut f, i, one, j, one) - at(&mut f, i, one, j, -one) - at(&mut f, i, -one, j, one)
+                    + at(&mut f, i, -one, j, -one))
+                    / (two * two * steps[i] * steps[j])
+            };
+            hessian[(i, j)] = value;
+            hessian[(j, i)] = value;
+        }
+    }
+    hessian
+}
+
+/// Jacobian `df/dx` at `x` by the complex step `Im f(x + ih e_j) / h`.
+///
+/// Free of subtractive cancellation, so the step can be tiny and the result
+/// is accurate to rounding. `f` must be written generically enough to take
+/// [`Complex`] arguments, e.g. over any `Float`.
+pub fn complex_step_jacobian<T, F, const M: usize, const N: usize>(mut f: F, x: &Vector<T, N>) -> Matrix<T, M, N>
+where
+    T: Float + Default,
+    F: FnMut(&Vector<Complex<T>, N>) -> Vector<Complex<T>, M>,
+{
+    let h = T::epsilon() * T::epsilon();
+    let mut jacobian = Matrix::zeros();
+    for j in 0..N {
+        let stepped = Vector::new(core::array::from_fn(|i| Complex::new(x[i], if i == j { h } else { T::zero() })));
+        let column = f(&stepped);
+        for i in 0..M {
+            jacobian[(i, j)] = column[i].im / h;
+        }
+    }
+    jacobian
+}
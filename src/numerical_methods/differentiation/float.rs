@@ -0,0 +1,329 @@
+//! `num_traits::Float` for the derivative-carrying number types.
+//!
+//! Every elementary function is lifted through [`Jet::chain`] from its value
+//! and first two derivatives at the real part, so the types only define their
+//! own algebra. Comparisons, rounding and classification look at the real part.
+
+use num_traits::Float;
+
+/// A real part carrying infinitesimal parts.
+pub(super) trait Jet<T: Float>: Copy {
+    /// A constant with no infinitesimal part.
+    fn real(value: T) -> Self;
+    fn re(&self) -> T;
+    /// True when every infinitesimal part is zero.
+    fn is_real(&self) -> bool;
+    /// `g(self)` from `g(re)`, `g'(re)` and `g''(re)`.
+    fn chain(self, value: T, first: T, second: T) -> Self;
+}
+
+macro_rules! jet_float {
+    ($jet:ident) => {
+        impl<T: Float> core::ops::Neg for $jet<T> {
+            type Output = Self;
+            fn neg(self) -> Self {
+                self.chain(-self.re(), -T::one(), T::zero())
+            }
+        }
+
+        impl<T: Float> core::ops::Sub for $jet<T> {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self {
+                self + -rhs
+            }
+        }
+
+        impl<T: Float> core::ops::Rem for $jet<T> {
+            type Output = Self;
+            fn rem(self, rhs: Self) -> Self {
+                self - rhs * Self::real((self.re() / rhs.re()).trunc())
+            }
+        }
+
+        impl<T: Float> core::ops::AddAssign for $jet<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl<T: Float> core::ops::SubAssign for $jet<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl<T: Float> core::ops::MulAssign for $jet<T> {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl<T: Float> core::ops::DivAssign for $jet<T> {
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl<T: Float> PartialOrd for $jet<T> {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                self.re().partial_cmp(&other.re())
+            }
+        }
+
+        impl<T: Float> num_traits::Zero for $jet<T> {
+            fn zero() -> Self {
+                Self::real(T::zero())
+            }
+            fn is_zero(&self) -> bool {
+                self.re().is_zero() && self.is_real()
+            }
+        }
+
+        impl<T: Float> num_traits::One for $jet<T> {
+            fn one() -> Self {
+                Self::real(T::one())
+            }
+        }
+
+        impl<T: Float> num_traits::Num for $jet<T> {
+            type FromStrRadixErr = T::FromStrRadixErr;
+            fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+                T::from_str_radix(s, radix).map(Self::real)
+            }
+        }
+
+        impl<T: Float> num_traits::ToPrimitive for $jet<T> {
+            fn to_i64(&self) -> Option<i64> {
+                self.re().to_i64()
+            }
+            fn to_u64(&self) -> Option<u64> {
+                self.re().to_u64()
+            }
+            fn to_f64(&self) -> Option<f64> {
+                self.re().to_f64()
+            }
+        }
+
+        impl<T: Float> num_traits::NumCast for $jet<T> {
+            fn from<N: num_traits::ToPrimitive>(n: N) -> Option<Self> {
+                <T as num_traits::NumCast>::from(n).map(Self::real)
+            }
+        }
+
+        impl<T: Float> Float for $jet<T> {
+            fn nan() -> Self {
+                Self::real(T::nan())
+            }
+            fn infinity() -> Self {
+                Self::real(T::infinity())
+            }
+            fn neg_infinity() -> Self {
+                Self::real(T::neg_infinity())
+            }
+            fn neg_zero() -> Self {
+                Self::real(T::neg_zero())
+            }
+            fn min_value() -> Self {
+                Self::real(T::min_value())
+            }
+            fn min_positive_value() -> Self {
+                Self::real(T::min_positive_value())
+            }
+            fn epsilon() -> Self {
+                Self::real(T::epsilon())
+            }
+            fn max_value() -> Self {
+                Self::real(T::max_value())
+            }
+            fn is_nan(self) -> bool {
+                self.re().is_nan()
+            }
+            fn is_infinite(self) -> bool {
+                self.re().is_infinite()
+            }
+            fn is_finite(self) -> bool {
+                self.re().is_finite()
+            }
+            fn is_normal(self) -> bool {
+                self.re().is_normal()
+            }
+            fn classify(self) -> core::num::FpCategory {
+                self.re().classify()
+            }
+            fn floor(self) -> Self {
+                Self::real(self.re().floor())
+            }
+            fn ceil(self) -> Self {
+                Self::real(self.re().ceil())
+            }
+            fn round(self) -> Self {
+                Self::real(self.re().round())
+            }
+            fn trunc(self) -> Self {
+                Self::real(self.re().trunc())
+            }
+            fn fract(self) -> Self {
+                self.chain(self.re().fract(), T::one(), T::zero())
+            }
+            fn abs(self) -> Self {
+                if self.re() < T::zero() { -self } else { self }
+            }
+            fn signum(self) -> Self {
+                Self::real(self.re().signum())
+            }
+            fn is_sign_positive(self) -> bool {
+                self.re().is_sign_positive()
+            }
+            fn is_sign_negative(self) -> bool {
+                self.re().is_sign_negative()
+            }
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                self * a + b
+            }
+            fn recip(self) -> Self {
+                let r = self.re().recip();
+                self.chain(r, -r * r, (r + r) * r * r)
+            }
+            fn powi(self, n: i32) -> Self {
+                match n {
+                    0 => return Self::real(T::one()),
+                    1 => return self,
+                    _ => {}
+                }
+                let a = self.re();
+                let k = T::from(n).unwrap();
+                self.chain(a.powi(n), k * a.powi(n - 1), k * (k - T::one()) * a.powi(n - 2))
+            }
+            fn powf(self, n: Self) -> Self {
+                if n.is_real() && n.re() == T::one() {
+                    self
+                } else if n.is_real() {
+                    let (a, k) = (self.re(), n.re());
+                    self.chain(a.powf(k), k * a.powf(k - T::one()), k * (k - T::one()) * a.powf(k - T::one() - T::one()))
+                } else {
+                    (self.ln() * n).exp()
+                }
+            }
+            fn sqrt(self) -> Self {
+                let s = self.re().sqrt();
+                let half = T::from(0.5).unwrap();
+                self.chain(s, half / s, -half * half / (s * s * s))
+            }
+            fn exp(self) -> Self {
+                let e = self.re().exp();
+                self.chain(e, e, e)
+            }
+            fn exp2(self) -> Self {
+                let (e, l) = (self.re().exp2(), T::from(core::f64::consts::LN_2).unwrap());
+                self.chain(e, l * e, l * l * e)
+            }
+            fn ln(self) -> Self {
+                let a = self.re();
+                self.chain(a.ln(), a.recip(), -(a * a).recip())
+            }
+            fn log(self, base: Self) -> Self {
+                self.ln() / base.ln()
+            }
+            fn log2(self) -> Self {
+                self.ln() / Self::real(T::from(core::f64::consts::LN_2).unwrap())
+            }
+            fn log10(self) -> Self {
+                self.ln() / Self::real(T::from(core::f64::consts::LN_10).unwrap())
+            }
+            fn max(self, other: Self) -> Self {
+                if other.re() > self.re() || self.re().is_nan() { other } else { self }
+            }
+            fn min(self, other: Self) -> Self {
+                if other.re() < self.re() || self.re().is_nan() { other } else { self }
+            }
+            fn abs_sub(self, other: Self) -> Self {
+                (self - other).max(Self::real(T::zero()))
+            }
+            fn cbrt(self) -> Self {
+                let c = self.re().cbrt();
+                let three = T::from(3.0).unwrap();
+                self.chain(c, (three * c * c).recip(), -(T::one() + T::one()) / (three * three * c.powi(5)))
+            }
+            fn hypot(self, other: Self) -> Self {
+                (self * self + other * other).sqrt()
+            }
+            fn sin(self) -> Self {
+                let (s, c) = self.re().sin_cos();
+                self.chain(s, c, -s)
+            }
+            fn cos(self) -> Self {
+                let (s, c) = self.re().sin_cos();
+                self.chain(c, -s, -c)
+            }
+            fn tan(self) -> Self {
+                let t = self.re().tan();
+                let d = T::one() + t * t;
+                self.chain(t, d, (t + t) * d)
+            }
+            fn asin(self) -> Self {
+                let a = self.re();
+                let d = T::one() - a * a;
+                self.chain(a.asin(), d.sqrt().recip(), a / (d * d.sqrt()))
+            }
+            fn acos(self) -> Self {
+                let a = self.re();
+                let d = T::one() - a * a;
+                self.chain(a.acos(), -d.sqrt().recip(), -a / (d * d.sqrt()))
+            }
+            fn atan(self) -> Self {
+                let a = self.re();
+                let d = T::one() + a * a;
+                self.chain(a.atan(), d.recip(), -(a + a) / (d * d))
+            }
+            fn atan2(self, other: Self) -> Self {
+                // Differentiate whichever ratio is bounded; the quadrant only shifts by a constant
+                let base = if other.re().abs() >= self.re().abs() { (self / other).atan() } else { -(other / self).atan() };
+                base + Self::real(self.re().atan2(other.re()) - base.re())
+            }
+            fn sin_cos(self) -> (Self, Self) {
+                (self.sin(), self.cos())
+            }
+            fn exp_m1(self) -> Self {
+                let e = self.re().exp();
+                self.chain(self.re().exp_m1(), e, e)
+            }
+            fn ln_1p(self) -> Self {
+                let d = (T::one() + self.re()).recip();
+                self.chain(self.re().ln_1p(), d, -d * d)
+            }
+            fn sinh(self) -> Self {
+                let a = self.re();
+                self.chain(a.sinh(), a.cosh(), a.sinh())
+            }
+            fn cosh(self) -> Self {
+                let a = self.re();
+                self.chain(a.cosh(), a.sinh(), a.cosh())
+            }
+            fn tanh(self) -> Self {
+                let t = self.re().tanh();
+                let d = T::one() - t * t;
+                self.chain(t, d, -(t + t) * d)
+            }
+            fn asinh(self) -> Self {
+                let a = self.re();
+                let d = a * a + T::one();
+                self.chain(a.asinh(), d.sqrt().recip(), -a / (d * d.sqrt()))
+            }
+            fn acosh(self) -> Self {
+                let a = self.re();
+                let d = a * a - T::one();
+                self.chain(a.acosh(), d.sqrt().recip(), -a / (d * d.sqrt()))
+            }
+            fn atanh(self) -> Self {
+                let a = self.re();
+                let d = T::one() - a * a;
+                self.chain(a.atanh(), d.recip(), (a + a) / (d * d))
+            }
+            fn integer_decode(self) -> (u64, i16, i8) {
+                self.re().integer_decode()
+            }
+        }
+    };
+}
+
+pub(super) use jet_float;
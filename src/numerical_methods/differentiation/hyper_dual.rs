@@ -0,0 +1,101 @@
+use core::ops::{Add, Div, Mul};
+use num_traits::Float;
+
+use super::float::{jet_float, Jet};
+use crate::math::{Matrix, Vector};
+
+/// Hyper-dual number `re + e1 ε₁ + e2 ε₂ + e12 ε₁ε₂` with `ε₁² = ε₂² = 0`
+/// (Fike & Alonso, AIAA 2011-886).
+///
+/// Seeding `x_i + ε₁` and `x_j + ε₂` gives `∂f/∂x_i`, `∂f/∂x_j` and
+/// `∂²f/∂x_i∂x_j` exactly in one evaluation; see
+/// [`hyper_dual_hessian`](super::hyper_dual_hessian).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct HyperDual<T> {
+    pub re: T,
+    pub e1: T,
+    pub e2: T,
+    pub e12: T,
+}
+
+impl<T: Float> HyperDual<T> {
+    pub fn new(re: T, e1: T, e2: T, e12: T) -> Self {
+        Self { re, e1, e2, e12 }
+    }
+
+    pub fn constant(x: T) -> Self {
+        Self::new(x, T::zero(), T::zero(), T::zero())
+    }
+}
+
+impl<T: Float> Jet<T> for HyperDual<T> {
+    fn real(value: T) -> Self {
+        Self::constant(value)
+    }
+    fn re(&self) -> T {
+        self.re
+    }
+    fn is_real(&self) -> bool {
+        self.e1 == T::zero() && self.e2 == T::zero() && self.e12 == T::zero()
+    }
+    fn chain(self, value: T, first: T, second: T) -> Self {
+        Self::new(value, first * self.e1, first * self.e2, first * self.e12 + second * self.e1 * self.e2)
+    }
+}
+
+impl<T: Float> Add for HyperDual<T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.re + rhs.re, self.e1 + rhs.e1, self.e2 + rhs.e2, self.e12 + rhs.e12)
+    }
+}
+
+impl<T: Float> Mul for HyperDual<T> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.re * rhs.re,
+            self.re * rhs.e1 + self.e1 * rhs.re,
+            self.re * rhs.e2 + self.e2 * rhs.re,
+            self.re * rhs.e12 + self.e1 * rhs.e2 + self.e2 * rhs.e1 + self.e12 * rhs.re,
+        )
+    }
+}
+
+impl<T: Float> Div for HyperDual<T> {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.recip()
+    }
+}
+
+jet_float!(HyperDual);
+
+/// Value, gradient and exact Hessian of a scalar `f` at `x`, from
+/// `N (N + 1) / 2` hyper-dual evaluations.
+pub fn hyper_dual_hessian<T, F, const N: usize>(mut f: F, x: &Vector<T, N>) -> (T, Vector<T, N>, Matrix<T, N, N>)
+where
+    T: Float + Default,
+    F: FnMut(&Vector<HyperDual<T>, N>) -> HyperDual<T>,
+{
+    let mut value = T::zero();
+    let mut gradient = Vector::new([T::zero(); N]);
+    let mut hessian = Matrix::zeros();
+    for i in 0..N {
+        for j in i..N {
+            let seeded = Vector::new(core::array::from_fn(|k| {
+                let seed = |m: usize| if k == m { T::one() } else { T::zero() };
+                HyperDual::new(x[k], seed(i), seed(j), T::zero())
+            }));
+            let y = f(&seeded);
+            value = y.re;
+            if i == j {
+                gradient[i] = y.e1;
+            }
+            hessian[(i, j)] = y.e12;
+            hessian[(j, i)] = y.e12;
+        }
+    }
+    (value, gradient, hessian)
+}
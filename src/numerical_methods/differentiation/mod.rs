@@ -0,0 +1,28 @@
+//! Numerical and automatic differentiation.
+//!
+//! [`jacobian`], [`gradient`] and [`hessian`] use finite differences and need
+//! only a function over `Vector<T, N>`. Functions written generically over
+//! `T: Float` can instead be evaluated on the derivative-carrying number types,
+//! which all implement [`Float`](num_traits::Float):
+//!
+//! - [`Dual`] for exact first derivatives ([`dual_jacobian`]),
+//! - [`HyperDual`] for exact second derivatives ([`hyper_dual_hessian`]),
+//! - [`Complex`] for the complex-step method ([`complex_step_jacobian`]).
+//!
+//! They pass through `Vector`, `Matrix`, `Quaternion` and the coordinate
+//! conversions unchanged. Comparisons and branches follow the real part, so
+//! derivatives of piecewise functions are those of the branch taken.
+
+mod complex;
+mod dual;
+mod finite;
+mod float;
+mod hyper_dual;
+pub use complex::Complex;
+pub use dual::{dual_jacobian, Dual};
+pub use finite::{complex_step_jacobian, gradient, hessian, jacobian, Difference};
+pub use hyper_dual::{hyper_dual_hessian, HyperDual};
+
+#[cfg(test)]
+#[path = "tests/mod.rs"]
+mod tests;
@@ -0,0 +1,132 @@
+#[cfg(test)]
+mod tests {
+    use crate::attitude::Quaternion;
+    use crate::coordinate::{Cartesian, Spherical};
+    use crate::math::{Matrix, Vector};
+    use crate::numerical_methods::differentiation::*;
+    use approx::assert_relative_eq;
+    use num_traits::Float;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Default)]
+    struct TestFrame;
+
+    // f(x, y, z) = (x y sin z, exp(x) / (1 + y^2)), written once for every number type
+    fn f<T: Float>(v: &Vector<T, 3>) -> Vector<T, 2> {
+        let [x, y, z] = v.data;
+        Vector::new([x * y * z.sin(), x.exp() / (T::one() + y * y)])
+    }
+
+    fn exact_jacobian(v: &Vector<f64, 3>) -> Matrix<f64, 2, 3> {
+        let [x, y, z] = v.data;
+        let d = 1.0 + y * y;
+        Matrix::new([
+            [y * z.sin(), x * z.sin(), x * y * z.cos()],
+            [x.exp() / d, -2.0 * y * x.exp() / (d * d), 0.0],
+        ])
+    }
+
+    fn assert_matrix_eq<const M: usize, const N: usize>(a: &Matrix<f64, M, N>, b: &Matrix<f64, M, N>, epsilon: f64) {
+        for i in 0..M {
+            for j in 0..N {
+                assert_relative_eq!(a[(i, j)], b[(i, j)], epsilon = epsilon, max_relative = epsilon);
+            }
+        }
+    }
+
+    #[test]
+    fn test_jacobians() {
+        let x = Vector::new([0.7, -1.3, 2.1]);
+        let exact = exact_jacobian(&x);
+        assert_matrix_eq(&jacobian(f, &x, Difference::Forward), &exact, 1e-7);
+        assert_matrix_eq(&jacobian(f, &x, Difference::Central), &exact, 1e-10);
+        assert_matrix_eq(&complex_step_jacobian(f, &x), &exact, 1e-15);
+        assert_matrix_eq(&dual_jacobian(f, &x), &exact, 1e-15);
+
+        let g = gradient(|v: &Vector<f64, 3>| f(v)[0], &x, Difference::Central);
+        assert_relative_eq!(g[2], exact[(0, 2)], epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_hessians() {
+        // g = x^2 y + sin(y z) + sqrt(x) / z
+        fn g<T: Float>(v: &Vector<T, 3>) -> T {
+            let [x, y, z] = v.data;
+            x * x * y + (y * z).sin() + x.sqrt() / z
+        }
+        let x = Vector::new([1.5, 0.4, -0.8]);
+        let [a, b, c] = x.data;
+        let exact = Matrix::new([
+            [2.0 * b - 0.25 * a.powf(-1.5) / c, 2.0 * a, -0.5 / (a.sqrt() * c * c)],
+            [2.0 * a, -c * c * (b * c).sin(), (b * c).cos() - b * c * (b * c).sin()],
+            [-0.5 / (a.sqrt() * c * c), (b * c).cos() - b * c * (b * c).sin(), -b * b * (b * c).sin() + 2.0 * a.sqrt() / (c * c * c)],
+        ]);
+        let (value, gradient, hessian_ad) = hyper_dual_hessian(g, &x);
+        assert_relative_eq!(value, g(&x), epsilon = 1e-15);
+        assert_relative_eq!(gradient[0], 2.0 * a * b + 0.5 / (a.sqrt() * c), epsilon = 1e-14);
+        assert_matrix_eq(&hessian_ad, &exact, 1e-13);
+        assert_matrix_eq(&hessian(g, &x), &exact, 1e-6);
+    }
+
+    #[test]
+    fn test_dual_arithmetic() {
+        let x = Dual::variable(0.5_f64);
+        // Quotient, powers, inverse trigonometric and hyperbolic functions
+        let y = (x.powi(3) + x.powf(Dual::constant(2.5))) / x.atan2(Dual::constant(-1.0)) + x.asinh().tanh();
+        let h = 1e-6;
+        let plain = |x: f64| (x.powi(3) + x.powf(2.5)) / x.atan2(-1.0) + x.asinh().tanh();
+        assert_relative_eq!(y.re, plain(0.5), epsilon = 1e-15);
+        assert_relative_eq!(y.du, (plain(0.5 + h) - plain(0.5 - h)) / (2.0 * h), epsilon = 1e-8);
+
+        // Comparisons follow the real part; a variable exponent differentiates through ln
+        assert!(Dual::new(1.0, 5.0) < Dual::new(2.0, -5.0));
+        assert_eq!(Dual::variable(-2.0).abs(), Dual::new(2.0, -1.0));
+        let z = Dual::constant(2.0_f64).powf(Dual::variable(3.0));
+        assert_relative_eq!(z.du, 8.0 * 2.0_f64.ln(), epsilon = 1e-14);
+        assert_eq!(<Dual<f64> as num_traits::NumCast>::from(3), Some(Dual::constant(3.0)));
+
+        // Second derivatives through the hyper-dual chain rule
+        let w = HyperDual::new(0.3_f64, 1.0, 1.0, 0.0);
+        let s = (w * w).exp().sqrt().recip();
+        assert_relative_eq!(s.e12, (0.3_f64 * 0.3 - 1.0) * (-0.3_f64 * 0.3 / 2.0).exp(), epsilon = 1e-14);
+    }
+
+    #[test]
+    fn test_generic_code() {
+        // Cartesian from spherical matches the hand-derived Jacobian
+        let point = Vector::new([7000.0, 0.4, 1.1]);
+        let to_cartesian = |v: &Vector<Dual<f64>, 3>| {
+            let spherical = Spherical::<_, TestFrame>::new(v[0], v[1], v[2]);
+            Cartesian::from(&spherical).data
+        };
+        let expected = Spherical::<f64, TestFrame>::new(point[0], point[1], point[2]).jacobian_to_cartesian();
+        assert_matrix_eq(&dual_jacobian(to_cartesian, &point), &expected, 1e-12);
+
+        // Rotating about z by a quaternion: d/dθ of the rotated x axis is the y axis
+        let rotate = |v: &Vector<Dual<f64>, 1>| {
+            let (s, c) = (v[0] * Dual::constant(0.5)).sin_cos();
+            let q = Quaternion::new(c, Dual::constant(0.0), Dual::constant(0.0), s);
+            let p = Quaternion::new(Dual::constant(0.0), Dual::constant(1.0), Dual::constant(0.0), Dual::constant(0.0));
+            let r = q * p * Quaternion::new(c, Dual::constant(0.0), Dual::constant(0.0), -s);
+            Vector::new([r.i(), r.j()])
+        };
+        let rate = dual_jacobian(rotate, &Vector::new([0.0]));
+        assert_relative_eq!(rate[(0, 0)], 0.0, epsilon = 1e-15);
+        assert_relative_eq!(rate[(1, 0)], 1.0, epsilon = 1e-15);
+
+        // Matrix inverse through LU: d(A^-1)/dt = -A^-1 A' A^-1
+        let inverse = |v: &Vector<Dual<f64>, 1>| {
+            let t = v[0];
+            let a = Matrix::new([[t + Dual::constant(2.0), Dual::constant(1.0)], [Dual::constant(1.0), t * t + Dual::constant(3.0)]]);
+            let inv = a.inverse().unwrap();
+            Vector::new([inv[(0, 0)], inv[(0, 1)], inv[(1, 1)]])
+        };
+        let t = 0.5;
+        let a = Matrix::new([[t + 2.0, 1.0], [1.0, t * t + 3.0]]);
+        let a_inv = a.inverse().unwrap();
+        let expected = -(a_inv * Matrix::new([[1.0, 0.0], [0.0, 2.0 * t]]) * a_inv);
+        let derivative = dual_jacobian(inverse, &Vector::new([t]));
+        assert_relative_eq!(derivative[(0, 0)], expected[(0, 0)], epsilon = 1e-14);
+        assert_relative_eq!(derivative[(1, 0)], expected[(0, 1)], epsilon = 1e-14);
+        assert_relative_eq!(derivative[(2, 0)], expected[(1, 1)], epsilon = 1e-14);
+    }
+}
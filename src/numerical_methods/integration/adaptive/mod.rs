@@ -0,0 +1,639 @@
+//! Adaptive Runge-Kutta solvers with embedded error estimation.
+//!
+//! Every step is attempted with the current step size and the embedded
+//! lower-order solution gives a local error estimate, weighed against
+//! per-component absolute and relative tolerances. Rejected steps are retried
+//! with a smaller step; accepted steps choose the next one. The solvers work on
+//! stack-allocated `Vector<T, N>` states and need no allocation, only the
+//! trajectory and dense-solution collectors require `std`.
+
+use num_traits::Float;
+
+use crate::math::Vector;
+use super::events::{Event, EventOutcome, EventRecord, EventTracker};
+
+pub(super) mod tableaux;
+use tableaux::{dop853, dopri5, rkf45};
+
+#[cfg(feature = "std")]
+use super::solver::Trajectory;
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+/// Enough stage storage for the largest tableau (DOP853 with dense output).
+const MAX_STAGES: usize = 16;
+/// Bounds on the step-size change after a single step.
+const MIN_FACTOR: f64 = 0.2;
+const MAX_FACTOR: f64 = 10.0;
+/// Consecutive trial steps with a non-finite error estimate before giving up.
+const MAX_NON_FINITE: usize = 10;
+
+/// Embedded Runge-Kutta pairs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmbeddedPair {
+    /// Runge-Kutta-Fehlberg 4(5) with cubic Hermite dense output.
+    Fehlberg45,
+    /// Dormand-Prince 5(4) with its fourth-order continuous extension.
+    DormandPrince54,
+    /// Dormand-Prince 8(5,3) (DOP853) with its seventh-order continuous extension.
+    DormandPrince853,
+}
+
+impl EmbeddedPair {
+    /// Order of the propagated solution.
+    pub fn order(&self) -> u32 {
+        match self {
+            EmbeddedPair::Fehlberg45 => 4,
+            EmbeddedPair::DormandPrince54 => 5,
+            EmbeddedPair::DormandPrince853 => 8,
+        }
+    }
+
+    /// `1 / (q + 1)` where `q` is the order of the error estimate.
+    fn error_exponent<T: Float>(&self) -> T {
+        match self {
+            EmbeddedPair::Fehlberg45 | EmbeddedPair::DormandPrince54 => T::from(1.0 / 5.0).unwrap(),
+            EmbeddedPair::DormandPrince853 => T::from(1.0 / 8.0).unwrap(),
+        }
+    }
+}
+
+/// Per-component error tolerances. A step is accepted when the RMS of
+/// `error[i] / (absolute[i] + relative[i] * |y[i]|)` is at most one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tolerances<T, const N: usize> {
+    pub absolute: Vector<T, N>,
+    pub relative: Vector<T, N>,
+}
+
+impl<T: Float, const N: usize> Tolerances<T, N> {
+    pub fn new(absolute: Vector<T, N>, relative: Vector<T, N>) -> Self {
+        Self { absolute, relative }
+    }
+
+    /// The same tolerances for every component.
+    pub fn uniform(absolute: T, relative: T) -> Self {
+        Self::new(Vector::new([absolute; N]), Vector::new([relative; N]))
+    }
+
+    /// Weighted RMS norm of a local error estimate over a step from `y0` to `y1`.
+    pub fn error_norm(&self, error: &Vector<T, N>, y0: &Vector<T, N>, y1: &Vector<T, N>) -> T {
+        rms(&self.weighted(error, y0, y1))
+    }
+
+    pub(crate) fn weighted(&self, v: &Vector<T, N>, y0: &Vector<T, N>, y1: &Vector<T, N>) -> Vector<T, N> {
+        let mut out = *v;
+        for i in 0..N {
+            let scale = self.absolute[i] + self.relative[i] * y0[i].abs().max(y1[i].abs());
+            out[i] = v[i] / scale;
+        }
+        out
+    }
+}
+
+/// Work counters, accumulated over every solve until reset.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Statistics {
+    pub accepted_steps: usize,
+    pub rejected_steps: usize,
+    pub function_evaluations: usize,
+    /// Jacobian evaluations, implicit solvers only
+    pub jacobian_evaluations: usize,
+    /// LU factorizations of iteration matrices, implicit solvers only
+    pub lu_decompositions: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IntegrationError<T> {
+    /// The step needed to meet the tolerances fell below the minimum step at `t`.
+    StepSizeTooSmall { t: T },
+    /// The step limit was reached at `t` before the final time.
+    MaxStepsExceeded { t: T },
+    /// The derivative was NaN or infinite at `t`, or on every trial step from `t`.
+    NonFinite { t: T },
+    /// The Newton iteration of a fixed implicit step from `t` failed, even on
+    /// the shortest substep.
+    ConvergenceFailure { t: T },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Interpolant {
+    /// `y0 + sum_j c[j] theta^(j+1)`
+    Power,
+    /// DOP853 form, nesting the coefficients alternately in `theta` and `1 - theta`
+    Alternating,
+}
+
+/// Continuous solution over one accepted step.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DenseSegment<T, const N: usize> {
+    t0: T,
+    t1: T,
+    y0: Vector<T, N>,
+    coefficients: [Vector<T, N>; 7],
+    len: usize,
+    form: Interpolant,
+}
+
+impl<T: Float, const N: usize> DenseSegment<T, N> {
+    /// Interpolant `y0 + sum_j c[j] theta^(j+1)` over `[t0, t1]`, at most seven coefficients.
+    pub(crate) fn power(t0: T, t1: T, y0: Vector<T, N>, c: &[Vector<T, N>]) -> Self {
+        let mut coefficients = [Vector::new([T::zero(); N]); 7];
+        coefficients[..c.len()].copy_from_slice(c);
+        Self { t0, t1, y0, coefficients, len: c.len(), form: Interpolant::Power }
+    }
+
+    pub fn start(&self) -> T { self.t0 }
+    pub fn end(&self) -> T { self.t1 }
+
+    /// Whether `t` lies within the step (in either direction of integration).
+    pub fn contains(&self, t: T) -> bool {
+        (t - self.t0) * (t - self.t1) <= T::zero()
+    }
+
+    /// Interpolated state at `t`. Outside the step the polynomial is extrapolated.
+    pub fn evaluate(&self, t: T) -> Vector<T, N> {
+        let theta = (t - self.t0) / (self.t1 - self.t0);
+        let coefficients = &self.coefficients[..self.len];
+        let mut acc = Vector::new([T::zero(); N]);
+        match self.form {
+            Interpolant::Power => {
+                for c in coefficients.iter().rev() {
+                    acc = (acc + *c) * theta;
+                }
+            }
+            Interpolant::Alternating => {
+                for (i, c) in coefficients.iter().rev().enumerate() {
+                    let factor = if i % 2 == 0 { theta } else { T::one() - theta };
+                    acc = (acc + *c) * factor;
+                }
+            }
+        }
+        self.y0 + acc
+    }
+}
+
+/// One accepted step, handed to the observer of [`AdaptiveSolver::solve_with`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdaptiveStep<T, const N: usize> {
+    pub t_previous: T,
+    pub y_previous: Vector<T, N>,
+    pub t: T,
+    pub y: Vector<T, N>,
+    /// Interpolant over the step, present when `dense_output` is enabled
+    pub dense: Option<DenseSegment<T, N>>,
+}
+
+/// Adaptive step-size solver for `y' = f(t, y)` built on an embedded pair.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AdaptiveSolver<T, const N: usize> {
+    pub method: EmbeddedPair,
+    pub tolerances: Tolerances<T, N>,
+    /// Smallest step before giving up; never below a few ulps of `t` or of
+    /// the integration span
+    pub min_step: T,
+    pub max_step: T,
+    /// First step size; chosen from the problem when `None`
+    pub initial_step: Option<T>,
+    /// Safety factor applied to the optimal step size
+    pub safety: T,
+    /// Maximum number of accepted steps per solve
+    pub max_steps: usize,
+    /// Build a [`DenseSegment`] for every step; costs three extra evaluations
+    /// per step with DOP853 and none with the other pairs
+    pub dense_output: bool,
+    statistics: Statistics,
+}
+
+impl<T: Float, const N: usize> AdaptiveSolver<T, N> {
+    pub fn new(method: EmbeddedPair, tolerances: Tolerances<T, N>) -> Self {
+        Self {
+            method,
+            tolerances,
+            min_step: T::zero(),
+            max_step: T::infinity(),
+            initial_step: None,
+            safety: T::from(0.9).unwrap(),
+            max_steps: 100_000,
+            dense_output: false,
+            statistics: Statistics::default(),
+        }
+    }
+
+    pub fn statistics(&self) -> Statistics {
+        self.statistics
+    }
+
+    pub fn reset_statistics(&mut self) {
+        self.statistics = Statistics::default();
+    }
+
+    /// Integrates from `t0` to `tf` (either direction), calling `observer` after
+    /// every accepted step. Returns the state at `tf`.
+    pub fn solve_with<F, O>(
+        &mut self,
+        mut f: F,
+        y0: &Vector<T, N>,
+        t0: T,
+        tf: T,
+        mut observer: O,
+    ) -> Result<Vector<T, N>, IntegrationError<T>>
+    where
+        F: FnMut(T, &Vector<T, N>) -> Vector<T, N>,
+        O: FnMut(&AdaptiveStep<T, N>),
+    {
+        let mut evaluations = 0;
+        let mut rhs = |t: T, y: &Vector<T, N>| {
+            evaluations += 1;
+            f(t, y)
+        };
+        let result = self.integrate(&mut rhs, y0, t0, tf, &mut |step: &AdaptiveStep<T, N>| {
+            observer(step);
+            true
+        });
+        self.statistics.function_evaluations += evaluations;
+        result
+    }
+
+    /// Integrates from `t0` to `tf`, watching `events`. Crossings are located
+    /// on the dense output, so `dense_output` is switched on for the solve.
+    /// `on_event` receives every crossing; the solve stops at the first
+    /// terminal one.
+    pub fn solve_with_events<F, O, const E: usize>(
+        &mut self,
+        mut f: F,
+        y0: &Vector<T, N>,
+        t0: T,
+        tf: T,
+        events: &[Event<'_, T, Vector<T, N>>; E],
+        mut on_event: O,
+    ) -> Result<EventOutcome<T, Vector<T, N>>, IntegrationError<T>>
+    where
+        F: FnMut(T, &Vector<T, N>) -> Vector<T, N>,
+        O: FnMut(&EventRecord<T, Vector<T, N>>),
+    {
+        let dense_output = self.dense_output;
+        self.dense_output = true;
+        let mut evaluations = 0;
+        let mut rhs = |t: T, y: &Vector<T, N>| {
+            evaluations += 1;
+            f(t, y)
+        };
+        let mut tracker = EventTracker::new(events, t0, y0);
+        let mut terminal = None;
+        let result = self.integrate(&mut rhs, y0, t0, tf, &mut |step: &AdaptiveStep<T, N>| {
+            let Some(dense) = step.dense else { return true };
+            terminal = tracker.check(events, step.t_previous, step.t, &step.y, |t| dense.evaluate(t), &mut on_event);
+            terminal.is_none()
+        });
+        self.statistics.function_evaluations += evaluations;
+        self.dense_output = dense_output;
+
+        let y = result?;
+        Ok(match terminal {
+            Some(record) => EventOutcome { t: record.t, state: record.state, terminated_by: Some(record.index) },
+            None => EventOutcome { t: tf, state: y, terminated_by: None },
+        })
+    }
+
+    /// Integrates from `t0` to `tf` and records every accepted step, starting with `t0`.
+    #[cfg(feature = "std")]
+    pub fn solve<F>(&mut self, f: F, y0: &Vector<T, N>, t0: T, tf: T) -> Result<Trajectory<T, Vector<T, N>>, IntegrationError<T>>
+    where
+        F: FnMut(T, &Vector<T, N>) -> Vector<T, N>,
+    {
+        let mut trajectory = Trajectory::new();
+        trajectory.push(t0, *y0);
+        self.solve_with(f, y0, t0, tf, |step| trajectory.push(step.t, step.y))?;
+        Ok(trajectory)
+    }
+
+    /// Integrates from `t0` to `tf` and keeps the interpolant of every step.
+    #[cfg(feature = "std")]
+    pub fn solve_dense<F>(&mut self, f: F, y0: &Vector<T, N>, t0: T, tf: T) -> Result<DenseSolution<T, N>, IntegrationError<T>>
+    where
+        F: FnMut(T, &Vector<T, N>) -> Vector<T, N>,
+    {
+        let dense_output = self.dense_output;
+        self.dense_output = true;
+        let mut segments = Vec::new();
+        let result = self.solve_with(f, y0, t0, tf, |step| segments.extend(step.dense));
+        self.dense_output = dense_output;
+        result.map(|_| DenseSolution { segments })
+    }
+
+    fn integrate<F, O>(
+        &mut self,
+        rhs: &mut F,
+        y0: &Vector<T, N>,
+        t0: T,
+        tf: T,
+        observer: &mut O,
+    ) -> Result<Vector<T, N>, IntegrationError<T>>
+    where
+        F: FnMut(T, &Vector<T, N>) -> Vector<T, N>,
+        O: FnMut(&AdaptiveStep<T, N>) -> bool,
+    {
+        let mut t = t0;
+        let mut y = *y0;
+        if tf == t0 {
+            return Ok(y);
+        }
+        let direction = (tf - t0).signum();
+        let mut dy = rhs(t, &y);
+        if !is_finite(&dy) {
+            return Err(IntegrationError::NonFinite { t });
+        }
+
+        let min_factor = T::from(MIN_FACTOR).unwrap();
+        let max_factor = T::from(MAX_FACTOR).unwrap();
+        let exponent = self.method.error_exponent::<T>();
+        let ulps = T::epsilon() * T::from(10.0).unwrap();
+        let span = (tf - t0).abs();
+
+        let mut h = match self.initial_step {
+            Some(h0) => h0.abs(),
+            None => initial_step_size(rhs, &self.tolerances, self.method.order(), self.max_step, t, &y, &dy, direction),
+        };
+        h = h.min(self.max_step).min((tf - t0).abs());
+
+        let mut k = [Vector::new([T::zero(); N]); MAX_STAGES];
+        let mut steps = 0;
+        while (tf - t) * direction > T::zero() {
+            if steps >= self.max_steps {
+                return Err(IntegrationError::MaxStepsExceeded { t });
+            }
+            let min_step = self.min_step.max(ulps * t.abs().max(span));
+            let mut rejected = false;
+            let mut non_finite = 0;
+            loop {
+                if h < min_step {
+                    return Err(IntegrationError::StepSizeTooSmall { t });
+                }
+                let mut step = h * direction;
+                let mut t_new = t + step;
+                if (t_new - tf) * direction >= T::zero() {
+                    t_new = tf;
+                    step = tf - t;
+                }
+
+                let (y_new, error) = self.attempt(rhs, t, &y, &dy, step, &mut k);
+                if !error.is_finite() {
+                    self.statistics.rejected_steps += 1;
+                    rejected = true;
+                    non_finite += 1;
+                    if non_finite >= MAX_NON_FINITE {
+                        return Err(IntegrationError::NonFinite { t });
+                    }
+                    h = step.abs() * min_factor;
+                    continue;
+                }
+                if error > T::one() {
+                    self.statistics.rejected_steps += 1;
+                    rejected = true;
+                    h = step.abs() * (self.safety * error.powf(-exponent)).max(min_factor);
+                    continue;
+                }
+
+                let mut factor = if error == T::zero() {
+                    max_factor
+                } else {
+                    (self.safety * error.powf(-exponent)).max(min_factor).min(max_factor)
+                };
+                if rejected {
+                    factor = factor.min(T::one());
+                }
+
+                let dy_new = self.end_derivative(rhs, t_new, &y_new, &mut k);
+                let dense = if self.dense_output {
+                    Some(self.dense_segment(rhs, t, t_new, &y, &y_new, &dy_new, step, &mut k))
+                } else {
+                    None
+                };
+                let proceed = observer(&AdaptiveStep { t_previous: t, y_previous: y, t: t_new, y: y_new, dense });
+
+                self.statistics.accepted_steps += 1;
+                steps += 1;
+                t = t_new;
+                y = y_new;
+                dy = dy_new;
+                h = (step.abs() * factor).min(self.max_step);
+                if !proceed {
+                    return Ok(y);
+                }
+                break;
+            }
+        }
+        Ok(y)
+    }
+
+    /// One trial step of size `h`, returning the new state and the weighted error norm.
+    fn attempt<F>(
+        &self,
+        rhs: &mut F,
+        t: T,
+        y: &Vector<T, N>,
+        dy: &Vector<T, N>,
+        h: T,
+        k: &mut [Vector<T, N>; MAX_STAGES],
+    ) -> (Vector<T, N>, T)
+    where
+        F: FnMut(T, &Vector<T, N>) -> Vector<T, N>,
+    {
+        k[0] = *dy;
+        match self.method {
+            EmbeddedPair::Fehlberg45 => {
+                stages(rhs, t, y, h, &rkf45::C, &rkf45::A, k, 1..6);
+                let y_new = combine(y, h, &rkf45::B, k);
+                let error = combine(&Vector::new([T::zero(); N]), h, &rkf45::E, k);
+                (y_new, self.tolerances.error_norm(&error, y, &y_new))
+            }
+            EmbeddedPair::DormandPrince54 => {
+                stages(rhs, t, y, h, &dopri5::C, &dopri5::A, k, 1..6);
+                let y_new = combine(y, h, &dopri5::B, k);
+                k[6] = rhs(t + h, &y_new);
+                let error = combine(&Vector::new([T::zero(); N]), h, &dopri5::E, k);
+                (y_new, self.tolerances.error_norm(&error, y, &y_new))
+            }
+            EmbeddedPair::DormandPrince853 => {
+                stages(rhs, t, y, h, &dop853::C, &dop853::A, k, 1..12);
+                let y_new = combine(y, h, dop853::A[12], k);
+                let zero = Vector::new([T::zero(); N]);
+                let err5 = self.tolerances.weighted(&combine(&zero, T::one(), &dop853::E5, k), y, &y_new);
+                let err3 = self.tolerances.weighted(&combine(&zero, T::one(), &dop853::E3, k), y, &y_new);
+                let e5 = err5.dot(&err5);
+                let e3 = err3.dot(&err3);
+                if e5 == T::zero() && e3 == T::zero() {
+                    return (y_new, T::zero());
+                }
+                // Hairer's blend keeps the fifth-order estimate from being overly optimistic
+                let denominator = (e5 + T::from(0.01).unwrap() * e3) * T::from(N).unwrap();
+                (y_new, h.abs() * e5 / denominator.sqrt())
+            }
+        }
+    }
+
+    /// Derivative at the end of an accepted step, reusing the last stage where possible.
+    fn end_derivative<F>(&self, rhs: &mut F, t: T, y: &Vector<T, N>, k: &mut [Vector<T, N>; MAX_STAGES]) -> Vector<T, N>
+    where
+        F: FnMut(T, &Vector<T, N>) -> Vector<T, N>,
+    {
+        match self.method {
+            EmbeddedPair::DormandPrince54 => k[6],
+            EmbeddedPair::Fehlberg45 => rhs(t, y),
+            EmbeddedPair::DormandPrince853 => {
+                k[12] = rhs(t, y);
+                k[12]
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn dense_segment<F>(
+        &self,
+        rhs: &mut F,
+        t0: T,
+        t1: T,
+        y0: &Vector<T, N>,
+        y1: &Vector<T, N>,
+        dy1: &Vector<T, N>,
+        h: T,
+        k: &mut [Vector<T, N>; MAX_STAGES],
+    ) -> DenseSegment<T, N>
+    where
+        F: FnMut(T, &Vector<T, N>) -> Vector<T, N>,
+    {
+        let zero = Vector::new([T::zero(); N]);
+        let mut coefficients = [zero; 7];
+        let delta = *y1 - *y0;
+        let (len, form) = match self.method {
+            EmbeddedPair::Fehlberg45 => {
+                // Cubic Hermite through both end points and slopes
+                let (two, three) = (T::from(2.0).unwrap(), T::from(3.0).unwrap());
+                coefficients[0] = k[0] * h;
+                coefficients[1] = delta * three - (k[0] * two + *dy1) * h;
+                coefficients[2] = (k[0] + *dy1) * h - delta * two;
+                (3, Interpolant::Power)
+            }
+            EmbeddedPair::DormandPrince54 => {
+                for (j, c) in coefficients.iter_mut().take(4).enumerate() {
+                    for (i, row) in dopri5::P.iter().enumerate() {
+                        *c = *c + k[i] * (T::from(row[j]).unwrap() * h);
+                    }
+                }
+                (4, Interpolant::Power)
+            }
+            EmbeddedPair::DormandPrince853 => {
+                stages(rhs, t0, y0, h, &dop853::C, &dop853::A, k, 13..16);
+                coefficients[0] = delta;
+                coefficients[1] = k[0] * h - delta;
+                coefficients[2] = delta * T::from(2.0).unwrap() - (*dy1 + k[0]) * h;
+                for (c, weights) in coefficients[3..].iter_mut().zip(dop853::D.iter()) {
+                    *c = combine(&zero, h, weights, k);
+                }
+                (7, Interpolant::Alternating)
+            }
+        };
+        DenseSegment { t0, t1, y0: *y0, coefficients, len, form }
+    }
+}
+
+/// Interpolants of every step of a solve, see [`AdaptiveSolver::solve_dense`].
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DenseSolution<T, const N: usize> {
+    segments: Vec<DenseSegment<T, N>>,
+}
+
+#[cfg(feature = "std")]
+impl<T: Float, const N: usize> DenseSolution<T, N> {
+    pub fn segments(&self) -> &[DenseSegment<T, N>] {
+        &self.segments
+    }
+
+    /// State at any `t` within the integration interval, `None` outside it.
+    pub fn evaluate(&self, t: T) -> Option<Vector<T, N>> {
+        let first = self.segments.first()?;
+        let direction = (first.end() - first.start()).signum();
+        let index = self.segments.partition_point(|s| (s.end() - t) * direction < T::zero());
+        let segment = self.segments.get(index)?;
+        segment.contains(t).then(|| segment.evaluate(t))
+    }
+}
+
+/// Starting step from the size of the solution and its derivatives
+/// (Hairer, Nørsett & Wanner, "Solving ODEs I", II.4).
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn initial_step_size<T: Float, F, const N: usize>(
+    rhs: &mut F,
+    tolerances: &Tolerances<T, N>,
+    order: u32,
+    max_step: T,
+    t: T,
+    y: &Vector<T, N>,
+    dy: &Vector<T, N>,
+    direction: T,
+) -> T
+where
+    F: FnMut(T, &Vector<T, N>) -> Vector<T, N>,
+{
+    let small = T::from(1e-5).unwrap();
+    let tiny = T::from(1e-15).unwrap();
+    let fallback = T::from(1e-6).unwrap();
+    let hundredth = T::from(0.01).unwrap();
+
+    let d0 = rms(&tolerances.weighted(y, y, y));
+    let d1 = rms(&tolerances.weighted(dy, y, y));
+    let h0 = if d0 < small || d1 < small { fallback } else { hundredth * d0 / d1 };
+    let h0 = h0.min(max_step);
+
+    let y1 = *y + *dy * (h0 * direction);
+    let dy1 = rhs(t + h0 * direction, &y1);
+    let d2 = rms(&tolerances.weighted(&(dy1 - *dy), y, y)) / h0;
+
+    let h1 = if d1.max(d2) <= tiny {
+        fallback.max(h0 * T::from(1e-3).unwrap())
+    } else {
+        (hundredth / d1.max(d2)).powf(T::one() / T::from(order + 1).unwrap())
+    };
+    (h0 * T::from(100.0).unwrap()).min(h1)
+}
+
+/// Evaluates stages `range` of an explicit tableau into `k`.
+#[allow(clippy::too_many_arguments)]
+fn stages<T: Float, F, const N: usize>(
+    rhs: &mut F,
+    t: T,
+    y: &Vector<T, N>,
+    h: T,
+    c: &[f64],
+    a: &[&[f64]],
+    k: &mut [Vector<T, N>; MAX_STAGES],
+    range: core::ops::Range<usize>,
+) where
+    F: FnMut(T, &Vector<T, N>) -> Vector<T, N>,
+{
+    for i in range {
+        let yi = combine(y, h, a[i], k);
+        k[i] = rhs(t + T::from(c[i]).unwrap() * h, &yi);
+    }
+}
+
+/// `y + h * sum_j weights[j] * k[j]`
+fn combine<T: Float, const N: usize>(y: &Vector<T, N>, h: T, weights: &[f64], k: &[Vector<T, N>]) -> Vector<T, N> {
+    let mut out = *y;
+    for (w, kj) in weights.iter().zip(k) {
+        if *w != 0.0 {
+            out = out + *kj * (T::from(*w).unwrap() * h);
+        }
+    }
+    out
+}
+
+pub(crate) fn rms<T: Float, const N: usize>(v: &Vector<T, N>) -> T {
+    (v.dot(v) / T::from(N).unwrap()).sqrt()
+}
+
+pub(crate) fn is_finite<T: Float, const N: usize>(v: &Vector<T, N>) -> bool {
+    v.data.iter().all(|x| x.is_finite())
+}
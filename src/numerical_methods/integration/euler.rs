@@ -1,7 +1,13 @@
+// The deprecated API keeps its implementations until it is removed.
+#![allow(deprecated)]
+
 use num_traits::Float;
 use crate::math::Vector;
 use crate::coordinate::Cartesian;
 
+/// Scales `self` by `dt`, which is only the increment of an Euler step when
+/// `self` is the derivative.
+#[deprecated(note = "use `ExplicitEuler` through `OdeSolver::step`")]
 pub trait EulerIntegrate<T: Float> {
     fn integrate_euler(&self, dt: T) -> Self;
 }
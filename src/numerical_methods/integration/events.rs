@@ -0,0 +1,175 @@
+//! Zero-crossing events during integration.
+//!
+//! An event is a scalar function `g(t, x)` watched at every step. When its sign
+//! changes across a step, the crossing time is located by root finding on the
+//! solver's interpolant, so no extra steps are taken. Several crossings of the
+//! same function within one step are not resolved; keep steps short compared
+//! to the spacing of expected events.
+
+use num_traits::Float;
+
+use super::state::State;
+
+const MAX_ITERATIONS: usize = 100;
+
+/// Which sign changes of an event function trigger it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Crossing {
+    /// Negative to positive
+    Rising,
+    /// Positive to negative
+    Falling,
+    Either,
+}
+
+/// A watched event function with its trigger direction.
+pub struct Event<'a, T, S> {
+    pub function: &'a dyn Fn(T, &S) -> T,
+    pub direction: Crossing,
+    /// Stop the integration at the first occurrence
+    pub terminal: bool,
+}
+
+impl<'a, T, S> Event<'a, T, S> {
+    pub fn new(function: &'a dyn Fn(T, &S) -> T, direction: Crossing, terminal: bool) -> Self {
+        Self { function, direction, terminal }
+    }
+}
+
+/// A located event: index into the event array, time and state.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EventRecord<T, S> {
+    pub index: usize,
+    pub t: T,
+    pub state: S,
+}
+
+/// Where an event-aware solve stopped.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EventOutcome<T, S> {
+    pub t: T,
+    pub state: S,
+    /// Index of the terminal event that stopped the solve, `None` if it reached the final time
+    pub terminated_by: Option<usize>,
+}
+
+/// Event function values at the start of the current step.
+pub(crate) struct EventTracker<T, const E: usize> {
+    values: [T; E],
+}
+
+impl<T: Float, const E: usize> EventTracker<T, E> {
+    pub(crate) fn new<S>(events: &[Event<'_, T, S>; E], t: T, x: &S) -> Self {
+        let mut values = [T::zero(); E];
+        for (value, event) in values.iter_mut().zip(events) {
+            *value = (event.function)(t, x);
+        }
+        Self { values }
+    }
+
+    /// Checks the step `t0 -> t1` ending at `x1`, reporting crossings in time
+    /// order. `interpolate` gives the state inside the step. Returns the
+    /// terminal event if one occurred; later crossings are then dropped.
+    pub(crate) fn check<S, I, R>(
+        &mut self,
+        events: &[Event<'_, T, S>; E],
+        t0: T,
+        t1: T,
+        x1: &S,
+        interpolate: I,
+        mut report: R,
+    ) -> Option<EventRecord<T, S>>
+    where
+        S: State<T>,
+        I: Fn(T) -> S,
+        R: FnMut(&EventRecord<T, S>),
+    {
+        let mut crossings: [Option<T>; E] = [None; E];
+        let mut values = [T::zero(); E];
+        for (i, event) in events.iter().enumerate() {
+            values[i] = (event.function)(t1, x1);
+            if triggers(event.direction, self.values[i], values[i]) {
+                let g = |t: T| (event.function)(t, &interpolate(t));
+                crossings[i] = Some(locate(g, t0, self.values[i], t1, values[i]));
+            }
+        }
+        self.values = values;
+
+        // Report in order of occurrence along the direction of integration
+        let direction = (t1 - t0).signum();
+        loop {
+            let mut next: Option<(usize, T)> = None;
+            for (i, crossing) in crossings.iter().enumerate() {
+                if let Some(t) = *crossing
+                    && next.is_none_or(|(_, tn)| (t - tn) * direction < T::zero())
+                {
+                    next = Some((i, t));
+                }
+            }
+            let (index, t) = next?;
+            crossings[index] = None;
+            let record = EventRecord { index, t, state: interpolate(t) };
+            report(&record);
+            if events[index].terminal {
+                return Some(record);
+            }
+        }
+    }
+}
+
+fn triggers<T: Float>(direction: Crossing, g0: T, g1: T) -> bool {
+    let rising = g0 < T::zero() && g1 >= T::zero();
+    let falling = g0 > T::zero() && g1 <= T::zero();
+    match direction {
+        Crossing::Rising => rising,
+        Crossing::Falling => falling,
+        Crossing::Either => rising || falling,
+    }
+}
+
+/// Root of `g` bracketed by `[a, b]` using the Illinois variant of regula
+/// falsi. Returns the bracket end on the far side of the crossing, so the event
+/// function has already changed sign at the reported time.
+fn locate<T: Float, G: Fn(T) -> T>(g: G, mut a: T, mut ga: T, mut b: T, mut gb: T) -> T {
+    let tolerance = T::epsilon() * T::from(4.0).unwrap() * a.abs().max(b.abs()).max(T::one());
+    let mut side = 0;
+    for _ in 0..MAX_ITERATIONS {
+        if gb == T::zero() || (b - a).abs() <= tolerance {
+            break;
+        }
+        let c = (a * gb - b * ga) / (gb - ga);
+        // Fall back to bisection if the secant leaves the bracket
+        let c = if (c - a) * (c - b) < T::zero() { c } else { (a + b) / T::from(2.0).unwrap() };
+        let gc = g(c);
+        if gc.signum() == gb.signum() || gc == T::zero() {
+            b = c;
+            gb = gc;
+            if side == 1 {
+                ga = ga / T::from(2.0).unwrap();
+            }
+            side = 1;
+        } else {
+            a = c;
+            ga = gc;
+            if side == -1 {
+                gb = gb / T::from(2.0).unwrap();
+            }
+            side = -1;
+        }
+    }
+    b
+}
+
+/// Cubic Hermite interpolant of a step from `(x0, f0)` to `(x1, f1)`.
+pub(crate) fn hermite<T: Float, S: State<T>>(x0: &S, f0: &S, x1: &S, f1: &S, h: T, theta: T) -> S {
+    let one = T::one();
+    let two = one + one;
+    let three = two + one;
+    let t2 = theta * theta;
+    let t3 = t2 * theta;
+    let h00 = two * t3 - three * t2 + one;
+    let h10 = t3 - two * t2 + theta;
+    let h01 = three * t2 - two * t3;
+    let h11 = t3 - t2;
+    x0.scaled(h00).scaled_add(h * h10, f0).scaled_add(h01, x1).scaled_add(h * h11, f1)
+}
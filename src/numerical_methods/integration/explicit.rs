@@ -0,0 +1,68 @@
+use num_traits::Float;
+use super::solver::OdeSolver;
+use super::state::State;
+
+/// Forward Euler, first order.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExplicitEuler;
+
+/// Heun's method (explicit trapezoidal rule), second order.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Heun;
+
+/// Explicit midpoint rule, second order.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Midpoint;
+
+/// Classic fourth-order Runge-Kutta.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Rk4;
+
+impl<T: Float, S: State<T>> OdeSolver<T, S> for ExplicitEuler {
+    fn step<F>(&mut self, f: &mut F, t: T, x: &S, dt: T) -> S
+    where
+        F: FnMut(T, &S) -> S,
+    {
+        x.scaled_add(dt, &f(t, x))
+    }
+}
+
+impl<T: Float, S: State<T>> OdeSolver<T, S> for Heun {
+    fn step<F>(&mut self, f: &mut F, t: T, x: &S, dt: T) -> S
+    where
+        F: FnMut(T, &S) -> S,
+    {
+        let half = T::from(0.5).unwrap();
+        let k1 = f(t, x);
+        let k2 = f(t + dt, &x.scaled_add(dt, &k1));
+        x.scaled_add(dt * half, &k1.scaled_add(T::one(), &k2))
+    }
+}
+
+impl<T: Float, S: State<T>> OdeSolver<T, S> for Midpoint {
+    fn step<F>(&mut self, f: &mut F, t: T, x: &S, dt: T) -> S
+    where
+        F: FnMut(T, &S) -> S,
+    {
+        let half_dt = dt * T::from(0.5).unwrap();
+        let k1 = f(t, x);
+        let k2 = f(t + half_dt, &x.scaled_add(half_dt, &k1));
+        x.scaled_add(dt, &k2)
+    }
+}
+
+impl<T: Float, S: State<T>> OdeSolver<T, S> for Rk4 {
+    fn step<F>(&mut self, f: &mut F, t: T, x: &S, dt: T) -> S
+    where
+        F: FnMut(T, &S) -> S,
+    {
+        let two = T::from(2.0).unwrap();
+        let half_dt = dt / two;
+        let k1 = f(t, x);
+        let k2 = f(t + half_dt, &x.scaled_add(half_dt, &k1));
+        let k3 = f(t + half_dt, &x.scaled_add(half_dt, &k2));
+        let k4 = f(t + dt, &x.scaled_add(dt, &k3));
+        let sum = k1.scaled_add(two, &k2).scaled_add(two, &k3).scaled_add(T::one(), &k4);
+        x.scaled_add(dt / T::from(6.0).unwrap(), &sum)
+    }
+}
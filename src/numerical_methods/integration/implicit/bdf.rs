@@ -0,0 +1,428 @@
+//! Variable-order, variable-step backward differentiation formulas (orders 1
+//! to 5) in the backward-difference form of Shampine & Reichelt, "The MATLAB
+//! ODE Suite" (1997), with the numerical-differentiation correction disabled.
+//!
+//! The solution history is kept as backward differences `D`; a step change by
+//! `factor` rescales them instead of restarting, and the order is raised or
+//! lowered after `order + 1` steps of constant size.
+
+use num_traits::Float;
+
+use super::{newton_tolerance, FiniteDifference, Jacobian};
+use crate::math::{Lu, Matrix, Vector};
+use crate::numerical_methods::integration::adaptive::{
+    initial_step_size, is_finite, rms, AdaptiveStep, DenseSegment, IntegrationError, Statistics, Tolerances,
+};
+
+#[cfg(feature = "std")]
+use crate::numerical_methods::integration::solver::Trajectory;
+
+const MAX_ORDER: usize = 5;
+const NEWTON_MAX_ITERATIONS: usize = 4;
+const MIN_FACTOR: f64 = 0.2;
+const MAX_FACTOR: f64 = 10.0;
+
+/// Adaptive BDF solver for stiff `y' = f(t, y)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bdf<T, const N: usize, J = FiniteDifference> {
+    pub tolerances: Tolerances<T, N>,
+    /// Smallest step before giving up; never below a few ulps of `t` or of
+    /// the integration span
+    pub min_step: T,
+    pub max_step: T,
+    /// First step size; chosen from the problem when `None`
+    pub initial_step: Option<T>,
+    /// Maximum number of accepted steps per solve
+    pub max_steps: usize,
+    /// Highest order used, 1 to 5
+    pub max_order: usize,
+    pub jacobian: J,
+    statistics: Statistics,
+}
+
+impl<T: Float, const N: usize> Bdf<T, N> {
+    pub fn new(tolerances: Tolerances<T, N>) -> Self {
+        Self::with_jacobian(tolerances, FiniteDifference)
+    }
+}
+
+impl<T: Float, const N: usize, J> Bdf<T, N, J> {
+    pub fn with_jacobian(tolerances: Tolerances<T, N>, jacobian: J) -> Self {
+        Self {
+            tolerances,
+            min_step: T::zero(),
+            max_step: T::infinity(),
+            initial_step: None,
+            max_steps: 100_000,
+            max_order: MAX_ORDER,
+            jacobian,
+            statistics: Statistics::default(),
+        }
+    }
+
+    pub fn statistics(&self) -> Statistics {
+        self.statistics
+    }
+
+    pub fn reset_statistics(&mut self) {
+        self.statistics = Statistics::default();
+    }
+}
+
+impl<T, const N: usize, J> Bdf<T, N, J>
+where
+    T: Float + Default,
+    J: Jacobian<T, N>,
+{
+    /// Integrates from `t0` to `tf` (either direction), calling `observer` after
+    /// every accepted step with the interpolating polynomial as dense output.
+    /// Returns the state at `tf`.
+    pub fn solve_with<F, O>(
+        &mut self,
+        mut f: F,
+        y0: &Vector<T, N>,
+        t0: T,
+        tf: T,
+        mut observer: O,
+    ) -> Result<Vector<T, N>, IntegrationError<T>>
+    where
+        F: FnMut(T, &Vector<T, N>) -> Vector<T, N>,
+        O: FnMut(&AdaptiveStep<T, N>),
+    {
+        let mut evaluations = 0;
+        let mut rhs = |t: T, y: &Vector<T, N>| {
+            evaluations += 1;
+            f(t, y)
+        };
+        let result = self.integrate(&mut rhs, y0, t0, tf, &mut observer);
+        self.statistics.function_evaluations += evaluations;
+        result
+    }
+
+    /// Integrates from `t0` to `tf` and records every accepted step, starting with `t0`.
+    #[cfg(feature = "std")]
+    pub fn solve<F>(&mut self, f: F, y0: &Vector<T, N>, t0: T, tf: T) -> Result<Trajectory<T, Vector<T, N>>, IntegrationError<T>>
+    where
+        F: FnMut(T, &Vector<T, N>) -> Vector<T, N>,
+    {
+        let mut trajectory = Trajectory::new();
+        trajectory.push(t0, *y0);
+        self.solve_with(f, y0, t0, tf, |step| trajectory.push(step.t, step.y))?;
+        Ok(trajectory)
+    }
+
+    fn integrate<F, O>(
+        &mut self,
+        rhs: &mut F,
+        y0: &Vector<T, N>,
+        t0: T,
+        tf: T,
+        observer: &mut O,
+    ) -> Result<Vector<T, N>, IntegrationError<T>>
+    where
+        F: FnMut(T, &Vector<T, N>) -> Vector<T, N>,
+        O: FnMut(&AdaptiveStep<T, N>),
+    {
+        let mut t = t0;
+        if tf == t0 {
+            return Ok(*y0);
+        }
+        let direction = (tf - t0).signum();
+        let dy = rhs(t, y0);
+        if !is_finite(&dy) {
+            return Err(IntegrationError::NonFinite { t });
+        }
+
+        let zero = Vector::new([T::zero(); N]);
+        let max_order = self.max_order.clamp(1, MAX_ORDER);
+        let min_factor = T::from(MIN_FACTOR).unwrap();
+        let max_factor = T::from(MAX_FACTOR).unwrap();
+        let half = T::from(0.5).unwrap();
+        let tol = newton_tolerance(&self.tolerances);
+        let ulps = T::epsilon() * T::from(10.0).unwrap();
+        let span = (tf - t0).abs();
+        let max_iterations = T::from(NEWTON_MAX_ITERATIONS).unwrap();
+
+        // gamma[k] = sum_{j<=k} 1/j; the error constant of order k is 1/(k+1)
+        let mut gamma = [T::zero(); MAX_ORDER + 1];
+        for k in 1..=MAX_ORDER {
+            gamma[k] = gamma[k - 1] + T::one() / T::from(k).unwrap();
+        }
+        let error_constant = |k: usize| T::one() / T::from(k + 1).unwrap();
+
+        let mut h_abs = match self.initial_step {
+            Some(h0) => h0.abs(),
+            None => initial_step_size(rhs, &self.tolerances, 1, self.max_step, t, y0, &dy, direction),
+        }
+        .min(self.max_step);
+
+        let mut d = [zero; MAX_ORDER + 3];
+        d[0] = *y0;
+        d[1] = dy * (h_abs * direction);
+        let mut order = 1;
+        let mut equal_steps = 0;
+        let mut jac = self.jacobian.jacobian(rhs, t, y0, &dy);
+        self.statistics.jacobian_evaluations += 1;
+        let mut lu: Option<Lu<T, N>> = None;
+
+        let mut steps = 0;
+        while (tf - t) * direction > T::zero() {
+            if steps >= self.max_steps {
+                return Err(IntegrationError::MaxStepsExceeded { t });
+            }
+            let min_step = self.min_step.max(ulps * t.abs().max(span));
+            if h_abs > self.max_step {
+                change_differences(&mut d, order, self.max_step / h_abs);
+                h_abs = self.max_step;
+                equal_steps = 0;
+                lu = None;
+            }
+            let mut current_jacobian = false;
+
+            let (y_new, diff, error_norm, iterations, t_new) = loop {
+                if h_abs < min_step {
+                    return Err(IntegrationError::StepSizeTooSmall { t });
+                }
+                let mut h = h_abs * direction;
+                let mut t_new = t + h;
+                if (t_new - tf) * direction > T::zero() {
+                    t_new = tf;
+                    change_differences(&mut d, order, (t_new - t).abs() / h_abs);
+                    equal_steps = 0;
+                    lu = None;
+                }
+                h = t_new - t;
+                h_abs = h.abs();
+
+                let mut y_predict = zero;
+                for dk in &d[..=order] {
+                    y_predict = y_predict + *dk;
+                }
+                let mut scale = y_predict;
+                for i in 0..N {
+                    scale[i] = self.tolerances.absolute[i] + self.tolerances.relative[i] * y_predict[i].abs();
+                }
+                let mut psi = zero;
+                for k in 1..=order {
+                    psi = psi + d[k] * gamma[k];
+                }
+                psi = psi / gamma[order];
+                let c = h / gamma[order];
+
+                let solution = loop {
+                    if lu.is_none() {
+                        lu = (Matrix::identity() - jac * c).lu();
+                        self.statistics.lu_decompositions += 1;
+                    }
+                    let attempt = match &lu {
+                        Some(lu) => newton(rhs, t_new, &y_predict, c, &psi, lu, &scale, tol),
+                        None => None,
+                    };
+                    if attempt.is_some() || current_jacobian {
+                        break attempt;
+                    }
+                    let f_predict = rhs(t_new, &y_predict);
+                    jac = self.jacobian.jacobian(rhs, t_new, &y_predict, &f_predict);
+                    self.statistics.jacobian_evaluations += 1;
+                    current_jacobian = true;
+                    lu = None;
+                };
+
+                let Some((iterations, y_new, diff)) = solution else {
+                    h_abs = h_abs * half;
+                    change_differences(&mut d, order, half);
+                    equal_steps = 0;
+                    lu = None;
+                    self.statistics.rejected_steps += 1;
+                    continue;
+                };
+
+                let two = T::from(2.0).unwrap();
+                let safety = T::from(0.9).unwrap() * (two * max_iterations + T::one())
+                    / (two * max_iterations + T::from(iterations).unwrap());
+                let error_norm = self.tolerances.error_norm(&(diff * error_constant(order)), &y_new, &y_new);
+                if error_norm > T::one() {
+                    let factor = (safety * error_norm.powf(-T::one() / T::from(order + 1).unwrap())).max(min_factor);
+                    h_abs = h_abs * factor;
+                    change_differences(&mut d, order, factor);
+                    equal_steps = 0;
+                    self.statistics.rejected_steps += 1;
+                    continue;
+                }
+                break (y_new, diff, error_norm, iterations, t_new);
+            };
+
+            // Update the backward differences with the new point
+            let y_previous = d[0];
+            d[order + 2] = diff - d[order + 1];
+            d[order + 1] = diff;
+            for i in (0..=order).rev() {
+                d[i] = d[i] + d[i + 1];
+            }
+
+            let segment = dense_segment(&d, order, t, t_new);
+            observer(&AdaptiveStep { t_previous: t, y_previous, t: t_new, y: y_new, dense: Some(segment) });
+            self.statistics.accepted_steps += 1;
+            steps += 1;
+            t = t_new;
+            equal_steps += 1;
+            if equal_steps < order + 1 {
+                continue;
+            }
+
+            // Pick the order whose error estimate allows the largest next step
+            let mut scale = y_new;
+            for i in 0..N {
+                scale[i] = self.tolerances.absolute[i] + self.tolerances.relative[i] * y_new[i].abs();
+            }
+            let weighted = |v: Vector<T, N>| {
+                let mut out = v;
+                for i in 0..N {
+                    out[i] = v[i] / scale[i];
+                }
+                rms(&out)
+            };
+            let error_lower = if order > 1 { weighted(d[order] * error_constant(order - 1)) } else { T::infinity() };
+            let error_higher = if order < max_order { weighted(d[order + 2] * error_constant(order + 1)) } else { T::infinity() };
+            let candidates = [(order - 1, error_lower), (order, error_norm), (order + 1, error_higher)];
+            let mut best = (order, T::zero());
+            for (k, error) in candidates {
+                if k == 0 {
+                    continue;
+                }
+                let factor = if error == T::zero() {
+                    T::infinity()
+                } else {
+                    error.powf(-T::one() / T::from(k + 1).unwrap())
+                };
+                if factor > best.1 {
+                    best = (k, factor);
+                }
+            }
+            order = best.0;
+            let two = T::from(2.0).unwrap();
+            let safety = T::from(0.9).unwrap() * (two * max_iterations + T::one())
+                / (two * max_iterations + T::from(iterations).unwrap());
+            let factor = (safety * best.1).min(max_factor);
+            h_abs = h_abs * factor;
+            change_differences(&mut d, order, factor);
+            equal_steps = 0;
+            lu = None;
+        }
+        Ok(d[0])
+    }
+}
+
+/// Newton iteration for `y = y_predict + sum(dy)` with `(I - c J) dy = c f - psi - d`.
+/// Returns the iteration count, the solution and its total correction `d`.
+#[allow(clippy::too_many_arguments)]
+fn newton<T, F, const N: usize>(
+    rhs: &mut F,
+    t: T,
+    y_predict: &Vector<T, N>,
+    c: T,
+    psi: &Vector<T, N>,
+    lu: &Lu<T, N>,
+    scale: &Vector<T, N>,
+    tol: T,
+) -> Option<(usize, Vector<T, N>, Vector<T, N>)>
+where
+    T: Float + Default,
+    F: FnMut(T, &Vector<T, N>) -> Vector<T, N>,
+{
+    let mut y = *y_predict;
+    let mut d = Vector::new([T::zero(); N]);
+    let mut norm_old: Option<T> = None;
+    for k in 0..NEWTON_MAX_ITERATIONS {
+        let f = rhs(t, &y);
+        if !is_finite(&f) {
+            return None;
+        }
+        let dy = lu.solve(&(f * c - *psi - d));
+        let mut weighted = dy;
+        for i in 0..N {
+            weighted[i] = dy[i] / scale[i];
+        }
+        let norm = rms(&weighted);
+        let rate = norm_old.map(|old| norm / old);
+        if let Some(r) = rate
+            && (r >= T::one() || r.powi((NEWTON_MAX_ITERATIONS - k) as i32) / (T::one() - r) * norm > tol)
+        {
+            return None;
+        }
+        y = y + dy;
+        d = d + dy;
+        if norm == T::zero() || rate.is_some_and(|r| r / (T::one() - r) * norm < tol) {
+            return Some((k + 1, y, d));
+        }
+        norm_old = Some(norm);
+    }
+    None
+}
+
+/// Rescales the differences `D[0..=order]` for a step size change by `factor`.
+fn change_differences<T: Float, const N: usize>(d: &mut [Vector<T, N>; MAX_ORDER + 3], order: usize, factor: T) {
+    let r = step_matrix(order, factor);
+    let u = step_matrix(order, T::one());
+    // D <- (R U)^T D
+    let mut ru = [[T::zero(); MAX_ORDER + 1]; MAX_ORDER + 1];
+    for i in 0..=order {
+        for j in 0..=order {
+            for k in 0..=order {
+                ru[i][j] = ru[i][j] + r[i][k] * u[k][j];
+            }
+        }
+    }
+    let mut updated = [Vector::new([T::zero(); N]); MAX_ORDER + 1];
+    for (i, out) in updated.iter_mut().enumerate().take(order + 1) {
+        for (j, dj) in d.iter().enumerate().take(order + 1) {
+            *out = *out + *dj * ru[j][i];
+        }
+    }
+    d[..=order].copy_from_slice(&updated[..=order]);
+}
+
+/// `R[i][j] = prod_{m=1..=i} (m - 1 - factor * j) / m` with `R[0][j] = 1`.
+fn step_matrix<T: Float>(order: usize, factor: T) -> [[T; MAX_ORDER + 1]; MAX_ORDER + 1] {
+    let mut r = [[T::zero(); MAX_ORDER + 1]; MAX_ORDER + 1];
+    r[0][..=order].fill(T::one());
+    for i in 1..=order {
+        let m = T::from(i).unwrap();
+        let previous = r[i - 1];
+        for (j, (rij, above)) in r[i].iter_mut().zip(previous).enumerate().take(order + 1).skip(1) {
+            *rij = above * (m - T::one() - factor * T::from(j).unwrap()) / m;
+        }
+    }
+    r
+}
+
+/// Interpolating polynomial of the last step in powers of `theta = (t - t0) / h`.
+/// In the difference form `y(t) = D0 + sum_k D_k prod_{m<k} (t - t1 + m h) / ((m + 1) h)`
+/// each factor is `(theta - 1 + m) / (m + 1)`.
+fn dense_segment<T: Float, const N: usize>(
+    d: &[Vector<T, N>; MAX_ORDER + 3],
+    order: usize,
+    t0: T,
+    t1: T,
+) -> DenseSegment<T, N> {
+    let zero = Vector::new([T::zero(); N]);
+    // Polynomial coefficients of the running product, lowest power first
+    let mut product = [T::zero(); MAX_ORDER + 1];
+    product[0] = T::one();
+    let mut y0 = d[0];
+    let mut coefficients = [zero; MAX_ORDER];
+    for (k, dk) in d.iter().enumerate().take(order + 1).skip(1) {
+        let m = T::from(k - 1).unwrap();
+        let a = T::one() / (m + T::one());
+        let b = (m - T::one()) * a;
+        for p in (0..=k).rev() {
+            let shifted = if p > 0 { product[p - 1] * a } else { T::zero() };
+            product[p] = product[p] * b + shifted;
+        }
+        y0 = y0 + *dk * product[0];
+        for (c, p) in coefficients[..k].iter_mut().zip(&product[1..=k]) {
+            *c = *c + *dk * *p;
+        }
+    }
+    DenseSegment::power(t0, t1, y0, &coefficients[..order])
+}
@@ -0,0 +1,308 @@
+//! Implicit solvers for stiff problems.
+//!
+//! Each step solves a nonlinear system by Newton iteration on an iteration
+//! matrix built from the Jacobian `df/dy` and factored with [`Matrix::lu`].
+//! The Jacobian comes from a [`Jacobian`] source: [`FiniteDifference`] by
+//! default, or any closure `FnMut(T, &Vector<T, N>) -> Matrix<T, N, N>`.
+//!
+//! [`BackwardEuler`] and [`CrankNicolson`] are fixed-step [`OdeSolver`]s which
+//! halve a step whose Newton iteration fails; their `try_step` and
+//! `try_solve_with` report a step that cannot be completed. [`Bdf`] and
+//! [`Radau`] choose their own steps like the explicit
+//! [`AdaptiveSolver`](super::AdaptiveSolver).
+
+use num_traits::Float;
+
+use super::adaptive::{is_finite, IntegrationError, Tolerances};
+use super::solver::{fixed_steps, OdeSolver};
+use crate::math::{Lu, Matrix, Vector};
+
+mod bdf;
+mod radau;
+pub use bdf::Bdf;
+pub use radau::Radau;
+
+/// Source of the Jacobian `df/dy`.
+pub trait Jacobian<T, const N: usize> {
+    /// Jacobian at `(t, y)`, where `fy = f(t, y)` is already known.
+    fn jacobian<F>(&mut self, f: &mut F, t: T, y: &Vector<T, N>, fy: &Vector<T, N>) -> Matrix<T, N, N>
+    where
+        F: FnMut(T, &Vector<T, N>) -> Vector<T, N>;
+}
+
+/// Forward-difference Jacobian, one extra evaluation of `f` per component.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FiniteDifference;
+
+impl<T: Float + Default, const N: usize> Jacobian<T, N> for FiniteDifference {
+    fn jacobian<F>(&mut self, f: &mut F, t: T, y: &Vector<T, N>, fy: &Vector<T, N>) -> Matrix<T, N, N>
+    where
+        F: FnMut(T, &Vector<T, N>) -> Vector<T, N>,
+    {
+        let sqrt_eps = T::epsilon().sqrt();
+        let mut jacobian = Matrix::<T, N, N>::zeros();
+        for j in 0..N {
+            let mut shifted = *y;
+            shifted[j] = y[j] + sqrt_eps * y[j].abs().max(T::one());
+            // Use the representable step actually taken
+            let step = shifted[j] - y[j];
+            let column = (f(t, &shifted) - *fy) / step;
+            for i in 0..N {
+                jacobian[(i, j)] = column[i];
+            }
+        }
+        jacobian
+    }
+}
+
+impl<T, const N: usize, G> Jacobian<T, N> for G
+where
+    G: FnMut(T, &Vector<T, N>) -> Matrix<T, N, N>,
+{
+    fn jacobian<F>(&mut self, _f: &mut F, t: T, y: &Vector<T, N>, _fy: &Vector<T, N>) -> Matrix<T, N, N>
+    where
+        F: FnMut(T, &Vector<T, N>) -> Vector<T, N>,
+    {
+        self(t, y)
+    }
+}
+
+/// Newton iteration settings for the fixed-step implicit methods.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Newton<T> {
+    /// Stop once the update is below `tolerance * (1 + |y|)`
+    pub tolerance: T,
+    /// Iterations per attempt before the step is halved
+    pub max_iterations: usize,
+    /// Halvings of a step before giving up
+    pub max_halvings: usize,
+}
+
+impl<T: Float> Default for Newton<T> {
+    fn default() -> Self {
+        Self { tolerance: T::from(1e-10).unwrap(), max_iterations: 10, max_halvings: 10 }
+    }
+}
+
+/// Backward (implicit) Euler, first order and L-stable.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BackwardEuler<T, J = FiniteDifference> {
+    pub jacobian: J,
+    pub newton: Newton<T>,
+}
+
+/// Crank-Nicolson (implicit trapezoidal rule), second order and A-stable.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CrankNicolson<T, J = FiniteDifference> {
+    pub jacobian: J,
+    pub newton: Newton<T>,
+}
+
+impl<T: Float> BackwardEuler<T> {
+    pub fn new() -> Self {
+        Self::with_jacobian(FiniteDifference)
+    }
+}
+
+impl<T: Float> Default for BackwardEuler<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Float, J> BackwardEuler<T, J> {
+    pub fn with_jacobian(jacobian: J) -> Self {
+        Self { jacobian, newton: Newton::default() }
+    }
+}
+
+impl<T: Float> CrankNicolson<T> {
+    pub fn new() -> Self {
+        Self::with_jacobian(FiniteDifference)
+    }
+}
+
+impl<T: Float> Default for CrankNicolson<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Float, J> CrankNicolson<T, J> {
+    pub fn with_jacobian(jacobian: J) -> Self {
+        Self { jacobian, newton: Newton::default() }
+    }
+}
+
+macro_rules! theta_method {
+    ($solver:ident, $theta:expr) => {
+        impl<T: Float + Default, J> $solver<T, J> {
+            /// Advances `y` at time `t` by `dt`, halving the step while its
+            /// Newton iteration fails.
+            pub fn try_step<F, const N: usize>(&mut self, f: &mut F, t: T, y: &Vector<T, N>, dt: T) -> Result<Vector<T, N>, IntegrationError<T>>
+            where
+                F: FnMut(T, &Vector<T, N>) -> Vector<T, N>,
+                J: Jacobian<T, N>,
+            {
+                theta_step(f, &mut self.jacobian, &self.newton, $theta, t, y, dt, 0)
+            }
+
+            /// [`OdeSolver::solve_with`], stopping at the first step that
+            /// cannot be completed.
+            pub fn try_solve_with<F, O, const N: usize>(
+                &mut self,
+                mut f: F,
+                y0: &Vector<T, N>,
+                t0: T,
+                tf: T,
+                dt: T,
+                mut observer: O,
+            ) -> Result<Vector<T, N>, IntegrationError<T>>
+            where
+                F: FnMut(T, &Vector<T, N>) -> Vector<T, N>,
+                O: FnMut(T, &Vector<T, N>),
+                J: Jacobian<T, N>,
+            {
+                let mut result = Ok(*y0);
+                observer(t0, y0);
+                fixed_steps(t0, tf, dt, |t, step, t_new| {
+                    let Ok(y) = &result else { return false };
+                    result = self.try_step(&mut f, t, y, step);
+                    match &result {
+                        Ok(y) => {
+                            observer(t_new, y);
+                            true
+                        }
+                        Err(_) => false,
+                    }
+                });
+                result
+            }
+        }
+
+        /// Steps that cannot be completed give NaN; see `try_step`.
+        impl<T, J, const N: usize> OdeSolver<T, Vector<T, N>> for $solver<T, J>
+        where
+            T: Float + Default,
+            J: Jacobian<T, N>,
+        {
+            fn step<F>(&mut self, f: &mut F, t: T, x: &Vector<T, N>, dt: T) -> Vector<T, N>
+            where
+                F: FnMut(T, &Vector<T, N>) -> Vector<T, N>,
+            {
+                self.try_step(f, t, x, dt).unwrap_or(Vector::new([T::nan(); N]))
+            }
+        }
+    };
+}
+
+theta_method!(BackwardEuler, T::one());
+theta_method!(CrankNicolson, T::from(0.5).unwrap());
+
+/// One step of `y1 = y0 + h ((1 - theta) f(t, y0) + theta f(t + h, y1))`. If
+/// the Newton iteration fails the step is taken as two halves, at most
+/// `newton.max_halvings` levels deep.
+#[allow(clippy::too_many_arguments)]
+fn theta_step<T, F, J, const N: usize>(
+    f: &mut F,
+    jacobian: &mut J,
+    newton: &Newton<T>,
+    theta: T,
+    t: T,
+    y: &Vector<T, N>,
+    h: T,
+    depth: usize,
+) -> Result<Vector<T, N>, IntegrationError<T>>
+where
+    T: Float + Default,
+    F: FnMut(T, &Vector<T, N>) -> Vector<T, N>,
+    J: Jacobian<T, N>,
+{
+    let f0 = f(t, y);
+    if !is_finite(&f0) {
+        return Err(IntegrationError::NonFinite { t });
+    }
+    if let Some(y1) = theta_newton(f, jacobian, newton, theta, t, y, &f0, h) {
+        return Ok(y1);
+    }
+    if depth >= newton.max_halvings {
+        return Err(IntegrationError::ConvergenceFailure { t });
+    }
+    let half = h * T::from(0.5).unwrap();
+    let middle = theta_step(f, jacobian, newton, theta, t, y, half, depth + 1)?;
+    theta_step(f, jacobian, newton, theta, t + half, &middle, h - half, depth + 1)
+}
+
+/// Newton iteration for the implicit equation of [`theta_step`], starting
+/// from `y0`. The Jacobian is refreshed at the current iterate when the
+/// updates shrink too slowly to converge within the remaining iterations;
+/// `None` if they grow under one Jacobian, an iterate is not finite, the
+/// iteration matrix is singular or the iterations run out.
+#[allow(clippy::too_many_arguments)]
+fn theta_newton<T, F, J, const N: usize>(
+    f: &mut F,
+    jacobian: &mut J,
+    newton: &Newton<T>,
+    theta: T,
+    t: T,
+    y: &Vector<T, N>,
+    f0: &Vector<T, N>,
+    h: T,
+) -> Option<Vector<T, N>>
+where
+    T: Float + Default,
+    F: FnMut(T, &Vector<T, N>) -> Vector<T, N>,
+    J: Jacobian<T, N>,
+{
+    let known = *y + *f0 * ((T::one() - theta) * h);
+    let t1 = t + h;
+    let th = theta * h;
+    let mut factor = |f: &mut F, y1: &Vector<T, N>, f1: &Vector<T, N>| -> Option<Lu<T, N>> {
+        (Matrix::identity() - jacobian.jacobian(f, t1, y1, f1) * th).lu()
+    };
+
+    let mut y1 = *y;
+    let mut f1 = f(t1, &y1);
+    if !is_finite(&f1) {
+        return None;
+    }
+    let mut lu = factor(f, &y1, &f1)?;
+    // Size of the previous update under the current Jacobian
+    let mut previous = T::infinity();
+    for iteration in 1..=newton.max_iterations {
+        let residual = y1 - known - f1 * th;
+        let delta = lu.solve(&-residual);
+        y1 = y1 + delta;
+        f1 = f(t1, &y1);
+        if !is_finite(&y1) || !is_finite(&f1) {
+            return None;
+        }
+        let norm = delta.norm();
+        let tolerance = newton.tolerance * (T::one() + y1.norm());
+        if norm <= tolerance {
+            return Some(y1);
+        }
+        let rate = norm / previous;
+        previous = norm;
+        if rate >= T::one() {
+            return None;
+        }
+        let remaining = (newton.max_iterations - iteration) as i32;
+        if norm * rate.powi(remaining) / (T::one() - rate) > tolerance {
+            lu = factor(f, &y1, &f1)?;
+            previous = T::infinity();
+        }
+    }
+    None
+}
+
+/// Newton convergence tolerance on the weighted increment norm
+/// (Hairer & Wanner, "Solving ODEs II", IV.8).
+pub(crate) fn newton_tolerance<T: Float, const N: usize>(tolerances: &Tolerances<T, N>) -> T {
+    let rtol = tolerances.relative.data.iter().fold(T::zero(), |m, &r| m.max(r));
+    let cap = T::from(0.03).unwrap();
+    if rtol == T::zero() {
+        return cap;
+    }
+    (T::from(10.0).unwrap() * T::epsilon() / rtol).max(cap.min(rtol.sqrt()))
+}
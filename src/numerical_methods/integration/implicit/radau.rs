@@ -0,0 +1,492 @@
+//! Three-stage Radau IIA, fifth order, L-stable and stiffly accurate
+//! (Hairer & Wanner, "Solving ODEs II", IV.8).
+//!
+//! The collocation system is decoupled by diagonalizing the inverse of the
+//! Butcher matrix: one real and one complex `N x N` linear system per Newton
+//! iteration instead of a `3N x 3N` one.
+
+use num_traits::Float;
+
+use super::{newton_tolerance, FiniteDifference, Jacobian};
+use crate::math::{Lu, Matrix, Vector};
+use crate::numerical_methods::integration::adaptive::{
+    initial_step_size, is_finite, AdaptiveStep, DenseSegment, IntegrationError, Statistics, Tolerances,
+};
+
+#[cfg(feature = "std")]
+use crate::numerical_methods::integration::solver::Trajectory;
+
+/// Collocation nodes `(4 -/+ sqrt 6) / 10` and 1.
+const C: [f64; 3] = [0.15505102572168222, 0.6449489742783178, 1.0];
+/// Real eigenvalue of the inverse Butcher matrix, `3 + 3^(2/3) - 3^(1/3)`.
+const GAMMA: f64 = 3.637834252744496;
+/// Complex eigenvalue pair `ALPHA +/- i BETA` of the inverse Butcher matrix.
+const ALPHA: f64 = 2.6810828736277523;
+const BETA: f64 = 3.050430199247411;
+/// Eigenvectors `T` with `A^-1 = T [[GAMMA, 0, 0], [0, ALPHA, -BETA], [0, BETA, ALPHA]] T^-1`.
+const T_MAT: [[f64; 3]; 3] = [
+    [0.09443876248897529, -0.1412552950209542, -0.030029194105147424],
+    [0.2502131229653334, 0.2041293522937999, 0.3829421127572619],
+    [1.0, 1.0, 0.0],
+];
+const T_INV: [[f64; 3]; 3] = [
+    [4.178718591551904, 0.3276828207610623, 0.5233764454994494],
+    [-4.178718591551904, -0.3276828207610623, 0.47662355450055055],
+    [-0.502872634945788, 2.5719269498556057, -0.5960392048282249],
+];
+/// Error estimate weights on the stage increments, `[-(13 + 7 sqrt 6), -13 + 7 sqrt 6, -1] / 3`.
+const E: [f64; 3] = [-10.048809399827414, 1.382142733160748, -1.0 / 3.0];
+/// Collocation polynomial: coefficient of `theta^(k+1)` is `sum_i P[i][k] z_i`.
+const P: [[f64; 3]; 3] = [
+    [10.048809399827414, -25.62959144707664, 15.580782047249224],
+    [-1.382142733160748, 10.296258113743303, -8.914115380582556],
+    [1.0 / 3.0, -8.0 / 3.0, 10.0 / 3.0],
+];
+
+const NEWTON_MAX_ITERATIONS: usize = 6;
+const MIN_FACTOR: f64 = 0.2;
+const MAX_FACTOR: f64 = 10.0;
+
+/// Adaptive Radau IIA solver for stiff `y' = f(t, y)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Radau<T, const N: usize, J = FiniteDifference> {
+    pub tolerances: Tolerances<T, N>,
+    /// Smallest step before giving up; never below a few ulps of `t` or of
+    /// the integration span
+    pub min_step: T,
+    pub max_step: T,
+    /// First step size; chosen from the problem when `None`
+    pub initial_step: Option<T>,
+    /// Maximum number of accepted steps per solve
+    pub max_steps: usize,
+    pub jacobian: J,
+    statistics: Statistics,
+}
+
+impl<T: Float, const N: usize> Radau<T, N> {
+    pub fn new(tolerances: Tolerances<T, N>) -> Self {
+        Self::with_jacobian(tolerances, FiniteDifference)
+    }
+}
+
+impl<T: Float, const N: usize, J> Radau<T, N, J> {
+    pub fn with_jacobian(tolerances: Tolerances<T, N>, jacobian: J) -> Self {
+        Self {
+            tolerances,
+            min_step: T::zero(),
+            max_step: T::infinity(),
+            initial_step: None,
+            max_steps: 100_000,
+            jacobian,
+            statistics: Statistics::default(),
+        }
+    }
+
+    pub fn statistics(&self) -> Statistics {
+        self.statistics
+    }
+
+    pub fn reset_statistics(&mut self) {
+        self.statistics = Statistics::default();
+    }
+}
+
+impl<T, const N: usize, J> Radau<T, N, J>
+where
+    T: Float + Default,
+    J: Jacobian<T, N>,
+{
+    /// Integrates from `t0` to `tf` (either direction), calling `observer` after
+    /// every accepted step with the collocation polynomial as dense output.
+    /// Returns the state at `tf`.
+    pub fn solve_with<F, O>(
+        &mut self,
+        mut f: F,
+        y0: &Vector<T, N>,
+        t0: T,
+        tf: T,
+        mut observer: O,
+    ) -> Result<Vector<T, N>, IntegrationError<T>>
+    where
+        F: FnMut(T, &Vector<T, N>) -> Vector<T, N>,
+        O: FnMut(&AdaptiveStep<T, N>),
+    {
+        let mut evaluations = 0;
+        let mut rhs = |t: T, y: &Vector<T, N>| {
+            evaluations += 1;
+            f(t, y)
+        };
+        let result = self.integrate(&mut rhs, y0, t0, tf, &mut observer);
+        self.statistics.function_evaluations += evaluations;
+        result
+    }
+
+    /// Integrates from `t0` to `tf` and records every accepted step, starting with `t0`.
+    #[cfg(feature = "std")]
+    pub fn solve<F>(&mut self, f: F, y0: &Vector<T, N>, t0: T, tf: T) -> Result<Trajectory<T, Vector<T, N>>, IntegrationError<T>>
+    where
+        F: FnMut(T, &Vector<T, N>) -> Vector<T, N>,
+    {
+        let mut trajectory = Trajectory::new();
+        trajectory.push(t0, *y0);
+        self.solve_with(f, y0, t0, tf, |step| trajectory.push(step.t, step.y))?;
+        Ok(trajectory)
+    }
+
+    fn integrate<F, O>(
+        &mut self,
+        rhs: &mut F,
+        y0: &Vector<T, N>,
+        t0: T,
+        tf: T,
+        observer: &mut O,
+    ) -> Result<Vector<T, N>, IntegrationError<T>>
+    where
+        F: FnMut(T, &Vector<T, N>) -> Vector<T, N>,
+        O: FnMut(&AdaptiveStep<T, N>),
+    {
+        let mut t = t0;
+        let mut y = *y0;
+        if tf == t0 {
+            return Ok(y);
+        }
+        let direction = (tf - t0).signum();
+        let mut dy = rhs(t, &y);
+        if !is_finite(&dy) {
+            return Err(IntegrationError::NonFinite { t });
+        }
+
+        let zero = Vector::new([T::zero(); N]);
+        let min_factor = T::from(MIN_FACTOR).unwrap();
+        let max_factor = T::from(MAX_FACTOR).unwrap();
+        let half = T::from(0.5).unwrap();
+        let tol = newton_tolerance(&self.tolerances);
+        let ulps = T::epsilon() * T::from(10.0).unwrap();
+        let span = (tf - t0).abs();
+        let max_iterations = T::from(NEWTON_MAX_ITERATIONS).unwrap();
+
+        let mut h_abs = match self.initial_step {
+            Some(h0) => h0.abs(),
+            None => initial_step_size(rhs, &self.tolerances, 3, self.max_step, t, &y, &dy, direction),
+        };
+        let mut jac = self.jacobian.jacobian(rhs, t, &y, &dy);
+        self.statistics.jacobian_evaluations += 1;
+        let mut current_jacobian = true;
+        let mut factored: Option<(Lu<T, N>, ComplexLu<T, N>)> = None;
+        let mut h_abs_old: Option<T> = None;
+        let mut error_norm_old: Option<T> = None;
+        let mut previous: Option<DenseSegment<T, N>> = None;
+
+        let mut steps = 0;
+        while (tf - t) * direction > T::zero() {
+            if steps >= self.max_steps {
+                return Err(IntegrationError::MaxStepsExceeded { t });
+            }
+            let min_step = self.min_step.max(ulps * t.abs().max(span));
+            if h_abs > self.max_step {
+                h_abs = self.max_step;
+                h_abs_old = None;
+                error_norm_old = None;
+            }
+
+            let mut rejected = false;
+            loop {
+                if h_abs < min_step {
+                    return Err(IntegrationError::StepSizeTooSmall { t });
+                }
+                let mut t_new = t + h_abs * direction;
+                if (t_new - tf) * direction > T::zero() {
+                    t_new = tf;
+                }
+                let h = t_new - t;
+                h_abs = h.abs();
+
+                // Start Newton from the previous collocation polynomial
+                let mut z0 = [zero; 3];
+                if let Some(segment) = &previous {
+                    for (z, c) in z0.iter_mut().zip(C) {
+                        *z = segment.evaluate(t + T::from(c).unwrap() * h) - y;
+                    }
+                }
+                let mut scale = y;
+                for i in 0..N {
+                    scale[i] = self.tolerances.absolute[i] + self.tolerances.relative[i] * y[i].abs();
+                }
+
+                let outcome = loop {
+                    if factored.is_none() {
+                        factored = factor(&jac, h);
+                        self.statistics.lu_decompositions += 2;
+                    }
+                    let attempt = match &factored {
+                        Some((real, complex)) => newton(rhs, t, &y, h, &z0, &scale, tol, real, complex),
+                        None => None,
+                    };
+                    if attempt.is_some() || current_jacobian {
+                        break attempt;
+                    }
+                    jac = self.jacobian.jacobian(rhs, t, &y, &dy);
+                    self.statistics.jacobian_evaluations += 1;
+                    current_jacobian = true;
+                    factored = None;
+                };
+                let Some((iterations, z, rate)) = outcome else {
+                    h_abs = h_abs * half;
+                    factored = None;
+                    rejected = true;
+                    self.statistics.rejected_steps += 1;
+                    continue;
+                };
+                let (real, _) = factored.as_ref().unwrap();
+
+                let y_new = y + z[2];
+                let mut ze = zero;
+                for (zi, e) in z.iter().zip(E) {
+                    ze = ze + *zi * (T::from(e).unwrap() / h);
+                }
+                let mut error = real.solve(&(dy + ze));
+                let mut error_norm = self.tolerances.error_norm(&error, &y, &y_new);
+                let iterations_t = T::from(iterations).unwrap();
+                let two = T::from(2.0).unwrap();
+                let safety = T::from(0.9).unwrap() * (two * max_iterations + T::one()) / (two * max_iterations + iterations_t);
+                if rejected && error_norm > T::one() {
+                    error = real.solve(&(rhs(t, &(y + error)) + ze));
+                    error_norm = self.tolerances.error_norm(&error, &y, &y_new);
+                }
+
+                if error_norm > T::one() {
+                    let factor = predict_factor(h_abs, h_abs_old, error_norm, error_norm_old);
+                    h_abs = h_abs * (safety * factor).max(min_factor);
+                    factored = None;
+                    rejected = true;
+                    self.statistics.rejected_steps += 1;
+                    continue;
+                }
+
+                let recompute_jacobian = iterations > 2 && rate.is_some_and(|r| r > T::from(1e-3).unwrap());
+                let mut factor = (safety * predict_factor(h_abs, h_abs_old, error_norm, error_norm_old)).min(max_factor);
+                if !recompute_jacobian && factor < T::from(1.2).unwrap() {
+                    factor = T::one();
+                } else {
+                    factored = None;
+                }
+
+                let dy_new = rhs(t_new, &y_new);
+                if recompute_jacobian {
+                    jac = self.jacobian.jacobian(rhs, t_new, &y_new, &dy_new);
+                    self.statistics.jacobian_evaluations += 1;
+                    current_jacobian = true;
+                } else {
+                    current_jacobian = false;
+                }
+
+                let mut q = [zero; 3];
+                for (k, qk) in q.iter_mut().enumerate() {
+                    for (zi, row) in z.iter().zip(P) {
+                        *qk = *qk + *zi * T::from(row[k]).unwrap();
+                    }
+                }
+                let segment = DenseSegment::power(t, t_new, y, &q);
+                observer(&AdaptiveStep { t_previous: t, y_previous: y, t: t_new, y: y_new, dense: Some(segment) });
+
+                self.statistics.accepted_steps += 1;
+                steps += 1;
+                previous = Some(segment);
+                h_abs_old = Some(h_abs);
+                error_norm_old = Some(error_norm);
+                h_abs = h_abs * factor;
+                t = t_new;
+                y = y_new;
+                dy = dy_new;
+                break;
+            }
+        }
+        Ok(y)
+    }
+}
+
+/// Step ratio from the current and previous error (Gustafsson's predictive controller).
+fn predict_factor<T: Float>(h_abs: T, h_abs_old: Option<T>, error_norm: T, error_norm_old: Option<T>) -> T {
+    let quarter = T::from(0.25).unwrap();
+    let multiplier = match (h_abs_old, error_norm_old) {
+        (Some(h_old), Some(e_old)) if error_norm > T::zero() => h_abs / h_old * (e_old / error_norm).powf(quarter),
+        _ => T::one(),
+    };
+    if error_norm == T::zero() {
+        return T::from(MAX_FACTOR).unwrap();
+    }
+    multiplier.min(T::one()) * error_norm.powf(-quarter)
+}
+
+/// Factors the real `GAMMA / h - J` and complex `(ALPHA + i BETA) / h - J` iteration matrices.
+fn factor<T: Float + Default, const N: usize>(jac: &Matrix<T, N, N>, h: T) -> Option<(Lu<T, N>, ComplexLu<T, N>)> {
+    let identity = Matrix::<T, N, N>::identity();
+    let real = (identity * (T::from(GAMMA).unwrap() / h) - *jac).lu()?;
+    let complex = ComplexLu::new(
+        identity * (T::from(ALPHA).unwrap() / h) - *jac,
+        identity * (T::from(BETA).unwrap() / h),
+    )?;
+    Some((real, complex))
+}
+
+/// Simplified Newton iteration on the transformed collocation system. Returns
+/// the iteration count, the stage increments `z_i = Y_i - y` and the last
+/// convergence rate, or `None` if the iteration diverged or stalled.
+#[allow(clippy::too_many_arguments)]
+fn newton<T, F, const N: usize>(
+    rhs: &mut F,
+    t: T,
+    y: &Vector<T, N>,
+    h: T,
+    z0: &[Vector<T, N>; 3],
+    scale: &Vector<T, N>,
+    tol: T,
+    real: &Lu<T, N>,
+    complex: &ComplexLu<T, N>,
+) -> Option<(usize, [Vector<T, N>; 3], Option<T>)>
+where
+    T: Float + Default,
+    F: FnMut(T, &Vector<T, N>) -> Vector<T, N>,
+{
+    let gamma = T::from(GAMMA).unwrap() / h;
+    let alpha = T::from(ALPHA).unwrap() / h;
+    let beta = T::from(BETA).unwrap() / h;
+    let mut w = transform(&T_INV, z0);
+    let mut z = *z0;
+    let mut norm_old: Option<T> = None;
+    for k in 0..NEWTON_MAX_ITERATIONS {
+        let mut stages = [Vector::new([T::zero(); N]); 3];
+        for ((fi, zi), c) in stages.iter_mut().zip(&z).zip(C) {
+            *fi = rhs(t + T::from(c).unwrap() * h, &(*y + *zi));
+        }
+        if !stages.iter().all(is_finite) {
+            return None;
+        }
+        let g = transform(&T_INV, &stages);
+        let dw0 = real.solve(&(g[0] - w[0] * gamma));
+        let (dw1, dw2) = complex.solve(
+            &(g[1] - (w[1] * alpha - w[2] * beta)),
+            &(g[2] - (w[1] * beta + w[2] * alpha)),
+        );
+        let dw = [dw0, dw1, dw2];
+
+        let mut sum = T::zero();
+        for d in &dw {
+            for i in 0..N {
+                let v = d[i] / scale[i];
+                sum = sum + v * v;
+            }
+        }
+        let norm = (sum / T::from(3 * N).unwrap()).sqrt();
+        let rate = norm_old.map(|old| norm / old);
+        if let Some(r) = rate
+            && (r >= T::one() || r.powi((NEWTON_MAX_ITERATIONS - k) as i32) / (T::one() - r) * norm > tol)
+        {
+            return None;
+        }
+        for (wi, di) in w.iter_mut().zip(dw) {
+            *wi = *wi + di;
+        }
+        z = transform(&T_MAT, &w);
+        if norm == T::zero() || rate.is_some_and(|r| r / (T::one() - r) * norm < tol) {
+            return Some((k + 1, z, rate));
+        }
+        norm_old = Some(norm);
+    }
+    None
+}
+
+/// Mixes the three stage vectors with a 3x3 coefficient matrix.
+fn transform<T: Float, const N: usize>(m: &[[f64; 3]; 3], v: &[Vector<T, N>; 3]) -> [Vector<T, N>; 3] {
+    let mut out = [Vector::new([T::zero(); N]); 3];
+    for (o, row) in out.iter_mut().zip(m) {
+        for (vj, &mij) in v.iter().zip(row) {
+            if mij != 0.0 {
+                *o = *o + *vj * T::from(mij).unwrap();
+            }
+        }
+    }
+    out
+}
+
+/// LU factorization with partial pivoting of the complex matrix `re + i im`.
+#[derive(Debug, Clone, Copy)]
+struct ComplexLu<T, const N: usize> {
+    re: [[T; N]; N],
+    im: [[T; N]; N],
+    permutation: [usize; N],
+}
+
+impl<T: Float, const N: usize> ComplexLu<T, N> {
+    fn new(re: Matrix<T, N, N>, im: Matrix<T, N, N>) -> Option<Self> {
+        let (mut re, mut im) = (re.data, im.data);
+        let mut permutation = [0; N];
+        for (i, p) in permutation.iter_mut().enumerate() {
+            *p = i;
+        }
+        for k in 0..N {
+            let modulus = |i: usize| re[i][k] * re[i][k] + im[i][k] * im[i][k];
+            let mut pivot = k;
+            for i in k + 1..N {
+                if modulus(i) > modulus(pivot) {
+                    pivot = i;
+                }
+            }
+            let m = modulus(pivot);
+            if m == T::zero() || !m.is_finite() {
+                return None;
+            }
+            re.swap(pivot, k);
+            im.swap(pivot, k);
+            permutation.swap(pivot, k);
+            for i in k + 1..N {
+                let (fr, fi) = div(re[i][k], im[i][k], re[k][k], im[k][k]);
+                re[i][k] = fr;
+                im[i][k] = fi;
+                for j in k + 1..N {
+                    let (pr, pi) = mul(fr, fi, re[k][j], im[k][j]);
+                    re[i][j] = re[i][j] - pr;
+                    im[i][j] = im[i][j] - pi;
+                }
+            }
+        }
+        Some(Self { re, im, permutation })
+    }
+
+    /// Solves for `x = xr + i xi` given `b = br + i bi`.
+    fn solve(&self, br: &Vector<T, N>, bi: &Vector<T, N>) -> (Vector<T, N>, Vector<T, N>) {
+        let mut xr = *br;
+        let mut xi = *bi;
+        for i in 0..N {
+            let (mut sr, mut si) = (br[self.permutation[i]], bi[self.permutation[i]]);
+            for j in 0..i {
+                let (pr, pi) = mul(self.re[i][j], self.im[i][j], xr[j], xi[j]);
+                sr = sr - pr;
+                si = si - pi;
+            }
+            xr[i] = sr;
+            xi[i] = si;
+        }
+        for i in (0..N).rev() {
+            let (mut sr, mut si) = (xr[i], xi[i]);
+            for j in i + 1..N {
+                let (pr, pi) = mul(self.re[i][j], self.im[i][j], xr[j], xi[j]);
+                sr = sr - pr;
+                si = si - pi;
+            }
+            let (qr, qi) = div(sr, si, self.re[i][i], self.im[i][i]);
+            xr[i] = qr;
+            xi[i] = qi;
+        }
+        (xr, xi)
+    }
+}
+
+fn mul<T: Float>(ar: T, ai: T, br: T, bi: T) -> (T, T) {
+    (ar * br - ai * bi, ar * bi + ai * br)
+}
+
+fn div<T: Float>(ar: T, ai: T, br: T, bi: T) -> (T, T) {
+    let d = br * br + bi * bi;
+    ((ar * br + ai * bi) / d, (ai * br - ar * bi) / d)
+}
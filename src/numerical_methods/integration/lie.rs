@@ -0,0 +1,105 @@
+//! Lie-group integration of attitude on SO(3).
+//!
+//! The attitude obeys `R' = R [w]x` with `w` the angular velocity in body axes
+//! (for the quaternion, `q' = q (0, w) / 2`). Instead of integrating the
+//! quaternion components and renormalizing, Runge-Kutta-Munthe-Kaas methods
+//! integrate a rotation vector `theta` in the Lie algebra and map back with
+//! `R = R0 exp(theta)`, so every stage and every step stays on SO(3).
+
+use num_traits::Float;
+
+use super::solver::fixed_steps;
+use super::state::State;
+use crate::attitude::Rotation;
+use crate::math::Vector;
+
+#[cfg(feature = "std")]
+use super::solver::Trajectory;
+
+/// Fourth-order Runge-Kutta-Munthe-Kaas.
+///
+/// Steps an attitude together with any other state `x`, e.g. the body rates of
+/// a rigid body. The right-hand side returns the body angular velocity and the
+/// derivative of `x`; for attitude kinematics alone use `x = Vector<T, 0>`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Rkmk4;
+
+impl Rkmk4 {
+    /// Advances `(R, x)` at time `t` by one step of size `dt`.
+    pub fn step<T, S, F>(&mut self, f: &mut F, t: T, state: &(Rotation<T>, S), dt: T) -> (Rotation<T>, S)
+    where
+        T: Float,
+        S: State<T>,
+        F: FnMut(T, &Rotation<T>, &S) -> (Vector<T, 3>, S),
+    {
+        let two = T::from(2.0).unwrap();
+        let half_dt = dt / two;
+        let (r0, x0) = state;
+        let stage = |theta: Vector<T, 3>| r0.compose(&Rotation::exp(theta));
+
+        let (w1, g1) = f(t, r0, x0);
+        let k1 = w1;
+        let theta = k1 * half_dt;
+        let (w2, g2) = f(t + half_dt, &stage(theta), &x0.scaled_add(half_dt, &g1));
+        let k2 = dexp_inverse(&theta, &w2);
+        let theta = k2 * half_dt;
+        let (w3, g3) = f(t + half_dt, &stage(theta), &x0.scaled_add(half_dt, &g2));
+        let k3 = dexp_inverse(&theta, &w3);
+        let theta = k3 * dt;
+        let (w4, g4) = f(t + dt, &stage(theta), &x0.scaled_add(dt, &g3));
+        let k4 = dexp_inverse(&theta, &w4);
+
+        let sixth = dt / T::from(6.0).unwrap();
+        let theta = (k1 + k2 * two + k3 * two + k4) * sixth;
+        let g = g1.scaled_add(two, &g2).scaled_add(two, &g3).scaled_add(T::one(), &g4);
+        (stage(theta), x0.scaled_add(sixth, &g))
+    }
+
+    /// Integrates from `t0` to `tf` with step `dt`, shortening the last step to
+    /// land on `tf`. `observer` is called at `t0` and after every step.
+    pub fn solve_with<T, S, F, O>(
+        &mut self,
+        mut f: F,
+        x0: &(Rotation<T>, S),
+        t0: T,
+        tf: T,
+        dt: T,
+        mut observer: O,
+    ) -> (Rotation<T>, S)
+    where
+        T: Float,
+        S: State<T>,
+        F: FnMut(T, &Rotation<T>, &S) -> (Vector<T, 3>, S),
+        O: FnMut(T, &(Rotation<T>, S)),
+    {
+        let mut x = x0.clone();
+        observer(t0, &x);
+        fixed_steps(t0, tf, dt, |t, step, t_new| {
+            x = self.step(&mut f, t, &x, step);
+            observer(t_new, &x);
+            true
+        });
+        x
+    }
+
+    /// Integrates from `t0` to `tf` with step `dt` and records every step.
+    #[cfg(feature = "std")]
+    pub fn solve<T, S, F>(&mut self, f: F, x0: &(Rotation<T>, S), t0: T, tf: T, dt: T) -> Trajectory<T, (Rotation<T>, S)>
+    where
+        T: Float,
+        S: State<T>,
+        F: FnMut(T, &Rotation<T>, &S) -> (Vector<T, 3>, S),
+    {
+        let mut trajectory = Trajectory::new();
+        self.solve_with(f, x0, t0, tf, dt, |t, x| trajectory.push(t, x.clone()));
+        trajectory
+    }
+}
+
+/// Inverse of the right-trivialized differential of `exp` at `theta`, applied
+/// to `w`, truncated after the terms a fourth-order method needs:
+/// `w + theta x w / 2 + theta x (theta x w) / 12`.
+fn dexp_inverse<T: Float>(theta: &Vector<T, 3>, w: &Vector<T, 3>) -> Vector<T, 3> {
+    let tw = theta.cross(*w);
+    *w + tw * T::from(0.5).unwrap() + theta.cross(tw) / T::from(12.0).unwrap()
+}
@@ -1,6 +1,35 @@
 pub mod euler;
 pub mod trapezoidal;
 pub mod rk4;
+pub mod state;
+pub mod solver;
+pub mod explicit;
+pub mod adaptive;
+pub mod events;
+pub mod implicit;
+pub mod symplectic;
+pub mod lie;
+pub mod multistep;
+#[allow(deprecated)]
 pub use euler::EulerIntegrate;
+#[allow(deprecated)]
 pub use rk4::Rk4Integrate;
+#[allow(deprecated)]
 pub use trapezoidal::TrapezoidalIntegrate;
+pub use state::State;
+pub use solver::OdeSolver;
+pub use implicit::{BackwardEuler, Bdf, CrankNicolson, FiniteDifference, Jacobian, Newton, Radau};
+pub use symplectic::{Leapfrog, SymplecticSolver, VelocityVerlet, Yoshida4, Yoshida6};
+pub use lie::Rkmk4;
+pub use multistep::{AdamsBashforthMoulton, GaussJackson};
+pub use events::{Crossing, Event, EventOutcome, EventRecord};
+#[cfg(feature = "std")]
+pub use solver::Trajectory;
+pub use explicit::{ExplicitEuler, Heun, Midpoint, Rk4};
+pub use adaptive::{AdaptiveSolver, AdaptiveStep, DenseSegment, EmbeddedPair, IntegrationError, Statistics, Tolerances};
+#[cfg(feature = "std")]
+pub use adaptive::DenseSolution;
+
+#[cfg(test)]
+#[path = "tests/mod.rs"]
+mod tests;
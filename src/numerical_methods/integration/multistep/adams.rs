@@ -0,0 +1,109 @@
+use num_traits::Float;
+
+use super::{startup_step, weighted_sum, History, ADAMS_BASHFORTH, ADAMS_MOULTON, MAX_ORDER};
+use crate::math::Vector;
+use crate::numerical_methods::integration::solver::OdeSolver;
+
+/// Adams-Bashforth-Moulton predictor-corrector for `y' = f(t, y)`.
+///
+/// Each step predicts with the `k`-step Adams-Bashforth formula and corrects
+/// `corrections` times with the Adams-Moulton formula of the same order `k`
+/// (one correction is the usual PECE scheme). With `variable_order`, `k` moves
+/// within `1..=order` to whichever order has the smallest local error estimate
+/// `h |gamma*_k| |nabla^k f|`; otherwise it stays at `order`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdamsBashforthMoulton<T, const N: usize> {
+    /// Highest order, in `1..=MAX_ORDER`
+    pub order: usize,
+    pub variable_order: bool,
+    pub corrections: usize,
+    pub startup_substeps: usize,
+    current_order: usize,
+    derivatives: History<T, N>,
+    /// Time, state and step size at the end of the last step
+    last: Option<(T, Vector<T, N>, T)>,
+}
+
+impl<T: Float, const N: usize> AdamsBashforthMoulton<T, N> {
+    /// Fixed-order PECE of the given order. Panics unless `1 <= order <= MAX_ORDER`.
+    pub fn new(order: usize) -> Self {
+        assert!((1..=MAX_ORDER).contains(&order), "Adams order must be in 1..={MAX_ORDER}");
+        Self {
+            order,
+            variable_order: false,
+            corrections: 1,
+            startup_substeps: 4,
+            current_order: order,
+            derivatives: History::new(),
+            last: None,
+        }
+    }
+
+    /// Order used for the most recent step.
+    pub fn current_order(&self) -> usize {
+        self.current_order
+    }
+
+    /// Forgets the history, so the next step starts up again.
+    pub fn reset(&mut self) {
+        self.derivatives.clear();
+        self.last = None;
+    }
+}
+
+impl<T, const N: usize> OdeSolver<T, Vector<T, N>> for AdamsBashforthMoulton<T, N>
+where
+    T: Float,
+{
+    fn step<F>(&mut self, f: &mut F, t: T, x: &Vector<T, N>, dt: T) -> Vector<T, N>
+    where
+        F: FnMut(T, &Vector<T, N>) -> Vector<T, N>,
+    {
+        let order = self.order.clamp(1, MAX_ORDER);
+        if self.last != Some((t, *x, dt)) {
+            self.derivatives.clear();
+            self.derivatives.push(f(t, x), order);
+            self.current_order = order;
+        }
+        let t_new = t + dt;
+
+        let (y, fy) = if self.derivatives.len < order {
+            let y = startup_step(f, t, x, dt, self.startup_substeps);
+            (y, f(t_new, &y))
+        } else {
+            let k = self.current_order.min(order);
+            let history = self.derivatives;
+            let predicted = history.differences(None, k);
+            let mut y = *x + weighted_sum(&ADAMS_BASHFORTH, &predicted, k) * dt;
+            let mut fy = f(t_new, &y);
+            for _ in 0..self.corrections {
+                let corrected = history.differences(Some(fy), k);
+                y = *x + weighted_sum(&ADAMS_MOULTON, &corrected, k) * dt;
+                fy = f(t_new, &y);
+            }
+            if self.variable_order {
+                self.current_order = select_order(&history, fy, k, order, dt);
+            }
+            (y, fy)
+        };
+
+        self.derivatives.push(fy, order);
+        self.last = Some((t_new, y, dt));
+        y
+    }
+}
+
+/// Next order from the estimates at `k - 1`, `k` and, when the history is
+/// long enough, `k + 1`.
+fn select_order<T: Float, const N: usize>(history: &History<T, N>, fy: Vector<T, N>, k: usize, order: usize, h: T) -> usize {
+    let available = (history.len + 1).min(k + 2);
+    let differences = history.differences(Some(fy), available);
+    let estimate = |j: usize| h.abs() * T::from(ADAMS_MOULTON[j].abs()).unwrap() * differences[j].norm();
+    if k > 1 && estimate(k - 1) <= estimate(k) {
+        k - 1
+    } else if k < order && available > k + 1 && estimate(k + 1) < estimate(k) {
+        k + 1
+    } else {
+        k
+    }
+}
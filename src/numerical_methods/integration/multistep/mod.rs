@@ -0,0 +1,186 @@
+//! Fixed-step multistep predictor-corrector methods.
+//!
+//! Multistep methods reuse derivatives from previous steps, so each step costs
+//! one or two evaluations of `f` whatever the order. They implement
+//! [`OdeSolver`](super::OdeSolver) and keep their history between calls to
+//! `step`: a call that continues from the end of the previous step with the
+//! same step size reuses it, anything else restarts. After a restart the history
+//! is rebuilt with fixed-step Dormand-Prince 8 steps, each split into
+//! `startup_substeps` substeps.
+//!
+//! All formulas are in backward-difference form, with coefficients from the
+//! generating functions in Hairer, Nørsett & Wanner, "Solving ODEs I", III.1
+//! and III.10.
+
+use num_traits::Float;
+
+use super::adaptive::tableaux::dop853;
+use super::state::State;
+use crate::math::Vector;
+
+mod adams;
+mod gauss_jackson;
+pub use adams::AdamsBashforthMoulton;
+pub use gauss_jackson::GaussJackson;
+
+/// Highest supported order.
+pub const MAX_ORDER: usize = 12;
+
+const COEFFICIENTS: usize = MAX_ORDER + 2;
+
+/// Adams-Bashforth `gamma_j`: `sum_j gamma_j x^j = -x / ((1 - x) ln(1 - x))`.
+const ADAMS_BASHFORTH: [f64; COEFFICIENTS] = adams_bashforth();
+
+/// Adams-Moulton `gamma*_j`: `sum_j gamma*_j x^j = -x / ln(1 - x)`.
+const ADAMS_MOULTON: [f64; COEFFICIENTS] = adams_moulton();
+
+/// Cowell `sigma*_j`: `sum_j sigma*_j x^j = x^2 / ln(1 - x)^2`.
+const COWELL: [f64; COEFFICIENTS] = cowell();
+
+/// Störmer `sigma_j`: `sum_j sigma_j x^j = x^2 / ((1 - x) ln(1 - x)^2)`.
+const STORMER: [f64; COEFFICIENTS] = stormer();
+
+const fn adams_bashforth() -> [f64; COEFFICIENTS] {
+    let mut gamma = [0.0; COEFFICIENTS];
+    let mut j = 0;
+    while j < COEFFICIENTS {
+        let mut sum = 0.0;
+        let mut i = 0;
+        while i < j {
+            sum += gamma[i] / (j + 1 - i) as f64;
+            i += 1;
+        }
+        gamma[j] = 1.0 - sum;
+        j += 1;
+    }
+    gamma
+}
+
+const fn adams_moulton() -> [f64; COEFFICIENTS] {
+    let mut gamma = [0.0; COEFFICIENTS];
+    gamma[0] = 1.0;
+    let mut j = 1;
+    while j < COEFFICIENTS {
+        let mut sum = 0.0;
+        let mut i = 0;
+        while i < j {
+            sum += gamma[i] / (j + 1 - i) as f64;
+            i += 1;
+        }
+        gamma[j] = -sum;
+        j += 1;
+    }
+    gamma
+}
+
+const fn cowell() -> [f64; COEFFICIENTS] {
+    let gamma = adams_moulton();
+    let mut sigma = [0.0; COEFFICIENTS];
+    let mut j = 0;
+    while j < COEFFICIENTS {
+        let mut i = 0;
+        while i <= j {
+            sigma[j] += gamma[i] * gamma[j - i];
+            i += 1;
+        }
+        j += 1;
+    }
+    sigma
+}
+
+const fn stormer() -> [f64; COEFFICIENTS] {
+    let mut sigma = cowell();
+    let mut j = 1;
+    while j < COEFFICIENTS {
+        sigma[j] += sigma[j - 1];
+        j += 1;
+    }
+    sigma
+}
+
+/// The most recent derivatives, newest first.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct History<T, const N: usize> {
+    values: [Vector<T, N>; MAX_ORDER],
+    len: usize,
+}
+
+impl<T: Float, const N: usize> History<T, N> {
+    fn new() -> Self {
+        Self { values: [Vector::new([T::zero(); N]); MAX_ORDER], len: 0 }
+    }
+
+    fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Adds the newest value, dropping the oldest beyond `capacity`.
+    fn push(&mut self, value: Vector<T, N>, capacity: usize) {
+        self.values.copy_within(0..MAX_ORDER - 1, 1);
+        self.values[0] = value;
+        self.len = (self.len + 1).min(capacity);
+    }
+
+    /// Backward differences `nabla^j` at the newest of `newest` followed by the
+    /// first `count - 1` stored values, for `j < count`.
+    fn differences(&self, newest: Option<Vector<T, N>>, count: usize) -> [Vector<T, N>; MAX_ORDER + 1] {
+        let mut d = [Vector::new([T::zero(); N]); MAX_ORDER + 1];
+        let offset = usize::from(newest.is_some());
+        if let Some(v) = newest {
+            d[0] = v;
+        }
+        d[offset..count].copy_from_slice(&self.values[..count - offset]);
+        let mut out = d;
+        for (j, o) in out.iter_mut().enumerate().take(count).skip(1) {
+            for i in 0..count - j {
+                d[i] = d[i] - d[i + 1];
+            }
+            *o = d[0];
+        }
+        out
+    }
+}
+
+/// `sum_j coefficients[j] * differences[j]` over the first `count` terms.
+fn weighted_sum<T: Float, const N: usize>(coefficients: &[f64], differences: &[Vector<T, N>], count: usize) -> Vector<T, N> {
+    let mut sum = Vector::new([T::zero(); N]);
+    for (c, d) in coefficients.iter().zip(differences).take(count) {
+        sum = sum + *d * T::from(*c).unwrap();
+    }
+    sum
+}
+
+/// One step of size `h` as `substeps` fixed Dormand-Prince 8 steps.
+fn startup_step<T, S, F>(f: &mut F, t: T, x: &S, h: T, substeps: usize) -> S
+where
+    T: Float,
+    S: State<T>,
+    F: FnMut(T, &S) -> S,
+{
+    let substeps = substeps.max(1);
+    let dt = h / T::from(substeps).unwrap();
+    let mut x = x.clone();
+    for i in 0..substeps {
+        let ti = t + dt * T::from(i).unwrap();
+        let mut k: [Option<S>; 12] = Default::default();
+        for (stage, row) in dop853::A[..12].iter().enumerate() {
+            let xi = combine(&x, dt, row, &k);
+            k[stage] = Some(f(ti + T::from(dop853::C[stage]).unwrap() * dt, &xi));
+        }
+        x = combine(&x, dt, dop853::A[12], &k);
+    }
+    x
+}
+
+/// `x + h * sum_j weights[j] * k[j]`
+fn combine<T: Float, S: State<T>>(x: &S, h: T, weights: &[f64], k: &[Option<S>]) -> S {
+    let mut out = x.clone();
+    for (w, kj) in weights.iter().zip(k) {
+        if *w != 0.0
+            && let Some(kj) = kj
+        {
+            out = out.scaled_add(T::from(*w).unwrap() * h, kj);
+        }
+    }
+    out
+}
@@ -1,7 +1,11 @@
+// The deprecated API keeps its implementations until it is removed.
+#![allow(deprecated)]
+
 use num_traits::Float;
 use crate::math::Vector;
 use crate::coordinate::Cartesian;
 
+#[deprecated(note = "use `Rk4` through `OdeSolver::step`")]
 pub trait Rk4Integrate<T: Float> {
     /// RK4 step given `self` as the current state,
     /// `f` as the derivative function, and `dt` as the time step.
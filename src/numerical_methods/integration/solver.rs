@@ -0,0 +1,145 @@
+use num_traits::Float;
+use super::events::{hermite, Event, EventOutcome, EventRecord, EventTracker};
+use super::state::State;
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+/// A one-step integrator for `x' = f(t, x)`.
+pub trait OdeSolver<T: Float, S: State<T>> {
+    /// Advances `x` at time `t` by one step of size `dt`.
+    fn step<F>(&mut self, f: &mut F, t: T, x: &S, dt: T) -> S
+    where
+        F: FnMut(T, &S) -> S;
+
+    /// Integrates from `t0` to `tf` with nominal step `dt`, shortening the last
+    /// step to land on `tf`. `observer` is called at `t0` and after every step.
+    /// Returns the state at `tf`.
+    fn solve_with<F, O>(&mut self, mut f: F, x0: &S, t0: T, tf: T, dt: T, mut observer: O) -> S
+    where
+        F: FnMut(T, &S) -> S,
+        O: FnMut(T, &S),
+    {
+        let mut x = x0.clone();
+        observer(t0, &x);
+        fixed_steps(t0, tf, dt, |t, step, t_new| {
+            x = self.step(&mut f, t, &x, step);
+            observer(t_new, &x);
+            true
+        });
+        x
+    }
+
+    /// Integrates from `t0` to `tf` with nominal step `dt`, watching `events`.
+    /// Crossings are located on a cubic Hermite interpolant of each step, which
+    /// costs one extra evaluation of `f` per step. `on_event` receives every
+    /// crossing; the solve stops at the first terminal one.
+    #[allow(clippy::too_many_arguments)]
+    fn solve_with_events<F, O, const E: usize>(
+        &mut self,
+        mut f: F,
+        x0: &S,
+        t0: T,
+        tf: T,
+        dt: T,
+        events: &[Event<'_, T, S>; E],
+        mut on_event: O,
+    ) -> EventOutcome<T, S>
+    where
+        F: FnMut(T, &S) -> S,
+        O: FnMut(&EventRecord<T, S>),
+    {
+        let mut t_end = t0;
+        let mut x = x0.clone();
+        if dt == T::zero() || tf == t0 {
+            return EventOutcome { t: t_end, state: x, terminated_by: None };
+        }
+        let mut tracker = EventTracker::new(events, t0, &x);
+        let mut dx = f(t0, &x);
+        let mut terminated_by = None;
+        fixed_steps(t0, tf, dt, |t, step, t_new| {
+            let x_new = self.step(&mut f, t, &x, step);
+            let dx_new = f(t_new, &x_new);
+            let interpolate = |s: T| hermite(&x, &dx, &x_new, &dx_new, step, (s - t) / step);
+            if let Some(record) = tracker.check(events, t, t_new, &x_new, interpolate, &mut on_event) {
+                (t_end, x, terminated_by) = (record.t, record.state, Some(record.index));
+                return false;
+            }
+            (t_end, x, dx) = (t_new, x_new, dx_new);
+            true
+        });
+        EventOutcome { t: t_end, state: x, terminated_by }
+    }
+
+    /// Integrates from `t0` to `tf` with nominal step `dt` and records every step.
+    #[cfg(feature = "std")]
+    fn solve<F>(&mut self, f: F, x0: &S, t0: T, tf: T, dt: T) -> Trajectory<T, S>
+    where
+        F: FnMut(T, &S) -> S,
+    {
+        let mut trajectory = Trajectory::new();
+        self.solve_with(f, x0, t0, tf, dt, |t, x| trajectory.push(t, x.clone()));
+        trajectory
+    }
+}
+
+/// Walks from `t0` to `tf` in steps of nominal size `dt`, shortening the last
+/// one to land exactly on `tf`. Calls `advance(t, step, t + step)` per step and
+/// stops early once it returns `false`.
+pub(crate) fn fixed_steps<T: Float>(t0: T, tf: T, dt: T, mut advance: impl FnMut(T, T, T) -> bool) {
+    if dt == T::zero() {
+        return;
+    }
+    let h = dt.abs() * (tf - t0).signum();
+    let slack = T::one() + T::epsilon() * T::from(100.0).unwrap();
+    let mut t = t0;
+    while (tf - t) * h > T::zero() {
+        let remaining = tf - t;
+        let last = h.abs() * slack >= remaining.abs();
+        let step = if last { remaining } else { h };
+        let t_new = if last { tf } else { t + step };
+        if !advance(t, step, t_new) {
+            return;
+        }
+        t = t_new;
+    }
+}
+
+/// Time-stamped states produced by a solver.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trajectory<T, S> {
+    pub times: Vec<T>,
+    pub states: Vec<S>,
+}
+
+#[cfg(feature = "std")]
+impl<T: Copy, S> Trajectory<T, S> {
+    pub fn new() -> Self {
+        Self { times: Vec::new(), states: Vec::new() }
+    }
+    pub fn push(&mut self, t: T, x: S) {
+        self.times.push(t);
+        self.states.push(x);
+    }
+    pub fn len(&self) -> usize {
+        self.times.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.times.is_empty()
+    }
+    /// Last recorded time and state.
+    pub fn last(&self) -> Option<(T, &S)> {
+        Some((*self.times.last()?, self.states.last()?))
+    }
+    pub fn iter(&self) -> impl Iterator<Item = (T, &S)> + '_ {
+        self.times.iter().copied().zip(self.states.iter())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Copy, S> Default for Trajectory<T, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
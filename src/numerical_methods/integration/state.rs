@@ -0,0 +1,148 @@
+use num_traits::Float;
+use crate::math::{Matrix, Vector};
+use crate::coordinate::Cartesian;
+
+/// Vector-space operations an ODE solver needs on a state.
+///
+/// Every linear combination used by the integrators is built from `zero_like`,
+/// `scaled` and `scaled_add`; `dimension`/`component` expose the state as a flat
+/// list of scalars for error norms and event functions.
+pub trait State<T: Float>: Clone {
+    /// A zero state with the same shape as `self`.
+    fn zero_like(&self) -> Self;
+    /// `k * self`
+    fn scaled(&self, k: T) -> Self;
+    /// `self + k * rhs`
+    fn scaled_add(&self, k: T, rhs: &Self) -> Self;
+    /// Number of scalar components.
+    fn dimension(&self) -> usize;
+    /// Scalar component `i` in `0..dimension()`.
+    fn component(&self, i: usize) -> T;
+}
+
+macro_rules! scalar_state {
+    ($($t:ty),*) => {$(
+        impl State<$t> for $t {
+            fn zero_like(&self) -> Self {
+                0.0
+            }
+            fn scaled(&self, k: $t) -> Self {
+                self * k
+            }
+            fn scaled_add(&self, k: $t, rhs: &Self) -> Self {
+                self + k * rhs
+            }
+            fn dimension(&self) -> usize {
+                1
+            }
+            fn component(&self, _i: usize) -> $t {
+                *self
+            }
+        }
+    )*};
+}
+
+scalar_state!(f32, f64);
+
+impl<T: Float, const N: usize> State<T> for Vector<T, N> {
+    fn zero_like(&self) -> Self {
+        Vector { data: [T::zero(); N] }
+    }
+    fn scaled(&self, k: T) -> Self {
+        *self * k
+    }
+    fn scaled_add(&self, k: T, rhs: &Self) -> Self {
+        *self + *rhs * k
+    }
+    fn dimension(&self) -> usize {
+        N
+    }
+    fn component(&self, i: usize) -> T {
+        self.data[i]
+    }
+}
+
+impl<T: Float, const M: usize, const N: usize> State<T> for Matrix<T, M, N> {
+    fn zero_like(&self) -> Self {
+        Matrix { data: [[T::zero(); N]; M] }
+    }
+    fn scaled(&self, k: T) -> Self {
+        *self * k
+    }
+    fn scaled_add(&self, k: T, rhs: &Self) -> Self {
+        *self + *rhs * k
+    }
+    fn dimension(&self) -> usize {
+        M * N
+    }
+    fn component(&self, i: usize) -> T {
+        self.data[i / N][i % N]
+    }
+}
+
+impl<T: Float, RF: Clone> State<T> for Cartesian<T, RF> {
+    fn zero_like(&self) -> Self {
+        Cartesian::zero()
+    }
+    fn scaled(&self, k: T) -> Self {
+        Cartesian::from_vector(self.data * k)
+    }
+    fn scaled_add(&self, k: T, rhs: &Self) -> Self {
+        Cartesian::from_vector(self.data + rhs.data * k)
+    }
+    fn dimension(&self) -> usize {
+        3
+    }
+    fn component(&self, i: usize) -> T {
+        self.data[i]
+    }
+}
+
+impl<T: Float, A: State<T>, B: State<T>> State<T> for (A, B) {
+    fn zero_like(&self) -> Self {
+        (self.0.zero_like(), self.1.zero_like())
+    }
+    fn scaled(&self, k: T) -> Self {
+        (self.0.scaled(k), self.1.scaled(k))
+    }
+    fn scaled_add(&self, k: T, rhs: &Self) -> Self {
+        (self.0.scaled_add(k, &rhs.0), self.1.scaled_add(k, &rhs.1))
+    }
+    fn dimension(&self) -> usize {
+        self.0.dimension() + self.1.dimension()
+    }
+    fn component(&self, i: usize) -> T {
+        let n0 = self.0.dimension();
+        if i < n0 { self.0.component(i) } else { self.1.component(i - n0) }
+    }
+}
+
+impl<T: Float, A: State<T>, B: State<T>, C: State<T>> State<T> for (A, B, C) {
+    fn zero_like(&self) -> Self {
+        (self.0.zero_like(), self.1.zero_like(), self.2.zero_like())
+    }
+    fn scaled(&self, k: T) -> Self {
+        (self.0.scaled(k), self.1.scaled(k), self.2.scaled(k))
+    }
+    fn scaled_add(&self, k: T, rhs: &Self) -> Self {
+        (
+            self.0.scaled_add(k, &rhs.0),
+            self.1.scaled_add(k, &rhs.1),
+            self.2.scaled_add(k, &rhs.2),
+        )
+    }
+    fn dimension(&self) -> usize {
+        self.0.dimension() + self.1.dimension() + self.2.dimension()
+    }
+    fn component(&self, i: usize) -> T {
+        let n0 = self.0.dimension();
+        let n1 = self.1.dimension();
+        if i < n0 {
+            self.0.component(i)
+        } else if i < n0 + n1 {
+            self.1.component(i - n0)
+        } else {
+            self.2.component(i - n0 - n1)
+        }
+    }
+}
@@ -0,0 +1,146 @@
+//! Symplectic integrators for separable second-order systems `q'' = a(t, q)`.
+//!
+//! The state is a `(position, velocity)` pair. These methods conserve a
+//! modified energy, so for conservative forces the energy error stays bounded
+//! over arbitrarily long runs instead of drifting as with [`Rk4`](super::Rk4).
+//! All of them are fixed-step: changing the step size breaks the conservation.
+
+use num_traits::Float;
+
+use super::solver::fixed_steps;
+use super::state::State;
+
+#[cfg(feature = "std")]
+use super::solver::Trajectory;
+
+/// Yoshida's triple-jump weights for fourth order, `[outer, inner]`.
+const YOSHIDA4: [f64; 2] = [1.3512071919596578, -1.7024143839193153];
+
+/// Yoshida's sixth-order weights (solution A), `[w3, w2, w1, w0]`
+/// (H. Yoshida, "Construction of higher order symplectic integrators", 1990).
+const YOSHIDA6: [f64; 4] = [0.784513610477560, 0.235573213359357, -1.17767998417887, 1.31518632068391];
+
+/// A one-step integrator for `q'' = a(t, q)` on a `(q, v)` state.
+pub trait SymplecticSolver<T: Float, S: State<T>> {
+    /// Advances `(q, v)` at time `t` by one step of size `dt`.
+    fn step<A>(&mut self, a: &mut A, t: T, x: &(S, S), dt: T) -> (S, S)
+    where
+        A: FnMut(T, &S) -> S;
+
+    /// Integrates from `t0` to `tf` with step `dt`, shortening the last step to
+    /// land on `tf`. `observer` is called at `t0` and after every step.
+    fn solve_with<A, O>(&mut self, mut a: A, x0: &(S, S), t0: T, tf: T, dt: T, mut observer: O) -> (S, S)
+    where
+        A: FnMut(T, &S) -> S,
+        O: FnMut(T, &(S, S)),
+    {
+        let mut x = x0.clone();
+        observer(t0, &x);
+        fixed_steps(t0, tf, dt, |t, step, t_new| {
+            x = self.step(&mut a, t, &x, step);
+            observer(t_new, &x);
+            true
+        });
+        x
+    }
+
+    /// Integrates from `t0` to `tf` with step `dt` and records every step.
+    #[cfg(feature = "std")]
+    fn solve<A>(&mut self, a: A, x0: &(S, S), t0: T, tf: T, dt: T) -> Trajectory<T, (S, S)>
+    where
+        A: FnMut(T, &S) -> S,
+    {
+        let mut trajectory = Trajectory::new();
+        self.solve_with(a, x0, t0, tf, dt, |t, x| trajectory.push(t, x.clone()));
+        trajectory
+    }
+}
+
+/// Velocity Verlet (kick-drift-kick), second order, two force evaluations per step.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VelocityVerlet;
+
+/// Leapfrog (drift-kick-drift), second order, one force evaluation per step.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Leapfrog;
+
+/// Fourth-order Yoshida composition of three velocity Verlet steps.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Yoshida4;
+
+/// Sixth-order Yoshida composition of seven velocity Verlet steps.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Yoshida6;
+
+impl<T: Float, S: State<T>> SymplecticSolver<T, S> for VelocityVerlet {
+    fn step<A>(&mut self, a: &mut A, t: T, x: &(S, S), dt: T) -> (S, S)
+    where
+        A: FnMut(T, &S) -> S,
+    {
+        verlet(a, t, x, dt)
+    }
+}
+
+impl<T: Float, S: State<T>> SymplecticSolver<T, S> for Leapfrog {
+    fn step<A>(&mut self, a: &mut A, t: T, x: &(S, S), dt: T) -> (S, S)
+    where
+        A: FnMut(T, &S) -> S,
+    {
+        let half_dt = dt * T::from(0.5).unwrap();
+        let (q, v) = x;
+        let q_half = q.scaled_add(half_dt, v);
+        let v_new = v.scaled_add(dt, &a(t + half_dt, &q_half));
+        (q_half.scaled_add(half_dt, &v_new), v_new)
+    }
+}
+
+impl<T: Float, S: State<T>> SymplecticSolver<T, S> for Yoshida4 {
+    fn step<A>(&mut self, a: &mut A, t: T, x: &(S, S), dt: T) -> (S, S)
+    where
+        A: FnMut(T, &S) -> S,
+    {
+        let [outer, inner] = YOSHIDA4;
+        compose(a, t, x, dt, &[outer, inner, outer])
+    }
+}
+
+impl<T: Float, S: State<T>> SymplecticSolver<T, S> for Yoshida6 {
+    fn step<A>(&mut self, a: &mut A, t: T, x: &(S, S), dt: T) -> (S, S)
+    where
+        A: FnMut(T, &S) -> S,
+    {
+        let [w3, w2, w1, w0] = YOSHIDA6;
+        compose(a, t, x, dt, &[w3, w2, w1, w0, w1, w2, w3])
+    }
+}
+
+fn verlet<T, S, A>(a: &mut A, t: T, x: &(S, S), dt: T) -> (S, S)
+where
+    T: Float,
+    S: State<T>,
+    A: FnMut(T, &S) -> S,
+{
+    let half_dt = dt * T::from(0.5).unwrap();
+    let (q, v) = x;
+    let v_half = v.scaled_add(half_dt, &a(t, q));
+    let q_new = q.scaled_add(dt, &v_half);
+    let v_new = v_half.scaled_add(half_dt, &a(t + dt, &q_new));
+    (q_new, v_new)
+}
+
+/// Symmetric composition of velocity Verlet substeps with the given weights.
+fn compose<T, S, A>(a: &mut A, t: T, x: &(S, S), dt: T, weights: &[f64]) -> (S, S)
+where
+    T: Float,
+    S: State<T>,
+    A: FnMut(T, &S) -> S,
+{
+    let mut t = t;
+    let mut x = x.clone();
+    for &w in weights {
+        let h = dt * T::from(w).unwrap();
+        x = verlet(a, t, &x, h);
+        t = t + h;
+    }
+    x
+}
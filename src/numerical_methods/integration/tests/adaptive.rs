@@ -0,0 +1,168 @@
+#[cfg(test)]
+mod tests {
+    use crate::math::Vector;
+    use crate::numerical_methods::integration::*;
+    use approx::assert_relative_eq;
+
+    const PAIRS: [EmbeddedPair; 3] = [EmbeddedPair::Fehlberg45, EmbeddedPair::DormandPrince54, EmbeddedPair::DormandPrince853];
+
+    fn oscillator(_t: f64, y: &Vector<f64, 2>) -> Vector<f64, 2> {
+        Vector::new([y[1], -y[0]])
+    }
+
+    fn exact(t: f64) -> Vector<f64, 2> {
+        Vector::new([t.cos(), -t.sin()])
+    }
+
+    #[test]
+    fn test_pairs_meet_tolerance() {
+        for pair in PAIRS {
+            for tol in [1e-6, 1e-9] {
+                let mut solver = AdaptiveSolver::new(pair, Tolerances::uniform(tol, tol));
+                let y = solver.solve_with(oscillator, &Vector::new([1.0, 0.0]), 0.0, 10.0, |_| {}).unwrap();
+                // Global error stays within a modest multiple of the local tolerance;
+                // Fehlberg propagates the lower-order solution
+                let error = (y - exact(10.0)).norm();
+                let bound = if pair == EmbeddedPair::Fehlberg45 { 1000.0 } else { 100.0 };
+                assert!(error < bound * tol, "{pair:?} tol {tol}: error {error}");
+
+                let stats = solver.statistics();
+                assert!(stats.accepted_steps > 0);
+                assert!(stats.function_evaluations > stats.accepted_steps);
+            }
+        }
+    }
+
+    #[test]
+    fn test_higher_order_takes_fewer_steps() {
+        let steps = |pair| {
+            let mut solver = AdaptiveSolver::new(pair, Tolerances::uniform(1e-10, 1e-10));
+            solver.solve_with(oscillator, &Vector::new([1.0, 0.0]), 0.0, 10.0, |_| {}).unwrap();
+            solver.statistics().accepted_steps
+        };
+        let rkf = steps(EmbeddedPair::Fehlberg45);
+        let dp5 = steps(EmbeddedPair::DormandPrince54);
+        let dop853 = steps(EmbeddedPair::DormandPrince853);
+        assert!(dop853 < dp5 && dp5 <= rkf, "{rkf} {dp5} {dop853}");
+    }
+
+    #[test]
+    fn test_statistics_accumulate_and_reset() {
+        let mut solver = AdaptiveSolver::new(EmbeddedPair::DormandPrince54, Tolerances::uniform(1e-8, 1e-8));
+        solver.solve_with(oscillator, &Vector::new([1.0, 0.0]), 0.0, 5.0, |_| {}).unwrap();
+        let first = solver.statistics();
+        // FSAL: six new evaluations per attempted step, plus the start-up ones
+        let attempts = first.accepted_steps + first.rejected_steps;
+        assert_eq!(first.function_evaluations, 6 * attempts + 2);
+
+        solver.solve_with(oscillator, &Vector::new([1.0, 0.0]), 0.0, 5.0, |_| {}).unwrap();
+        assert_eq!(solver.statistics().accepted_steps, 2 * first.accepted_steps);
+        solver.reset_statistics();
+        assert_eq!(solver.statistics(), Statistics::default());
+    }
+
+    #[test]
+    fn test_per_component_tolerances() {
+        // Two decoupled decays, only the second one held to a tight tolerance
+        let decay = |_t: f64, y: &Vector<f64, 2>| -*y;
+        let tolerances = Tolerances::new(Vector::new([1e-3, 1e-11]), Vector::new([0.0, 0.0]));
+        let mut solver = AdaptiveSolver::new(EmbeddedPair::DormandPrince54, tolerances);
+        let tight = solver.solve_with(decay, &Vector::new([1.0, 1.0]), 0.0, 2.0, |_| {}).unwrap();
+        let tight_steps = solver.statistics().accepted_steps;
+        assert_relative_eq!(tight[1], (-2.0_f64).exp(), epsilon = 1e-9);
+
+        let mut loose = AdaptiveSolver::new(EmbeddedPair::DormandPrince54, Tolerances::new(Vector::new([1e-3, 1e-3]), Vector::new([0.0, 0.0])));
+        loose.solve_with(decay, &Vector::new([1.0, 1.0]), 0.0, 2.0, |_| {}).unwrap();
+        assert!(loose.statistics().accepted_steps < tight_steps);
+    }
+
+    #[test]
+    fn test_step_limits() {
+        let mut solver = AdaptiveSolver::new(EmbeddedPair::DormandPrince853, Tolerances::uniform(1e-6, 1e-6));
+        solver.max_step = 0.25;
+        let mut largest: f64 = 0.0;
+        solver.solve_with(oscillator, &Vector::new([1.0, 0.0]), 0.0, 10.0, |s| largest = largest.max(s.t - s.t_previous)).unwrap();
+        assert!(largest <= 0.25);
+        assert!(solver.statistics().accepted_steps >= 40);
+
+        // y' = y^2 blows up at t = 1
+        let mut solver = AdaptiveSolver::new(EmbeddedPair::DormandPrince54, Tolerances::uniform(1e-8, 1e-8));
+        solver.min_step = 1e-6;
+        let result = solver.solve_with(|_t, y: &Vector<f64, 1>| Vector::new([y[0] * y[0]]), &Vector::new([1.0]), 0.0, 2.0, |_| {});
+        match result {
+            Err(IntegrationError::StepSizeTooSmall { t }) => assert!(t > 0.99 && t < 1.0),
+            other => panic!("unexpected {other:?}"),
+        }
+
+        // A derivative that turns NaN just after the start is reported as
+        // such, not retried down to zero-length steps
+        for pair in PAIRS {
+            let mut solver = AdaptiveSolver::new(pair, Tolerances::uniform(1e-8, 1e-8));
+            let nan_after_start = |t: f64, y: &Vector<f64, 1>| if t > 0.0 { Vector::new([f64::NAN]) } else { -*y };
+            let result = solver.solve_with(nan_after_start, &Vector::new([1.0]), 0.0, 1.0, |_| {});
+            assert_eq!(result, Err(IntegrationError::NonFinite { t: 0.0 }));
+            assert_eq!(solver.statistics().accepted_steps, 0);
+            assert!(solver.statistics().rejected_steps <= 10);
+        }
+
+        // y' = 1/t has an error estimate independent of the step size at t = 0,
+        // so the step shrinks to the floor set by the integration span
+        let mut solver = AdaptiveSolver::new(EmbeddedPair::DormandPrince54, Tolerances::uniform(1e-8, 1e-8));
+        let singular = |t: f64, _y: &Vector<f64, 1>| Vector::new([if t > 0.0 { 1.0 / t } else { 0.0 }]);
+        let result = solver.solve_with(singular, &Vector::new([0.0]), 0.0, 1.0, |_| {});
+        assert_eq!(result, Err(IntegrationError::StepSizeTooSmall { t: 0.0 }));
+
+        let mut solver = AdaptiveSolver::new(EmbeddedPair::DormandPrince54, Tolerances::uniform(1e-8, 1e-8));
+        solver.max_steps = 3;
+        let result = solver.solve_with(oscillator, &Vector::new([1.0, 0.0]), 0.0, 10.0, |_| {});
+        assert!(matches!(result, Err(IntegrationError::MaxStepsExceeded { .. })));
+    }
+
+    #[test]
+    fn test_backward_integration_lands_on_final_time() {
+        for pair in PAIRS {
+            let mut solver = AdaptiveSolver::new(pair, Tolerances::uniform(1e-10, 1e-10));
+            let trajectory = solver.solve(oscillator, &exact(3.0), 3.0, -1.0).unwrap();
+            let (t, y) = trajectory.last().unwrap();
+            assert_eq!(t, -1.0);
+            assert!((*y - exact(-1.0)).norm() < 1e-7);
+            assert!(trajectory.times.windows(2).all(|w| w[1] < w[0]));
+        }
+    }
+
+    #[test]
+    fn test_dense_output() {
+        // Nonlinear problem y' = y cos t, y = exp(sin t)
+        let f = |t: f64, y: &Vector<f64, 1>| Vector::new([y[0] * t.cos()]);
+        for (pair, tol) in [(EmbeddedPair::Fehlberg45, 1e-5), (EmbeddedPair::DormandPrince54, 1e-6), (EmbeddedPair::DormandPrince853, 1e-8)] {
+            let mut solver = AdaptiveSolver::new(pair, Tolerances::uniform(1e-10, 1e-10));
+            let solution = solver.solve_dense(f, &Vector::new([1.0]), 0.0, 6.0).unwrap();
+            assert!(!solver.dense_output);
+            for i in 0..=60 {
+                let t = 0.1 * i as f64;
+                let y = solution.evaluate(t).unwrap();
+                assert!((y[0] - t.sin().exp()).abs() < tol, "{pair:?} at {t}");
+            }
+            assert!(solution.evaluate(6.5).is_none());
+
+            // Segments tile the interval and match the step end points
+            let segments = solution.segments();
+            assert_eq!(segments.first().unwrap().start(), 0.0);
+            assert_eq!(segments.last().unwrap().end(), 6.0);
+            assert!(segments.windows(2).all(|w| w[0].end() == w[1].start()));
+        }
+    }
+
+    #[test]
+    fn test_dense_segments_interpolate_step_ends() {
+        let mut solver = AdaptiveSolver::new(EmbeddedPair::DormandPrince853, Tolerances::uniform(1e-9, 1e-9));
+        solver.dense_output = true;
+        solver
+            .solve_with(oscillator, &Vector::new([1.0, 0.0]), 0.0, 4.0, |step| {
+                let dense = step.dense.unwrap();
+                assert_relative_eq!(dense.evaluate(step.t_previous)[0], step.y_previous[0], epsilon = 1e-14);
+                assert_relative_eq!(dense.evaluate(step.t)[0], step.y[0], epsilon = 1e-12);
+            })
+            .unwrap();
+    }
+}
@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod tests {
+    use crate::math::Vector;
+    use crate::numerical_methods::integration::*;
+    use approx::assert_relative_eq;
+    use core::f64::consts::PI;
+
+    const G: f64 = 9.81;
+
+    fn ballistic(_t: f64, y: &Vector<f64, 2>) -> Vector<f64, 2> {
+        Vector::new([y[1], -G])
+    }
+
+    fn oscillator(_t: f64, y: &Vector<f64, 2>) -> Vector<f64, 2> {
+        Vector::new([y[1], -y[0]])
+    }
+
+    #[test]
+    fn test_terminal_ground_impact() {
+        let altitude = |_t: f64, y: &Vector<f64, 2>| y[0];
+        let events = [Event::new(&altitude, Crossing::Falling, true)];
+        let mut solver = AdaptiveSolver::new(EmbeddedPair::DormandPrince853, Tolerances::uniform(1e-12, 1e-12));
+        let outcome = solver.solve_with_events(ballistic, &Vector::new([10.0, 0.0]), 0.0, 100.0, &events, |_| {}).unwrap();
+
+        assert_eq!(outcome.terminated_by, Some(0));
+        assert_relative_eq!(outcome.t, (20.0 / G).sqrt(), epsilon = 1e-10);
+        assert_relative_eq!(outcome.state[0], 0.0, epsilon = 1e-9);
+        assert_relative_eq!(outcome.state[1], -(20.0 * G).sqrt(), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_apogee_then_impact() {
+        let altitude = |_t: f64, y: &Vector<f64, 2>| y[0];
+        let vertical_speed = |_t: f64, y: &Vector<f64, 2>| y[1];
+        let events = [
+            Event::new(&altitude, Crossing::Falling, true),
+            Event::new(&vertical_speed, Crossing::Falling, false),
+        ];
+        for pair in [EmbeddedPair::Fehlberg45, EmbeddedPair::DormandPrince54, EmbeddedPair::DormandPrince853] {
+            let mut solver = AdaptiveSolver::new(pair, Tolerances::uniform(1e-10, 1e-10));
+            let mut records = [None; 2];
+            let mut count = 0;
+            let outcome = solver
+                .solve_with_events(ballistic, &Vector::new([0.0, 20.0]), 0.0, 100.0, &events, |r| {
+                    records[count] = Some(*r);
+                    count += 1;
+                })
+                .unwrap();
+            assert_eq!(count, 2);
+            let apogee = records[0].unwrap();
+            assert_eq!(apogee.index, 1);
+            assert_relative_eq!(apogee.t, 20.0 / G, epsilon = 1e-8);
+            assert_relative_eq!(apogee.state[0], 200.0 / G, epsilon = 1e-8);
+            assert_eq!(records[1].unwrap().index, 0);
+            assert_eq!(outcome.terminated_by, Some(0));
+            assert_relative_eq!(outcome.t, 40.0 / G, epsilon = 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_direction_filters() {
+        // x = cos t crosses zero at pi/2 (falling), 3pi/2 (rising), 5pi/2 (falling)
+        let position = |_t: f64, y: &Vector<f64, 2>| y[0];
+        let count = |direction| {
+            let events = [Event::new(&position, direction, false)];
+            let mut solver = AdaptiveSolver::new(EmbeddedPair::DormandPrince54, Tolerances::uniform(1e-10, 1e-10));
+            let mut times = [0.0; 4];
+            let mut n = 0;
+            let outcome = solver
+                .solve_with_events(oscillator, &Vector::new([1.0, 0.0]), 0.0, 10.0, &events, |r| {
+                    times[n] = r.t;
+                    n += 1;
+                })
+                .unwrap();
+            assert_eq!(outcome.terminated_by, None);
+            assert_eq!(outcome.t, 10.0);
+            (n, times)
+        };
+
+        let (n, times) = count(Crossing::Either);
+        assert_eq!(n, 3);
+        for (t, expected) in times.iter().zip([PI / 2.0, 1.5 * PI, 2.5 * PI]) {
+            assert_relative_eq!(*t, expected, epsilon = 1e-8);
+        }
+        let (n, times) = count(Crossing::Rising);
+        assert_eq!(n, 1);
+        assert_relative_eq!(times[0], 1.5 * PI, epsilon = 1e-8);
+        assert_eq!(count(Crossing::Falling).0, 2);
+    }
+
+    #[test]
+    fn test_fixed_step_events() {
+        // RK4 and the cubic Hermite interpolant are exact for a parabola
+        let altitude = |_t: f64, y: &Vector<f64, 2>| y[0];
+        let events = [Event::new(&altitude, Crossing::Either, true)];
+        let outcome = Rk4.solve_with_events(ballistic, &Vector::new([10.0, 0.0]), 0.0, 5.0, 0.1, &events, |_| {});
+        assert_eq!(outcome.terminated_by, Some(0));
+        assert_relative_eq!(outcome.t, (20.0 / G).sqrt(), epsilon = 1e-12);
+
+        // Backwards in time through the oscillator's zero at -pi/2
+        let position = |_t: f64, y: &Vector<f64, 2>| y[0];
+        let events = [Event::new(&position, Crossing::Either, true)];
+        let outcome = Rk4.solve_with_events(oscillator, &Vector::new([1.0, 0.0]), 0.0, -3.0, 0.01, &events, |_| {});
+        assert_eq!(outcome.terminated_by, Some(0));
+        assert_relative_eq!(outcome.t, -PI / 2.0, epsilon = 1e-8);
+    }
+}
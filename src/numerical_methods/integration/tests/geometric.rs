@@ -0,0 +1,140 @@
+#[cfg(test)]
+mod tests {
+    use crate::attitude::Rotation;
+    use crate::math::Vector;
+    use crate::numerical_methods::integration::*;
+    use approx::assert_relative_eq;
+
+    type Phase = (Vector<f64, 2>, Vector<f64, 2>);
+
+    fn oscillator(_t: f64, q: &Vector<f64, 1>) -> Vector<f64, 1> {
+        -*q
+    }
+
+    fn kepler(_t: f64, r: &Vector<f64, 2>) -> Vector<f64, 2> {
+        *r * (-1.0 / r.norm().powi(3))
+    }
+
+    fn energy(x: &Phase) -> f64 {
+        0.5 * x.1.dot(&x.1) - 1.0 / x.0.norm()
+    }
+
+    fn global_error<S: SymplecticSolver<f64, Vector<f64, 1>>>(mut solver: S, dt: f64) -> f64 {
+        let x0 = (Vector::new([1.0]), Vector::new([0.0]));
+        let (q, v) = solver.solve_with(oscillator, &x0, 0.0, 2.0, dt, |_, _| {});
+        (q[0] - 2.0_f64.cos()).abs() + (v[0] + 2.0_f64.sin()).abs()
+    }
+
+    #[test]
+    fn test_symplectic_orders() {
+        let ratio = |e1: f64, e2: f64| (e1 / e2).log2();
+        assert_relative_eq!(ratio(global_error(VelocityVerlet, 0.02), global_error(VelocityVerlet, 0.01)), 2.0, epsilon = 0.05);
+        assert_relative_eq!(ratio(global_error(Leapfrog, 0.02), global_error(Leapfrog, 0.01)), 2.0, epsilon = 0.05);
+        assert_relative_eq!(ratio(global_error(Yoshida4, 0.1), global_error(Yoshida4, 0.05)), 4.0, epsilon = 0.1);
+        assert_relative_eq!(ratio(global_error(Yoshida6, 0.2), global_error(Yoshida6, 0.1)), 6.0, epsilon = 0.2);
+    }
+
+    /// Largest energy error over the first and the last ten orbits of an
+    /// eccentric Kepler orbit followed for `orbits` periods.
+    fn energy_errors(mut step: impl FnMut(&Phase, f64) -> Phase, dt: f64, orbits: usize) -> (f64, f64) {
+        // e = 0.5, a = 1: period 2 pi
+        let mut x: Phase = (Vector::new([0.5, 0.0]), Vector::new([0.0, 3.0_f64.sqrt()]));
+        let e0 = energy(&x);
+        let steps_per_orbit = (core::f64::consts::TAU / dt).round() as usize;
+        let dt = core::f64::consts::TAU / steps_per_orbit as f64;
+        let (mut first, mut last) = (0.0_f64, 0.0_f64);
+        for orbit in 0..orbits {
+            for _ in 0..steps_per_orbit {
+                x = step(&x, dt);
+                let error = (energy(&x) - e0).abs();
+                if orbit < 10 {
+                    first = first.max(error);
+                }
+                if orbit >= orbits - 10 {
+                    last = last.max(error);
+                }
+            }
+        }
+        (first, last)
+    }
+
+    #[test]
+    fn test_kepler_energy_conservation() {
+        let dt = 0.01;
+        let orbits = 200;
+        let (first, last) = energy_errors(|x, h| VelocityVerlet.step(&mut kepler, 0.0, x, h), dt, orbits);
+        assert!(last < 2.0 * first && last < 1e-3, "{first} {last}");
+        let (first, last) = energy_errors(|x, h| Yoshida4.step(&mut kepler, 0.0, x, h), dt, orbits);
+        assert!(last < 2.0 * first && last < 1e-6, "{first} {last}");
+
+        // RK4 on the same problem drifts secularly
+        let mut rhs = |_t: f64, x: &Phase| (x.1, kepler(0.0, &x.0));
+        let (first, last) = energy_errors(|x, h| Rk4.step(&mut rhs, 0.0, x, h), dt, orbits);
+        assert!(last > 10.0 * first, "{first} {last}");
+    }
+
+    #[test]
+    fn test_symplectic_trajectory() {
+        let x0 = (Vector::new([1.0]), Vector::new([0.0]));
+        let trajectory = Leapfrog.solve(oscillator, &x0, 0.0, 1.05, 0.1);
+        assert_eq!(trajectory.len(), 12);
+        let (t, (q, _)) = trajectory.last().unwrap();
+        assert_eq!(t, 1.05);
+        assert_relative_eq!(q[0], 1.05_f64.cos(), epsilon = 1e-2);
+    }
+
+    #[test]
+    fn test_rkmk_constant_rate() {
+        // Constant body rate: R(t) = R0 exp(w t), which RKMK reproduces exactly
+        let w = Vector::new([0.3, -0.2, 0.9]);
+        let r0 = Rotation::exp(Vector::new([0.1, 0.2, 0.3]));
+        let x0 = (r0, Vector::<f64, 0>::new([]));
+        let (r, _) = Rkmk4.solve_with(|_t, _r: &Rotation<f64>, x: &Vector<f64, 0>| (w, *x), &x0, 0.0, 100.0, 0.1, |_, _| {});
+        let expected = r0.compose(&Rotation::exp(w * 100.0));
+        let q = r.quaternion().data;
+        let e = expected.quaternion().data;
+        assert!((q - e).norm() < 1e-12 || (q + e).norm() < 1e-12);
+    }
+
+    /// Torque-free rigid body with principal inertia `INERTIA`, state `(R, w)`.
+    const INERTIA: [f64; 3] = [1.0, 2.0, 3.0];
+
+    fn euler_equations(_t: f64, _r: &Rotation<f64>, w: &Vector<f64, 3>) -> (Vector<f64, 3>, Vector<f64, 3>) {
+        let [i1, i2, i3] = INERTIA;
+        let dw = Vector::new([
+            (i2 - i3) * w[1] * w[2] / i1,
+            (i3 - i1) * w[2] * w[0] / i2,
+            (i1 - i2) * w[0] * w[1] / i3,
+        ]);
+        (*w, dw)
+    }
+
+    fn inertial_momentum(x: &(Rotation<f64>, Vector<f64, 3>)) -> Vector<f64, 3> {
+        let h = Vector::new([INERTIA[0] * x.1[0], INERTIA[1] * x.1[1], INERTIA[2] * x.1[2]]);
+        x.0.rotate(&h)
+    }
+
+    #[test]
+    fn test_rkmk_rigid_body() {
+        let x0 = (Rotation::exp(Vector::new([0.4, -0.1, 0.2])), Vector::new([1.0, 0.1, 0.5]));
+        let h0 = inertial_momentum(&x0);
+        let mut worst_norm = 0.0_f64;
+        let mut worst_momentum = 0.0_f64;
+        let x = Rkmk4.solve_with(euler_equations, &x0, 0.0, 200.0, 0.01, |_, x| {
+            worst_norm = worst_norm.max((x.0.quaternion().norm() - 1.0).abs());
+            worst_momentum = worst_momentum.max((inertial_momentum(x) - h0).norm());
+        });
+        // Stays on SO(3) without renormalization
+        assert!(worst_norm < 1e-12, "{worst_norm}");
+        assert!(worst_momentum < 1e-7, "{worst_momentum}");
+
+        // Fourth order against a fine reference
+        let reference = Rkmk4.solve_with(euler_equations, &x0, 0.0, 10.0, 0.0025, |_, _| {});
+        let error = |dt: f64| {
+            let x = Rkmk4.solve_with(euler_equations, &x0, 0.0, 10.0, dt, |_, _| {});
+            (x.0.quaternion().data - reference.0.quaternion().data).norm() + (x.1 - reference.1).norm()
+        };
+        assert_relative_eq!((error(0.04) / error(0.02)).log2(), 4.0, epsilon = 0.2);
+        assert!(x.1.norm().is_finite());
+    }
+}
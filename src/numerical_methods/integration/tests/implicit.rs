@@ -0,0 +1,168 @@
+#[cfg(test)]
+mod tests {
+    use crate::math::{Matrix, Vector};
+    use crate::numerical_methods::integration::*;
+    use approx::assert_relative_eq;
+
+    fn decay(_t: f64, y: &Vector<f64, 1>) -> Vector<f64, 1> {
+        -*y
+    }
+
+    /// Stiff relaxation onto cos t
+    fn relaxation(t: f64, y: &Vector<f64, 1>) -> Vector<f64, 1> {
+        Vector::new([-1000.0 * (y[0] - t.cos())])
+    }
+
+    /// Robertson's chemical kinetics (Hairer & Wanner, "Solving ODEs II", IV.1)
+    fn robertson(_t: f64, y: &Vector<f64, 3>) -> Vector<f64, 3> {
+        Vector::new([
+            -0.04 * y[0] + 1e4 * y[1] * y[2],
+            0.04 * y[0] - 1e4 * y[1] * y[2] - 3e7 * y[1] * y[1],
+            3e7 * y[1] * y[1],
+        ])
+    }
+
+    fn robertson_jacobian(_t: f64, y: &Vector<f64, 3>) -> Matrix<f64, 3, 3> {
+        Matrix::new([
+            [-0.04, 1e4 * y[2], 1e4 * y[1]],
+            [0.04, -1e4 * y[2] - 6e7 * y[1], -1e4 * y[1]],
+            [0.0, 6e7 * y[1], 0.0],
+        ])
+    }
+
+    /// Reference solution at t = 40
+    const ROBERTSON_40: [f64; 3] = [0.7158270687193135, 9.185534764529e-6, 0.2841637457458];
+
+    #[test]
+    fn test_fixed_step_orders() {
+        let error = |mut solver: Box<dyn FnMut(f64) -> f64>, dt: f64| (solver(dt) - (-1.0_f64).exp()).abs();
+        let backward = |dt: f64| BackwardEuler::new().solve_with(decay, &Vector::new([1.0]), 0.0, 1.0, dt, |_, _| {})[0];
+        let trapezoid = |dt: f64| CrankNicolson::new().solve_with(decay, &Vector::new([1.0]), 0.0, 1.0, dt, |_, _| {})[0];
+        let ratio = |e1: f64, e2: f64| (e1 / e2).log2();
+        assert_relative_eq!(ratio(error(Box::new(backward), 0.01), error(Box::new(backward), 0.005)), 1.0, epsilon = 0.05);
+        assert_relative_eq!(ratio(error(Box::new(trapezoid), 0.01), error(Box::new(trapezoid), 0.005)), 2.0, epsilon = 0.05);
+    }
+
+    #[test]
+    fn test_fixed_step_stiff_stability() {
+        // h * lambda = -100: RK4 blows up, the implicit methods track cos t
+        let y = Rk4.solve_with(relaxation, &Vector::new([0.0]), 0.0, 2.0, 0.1, |_, _| {});
+        assert!(y[0].abs() > 1e10);
+        let y = BackwardEuler::new().solve_with(relaxation, &Vector::new([0.0]), 0.0, 2.0, 0.1, |_, _| {});
+        assert_relative_eq!(y[0], 2.0_f64.cos(), epsilon = 2e-3);
+        let y = CrankNicolson::with_jacobian(|_t: f64, _y: &Vector<f64, 1>| Matrix::new([[-1000.0]]))
+            .solve_with(relaxation, &Vector::new([1.0]), 0.0, 2.0, 0.1, |_, _| {});
+        assert_relative_eq!(y[0], 2.0_f64.cos(), epsilon = 1e-2);
+    }
+
+    #[test]
+    fn test_fixed_step_newton_failures() {
+        // y' = y with h = 1 makes I - hJ singular: two half steps instead
+        let growth = |_t: f64, y: &Vector<f64, 1>| *y;
+        let y = BackwardEuler::new().try_step(&mut { growth }, 0.0, &Vector::new([1.0]), 1.0).unwrap();
+        assert_relative_eq!(y[0], 4.0, epsilon = 1e-9);
+        assert_relative_eq!(BackwardEuler::new().step(&mut { growth }, 0.0, &Vector::new([1.0]), 1.0)[0], 4.0, epsilon = 1e-9);
+
+        // y' = -y^3 from 10 with h = 1: the root of y + y^3 = 10 given enough
+        // iterations, otherwise backward Euler on substeps, nearer the exact
+        // 10 / sqrt(201)
+        let cubic = |_t: f64, y: &Vector<f64, 1>| Vector::new([-y[0].powi(3)]);
+        let mut solver = BackwardEuler::new();
+        let y = solver.try_step(&mut { cubic }, 0.0, &Vector::new([10.0]), 1.0).unwrap();
+        assert!(y[0] > 10.0 / 201.0_f64.sqrt() && y[0] < 2.0);
+        solver.newton.max_iterations = 50;
+        let y = solver.try_step(&mut { cubic }, 0.0, &Vector::new([10.0]), 1.0).unwrap();
+        assert_relative_eq!(y[0], 2.0, epsilon = 1e-9);
+        let y = CrankNicolson::new().try_step(&mut { cubic }, 0.0, &Vector::new([2.0]), 0.1).unwrap();
+        assert!((y[0] - 2.0 + 0.05 * (8.0 + y[0].powi(3))).abs() < 1e-9);
+
+        // A derivative that breaks down after the start cannot be stepped over
+        let broken = |t: f64, y: &Vector<f64, 1>| if t > 0.0 { Vector::new([f64::NAN]) } else { -*y };
+        let mut solver = BackwardEuler::new();
+        assert_eq!(solver.try_step(&mut { broken }, 0.0, &Vector::new([1.0]), 0.1), Err(IntegrationError::ConvergenceFailure { t: 0.0 }));
+        assert!(solver.step(&mut { broken }, 0.0, &Vector::new([1.0]), 0.1)[0].is_nan());
+        assert_eq!(solver.try_step(&mut { broken }, 1.0, &Vector::new([1.0]), 0.1), Err(IntegrationError::NonFinite { t: 1.0 }));
+
+        // The step from 0.5 completes its first half before failing
+        let late = |t: f64, y: &Vector<f64, 1>| if t > 0.55 { Vector::new([f64::NAN]) } else { -*y };
+        let mut observed = 0;
+        let result = CrankNicolson::new().try_solve_with(late, &Vector::new([1.0]), 0.0, 1.0, 0.1, |_, _| observed += 1);
+        assert!(matches!(result, Err(IntegrationError::ConvergenceFailure { t }) if (t - 0.55).abs() < 1e-12));
+        assert_eq!(observed, 6);
+        let y = CrankNicolson::new().try_solve_with(decay, &Vector::new([1.0]), 0.0, 1.0, 0.01, |_, _| {}).unwrap();
+        assert_relative_eq!(y[0], (-1.0_f64).exp(), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_finite_difference_jacobian() {
+        let y = Vector::new([0.9, 2e-5, 0.1]);
+        let mut f = robertson;
+        let fd = FiniteDifference.jacobian(&mut f, 0.0, &y, &robertson(0.0, &y));
+        let exact = robertson_jacobian(0.0, &y);
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_relative_eq!(fd[(i, j)], exact[(i, j)], epsilon = 1e-6, max_relative = 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn test_radau_robertson() {
+        let tolerances = Tolerances::new(Vector::new([1e-10, 1e-12, 1e-10]), Vector::new([1e-8; 3]));
+        let mut solver = Radau::with_jacobian(tolerances, robertson_jacobian);
+        let y = solver.solve_with(robertson, &Vector::new([1.0, 0.0, 0.0]), 0.0, 40.0, |_| {}).unwrap();
+        for i in 0..3 {
+            assert_relative_eq!(y[i], ROBERTSON_40[i], max_relative = 1e-6);
+        }
+        let stats = solver.statistics();
+        assert!(stats.accepted_steps < 400, "{stats:?}");
+        assert!(stats.jacobian_evaluations > 0 && stats.lu_decompositions > 0);
+    }
+
+    #[test]
+    fn test_bdf_robertson() {
+        let tolerances = Tolerances::new(Vector::new([1e-10, 1e-12, 1e-10]), Vector::new([1e-7; 3]));
+        let mut solver = Bdf::new(tolerances);
+        let y = solver.solve_with(robertson, &Vector::new([1.0, 0.0, 0.0]), 0.0, 40.0, |_| {}).unwrap();
+        for i in 0..3 {
+            assert_relative_eq!(y[i], ROBERTSON_40[i], max_relative = 1e-4);
+        }
+        let stats = solver.statistics();
+        assert!(stats.accepted_steps < 1000, "{stats:?}");
+
+        // Capping the order at one is backward Euler with step control
+        let mut first_order = Bdf::new(tolerances);
+        first_order.max_order = 1;
+        first_order.solve_with(robertson, &Vector::new([1.0, 0.0, 0.0]), 0.0, 40.0, |_| {}).unwrap();
+        assert!(first_order.statistics().accepted_steps > stats.accepted_steps);
+    }
+
+    #[test]
+    fn test_adaptive_implicit_accuracy_and_dense_output() {
+        // Non-stiff oscillator, y = [cos t, -sin t]
+        let oscillator = |_t: f64, y: &Vector<f64, 2>| Vector::new([y[1], -y[0]]);
+        let exact = |t: f64| Vector::new([t.cos(), -t.sin()]);
+        let tolerances = Tolerances::uniform(1e-9, 1e-9);
+
+        let mut radau = Radau::new(tolerances);
+        let mut bdf = Bdf::new(tolerances);
+        let mut worst: [f64; 2] = [0.0; 2];
+        let check = |worst: &mut f64, step: &AdaptiveStep<f64, 2>| {
+            let dense = step.dense.unwrap();
+            let mid = 0.5 * (step.t_previous + step.t);
+            *worst = worst.max((dense.evaluate(mid) - exact(mid)).norm());
+            *worst = worst.max((dense.evaluate(step.t) - step.y).norm());
+        };
+        let y = radau.solve_with(oscillator, &exact(0.0), 0.0, 10.0, |s| check(&mut worst[0], s)).unwrap();
+        assert!((y - exact(10.0)).norm() < 1e-7);
+        let y = bdf.solve_with(oscillator, &exact(0.0), 0.0, 10.0, |s| check(&mut worst[1], s)).unwrap();
+        assert!((y - exact(10.0)).norm() < 1e-6);
+        assert!(worst[0] < 1e-7 && worst[1] < 1e-6, "{worst:?}");
+
+        // Backwards in time
+        let trajectory = Radau::new(tolerances).solve(oscillator, &exact(2.0), 2.0, -1.0).unwrap();
+        let (t, y) = trajectory.last().unwrap();
+        assert_eq!(t, -1.0);
+        assert!((*y - exact(-1.0)).norm() < 1e-7);
+    }
+}
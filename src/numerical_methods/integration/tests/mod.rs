@@ -0,0 +1,12 @@
+#[cfg(test)]
+mod solver;
+#[cfg(test)]
+mod adaptive;
+#[cfg(test)]
+mod events;
+#[cfg(test)]
+mod implicit;
+#[cfg(test)]
+mod geometric;
+#[cfg(test)]
+mod multistep;
@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod tests {
+    use crate::math::Vector;
+    use crate::numerical_methods::integration::*;
+    use approx::assert_relative_eq;
+
+    type Phase = (Vector<f64, 2>, Vector<f64, 2>);
+
+    fn oscillator(_t: f64, y: &Vector<f64, 2>) -> Vector<f64, 2> {
+        Vector::new([y[1], -y[0]])
+    }
+
+    fn kepler(_t: f64, x: &Phase) -> Phase {
+        (x.1, x.0 * (-1.0 / x.0.norm().powi(3)))
+    }
+
+    fn adams_error(order: usize, dt: f64) -> f64 {
+        let mut solver = AdamsBashforthMoulton::new(order);
+        let y = solver.solve_with(oscillator, &Vector::new([1.0, 0.0]), 0.0, 4.0, dt, |_, _| {});
+        (y - Vector::new([4.0_f64.cos(), -4.0_f64.sin()])).norm()
+    }
+
+    #[test]
+    fn test_adams_orders() {
+        let ratio = |order: usize, dt: f64| (adams_error(order, dt) / adams_error(order, dt / 2.0)).log2();
+        assert_relative_eq!(ratio(1, 0.01), 1.0, epsilon = 0.05);
+        assert_relative_eq!(ratio(2, 0.01), 2.0, epsilon = 0.05);
+        assert_relative_eq!(ratio(4, 0.02), 4.0, epsilon = 0.1);
+        assert_relative_eq!(ratio(6, 0.05), 6.0, epsilon = 0.2);
+    }
+
+    #[test]
+    fn test_adams_trajectory_and_restart() {
+        let mut solver = AdamsBashforthMoulton::new(8);
+        let trajectory = solver.solve(oscillator, &Vector::new([1.0, 0.0]), 0.0, 10.07, 0.05);
+        assert_eq!(trajectory.len(), 203);
+        let (t, y) = trajectory.last().unwrap();
+        assert_eq!(t, 10.07);
+        assert!((*y - Vector::new([10.07_f64.cos(), -10.07_f64.sin()])).norm() < 1e-9);
+
+        // A new solve restarts rather than reusing the stale history
+        let y = solver.solve_with(oscillator, &Vector::new([0.0, 1.0]), 0.0, 1.0, 0.05, |_, _| {});
+        assert!((y - Vector::new([1.0_f64.sin(), 1.0_f64.cos()])).norm() < 1e-10);
+    }
+
+    #[test]
+    fn test_adams_variable_order() {
+        let mut solver = AdamsBashforthMoulton::new(10);
+        solver.variable_order = true;
+        let y = solver.solve_with(oscillator, &Vector::new([1.0, 0.0]), 0.0, 10.0, 0.01, |_, _| {});
+        assert!((y - Vector::new([10.0_f64.cos(), -10.0_f64.sin()])).norm() < 1e-10);
+
+        // Steps too long for the highest orders push the order down
+        let mut solver = AdamsBashforthMoulton::new(10);
+        solver.variable_order = true;
+        let mut lowest = 10;
+        solver.solve_with(oscillator, &Vector::new([1.0, 0.0]), 0.0, 50.0, 0.5, |_, _| {});
+        lowest = lowest.min(solver.current_order());
+        assert!(lowest < 10);
+    }
+
+    fn kepler_error(order: usize, steps_per_orbit: usize, orbits: usize) -> f64 {
+        // Circular orbit, r = (cos t, sin t)
+        let dt = core::f64::consts::TAU / steps_per_orbit as f64;
+        let tf = core::f64::consts::TAU * orbits as f64;
+        let x0 = (Vector::new([1.0, 0.0]), Vector::new([0.0, 1.0]));
+        let (r, v) = GaussJackson::new(order).solve_with(kepler, &x0, 0.0, tf, dt, |_, _| {});
+        (r - Vector::new([tf.cos(), tf.sin()])).norm() + (v - Vector::new([-tf.sin(), tf.cos()])).norm()
+    }
+
+    #[test]
+    fn test_gauss_jackson_kepler() {
+        assert_relative_eq!((kepler_error(4, 100, 1) / kepler_error(4, 200, 1)).log2(), 4.0, epsilon = 0.2);
+        assert_relative_eq!((kepler_error(6, 50, 1) / kepler_error(6, 100, 1)).log2(), 6.0, epsilon = 0.3);
+        // GJ8 over fifty orbits
+        assert!(kepler_error(8, 200, 50) < 1e-8);
+    }
+
+    #[test]
+    fn test_gauss_jackson_velocity_dependent() {
+        // Damped oscillator r'' = -r - 0.2 r'
+        let damped = |_t: f64, x: &(Vector<f64, 1>, Vector<f64, 1>)| (x.1, -x.0 - x.1 * 0.2);
+        let x0 = (Vector::new([1.0]), Vector::new([0.0]));
+        let mut solver = GaussJackson::default();
+        let trajectory = solver.solve(damped, &x0, 0.0, 20.0, 0.05);
+        let omega = 0.99_f64.sqrt();
+        for (t, (r, v)) in trajectory.iter() {
+            let decay = (-0.1 * t).exp();
+            let exact_r = decay * ((omega * t).cos() + 0.1 / omega * (omega * t).sin());
+            let exact_v = -decay * (omega * t).sin() / omega;
+            assert_relative_eq!(r[0], exact_r, epsilon = 1e-10);
+            assert_relative_eq!(v[0], exact_v, epsilon = 1e-10);
+        }
+
+        // Agrees with the Adams solver on the same problem in first-order form
+        let first_order = |_t: f64, y: &Vector<f64, 2>| Vector::new([y[1], -y[0] - 0.2 * y[1]]);
+        let y = AdamsBashforthMoulton::new(8).solve_with(first_order, &Vector::new([1.0, 0.0]), 0.0, 20.0, 0.05, |_, _| {});
+        let (_, (r, v)) = trajectory.last().unwrap();
+        assert_relative_eq!(y[0], r[0], epsilon = 1e-9);
+        assert_relative_eq!(y[1], v[0], epsilon = 1e-9);
+    }
+}
@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod tests {
+    use crate::coordinate::Cartesian;
+    use crate::math::{Matrix, Vector};
+    use crate::numerical_methods::integration::*;
+    use crate::reference_frame::Body;
+    use approx::assert_relative_eq;
+
+    fn decay(_t: f64, x: &Vector<f64, 1>) -> Vector<f64, 1> {
+        -*x
+    }
+
+    fn global_error<S: OdeSolver<f64, Vector<f64, 1>>>(mut solver: S, dt: f64) -> f64 {
+        let x = solver.solve_with(decay, &Vector::new([1.0]), 0.0, 1.0, dt, |_, _| {});
+        (x[0] - (-1.0_f64).exp()).abs()
+    }
+
+    #[test]
+    fn test_convergence_orders() {
+        // Halving the step divides the error by 2^order
+        let ratio = |e1: f64, e2: f64| (e1 / e2).log2();
+        assert_relative_eq!(ratio(global_error(ExplicitEuler, 0.01), global_error(ExplicitEuler, 0.005)), 1.0, epsilon = 0.05);
+        assert_relative_eq!(ratio(global_error(Heun, 0.01), global_error(Heun, 0.005)), 2.0, epsilon = 0.05);
+        assert_relative_eq!(ratio(global_error(Midpoint, 0.01), global_error(Midpoint, 0.005)), 2.0, epsilon = 0.05);
+        assert_relative_eq!(ratio(global_error(Rk4, 0.1), global_error(Rk4, 0.05)), 4.0, epsilon = 0.1);
+    }
+
+    #[test]
+    fn test_trajectory_lands_on_final_time() {
+        let traj = Rk4.solve(decay, &Vector::new([1.0]), 0.0, 1.05, 0.1);
+        assert_eq!(traj.len(), 12);
+        let (t, x) = traj.last().unwrap();
+        assert_eq!(t, 1.05);
+        assert_relative_eq!(x[0], (-1.05_f64).exp(), epsilon = 1e-6);
+
+        // Backwards in time
+        let traj = Rk4.solve(decay, &Vector::new([1.0]), 1.0, 0.0, 0.1);
+        assert_eq!(traj.times.first(), Some(&1.0));
+        assert_relative_eq!(traj.last().unwrap().1[0], 1.0_f64.exp(), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_tuple_state_harmonic_oscillator() {
+        // (position, velocity) with x'' = -x
+        type Frame = Body<f64>;
+        let x0: (Cartesian<f64, Frame>, Cartesian<f64, Frame>) = (Cartesian::new(1.0, 0.0, 0.0), Cartesian::new(0.0, 1.0, 0.0));
+        let mut steps = 0;
+        let x = Rk4.solve_with(
+            |_t, s: &(Cartesian<f64, Frame>, Cartesian<f64, Frame>)| (s.1, -s.0),
+            &x0,
+            0.0,
+            core::f64::consts::PI,
+            0.01,
+            |_, _| steps += 1,
+        );
+        assert_eq!(steps, 316);
+        assert_relative_eq!(x.0.x(), -1.0, epsilon = 1e-8);
+        assert_relative_eq!(x.0.y(), 0.0, epsilon = 1e-8);
+        assert_eq!(x0.dimension(), 6);
+        assert_eq!(x0.component(4), 1.0);
+    }
+
+    #[test]
+    fn test_matrix_state() {
+        // Phi' = A Phi, Phi(0) = I for a nilpotent A gives Phi(t) = I + A t
+        let a = Matrix::new([[0.0, 1.0], [0.0, 0.0]]);
+        let phi = Heun.solve_with(|_t, p: &Matrix<f64, 2, 2>| a * *p, &Matrix::identity(), 0.0, 2.0, 0.5, |_, _| {});
+        assert_relative_eq!(phi[(0, 1)], 2.0, epsilon = 1e-12);
+        assert_eq!(phi.component(3), 1.0);
+    }
+}
@@ -1,6 +1,10 @@
+// The deprecated API keeps its implementations until it is removed.
+#![allow(deprecated)]
+
 use num_traits::Float;
 use crate::math::Vector;
 use crate::coordinate::Cartesian;
+#[deprecated(note = "use `Heun`, or `CrankNicolson` for stiff problems, through `OdeSolver::step`")]
 pub trait TrapezoidalIntegrate {
     type Scalar;
 
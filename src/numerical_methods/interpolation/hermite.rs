@@ -0,0 +1,57 @@
+use num_traits::Float;
+use std::vec::Vec;
+
+use super::{check_abscissae, interval, Extrapolation, Interpolate, InterpolationError};
+use crate::numerical_methods::integration::events::hermite;
+use crate::numerical_methods::integration::State;
+
+/// Piecewise cubic Hermite interpolation of values and their derivatives, such
+/// as positions and velocities from a propagator. The interpolant and its
+/// first derivative are continuous.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hermite<T, V> {
+    x: Vec<T>,
+    y: Vec<V>,
+    dy: Vec<V>,
+    pub extrapolation: Extrapolation,
+}
+
+impl<T: Float, V: State<T>> Hermite<T, V> {
+    /// Interpolates `y[i]` with slope `dy[i]` at `x[i]`; needs at least two
+    /// points.
+    pub fn new(x: Vec<T>, y: Vec<V>, dy: Vec<V>) -> Result<Self, InterpolationError<T>> {
+        check_abscissae(&x, 2)?;
+        if y.len() != x.len() || dy.len() != x.len() {
+            return Err(InterpolationError::LengthMismatch);
+        }
+        Ok(Self { x, y, dy, extrapolation: Extrapolation::default() })
+    }
+}
+
+impl<T: Float, V: State<T>> Interpolate<T, V> for Hermite<T, V> {
+    fn domain(&self) -> (T, T) {
+        (self.x[0], self.x[self.x.len() - 1])
+    }
+
+    fn extrapolation(&self) -> Extrapolation {
+        self.extrapolation
+    }
+
+    fn value_and_derivative(&self, x: T) -> (V, V) {
+        let i = interval(&self.x, x);
+        let h = self.x[i + 1] - self.x[i];
+        let theta = (x - self.x[i]) / h;
+        let (y0, f0, y1, f1) = (&self.y[i], &self.dy[i], &self.y[i + 1], &self.dy[i + 1]);
+        let value = hermite(y0, f0, y1, f1, h, theta);
+
+        // Derivatives of the Hermite basis with respect to theta
+        let (one, two, three) = (T::one(), T::from(2.0).unwrap(), T::from(3.0).unwrap());
+        let six = three + three;
+        let t2 = theta * theta;
+        let d00 = six * (t2 - theta);
+        let d10 = three * t2 - two * two * theta + one;
+        let d11 = three * t2 - two * theta;
+        let derivative = y1.scaled_add(-one, y0).scaled(-d00 / h).scaled_add(d10, f0).scaled_add(d11, f1);
+        (value, derivative)
+    }
+}
@@ -0,0 +1,65 @@
+use num_traits::Float;
+use std::vec::Vec;
+
+use super::{check_abscissae, interval, Extrapolation, Interpolate, InterpolationError};
+use crate::numerical_methods::integration::State;
+
+/// Lagrange interpolation through a moving window of `points` neighbouring
+/// samples, the usual scheme for tabulated ephemerides.
+///
+/// The window is centred on the interval holding the query and shifted inwards
+/// near the ends; the polynomial of degree `points - 1` is evaluated with
+/// Neville's algorithm.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lagrange<T, V> {
+    x: Vec<T>,
+    y: Vec<V>,
+    points: usize,
+    pub extrapolation: Extrapolation,
+}
+
+impl<T: Float, V: State<T>> Lagrange<T, V> {
+    /// Interpolates `y[i]` at `x[i]` with windows of `points` samples, at least
+    /// two and at most `x.len()`.
+    pub fn new(x: Vec<T>, y: Vec<V>, points: usize) -> Result<Self, InterpolationError<T>> {
+        check_abscissae(&x, points.max(2))?;
+        if y.len() != x.len() {
+            return Err(InterpolationError::LengthMismatch);
+        }
+        Ok(Self { x, y, points: points.max(2), extrapolation: Extrapolation::default() })
+    }
+
+    pub fn points(&self) -> usize {
+        self.points
+    }
+}
+
+impl<T: Float, V: State<T>> Interpolate<T, V> for Lagrange<T, V> {
+    fn domain(&self) -> (T, T) {
+        (self.x[0], self.x[self.x.len() - 1])
+    }
+
+    fn extrapolation(&self) -> Extrapolation {
+        self.extrapolation
+    }
+
+    fn value_and_derivative(&self, x: T) -> (V, V) {
+        let n = self.points;
+        let start = (interval(&self.x, x) + 1).saturating_sub(n / 2).min(self.x.len() - n);
+        let xs = &self.x[start..start + n];
+
+        // Neville's tableau with derivatives carried alongside
+        let mut p: Vec<V> = self.y[start..start + n].to_vec();
+        let mut d: Vec<V> = p.iter().map(|v| v.zero_like()).collect();
+        for m in 1..n {
+            for i in 0..n - m {
+                let (a, b) = (x - xs[i + m], xs[i] - x);
+                let scale = T::one() / (xs[i] - xs[i + m]);
+                let derivative = p[i].scaled_add(-T::one(), &p[i + 1]).scaled_add(a, &d[i]).scaled_add(b, &d[i + 1]);
+                d[i] = derivative.scaled(scale);
+                p[i] = p[i].scaled(a).scaled_add(b, &p[i + 1]).scaled(scale);
+            }
+        }
+        (p.swap_remove(0), d.swap_remove(0))
+    }
+}
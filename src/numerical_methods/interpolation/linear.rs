@@ -0,0 +1,40 @@
+use num_traits::Float;
+use std::vec::Vec;
+
+use super::{check_abscissae, interval, Extrapolation, Interpolate, InterpolationError};
+use crate::numerical_methods::integration::State;
+
+/// Piecewise-linear interpolation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Linear<T, V> {
+    x: Vec<T>,
+    y: Vec<V>,
+    pub extrapolation: Extrapolation,
+}
+
+impl<T: Float, V: State<T>> Linear<T, V> {
+    /// Interpolates `y[i]` at `x[i]`; needs at least two points.
+    pub fn new(x: Vec<T>, y: Vec<V>) -> Result<Self, InterpolationError<T>> {
+        check_abscissae(&x, 2)?;
+        if y.len() != x.len() {
+            return Err(InterpolationError::LengthMismatch);
+        }
+        Ok(Self { x, y, extrapolation: Extrapolation::default() })
+    }
+}
+
+impl<T: Float, V: State<T>> Interpolate<T, V> for Linear<T, V> {
+    fn domain(&self) -> (T, T) {
+        (self.x[0], self.x[self.x.len() - 1])
+    }
+
+    fn extrapolation(&self) -> Extrapolation {
+        self.extrapolation
+    }
+
+    fn value_and_derivative(&self, x: T) -> (V, V) {
+        let i = interval(&self.x, x);
+        let slope = self.y[i + 1].scaled_add(-T::one(), &self.y[i]).scaled(T::one() / (self.x[i + 1] - self.x[i]));
+        (self.y[i].scaled_add(x - self.x[i], &slope), slope)
+    }
+}
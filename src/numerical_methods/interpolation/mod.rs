@@ -0,0 +1,109 @@
+//! Interpolation of tabulated data.
+//!
+//! One-dimensional interpolants over strictly increasing abscissae implement
+//! [`Interpolate`]: piecewise [`Linear`], moving-window [`Lagrange`], cubic
+//! [`Hermite`] from values and derivatives, and [`CubicSpline`] with natural or
+//! clamped ends. Values may be any
+//! [`State`](crate::numerical_methods::integration::State), so a scalar, a
+//! `Vector<T, N>` such as an ephemeris position, or a tuple of them.
+//! [`Bilinear`] and [`Trilinear`] look up values on rectangular grids, e.g.
+//! aerodynamic coefficient tables.
+//!
+//! Queries outside the data follow an [`Extrapolation`] policy.
+
+use num_traits::Float;
+
+use crate::numerical_methods::integration::State;
+
+mod hermite;
+mod lagrange;
+mod linear;
+mod spline;
+mod table;
+pub use hermite::Hermite;
+pub use lagrange::Lagrange;
+pub use linear::Linear;
+pub use spline::{CubicSpline, SplineBoundary};
+pub use table::{Bilinear, Trilinear};
+
+/// What to return for a query outside the tabulated range.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Extrapolation {
+    /// The value at the nearest end of the range
+    Clamp,
+    /// Fail with [`InterpolationError::OutOfRange`]
+    #[default]
+    Error,
+    /// Continue along the tangent at the nearest end (the edge cell for tables)
+    Linear,
+}
+
+/// Why an interpolant could not be built or evaluated.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InterpolationError<T> {
+    /// Query outside the data with [`Extrapolation::Error`]
+    OutOfRange { x: T },
+    /// The method needs at least `needed` points
+    TooFewPoints { needed: usize },
+    /// Abscissa `index` is not greater than the one before it, or not finite
+    NotIncreasing { index: usize },
+    /// The value and abscissa tables differ in length
+    LengthMismatch,
+}
+
+/// A one-dimensional interpolant with an extrapolation policy.
+pub trait Interpolate<T: Float, V: State<T>> {
+    /// First and last abscissa.
+    fn domain(&self) -> (T, T);
+
+    fn extrapolation(&self) -> Extrapolation;
+
+    /// Value and first derivative at `x` within the domain.
+    fn value_and_derivative(&self, x: T) -> (V, V);
+
+    /// Value at `x`, extrapolated outside the domain.
+    fn evaluate(&self, x: T) -> Result<V, InterpolationError<T>> {
+        Ok(self.evaluate_with_derivative(x)?.0)
+    }
+
+    /// First derivative at `x`, extrapolated outside the domain.
+    fn derivative(&self, x: T) -> Result<V, InterpolationError<T>> {
+        Ok(self.evaluate_with_derivative(x)?.1)
+    }
+
+    /// Value and first derivative at `x`; a clamped value has zero derivative.
+    fn evaluate_with_derivative(&self, x: T) -> Result<(V, V), InterpolationError<T>> {
+        let (lower, upper) = self.domain();
+        if x >= lower && x <= upper {
+            return Ok(self.value_and_derivative(x));
+        }
+        let end = if x < lower { lower } else { upper };
+        let (value, slope) = self.value_and_derivative(end);
+        match self.extrapolation() {
+            Extrapolation::Clamp => Ok((value, slope.zero_like())),
+            Extrapolation::Error => Err(InterpolationError::OutOfRange { x }),
+            Extrapolation::Linear => Ok((value.scaled_add(x - end, &slope), slope)),
+        }
+    }
+}
+
+/// Checks that `x` has at least `needed` strictly increasing, finite entries.
+fn check_abscissae<T: Float>(x: &[T], needed: usize) -> Result<(), InterpolationError<T>> {
+    if x.len() < needed {
+        return Err(InterpolationError::TooFewPoints { needed });
+    }
+    if let Some(index) = (0..x.len()).find(|&i| !x[i].is_finite() || (i > 0 && x[i] <= x[i - 1])) {
+        return Err(InterpolationError::NotIncreasing { index });
+    }
+    Ok(())
+}
+
+/// Index `i` of the interval `[x[i], x[i + 1]]` holding `t`, the nearest one
+/// outside the data.
+fn interval<T: Float>(x: &[T], t: T) -> usize {
+    x.partition_point(|&xi| xi <= t).clamp(1, x.len() - 1) - 1
+}
+
+#[cfg(test)]
+#[path = "tests/mod.rs"]
+mod tests;
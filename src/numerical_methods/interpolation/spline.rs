@@ -0,0 +1,132 @@
+use num_traits::Float;
+use std::vec::Vec;
+
+use super::{check_abscissae, interval, Extrapolation, Interpolate, InterpolationError};
+use crate::numerical_methods::integration::State;
+
+/// End conditions of a cubic spline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SplineBoundary<V> {
+    /// Zero second derivative at both ends
+    Natural,
+    /// Prescribed first derivatives at the start and end
+    Clamped { start: V, end: V },
+}
+
+/// Cubic spline with continuous second derivative.
+///
+/// The second derivatives at the knots solve a tridiagonal system set up from
+/// the [`SplineBoundary`], once at construction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CubicSpline<T, V> {
+    x: Vec<T>,
+    y: Vec<V>,
+    /// Second derivative at each knot
+    curvature: Vec<V>,
+    pub extrapolation: Extrapolation,
+}
+
+impl<T: Float, V: State<T>> CubicSpline<T, V> {
+    /// Interpolates `y[i]` at `x[i]`; needs at least two points.
+    pub fn new(x: Vec<T>, y: Vec<V>, boundary: SplineBoundary<V>) -> Result<Self, InterpolationError<T>> {
+        check_abscissae(&x, 2)?;
+        if y.len() != x.len() {
+            return Err(InterpolationError::LengthMismatch);
+        }
+        let n = x.len();
+        let (two, six) = (T::from(2.0).unwrap(), T::from(6.0).unwrap());
+        let h: Vec<T> = x.windows(2).map(|w| w[1] - w[0]).collect();
+        let secant: Vec<V> = (0..n - 1).map(|i| y[i + 1].scaled_add(-T::one(), &y[i]).scaled(T::one() / h[i])).collect();
+        let zero = y[0].zero_like();
+
+        // Rows `lower * M[i-1] + diagonal * M[i] + upper * M[i+1] = rhs`
+        let mut lower = Vec::with_capacity(n);
+        let mut diagonal = Vec::with_capacity(n);
+        let mut upper = Vec::with_capacity(n);
+        let mut rhs = Vec::with_capacity(n);
+        match &boundary {
+            SplineBoundary::Natural => {
+                lower.push(T::zero());
+                diagonal.push(T::one());
+                upper.push(T::zero());
+                rhs.push(zero.clone());
+            }
+            SplineBoundary::Clamped { start, .. } => {
+                lower.push(T::zero());
+                diagonal.push(two * h[0]);
+                upper.push(h[0]);
+                rhs.push(secant[0].scaled_add(-T::one(), start).scaled(six));
+            }
+        }
+        for i in 1..n - 1 {
+            lower.push(h[i - 1]);
+            diagonal.push(two * (h[i - 1] + h[i]));
+            upper.push(h[i]);
+            rhs.push(secant[i].scaled_add(-T::one(), &secant[i - 1]).scaled(six));
+        }
+        match &boundary {
+            SplineBoundary::Natural => {
+                lower.push(T::zero());
+                diagonal.push(T::one());
+                rhs.push(zero);
+            }
+            SplineBoundary::Clamped { end, .. } => {
+                lower.push(h[n - 2]);
+                diagonal.push(two * h[n - 2]);
+                rhs.push(end.scaled_add(-T::one(), &secant[n - 2]).scaled(six));
+            }
+        }
+        upper.push(T::zero());
+
+        // Thomas algorithm; the system is diagonally dominant
+        for i in 1..n {
+            let factor = lower[i] / diagonal[i - 1];
+            diagonal[i] = diagonal[i] - factor * upper[i - 1];
+            rhs[i] = rhs[i].scaled_add(-factor, &rhs[i - 1]);
+        }
+        let mut curvature = rhs;
+        curvature[n - 1] = curvature[n - 1].scaled(T::one() / diagonal[n - 1]);
+        for i in (0..n - 1).rev() {
+            curvature[i] = curvature[i].scaled_add(-upper[i], &curvature[i + 1]).scaled(T::one() / diagonal[i]);
+        }
+        Ok(Self { x, y, curvature, extrapolation: Extrapolation::default() })
+    }
+
+    /// Second derivative at `x` within the domain.
+    pub fn second_derivative(&self, x: T) -> V {
+        let i = interval(&self.x, x);
+        let a = (self.x[i + 1] - x) / (self.x[i + 1] - self.x[i]);
+        self.curvature[i].scaled(a).scaled_add(T::one() - a, &self.curvature[i + 1])
+    }
+}
+
+impl<T: Float, V: State<T>> Interpolate<T, V> for CubicSpline<T, V> {
+    fn domain(&self) -> (T, T) {
+        (self.x[0], self.x[self.x.len() - 1])
+    }
+
+    fn extrapolation(&self) -> Extrapolation {
+        self.extrapolation
+    }
+
+    fn value_and_derivative(&self, x: T) -> (V, V) {
+        let i = interval(&self.x, x);
+        let h = self.x[i + 1] - self.x[i];
+        let a = (self.x[i + 1] - x) / h;
+        let b = T::one() - a;
+        let (one, three, six) = (T::one(), T::from(3.0).unwrap(), T::from(6.0).unwrap());
+        let (m0, m1) = (&self.curvature[i], &self.curvature[i + 1]);
+
+        let value = self.y[i]
+            .scaled(a)
+            .scaled_add(b, &self.y[i + 1])
+            .scaled_add((a * a * a - a) * h * h / six, m0)
+            .scaled_add((b * b * b - b) * h * h / six, m1);
+        let derivative = self.y[i + 1]
+            .scaled_add(-one, &self.y[i])
+            .scaled(one / h)
+            .scaled_add(-(three * a * a - one) * h / six, m0)
+            .scaled_add((three * b * b - one) * h / six, m1);
+        (value, derivative)
+    }
+}
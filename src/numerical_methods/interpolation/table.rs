@@ -0,0 +1,93 @@
+use num_traits::Float;
+use std::vec::Vec;
+
+use super::{check_abscissae, interval, Extrapolation, InterpolationError};
+use crate::numerical_methods::integration::State;
+
+/// Cell `i` of `axis` holding `t` and the fraction of the way across it,
+/// outside `[0, 1]` only for linear extrapolation.
+fn cell<T: Float>(axis: &[T], t: T, extrapolation: Extrapolation) -> Result<(usize, T), InterpolationError<T>> {
+    let i = interval(axis, t);
+    let fraction = (t - axis[i]) / (axis[i + 1] - axis[i]);
+    if t >= axis[0] && t <= axis[axis.len() - 1] {
+        return Ok((i, fraction));
+    }
+    match extrapolation {
+        Extrapolation::Clamp => Ok((i, fraction.max(T::zero()).min(T::one()))),
+        Extrapolation::Error => Err(InterpolationError::OutOfRange { x: t }),
+        Extrapolation::Linear => Ok((i, fraction)),
+    }
+}
+
+/// `(1 - t) a + t b`
+fn lerp<T: Float, V: State<T>>(a: &V, b: &V, t: T) -> V {
+    a.scaled(T::one() - t).scaled_add(t, b)
+}
+
+/// Bilinear interpolation on a rectangular grid.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bilinear<T, V> {
+    x: Vec<T>,
+    y: Vec<T>,
+    values: Vec<V>,
+    /// Applied to each axis independently
+    pub extrapolation: Extrapolation,
+}
+
+impl<T: Float, V: State<T>> Bilinear<T, V> {
+    /// Grid of `values[i * y.len() + j]` at `(x[i], y[j])`; each axis needs at
+    /// least two points.
+    pub fn new(x: Vec<T>, y: Vec<T>, values: Vec<V>) -> Result<Self, InterpolationError<T>> {
+        check_abscissae(&x, 2)?;
+        check_abscissae(&y, 2)?;
+        if values.len() != x.len() * y.len() {
+            return Err(InterpolationError::LengthMismatch);
+        }
+        Ok(Self { x, y, values, extrapolation: Extrapolation::default() })
+    }
+
+    pub fn evaluate(&self, x: T, y: T) -> Result<V, InterpolationError<T>> {
+        let (i, u) = cell(&self.x, x, self.extrapolation)?;
+        let (j, v) = cell(&self.y, y, self.extrapolation)?;
+        let at = |i: usize, j: usize| &self.values[i * self.y.len() + j];
+        let lower = lerp(at(i, j), at(i, j + 1), v);
+        let upper = lerp(at(i + 1, j), at(i + 1, j + 1), v);
+        Ok(lerp(&lower, &upper, u))
+    }
+}
+
+/// Trilinear interpolation on a rectangular grid.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trilinear<T, V> {
+    x: Vec<T>,
+    y: Vec<T>,
+    z: Vec<T>,
+    values: Vec<V>,
+    /// Applied to each axis independently
+    pub extrapolation: Extrapolation,
+}
+
+impl<T: Float, V: State<T>> Trilinear<T, V> {
+    /// Grid of `values[(i * y.len() + j) * z.len() + k]` at `(x[i], y[j], z[k])`;
+    /// each axis needs at least two points.
+    pub fn new(x: Vec<T>, y: Vec<T>, z: Vec<T>, values: Vec<V>) -> Result<Self, InterpolationError<T>> {
+        check_abscissae(&x, 2)?;
+        check_abscissae(&y, 2)?;
+        check_abscissae(&z, 2)?;
+        if values.len() != x.len() * y.len() * z.len() {
+            return Err(InterpolationError::LengthMismatch);
+        }
+        Ok(Self { x, y, z, values, extrapolation: Extrapolation::default() })
+    }
+
+    pub fn evaluate(&self, x: T, y: T, z: T) -> Result<V, InterpolationError<T>> {
+        let (i, u) = cell(&self.x, x, self.extrapolation)?;
+        let (j, v) = cell(&self.y, y, self.extrapolation)?;
+        let (k, w) = cell(&self.z, z, self.extrapolation)?;
+        let at = |i: usize, j: usize, k: usize| &self.values[(i * self.y.len() + j) * self.z.len() + k];
+        let along_z = |i: usize, j: usize| lerp(at(i, j, k), at(i, j, k + 1), w);
+        let lower = lerp(&along_z(i, j), &along_z(i, j + 1), v);
+        let upper = lerp(&along_z(i + 1, j), &along_z(i + 1, j + 1), v);
+        Ok(lerp(&lower, &upper, u))
+    }
+}
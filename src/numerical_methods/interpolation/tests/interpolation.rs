@@ -0,0 +1,133 @@
+#[cfg(test)]
+mod tests {
+    use crate::math::Vector;
+    use crate::numerical_methods::interpolation::*;
+    use approx::assert_relative_eq;
+
+    fn grid(a: f64, b: f64, n: usize) -> Vec<f64> {
+        (0..n).map(|i| a + (b - a) * i as f64 / (n - 1) as f64).collect()
+    }
+
+    #[test]
+    fn test_linear_and_extrapolation() {
+        let mut line = Linear::new(vec![0.0, 1.0, 3.0], vec![1.0, 3.0, 2.0]).unwrap();
+        assert_relative_eq!(line.evaluate(0.5).unwrap(), 2.0);
+        assert_relative_eq!(line.evaluate(2.0).unwrap(), 2.5);
+        assert_relative_eq!(line.derivative(2.0).unwrap(), -0.5);
+        assert_eq!(line.evaluate(3.0).unwrap(), 2.0);
+
+        assert_eq!(line.evaluate(4.0), Err(InterpolationError::OutOfRange { x: 4.0 }));
+        line.extrapolation = Extrapolation::Clamp;
+        assert_eq!(line.evaluate_with_derivative(-1.0).unwrap(), (1.0, 0.0));
+        line.extrapolation = Extrapolation::Linear;
+        assert_relative_eq!(line.evaluate(-1.0).unwrap(), -1.0);
+        assert_relative_eq!(line.evaluate(5.0).unwrap(), 1.0);
+
+        assert_eq!(Linear::new(vec![0.0, 1.0, 1.0], vec![0.0; 3]), Err(InterpolationError::NotIncreasing { index: 2 }));
+        assert_eq!(Linear::new(vec![0.0], vec![0.0]), Err(InterpolationError::TooFewPoints { needed: 2 }));
+        assert_eq!(Linear::new(vec![0.0, 1.0], vec![0.0]), Err(InterpolationError::LengthMismatch));
+    }
+
+    #[test]
+    fn test_lagrange_and_hermite() {
+        // A window of 4 points reproduces cubics anywhere, including near the ends
+        let x = grid(-1.0, 2.0, 9);
+        let cubic = |t: f64| 2.0 * t * t * t - t + 0.5;
+        let lagrange = Lagrange::new(x.clone(), x.iter().map(|&t| cubic(t)).collect(), 4).unwrap();
+        for t in [-1.0, -0.9, 0.3, 1.17, 1.99, 2.0] {
+            let (value, slope) = lagrange.evaluate_with_derivative(t).unwrap();
+            assert_relative_eq!(value, cubic(t), epsilon = 1e-12);
+            assert_relative_eq!(slope, 6.0 * t * t - 1.0, epsilon = 1e-11);
+        }
+        assert!(Lagrange::new(x.clone(), vec![0.0; 9], 10).is_err());
+
+        // Ephemeris-style: position on a circle from samples of position and velocity
+        let x = grid(0.0, 6.0, 13);
+        let position = |t: f64| Vector::new([t.cos(), t.sin(), 0.1 * t]);
+        let velocity = |t: f64| Vector::new([-t.sin(), t.cos(), 0.1]);
+        let hermite = Hermite::new(x.clone(), x.iter().map(|&t| position(t)).collect(), x.iter().map(|&t| velocity(t)).collect()).unwrap();
+        let lagrange = Lagrange::new(x.clone(), x.iter().map(|&t| position(t)).collect(), 8).unwrap();
+        for t in [0.1, 2.22, 4.9] {
+            let (p, v) = hermite.evaluate_with_derivative(t).unwrap();
+            let q = lagrange.evaluate(t).unwrap();
+            for i in 0..3 {
+                assert_relative_eq!(p[i], position(t)[i], epsilon = 2e-4);
+                assert_relative_eq!(v[i], velocity(t)[i], epsilon = 2e-3);
+                assert_relative_eq!(q[i], position(t)[i], epsilon = 1e-4);
+            }
+        }
+        // Hermite matches the samples and slopes at the knots
+        assert_eq!(hermite.evaluate(x[4]).unwrap(), position(x[4]));
+        assert_relative_eq!(hermite.derivative(x[4]).unwrap()[0], velocity(x[4])[0], epsilon = 1e-14);
+    }
+
+    #[test]
+    fn test_cubic_spline() {
+        // A natural spline through a line is that line
+        let x = vec![0.0, 0.5, 2.0, 3.0];
+        let spline = CubicSpline::new(x.clone(), x.iter().map(|t| 3.0 * t - 1.0).collect(), SplineBoundary::Natural).unwrap();
+        assert_relative_eq!(spline.evaluate(1.3).unwrap(), 2.9, epsilon = 1e-14);
+        assert_relative_eq!(spline.second_derivative(0.0), 0.0);
+
+        // Clamped with exact end slopes reproduces cubics; fourth order on sin
+        let x = vec![0.0, 0.4, 1.0, 1.1, 2.0];
+        let cubic = |t: f64| t * t * t - 2.0 * t;
+        let boundary = SplineBoundary::Clamped { start: -2.0, end: 10.0 };
+        let spline = CubicSpline::new(x.clone(), x.iter().map(|&t| cubic(t)).collect(), boundary).unwrap();
+        for t in [0.1, 0.7, 1.05, 1.6] {
+            assert_relative_eq!(spline.evaluate(t).unwrap(), cubic(t), epsilon = 1e-12);
+            assert_relative_eq!(spline.derivative(t).unwrap(), 3.0 * t * t - 2.0, epsilon = 1e-12);
+            assert_relative_eq!(spline.second_derivative(t), 6.0 * t, epsilon = 1e-12);
+        }
+
+        let error = |n: usize| {
+            let x = grid(0.0, 3.0, n);
+            let boundary = SplineBoundary::Clamped { start: 1.0, end: 3.0_f64.cos() };
+            let spline = CubicSpline::new(x.clone(), x.iter().map(|t| t.sin()).collect(), boundary).unwrap();
+            grid(0.0, 3.0, 301).iter().fold(0.0_f64, |m, &t| m.max((spline.evaluate(t).unwrap() - t.sin()).abs()))
+        };
+        assert_relative_eq!((error(11) / error(21)).log2(), 4.0, epsilon = 0.3);
+
+        // Vector values with linear extrapolation from the end tangent
+        let x = grid(0.0, 1.0, 5);
+        let mut spline = CubicSpline::new(x.clone(), x.iter().map(|&t| Vector::new([t, t * t])).collect(), SplineBoundary::Natural).unwrap();
+        spline.extrapolation = Extrapolation::Linear;
+        let (end, slope) = spline.evaluate_with_derivative(1.0).unwrap();
+        let beyond = spline.evaluate(1.5).unwrap();
+        assert_relative_eq!(beyond[1], end[1] + 0.5 * slope[1], epsilon = 1e-14);
+        assert_relative_eq!(beyond[0], 1.5, epsilon = 1e-14);
+    }
+
+    #[test]
+    fn test_tables() {
+        // Bilinear reproduces f = 1 + 2x - y + 0.5xy exactly
+        let f = |x: f64, y: f64| 1.0 + 2.0 * x - y + 0.5 * x * y;
+        let (xs, ys) = (vec![0.0, 1.0, 4.0], vec![-1.0, 0.0, 2.0, 3.0]);
+        let values = xs.iter().flat_map(|&x| ys.iter().map(move |&y| f(x, y))).collect();
+        let mut table = Bilinear::new(xs.clone(), ys.clone(), values).unwrap();
+        assert_relative_eq!(table.evaluate(2.5, 1.2).unwrap(), f(2.5, 1.2), epsilon = 1e-14);
+        assert_relative_eq!(table.evaluate(4.0, -1.0).unwrap(), f(4.0, -1.0), epsilon = 1e-14);
+        assert!(matches!(table.evaluate(5.0, 0.0), Err(InterpolationError::OutOfRange { x }) if x == 5.0));
+        table.extrapolation = Extrapolation::Clamp;
+        assert_relative_eq!(table.evaluate(5.0, 4.0).unwrap(), f(4.0, 3.0), epsilon = 1e-14);
+        table.extrapolation = Extrapolation::Linear;
+        assert_relative_eq!(table.evaluate(5.0, 4.0).unwrap(), f(5.0, 4.0), epsilon = 1e-13);
+        assert!(Bilinear::new(xs, ys, vec![0.0; 11]).is_err());
+
+        // Trilinear with vector values of a trilinear function
+        let g = |x: f64, y: f64, z: f64| Vector::new([x * y * z, x - y + 2.0 * z]);
+        let (xs, ys, zs) = (grid(0.0, 1.0, 3), grid(0.0, 2.0, 4), grid(-1.0, 1.0, 2));
+        let mut values = Vec::new();
+        for &x in &xs {
+            for &y in &ys {
+                for &z in &zs {
+                    values.push(g(x, y, z));
+                }
+            }
+        }
+        let table = Trilinear::new(xs, ys, zs, values).unwrap();
+        let v = table.evaluate(0.3, 1.7, 0.25).unwrap();
+        assert_relative_eq!(v[0], g(0.3, 1.7, 0.25)[0], epsilon = 1e-14);
+        assert_relative_eq!(v[1], g(0.3, 1.7, 0.25)[1], epsilon = 1e-14);
+    }
+}
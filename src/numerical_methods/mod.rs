@@ -1 +1,7 @@
-pub mod integration;
\ No newline at end of file
+pub mod differentiation;
+pub mod integration;
+#[cfg(feature = "std")]
+pub mod interpolation;
+pub mod optimize;
+pub mod quadrature;
+pub mod roots;
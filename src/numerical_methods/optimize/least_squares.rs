@@ -0,0 +1,157 @@
+//! Nonlinear least squares `min |r(x)|² / 2`.
+
+use num_traits::Float;
+
+use super::{Criteria, Minimum, Status};
+use crate::math::{Matrix, Vector};
+
+/// Cost `|r|² / 2` and gradient `Jᵀr`.
+fn cost<T: Float + Default, const M: usize, const N: usize>(r: &Vector<T, M>, j: &Matrix<T, M, N>) -> (T, Vector<T, N>) {
+    (r.dot(r) / T::from(2.0).unwrap(), j.transpose() * *r)
+}
+
+/// Gauss-Newton for residuals `r(x)` with Jacobian `jacobian(x) = dr/dx`.
+///
+/// Each step solves the normal equations `JᵀJ δ = -Jᵀr` and is halved until
+/// the cost decreases sufficiently. Converges fast on small-residual problems;
+/// stops with [`Status::Singular`] if `JᵀJ` is singular, where
+/// [`levenberg_marquardt`] still makes progress.
+pub fn gauss_newton<T, R, J, const M: usize, const N: usize>(
+    mut residual: R,
+    mut jacobian: J,
+    x0: Vector<T, N>,
+    criteria: &Criteria<T>,
+) -> Minimum<T, N>
+where
+    T: Float + Default,
+    R: FnMut(&Vector<T, N>) -> Vector<T, M>,
+    J: FnMut(&Vector<T, N>) -> Matrix<T, M, N>,
+{
+    let sufficient = T::from(1e-4).unwrap();
+    let mut j = jacobian(&x0);
+    let (value, gradient) = cost(&residual(&x0), &j);
+    let mut minimum = Minimum { x: x0, value, gradient, iterations: 0, evaluations: 1, status: Status::MaxIterations };
+    while minimum.iterations < criteria.max_iterations {
+        if criteria.gradient_met(&minimum.gradient) {
+            minimum.status = Status::GradientConverged;
+            return minimum;
+        }
+        let Some(lu) = (j.transpose() * j).lu() else {
+            minimum.status = Status::Singular;
+            return minimum;
+        };
+        let step = lu.solve(&-minimum.gradient);
+        let slope = minimum.gradient.dot(&step);
+
+        let mut alpha = T::one();
+        let accepted = loop {
+            let trial = minimum.x + step * alpha;
+            let r = residual(&trial);
+            minimum.evaluations += 1;
+            let trial_value = r.dot(&r) / T::from(2.0).unwrap();
+            if trial_value.is_finite() && trial_value <= minimum.value + sufficient * alpha * slope {
+                break Some((trial, r));
+            }
+            alpha = alpha * T::from(0.5).unwrap();
+            if alpha < T::epsilon() {
+                break None;
+            }
+        };
+        let Some((x, r)) = accepted else {
+            minimum.status = Status::LineSearchFailed;
+            return minimum;
+        };
+
+        j = jacobian(&x);
+        let (value, gradient) = cost(&r, &j);
+        let previous = minimum.value;
+        let taken = x - minimum.x;
+        minimum = Minimum { x, value, gradient, iterations: minimum.iterations + 1, ..minimum };
+        if criteria.step_met(&taken, &minimum.x) {
+            minimum.status = Status::StepConverged;
+            return minimum;
+        }
+        if criteria.value_met(previous, value) {
+            minimum.status = Status::ValueConverged;
+            return minimum;
+        }
+    }
+    if criteria.gradient_met(&minimum.gradient) {
+        minimum.status = Status::GradientConverged;
+    }
+    minimum
+}
+
+/// Levenberg-Marquardt for residuals `r(x)` with Jacobian
+/// `jacobian(x) = dr/dx`.
+///
+/// Solves `(JᵀJ + λI) δ = -Jᵀr`, adapting the damping `λ` from the ratio of
+/// actual to predicted cost decrease (Nielsen's update, Madsen, Nielsen &
+/// Tingleff, "Methods for Non-Linear Least Squares Problems", 3.2). Rejected
+/// trial steps count as iterations.
+pub fn levenberg_marquardt<T, R, J, const M: usize, const N: usize>(
+    mut residual: R,
+    mut jacobian: J,
+    x0: Vector<T, N>,
+    criteria: &Criteria<T>,
+) -> Minimum<T, N>
+where
+    T: Float + Default,
+    R: FnMut(&Vector<T, N>) -> Vector<T, M>,
+    J: FnMut(&Vector<T, N>) -> Matrix<T, M, N>,
+{
+    let (two, three) = (T::from(2.0).unwrap(), T::from(3.0).unwrap());
+    let mut j = jacobian(&x0);
+    let (value, gradient) = cost(&residual(&x0), &j);
+    let mut minimum = Minimum { x: x0, value, gradient, iterations: 0, evaluations: 1, status: Status::MaxIterations };
+    let mut normal = j.transpose() * j;
+    let largest_diagonal = (0..N).fold(T::zero(), |m, i| m.max(normal[(i, i)]));
+    let mut damping = T::from(1e-3).unwrap() * largest_diagonal.max(T::epsilon());
+    let mut growth = two;
+
+    while minimum.iterations < criteria.max_iterations {
+        if criteria.gradient_met(&minimum.gradient) {
+            minimum.status = Status::GradientConverged;
+            return minimum;
+        }
+        minimum.iterations += 1;
+        let Some(lu) = (normal + Matrix::identity() * damping).lu() else {
+            damping = damping * growth;
+            growth = growth * two;
+            continue;
+        };
+        let step = lu.solve(&-minimum.gradient);
+        if criteria.step_met(&step, &minimum.x) {
+            minimum.status = Status::StepConverged;
+            return minimum;
+        }
+
+        let x = minimum.x + step;
+        let r = residual(&x);
+        minimum.evaluations += 1;
+        let trial_value = r.dot(&r) / two;
+        let predicted = step.dot(&(step * damping - minimum.gradient)) / two;
+        let ratio = (minimum.value - trial_value) / predicted;
+        if trial_value.is_finite() && ratio > T::zero() {
+            j = jacobian(&x);
+            normal = j.transpose() * j;
+            let previous = minimum.value;
+            let (value, gradient) = cost(&r, &j);
+            minimum = Minimum { x, value, gradient, ..minimum };
+            let shrink = T::one() - (two * ratio - T::one()).powi(3);
+            damping = damping * shrink.max(T::one() / three);
+            growth = two;
+            if criteria.value_met(previous, value) {
+                minimum.status = Status::ValueConverged;
+                return minimum;
+            }
+        } else {
+            damping = damping * growth;
+            growth = growth * two;
+        }
+    }
+    if criteria.gradient_met(&minimum.gradient) {
+        minimum.status = Status::GradientConverged;
+    }
+    minimum
+}
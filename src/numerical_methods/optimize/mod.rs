@@ -0,0 +1,170 @@
+//! Minimization of functions over `Vector<T, N>`.
+//!
+//! - [`nelder_mead`]: derivative-free simplex search;
+//! - [`bfgs`] and [`lbfgs`]: quasi-Newton methods for smooth objectives, given
+//!   the value and gradient;
+//! - [`gauss_newton`] and [`levenberg_marquardt`]: nonlinear least squares
+//!   `min |r(x)|² / 2` for residuals `r: Vector<T, N> -> Vector<T, M>`;
+//! - [`projected_gradient`]: smooth objectives within [`Bounds`].
+//!
+//! Gradients can come from [`differentiation`](super::differentiation). Every
+//! method stops under the same [`Criteria`] and returns a [`Minimum`] whose
+//! [`Status`] says why it stopped; running out of iterations is a status, not
+//! an error, so the best point found is always available.
+
+use num_traits::Float;
+
+use crate::math::{Matrix, Vector};
+
+mod least_squares;
+mod nelder_mead;
+mod projected;
+mod quasi_newton;
+pub use least_squares::{gauss_newton, levenberg_marquardt};
+pub use nelder_mead::nelder_mead;
+pub use projected::projected_gradient;
+pub use quasi_newton::{bfgs, lbfgs};
+
+/// Stopping criteria shared by the minimizers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Criteria<T> {
+    /// Stop once the largest gradient component is at most this
+    pub gradient: T,
+    /// Stop once a step is below `step * (1 + |x|)`
+    pub step: T,
+    /// Stop once the objective changes by less than `value * (1 + |f|)`
+    pub value: T,
+    pub max_iterations: usize,
+}
+
+impl<T: Float> Default for Criteria<T> {
+    fn default() -> Self {
+        let epsilon = T::epsilon();
+        Self { gradient: epsilon.sqrt(), step: epsilon, value: epsilon, max_iterations: 1000 }
+    }
+}
+
+impl<T: Float> Criteria<T> {
+    fn gradient_met<const N: usize>(&self, gradient: &Vector<T, N>) -> bool {
+        max_abs(gradient) <= self.gradient
+    }
+
+    fn step_met<const N: usize>(&self, step: &Vector<T, N>, x: &Vector<T, N>) -> bool {
+        step.norm() <= self.step * (T::one() + x.norm())
+    }
+
+    fn value_met(&self, previous: T, value: T) -> bool {
+        (previous - value).abs() <= self.value * (T::one() + value.abs())
+    }
+}
+
+/// Why a minimizer stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    GradientConverged,
+    StepConverged,
+    ValueConverged,
+    MaxIterations,
+    /// No step along the search direction decreased the objective
+    LineSearchFailed,
+    /// The normal equations of a least-squares step could not be solved
+    Singular,
+}
+
+impl Status {
+    /// True if one of the [`Criteria`] was met.
+    pub fn converged(self) -> bool {
+        matches!(self, Status::GradientConverged | Status::StepConverged | Status::ValueConverged)
+    }
+}
+
+/// The outcome of a minimization.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Minimum<T, const N: usize> {
+    pub x: Vector<T, N>,
+    pub value: T,
+    /// Gradient at `x`, the full (unprojected) gradient for bounded problems
+    pub gradient: Vector<T, N>,
+    pub iterations: usize,
+    /// Objective (or residual) evaluations; gradients and Jacobians computed
+    /// alongside are not counted separately
+    pub evaluations: usize,
+    pub status: Status,
+}
+
+/// Box constraints `lower <= x <= upper`, componentwise. Infinite bounds leave
+/// a component free.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bounds<T, const N: usize> {
+    pub lower: Vector<T, N>,
+    pub upper: Vector<T, N>,
+}
+
+impl<T: Float, const N: usize> Bounds<T, N> {
+    pub fn new(lower: Vector<T, N>, upper: Vector<T, N>) -> Self {
+        Self { lower, upper }
+    }
+
+    /// The nearest point of the box.
+    pub fn project(&self, x: &Vector<T, N>) -> Vector<T, N> {
+        Vector::new(core::array::from_fn(|i| x[i].max(self.lower[i]).min(self.upper[i])))
+    }
+
+    pub fn contains(&self, x: &Vector<T, N>) -> bool {
+        (0..N).all(|i| x[i] >= self.lower[i] && x[i] <= self.upper[i])
+    }
+}
+
+fn max_abs<T: Float, const N: usize>(v: &Vector<T, N>) -> T {
+    v.data.iter().fold(T::zero(), |m, x| m.max(x.abs()))
+}
+
+/// `a bᵀ`
+fn outer<T: Float + Default, const N: usize>(a: &Vector<T, N>, b: &Vector<T, N>) -> Matrix<T, N, N> {
+    Matrix::new(core::array::from_fn(|i| core::array::from_fn(|j| a[i] * b[j])))
+}
+
+/// An accepted line-search step.
+struct Step<T, const N: usize> {
+    x: Vector<T, N>,
+    value: T,
+    gradient: Vector<T, N>,
+    evaluations: usize,
+}
+
+/// Backtracking line search from `x` along `direction` with the Armijo
+/// condition `f(x + αd) <= f(x) + c α gᵀd`, halving `α` from `alpha` up to 50
+/// times. `None` if `direction` is not a descent direction or no step is
+/// accepted.
+fn backtrack<T, F, const N: usize>(
+    fg: &mut F,
+    x: &Vector<T, N>,
+    value: T,
+    gradient: &Vector<T, N>,
+    direction: &Vector<T, N>,
+    alpha: T,
+) -> Option<Step<T, N>>
+where
+    T: Float,
+    F: FnMut(&Vector<T, N>) -> (T, Vector<T, N>),
+{
+    let slope = gradient.dot(direction);
+    if slope >= T::zero() || !slope.is_finite() {
+        return None;
+    }
+    let sufficient = T::from(1e-4).unwrap();
+    let mut alpha = alpha;
+    for evaluations in 1..=50 {
+        let trial = *x + *direction * alpha;
+        let (trial_value, trial_gradient) = fg(&trial);
+        if trial_value.is_finite() && trial_value <= value + sufficient * alpha * slope {
+            return Some(Step { x: trial, value: trial_value, gradient: trial_gradient, evaluations });
+        }
+        alpha = alpha * T::from(0.5).unwrap();
+    }
+    None
+}
+
+#[cfg(test)]
+#[path = "tests/mod.rs"]
+mod tests;
@@ -0,0 +1,125 @@
+//! Derivative-free simplex search.
+
+use num_traits::Float;
+
+use super::{Criteria, Minimum, Status};
+use crate::math::Vector;
+use crate::numerical_methods::differentiation::{gradient, Difference};
+
+/// The `N + 1` vertices of a simplex with their objective values; vertex 0 is
+/// kept apart since `[_; N + 1]` is not expressible.
+struct Simplex<T, const N: usize> {
+    first: (Vector<T, N>, T),
+    rest: [(Vector<T, N>, T); N],
+}
+
+impl<T: Float, const N: usize> Simplex<T, N> {
+    fn get(&self, k: usize) -> &(Vector<T, N>, T) {
+        if k == 0 { &self.first } else { &self.rest[k - 1] }
+    }
+
+    fn get_mut(&mut self, k: usize) -> &mut (Vector<T, N>, T) {
+        if k == 0 { &mut self.first } else { &mut self.rest[k - 1] }
+    }
+
+    /// Indices of the best, second-worst and worst vertices.
+    fn order(&self) -> (usize, usize, usize) {
+        let value = |k: usize| self.get(k).1;
+        let (mut best, mut worst) = (0, 0);
+        for k in 1..=N {
+            if value(k) < value(best) {
+                best = k;
+            }
+            if value(k) >= value(worst) {
+                worst = k;
+            }
+        }
+        let second = (0..=N).filter(|&k| k != worst).fold(best, |s, k| if value(k) > value(s) { k } else { s });
+        (best, second, worst)
+    }
+}
+
+/// Nelder-Mead simplex minimization with the dimension-adaptive coefficients of
+/// Gao & Han (Comput. Optim. Appl. 51, 2012).
+///
+/// The initial simplex is `x0` and `x0 + step e_i`. The search stops when the
+/// values across the simplex agree within `criteria.value` or its vertices
+/// within `criteria.step`; the returned gradient is a central finite difference
+/// at the best vertex, `2N` extra evaluations.
+pub fn nelder_mead<T, F, const N: usize>(mut f: F, x0: Vector<T, N>, step: T, criteria: &Criteria<T>) -> Minimum<T, N>
+where
+    T: Float + Default,
+    F: FnMut(&Vector<T, N>) -> T,
+{
+    let n = T::from(N.max(1)).unwrap();
+    let (one, two, half) = (T::one(), T::from(2.0).unwrap(), T::from(0.5).unwrap());
+    let reflection = one;
+    let expansion = one + two / n;
+    let contraction = T::from(0.75).unwrap() - half / n;
+    let shrink = one - one / n;
+
+    let mut evaluations = N + 1;
+    let mut simplex = Simplex {
+        first: (x0, f(&x0)),
+        rest: core::array::from_fn(|i| {
+            let mut vertex = x0;
+            vertex[i] = vertex[i] + step;
+            (vertex, f(&vertex))
+        }),
+    };
+
+    let mut iterations = 0;
+    let status = loop {
+        let (best, second, worst) = simplex.order();
+        let (x_best, f_best) = *simplex.get(best);
+        let f_worst = simplex.get(worst).1;
+        let diameter = (0..=N).fold(T::zero(), |m, k| m.max((simplex.get(k).0 - x_best).norm()));
+        if criteria.value_met(f_worst, f_best) {
+            break Status::ValueConverged;
+        }
+        if diameter <= criteria.step * (one + x_best.norm()) {
+            break Status::StepConverged;
+        }
+        if iterations == criteria.max_iterations {
+            break Status::MaxIterations;
+        }
+        iterations += 1;
+
+        let x_worst = simplex.get(worst).0;
+        let sum = (1..=N).fold(simplex.get(0).0, |s, k| s + simplex.get(k).0);
+        let centroid = (sum - x_worst) / n;
+        let mut evaluate = |x: Vector<T, N>| {
+            evaluations += 1;
+            (x, f(&x))
+        };
+
+        let reflected = evaluate(centroid + (centroid - x_worst) * reflection);
+        let accepted = if reflected.1 < f_best {
+            let expanded = evaluate(centroid + (reflected.0 - centroid) * expansion);
+            Some(if expanded.1 < reflected.1 { expanded } else { reflected })
+        } else if reflected.1 < simplex.get(second).1 {
+            Some(reflected)
+        } else if reflected.1 < f_worst {
+            let outside = evaluate(centroid + (reflected.0 - centroid) * contraction);
+            (outside.1 <= reflected.1).then_some(outside)
+        } else {
+            let inside = evaluate(centroid + (x_worst - centroid) * contraction);
+            (inside.1 < f_worst).then_some(inside)
+        };
+
+        match accepted {
+            Some(vertex) => *simplex.get_mut(worst) = vertex,
+            None => {
+                for k in (0..=N).filter(|&k| k != best) {
+                    let x = x_best + (simplex.get(k).0 - x_best) * shrink;
+                    *simplex.get_mut(k) = evaluate(x);
+                }
+            }
+        }
+    };
+
+    let (best, _, _) = simplex.order();
+    let (x, value) = *simplex.get(best);
+    let gradient = gradient(&mut f, &x, Difference::Central);
+    Minimum { x, value, gradient, iterations, evaluations: evaluations + 2 * N, status }
+}
@@ -0,0 +1,79 @@
+//! Box-constrained minimization.
+
+use num_traits::Float;
+
+use super::{max_abs, Bounds, Criteria, Minimum, Status};
+use crate::math::Vector;
+
+/// Projected gradient descent within `bounds` with Barzilai-Borwein step
+/// lengths and an Armijo search along the projection arc
+/// (Birgin, Martínez & Raydan, SIAM J. Optim. 10, 2000, without the
+/// nonmonotone safeguard).
+///
+/// `fg(x)` returns the objective and its gradient; `x0` is projected into the
+/// box first. Convergence is measured on the projected gradient
+/// `P(x - g) - x`, which vanishes at a constrained minimum even where the
+/// gradient itself does not.
+pub fn projected_gradient<T, F, const N: usize>(
+    mut fg: F,
+    x0: Vector<T, N>,
+    bounds: &Bounds<T, N>,
+    criteria: &Criteria<T>,
+) -> Minimum<T, N>
+where
+    T: Float + Default,
+    F: FnMut(&Vector<T, N>) -> (T, Vector<T, N>),
+{
+    let sufficient = T::from(1e-4).unwrap();
+    let (min_step, max_step) = (T::from(1e-10).unwrap(), T::from(1e10).unwrap());
+    let x0 = bounds.project(&x0);
+    let (value, gradient) = fg(&x0);
+    let mut minimum = Minimum { x: x0, value, gradient, iterations: 0, evaluations: 1, status: Status::MaxIterations };
+    let projected = |m: &Minimum<T, N>| bounds.project(&(m.x - m.gradient)) - m.x;
+    let mut length = T::one() / max_abs(&projected(&minimum)).max(T::one());
+
+    while minimum.iterations < criteria.max_iterations {
+        if max_abs(&projected(&minimum)) <= criteria.gradient {
+            minimum.status = Status::GradientConverged;
+            return minimum;
+        }
+
+        // Backtrack along the arc P(x - α g)
+        let mut alpha = length;
+        let mut accepted = None;
+        for _ in 0..50 {
+            let trial = bounds.project(&(minimum.x - minimum.gradient * alpha));
+            let (trial_value, trial_gradient) = fg(&trial);
+            minimum.evaluations += 1;
+            let decrease = minimum.gradient.dot(&(trial - minimum.x));
+            if trial_value.is_finite() && trial_value <= minimum.value + sufficient * decrease {
+                accepted = Some((trial, trial_value, trial_gradient));
+                break;
+            }
+            alpha = alpha * T::from(0.5).unwrap();
+        }
+        let Some((x, value, gradient)) = accepted else {
+            minimum.status = Status::LineSearchFailed;
+            return minimum;
+        };
+
+        let s = x - minimum.x;
+        let y = gradient - minimum.gradient;
+        let previous = minimum.value;
+        minimum = Minimum { x, value, gradient, iterations: minimum.iterations + 1, ..minimum };
+        if criteria.step_met(&s, &minimum.x) {
+            minimum.status = Status::StepConverged;
+            return minimum;
+        }
+        if criteria.value_met(previous, value) {
+            minimum.status = Status::ValueConverged;
+            return minimum;
+        }
+        let sy = s.dot(&y);
+        length = if sy > T::zero() { (s.dot(&s) / sy).max(min_step).min(max_step) } else { max_step };
+    }
+    if max_abs(&projected(&minimum)) <= criteria.gradient {
+        minimum.status = Status::GradientConverged;
+    }
+    minimum
+}
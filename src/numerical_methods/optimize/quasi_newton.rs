@@ -0,0 +1,143 @@
+//! Quasi-Newton minimizers.
+
+use num_traits::Float;
+
+use super::{backtrack, outer, Criteria, Minimum, Status};
+use crate::math::{Matrix, Vector};
+
+/// Largest L-BFGS history.
+const MAX_MEMORY: usize = 32;
+
+/// BFGS with a dense inverse-Hessian approximation and Armijo backtracking
+/// (Nocedal & Wright, "Numerical Optimization", 6.1).
+///
+/// `fg(x)` returns the objective and its gradient. The update is skipped when
+/// the curvature condition `sᵀy > 0` fails, keeping the approximation positive
+/// definite.
+pub fn bfgs<T, F, const N: usize>(mut fg: F, x0: Vector<T, N>, criteria: &Criteria<T>) -> Minimum<T, N>
+where
+    T: Float + Default,
+    F: FnMut(&Vector<T, N>) -> (T, Vector<T, N>),
+{
+    let identity = Matrix::<T, N, N>::identity();
+    let mut inverse = identity;
+    let mut first = true;
+    quasi_newton(&mut fg, x0, criteria, |gradient, update| {
+        if let Some((s, y)) = update {
+            let sy = s.dot(&y);
+            if sy > T::epsilon() * s.norm() * y.norm() {
+                let rho = T::one() / sy;
+                if first {
+                    // Scale the initial guess to the observed curvature
+                    inverse = identity * (sy / y.dot(&y));
+                    first = false;
+                }
+                let left = identity - outer(&s, &y) * rho;
+                inverse = left * inverse * left.transpose() + outer(&s, &s) * rho;
+            }
+        }
+        let direction = -(inverse * *gradient);
+        if direction.dot(gradient) < T::zero() {
+            direction
+        } else {
+            inverse = identity;
+            -*gradient
+        }
+    })
+}
+
+/// Limited-memory BFGS keeping the last `memory` step pairs (at most 32),
+/// applied by the two-loop recursion (Nocedal & Wright, 7.2). Cheaper per
+/// iteration than [`bfgs`] for large `N`.
+pub fn lbfgs<T, F, const N: usize>(mut fg: F, x0: Vector<T, N>, memory: usize, criteria: &Criteria<T>) -> Minimum<T, N>
+where
+    T: Float + Default,
+    F: FnMut(&Vector<T, N>) -> (T, Vector<T, N>),
+{
+    let memory = memory.clamp(1, MAX_MEMORY);
+    let zero = Vector::new([T::zero(); N]);
+    let mut pairs: [(Vector<T, N>, Vector<T, N>, T); MAX_MEMORY] = [(zero, zero, T::zero()); MAX_MEMORY];
+    let (mut newest, mut len) = (0, 0);
+    quasi_newton(&mut fg, x0, criteria, |gradient, update| {
+        if let Some((s, y)) = update {
+            let sy = s.dot(&y);
+            if sy > T::epsilon() * s.norm() * y.norm() {
+                newest = (newest + 1) % memory;
+                pairs[newest] = (s, y, T::one() / sy);
+                len = (len + 1).min(memory);
+            }
+        }
+        let order = |k: usize| (newest + memory - k) % memory;
+        let mut q = *gradient;
+        let mut alpha = [T::zero(); MAX_MEMORY];
+        for k in 0..len {
+            let (s, y, rho) = &pairs[order(k)];
+            alpha[k] = *rho * s.dot(&q);
+            q = q - *y * alpha[k];
+        }
+        if len > 0 {
+            let (s, y, _) = &pairs[newest];
+            q = q * (s.dot(y) / y.dot(y));
+        }
+        for k in (0..len).rev() {
+            let (s, y, rho) = &pairs[order(k)];
+            let beta = *rho * y.dot(&q);
+            q = q + *s * (alpha[k] - beta);
+        }
+        -q
+    })
+}
+
+/// Shared iteration: `direction(g, Some((s, y)))` updates the model with the
+/// last step and returns the next search direction.
+fn quasi_newton<T, F, D, const N: usize>(fg: &mut F, x0: Vector<T, N>, criteria: &Criteria<T>, mut direction: D) -> Minimum<T, N>
+where
+    T: Float + Default,
+    F: FnMut(&Vector<T, N>) -> (T, Vector<T, N>),
+    D: FnMut(&Vector<T, N>, Option<(Vector<T, N>, Vector<T, N>)>) -> Vector<T, N>,
+{
+    let (value, gradient) = fg(&x0);
+    let mut minimum = Minimum { x: x0, value, gradient, iterations: 0, evaluations: 1, status: Status::MaxIterations };
+    let mut update = None;
+    while minimum.iterations < criteria.max_iterations {
+        if criteria.gradient_met(&minimum.gradient) {
+            minimum.status = Status::GradientConverged;
+            return minimum;
+        }
+        let d = direction(&minimum.gradient, update);
+        // A unit first step can be wildly scaled; cap it to the size of x
+        let alpha = if update.is_none() {
+            T::one().min((T::one() + minimum.x.norm()) / d.norm())
+        } else {
+            T::one()
+        };
+        let Some(step) = backtrack(fg, &minimum.x, minimum.value, &minimum.gradient, &d, alpha) else {
+            minimum.status = Status::LineSearchFailed;
+            return minimum;
+        };
+        let s = step.x - minimum.x;
+        let y = step.gradient - minimum.gradient;
+        let previous = minimum.value;
+        minimum = Minimum {
+            x: step.x,
+            value: step.value,
+            gradient: step.gradient,
+            iterations: minimum.iterations + 1,
+            evaluations: minimum.evaluations + step.evaluations,
+            status: Status::MaxIterations,
+        };
+        if criteria.step_met(&s, &minimum.x) {
+            minimum.status = Status::StepConverged;
+            return minimum;
+        }
+        if criteria.value_met(previous, minimum.value) {
+            minimum.status = Status::ValueConverged;
+            return minimum;
+        }
+        update = Some((s, y));
+    }
+    if criteria.gradient_met(&minimum.gradient) {
+        minimum.status = Status::GradientConverged;
+    }
+    minimum
+}
@@ -0,0 +1,132 @@
+#[cfg(test)]
+mod tests {
+    use crate::math::{Matrix, Vector};
+    use crate::numerical_methods::optimize::*;
+    use approx::assert_relative_eq;
+
+    // Extended Rosenbrock, minimum 0 at (1, ..., 1)
+    fn rosenbrock<const N: usize>(x: &Vector<f64, N>) -> (f64, Vector<f64, N>) {
+        let mut value = 0.0;
+        let mut gradient = Vector::new([0.0; N]);
+        for i in 0..N - 1 {
+            let a = x[i + 1] - x[i] * x[i];
+            let b = 1.0 - x[i];
+            value += 100.0 * a * a + b * b;
+            gradient[i] += -400.0 * x[i] * a - 2.0 * b;
+            gradient[i + 1] += 200.0 * a;
+        }
+        (value, gradient)
+    }
+
+    #[test]
+    fn test_quasi_newton() {
+        let criteria = Criteria { gradient: 1e-9, ..Criteria::default() };
+        let start = Vector::new([-1.2, 1.0, -0.5, 0.8]);
+        let dense = bfgs(rosenbrock, start, &criteria);
+        let limited = lbfgs(rosenbrock, start, 5, &criteria);
+        for minimum in [dense, limited] {
+            assert!(minimum.status.converged(), "{:?}", minimum.status);
+            for i in 0..4 {
+                assert_relative_eq!(minimum.x[i], 1.0, epsilon = 1e-6);
+            }
+            assert!(minimum.value < 1e-12);
+            assert!(minimum.evaluations >= minimum.iterations);
+        }
+        assert!(dense.gradient.norm() < 1e-6);
+
+        // Too few iterations
+        let short = Criteria { max_iterations: 5, ..criteria };
+        let minimum = bfgs(rosenbrock, start, &short);
+        assert_eq!((minimum.status, minimum.iterations), (Status::MaxIterations, 5));
+        assert!(!minimum.status.converged());
+    }
+
+    #[test]
+    fn test_nelder_mead() {
+        let criteria = Criteria { value: 1e-15, max_iterations: 5000, ..Criteria::default() };
+        let minimum = nelder_mead(|x: &Vector<f64, 2>| rosenbrock(x).0, Vector::new([-1.2, 1.0]), 0.5, &criteria);
+        assert!(minimum.status.converged(), "{:?}", minimum.status);
+        assert_relative_eq!(minimum.x[0], 1.0, epsilon = 1e-5);
+        assert_relative_eq!(minimum.x[1], 1.0, epsilon = 1e-5);
+        // Finite-difference gradient at the best vertex
+        assert!(minimum.gradient.norm() < 1e-3);
+
+        // A non-smooth objective
+        let minimum = nelder_mead(|x: &Vector<f64, 3>| (x[0] - 1.0).abs() + (x[1] + 2.0).abs() + x[2].abs(), Vector::new([0.0; 3]), 1.0, &criteria);
+        assert_relative_eq!(minimum.x[0], 1.0, epsilon = 1e-6);
+        assert_relative_eq!(minimum.x[1], -2.0, epsilon = 1e-6);
+    }
+
+    // y = a exp(b t) + c sampled at 8 times
+    const TIMES: [f64; 8] = [0.0, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0, 3.5];
+    const TRUTH: [f64; 3] = [2.0, -0.7, 0.5];
+
+    fn model(p: &Vector<f64, 3>, t: f64) -> f64 {
+        p[0] * (p[1] * t).exp() + p[2]
+    }
+
+    fn residuals(p: &Vector<f64, 3>) -> Vector<f64, 8> {
+        let truth = Vector::new(TRUTH);
+        Vector::new(core::array::from_fn(|i| model(p, TIMES[i]) - model(&truth, TIMES[i])))
+    }
+
+    fn residual_jacobian(p: &Vector<f64, 3>) -> Matrix<f64, 8, 3> {
+        Matrix::new(core::array::from_fn(|i| {
+            let e = (p[1] * TIMES[i]).exp();
+            [e, p[0] * TIMES[i] * e, 1.0]
+        }))
+    }
+
+    #[test]
+    fn test_least_squares() {
+        let criteria = Criteria::default();
+        let start = Vector::new([1.0, -0.2, 0.0]);
+        for minimum in [
+            gauss_newton(residuals, residual_jacobian, start, &criteria),
+            levenberg_marquardt(residuals, residual_jacobian, start, &criteria),
+        ] {
+            assert!(minimum.status.converged(), "{:?}", minimum.status);
+            for i in 0..3 {
+                assert_relative_eq!(minimum.x[i], TRUTH[i], epsilon = 1e-8);
+            }
+            assert!(minimum.value < 1e-14);
+        }
+
+        // A redundant parameter makes JᵀJ singular for Gauss-Newton only
+        let redundant = |p: &Vector<f64, 2>| Vector::new([p[0] + p[1] - 1.0, 2.0 * (p[0] + p[1]) - 2.0]);
+        let jacobian = |_: &Vector<f64, 2>| Matrix::new([[1.0, 1.0], [2.0, 2.0]]);
+        let start = Vector::new([3.0, 0.0]);
+        assert_eq!(gauss_newton(redundant, jacobian, start, &criteria).status, Status::Singular);
+        let minimum = levenberg_marquardt(redundant, jacobian, start, &criteria);
+        assert!(minimum.status.converged());
+        assert_relative_eq!(minimum.x[0] + minimum.x[1], 1.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_projected_gradient() {
+        // Quadratic bowl centred outside the box: the minimum sits on a face
+        let centre = Vector::new([2.0, -0.5, 0.3]);
+        let weights = Vector::new([1.0, 4.0, 10.0]);
+        let bowl = |x: &Vector<f64, 3>| {
+            let d = *x - centre;
+            let gradient = Vector::new(core::array::from_fn(|i| 2.0 * weights[i] * d[i]));
+            ((0..3).map(|i| weights[i] * d[i] * d[i]).sum::<f64>(), gradient)
+        };
+        let bounds = Bounds::new(Vector::new([-1.0, 0.0, -1.0]), Vector::new([1.0, 1.0, f64::INFINITY]));
+        let minimum = projected_gradient(bowl, Vector::new([5.0, 5.0, 5.0]), &bounds, &Criteria::default());
+        assert_eq!(minimum.status, Status::GradientConverged);
+        assert!(bounds.contains(&minimum.x));
+        assert_eq!((minimum.x[0], minimum.x[1]), (1.0, 0.0));
+        assert_relative_eq!(minimum.x[2], 0.3, epsilon = 1e-9);
+        // The full gradient pushes outward on the active faces
+        assert_relative_eq!(minimum.gradient[0], -2.0, epsilon = 1e-9);
+        assert_relative_eq!(minimum.gradient[1], 4.0, epsilon = 1e-9);
+
+        // Unconstrained Rosenbrock through the same routine
+        let free = Bounds::new(Vector::new([f64::NEG_INFINITY; 2]), Vector::new([f64::INFINITY; 2]));
+        let criteria = Criteria { gradient: 1e-6, max_iterations: 20000, ..Criteria::default() };
+        let minimum = projected_gradient(rosenbrock, Vector::new([-1.2, 1.0]), &free, &criteria);
+        assert!(minimum.status.converged(), "{:?}", minimum.status);
+        assert_relative_eq!(minimum.x[0], 1.0, epsilon = 1e-4);
+    }
+}
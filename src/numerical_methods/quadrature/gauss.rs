@@ -0,0 +1,274 @@
+use num_traits::Float;
+
+use super::{is_finite, tolerance, Estimate, QuadratureError};
+use crate::numerical_methods::integration::State;
+
+/// `N`-point Gauss-Legendre rule, exact for polynomials of degree `2N - 1`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GaussLegendre<T, const N: usize> {
+    /// Nodes on `[-1, 1]`, descending
+    pub nodes: [T; N],
+    pub weights: [T; N],
+}
+
+impl<T: Float, const N: usize> GaussLegendre<T, N> {
+    /// Computes the nodes as roots of the Legendre polynomial `P_N` by Newton
+    /// iteration from Tricomi's approximation.
+    pub fn new() -> Self {
+        let one = T::one();
+        let two = one + one;
+        let n = T::from(N).unwrap();
+        let mut nodes = [T::zero(); N];
+        let mut weights = [T::zero(); N];
+        for i in 0..N.div_ceil(2) {
+            let mut x = (T::from(core::f64::consts::PI).unwrap() * (T::from(i).unwrap() + T::from(0.75).unwrap())
+                / (n + T::from(0.5).unwrap()))
+            .cos();
+            for _ in 0..100 {
+                let (p, dp) = legendre(N, x);
+                let dx = p / dp;
+                x = x - dx;
+                if dx.abs() <= T::epsilon() {
+                    break;
+                }
+            }
+            let derivative = legendre(N, x).1;
+            let weight = two / ((one - x * x) * derivative * derivative);
+            nodes[i] = x;
+            nodes[N - 1 - i] = -x;
+            weights[i] = weight;
+            weights[N - 1 - i] = weight;
+        }
+        if N % 2 == 1 {
+            nodes[N / 2] = T::zero();
+        }
+        Self { nodes, weights }
+    }
+
+    /// Integrates `f` over `[a, b]` with one application of the rule.
+    pub fn integrate<V, F>(&self, mut f: F, a: T, b: T) -> V
+    where
+        V: State<T>,
+        F: FnMut(T) -> V,
+    {
+        let half_width = (b - a) * T::from(0.5).unwrap();
+        let center = (a + b) * T::from(0.5).unwrap();
+        let mut sum: Option<V> = None;
+        for (x, w) in self.nodes.iter().zip(&self.weights) {
+            let y = f(center + half_width * *x);
+            sum = Some(match sum {
+                Some(s) => s.scaled_add(*w, &y),
+                None => y.scaled(*w),
+            });
+        }
+        sum.expect("Gauss-Legendre rule needs at least one node").scaled(half_width)
+    }
+
+    /// Integrates `f` over `[a, b]` split into `panels` equal panels.
+    pub fn integrate_composite<V, F>(&self, mut f: F, a: T, b: T, panels: usize) -> V
+    where
+        V: State<T>,
+        F: FnMut(T) -> V,
+    {
+        let panels = panels.max(1);
+        let width = (b - a) / T::from(panels).unwrap();
+        let mut total = self.integrate(&mut f, a, a + width);
+        for i in 1..panels {
+            let start = a + width * T::from(i).unwrap();
+            total = total.scaled_add(T::one(), &self.integrate(&mut f, start, start + width));
+        }
+        total
+    }
+}
+
+impl<T: Float, const N: usize> Default for GaussLegendre<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `P_n(x)` and `P_n'(x)` by the three-term recurrence.
+fn legendre<T: Float>(n: usize, x: T) -> (T, T) {
+    let one = T::one();
+    let mut p0 = one;
+    let mut p1 = x;
+    if n == 0 {
+        return (one, T::zero());
+    }
+    for k in 2..=n {
+        let k = T::from(k).unwrap();
+        let p2 = ((k + k - one) * x * p1 - (k - one) * p0) / k;
+        p0 = p1;
+        p1 = p2;
+    }
+    let n = T::from(n).unwrap();
+    (p1, n * (x * p1 - p0) / (x * x - one))
+}
+
+/// Kronrod nodes of the 15-point rule on `[0, 1)`, descending; the odd
+/// entries are the 7-point Gauss nodes (QUADPACK `qk15`).
+#[allow(clippy::excessive_precision)]
+const KRONROD_NODES: [f64; 8] = [
+    0.991455371120812639206854697526329,
+    0.949107912342758524526189684047851,
+    0.864864423359769072789712788640926,
+    0.741531185599394439863864773280788,
+    0.586087235467691130294144845693013,
+    0.405845151377397166906606412076961,
+    0.207784955007898467600689403773245,
+    0.0,
+];
+
+#[allow(clippy::excessive_precision)]
+const KRONROD_WEIGHTS: [f64; 8] = [
+    0.022935322010529224963732008058970,
+    0.063092092629978553290700663189204,
+    0.104790010322250183839876322541518,
+    0.140653259715525918745189590510238,
+    0.169004726639267902826583426598550,
+    0.190350578064785409913256402421014,
+    0.204432940075298892414161999234649,
+    0.209482141084727828012999174891714,
+];
+
+/// Weights of the embedded 7-point Gauss rule at `KRONROD_NODES[1, 3, 5, 7]`.
+#[allow(clippy::excessive_precision)]
+const GAUSS_WEIGHTS: [f64; 4] = [
+    0.129484966168869693270611432679082,
+    0.279705391489276667901467771423780,
+    0.381830050505118944950369775488975,
+    0.417959183673469387755102040816327,
+];
+
+/// A panel `[a, b]` with its estimate.
+type Panel<T, V> = (T, T, Estimate<T, V>);
+
+/// Most panels an adaptive Gauss-Kronrod integration can hold.
+const MAX_PANELS: usize = 500;
+
+/// Adaptive Gauss-Kronrod (G7K15) quadrature, globally adaptive as QUADPACK's
+/// `qag`: the panel with the largest error is bisected until the summed error
+/// meets the tolerance.
+///
+/// The error of a panel comes from the difference between the 15-point Kronrod
+/// rule and its embedded 7-point Gauss rule, scaled as in QUADPACK. The
+/// integrand is never evaluated at the end points, so integrable end-point
+/// singularities are handled.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GaussKronrod<T> {
+    pub absolute: T,
+    pub relative: T,
+    /// At most 500
+    pub max_panels: usize,
+}
+
+impl<T: Float> GaussKronrod<T> {
+    pub fn new(absolute: T, relative: T) -> Self {
+        Self { absolute, relative, max_panels: MAX_PANELS }
+    }
+
+    /// One application of the G7K15 pair on `[a, b]`.
+    pub fn panel<V, F>(mut f: F, a: T, b: T) -> Result<Estimate<T, V>, QuadratureError<T, V>>
+    where
+        V: State<T>,
+        F: FnMut(T) -> V,
+    {
+        let half_width = (b - a) * T::from(0.5).unwrap();
+        let center = (a + b) * T::from(0.5).unwrap();
+        let mut values: [Option<V>; 15] = Default::default();
+        for (i, value) in values.iter_mut().enumerate() {
+            // Center first, then symmetric pairs from the outside in
+            let offset = if i == 0 { T::zero() } else { half_width * T::from(KRONROD_NODES[(i - 1) / 2]).unwrap() };
+            let x = if i % 2 == 1 { center - offset } else { center + offset };
+            let y = f(x);
+            if !is_finite(&y) {
+                return Err(QuadratureError::NonFinite { x });
+            }
+            *value = Some(y);
+        }
+        let y = |i: usize| values[i].as_ref().unwrap();
+        let kronrod_weight = |i: usize| T::from(KRONROD_WEIGHTS[if i == 0 { 7 } else { (i - 1) / 2 }]).unwrap();
+        let gauss_weight = |i: usize| match i {
+            0 => Some(GAUSS_WEIGHTS[3]),
+            _ if (i - 1) / 2 % 2 == 1 => Some(GAUSS_WEIGHTS[(i - 1) / 4]),
+            _ => None,
+        };
+
+        let mut kronrod = y(0).zero_like();
+        let mut gauss = y(0).zero_like();
+        for i in 0..15 {
+            kronrod = kronrod.scaled_add(kronrod_weight(i), y(i));
+            if let Some(w) = gauss_weight(i) {
+                gauss = gauss.scaled_add(T::from(w).unwrap(), y(i));
+            }
+        }
+        let value = kronrod.scaled(half_width);
+
+        // QUADPACK's error scaling, per component
+        let width = half_width.abs();
+        let two_hundred = T::from(200.0).unwrap();
+        let floor = T::from(50.0).unwrap() * T::epsilon();
+        let mut error = T::zero();
+        for c in 0..value.dimension() {
+            let mean = kronrod.component(c) * T::from(0.5).unwrap();
+            let mut absolute = T::zero();
+            let mut spread = T::zero();
+            for i in 0..15 {
+                absolute = absolute + kronrod_weight(i) * y(i).component(c).abs();
+                spread = spread + kronrod_weight(i) * (y(i).component(c) - mean).abs();
+            }
+            let (absolute, spread) = (absolute * width, spread * width);
+            let mut e = ((kronrod.component(c) - gauss.component(c)) * half_width).abs();
+            if spread != T::zero() && e != T::zero() {
+                e = spread * T::one().min((two_hundred * e / spread).powf(T::from(1.5).unwrap()));
+            }
+            if absolute > T::min_positive_value() / floor {
+                e = e.max(floor * absolute);
+            }
+            error = error.max(e);
+        }
+        Ok(Estimate { value, error, evaluations: 15 })
+    }
+
+    /// Integrates `f` over `[a, b]` to within `max(absolute, relative * |I|)`.
+    pub fn integrate<V, F>(&self, mut f: F, a: T, b: T) -> Result<Estimate<T, V>, QuadratureError<T, V>>
+    where
+        V: State<T>,
+        F: FnMut(T) -> V,
+    {
+        let limit = self.max_panels.clamp(1, MAX_PANELS);
+        let mut panels: [Option<Panel<T, V>>; MAX_PANELS] = core::array::from_fn(|_| None);
+        panels[0] = Some((a, b, Self::panel(&mut f, a, b)?));
+        let mut count = 1;
+        let mut evaluations = 15;
+        loop {
+            let mut worst = 0;
+            let mut value = None::<V>;
+            let mut error = T::zero();
+            for (i, (_, _, panel)) in panels[..count].iter().flatten().enumerate() {
+                value = Some(match value {
+                    Some(v) => v.scaled_add(T::one(), &panel.value),
+                    None => panel.value.clone(),
+                });
+                error = error + panel.error;
+                if panel.error > panels[worst].as_ref().unwrap().2.error {
+                    worst = i;
+                }
+            }
+            let estimate = Estimate { value: value.unwrap(), error, evaluations };
+            if error <= tolerance(self.absolute, self.relative, &estimate.value) {
+                return Ok(estimate);
+            }
+
+            let (lower, upper, _) = panels[worst].clone().unwrap();
+            let mid = (lower + upper) * T::from(0.5).unwrap();
+            if count == limit || mid <= lower.min(upper) || mid >= lower.max(upper) {
+                return Err(QuadratureError::MaxRefinement { estimate });
+            }
+            panels[worst] = Some((lower, mid, Self::panel(&mut f, lower, mid)?));
+            panels[count] = Some((mid, upper, Self::panel(&mut f, mid, upper)?));
+            count += 1;
+            evaluations += 30;
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//! Numerical quadrature.
+//!
+//! Sampled data is integrated with the composite rules in [`samples`];
+//! functions with [`Romberg`], fixed-order [`GaussLegendre`] or adaptive
+//! Gauss-Kronrod ([`GaussKronrod`]). Integrands may return any
+//! [`State`](crate::numerical_methods::integration::State): a scalar `f32`/`f64`,
+//! a `Vector<T, N>`, or a tuple of them. Error estimates of vector-valued
+//! integrands are the largest component error.
+
+use num_traits::Float;
+
+use crate::numerical_methods::integration::State;
+
+pub mod samples;
+mod gauss;
+mod romberg;
+pub use gauss::{GaussKronrod, GaussLegendre};
+pub use romberg::Romberg;
+pub use samples::{simpson, trapezoid, trapezoid_nonuniform};
+
+/// An integral with its estimated absolute error.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Estimate<T, V> {
+    pub value: V,
+    pub error: T,
+    /// Number of integrand evaluations
+    pub evaluations: usize,
+}
+
+/// Why an adaptive quadrature did not reach its tolerance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QuadratureError<T, V> {
+    /// Refinement limit hit; `estimate` is the best value found
+    MaxRefinement { estimate: Estimate<T, V> },
+    /// The integrand returned NaN or infinity at `x`
+    NonFinite { x: T },
+}
+
+/// Largest absolute component.
+fn max_abs<T: Float, V: State<T>>(v: &V) -> T {
+    (0..v.dimension()).fold(T::zero(), |m, i| m.max(v.component(i).abs()))
+}
+
+fn is_finite<T: Float, V: State<T>>(v: &V) -> bool {
+    (0..v.dimension()).all(|i| v.component(i).is_finite())
+}
+
+/// `max(absolute, relative * |value|)`
+fn tolerance<T: Float, V: State<T>>(absolute: T, relative: T, value: &V) -> T {
+    absolute.max(relative * max_abs(value))
+}
+
+#[cfg(test)]
+#[path = "tests/mod.rs"]
+mod tests;
@@ -0,0 +1,63 @@
+//! Composite rules over sampled values.
+
+use num_traits::Float;
+
+use crate::numerical_methods::integration::State;
+
+/// Composite trapezoidal rule over samples spaced `dx` apart.
+///
+/// Panics if `samples` is empty.
+pub fn trapezoid<T: Float, V: State<T>>(samples: &[V], dx: T) -> V {
+    let (first, rest) = samples.split_first().expect("trapezoid needs at least one sample");
+    let Some((last, interior)) = rest.split_last() else {
+        return first.zero_like();
+    };
+    let half = T::from(0.5).unwrap();
+    let sum = interior.iter().fold(first.scaled_add(T::one(), last).scaled(half), |sum, y| sum.scaled_add(T::one(), y));
+    sum.scaled(dx)
+}
+
+/// Trapezoidal rule over samples `y` at increasing or decreasing abscissae `x`.
+///
+/// Panics if the slices are empty or differ in length.
+pub fn trapezoid_nonuniform<T: Float, V: State<T>>(x: &[T], y: &[V]) -> V {
+    assert!(!y.is_empty() && x.len() == y.len(), "trapezoid needs one abscissa per sample");
+    let half = T::from(0.5).unwrap();
+    let mut sum = y[0].zero_like();
+    for (xs, ys) in x.windows(2).zip(y.windows(2)) {
+        let h = (xs[1] - xs[0]) * half;
+        sum = sum.scaled_add(h, &ys[0]).scaled_add(h, &ys[1]);
+    }
+    sum
+}
+
+/// Composite Simpson rule over samples spaced `dx` apart.
+///
+/// With an even number of samples the last three intervals use Simpson's 3/8
+/// rule, keeping fourth order throughout; two samples fall back to the
+/// trapezoidal rule. Panics if `samples` is empty.
+pub fn simpson<T: Float, V: State<T>>(samples: &[V], dx: T) -> V {
+    let n = samples.len();
+    if n <= 2 {
+        return trapezoid(samples, dx);
+    }
+    let (simpson_part, tail) = if n % 2 == 1 { (samples, &samples[n..]) } else { (&samples[..n - 3], &samples[n - 4..]) };
+
+    let mut sum = samples[0].zero_like();
+    if simpson_part.len() >= 3 {
+        let last = simpson_part.len() - 1;
+        sum = simpson_part[0].scaled_add(T::one(), &simpson_part[last]);
+        for (i, y) in simpson_part.iter().enumerate().take(last).skip(1) {
+            let weight = if i % 2 == 1 { T::from(4.0).unwrap() } else { T::from(2.0).unwrap() };
+            sum = sum.scaled_add(weight, y);
+        }
+        sum = sum.scaled(dx / T::from(3.0).unwrap());
+    }
+    if let [y0, y1, y2, y3] = tail {
+        // The 3/8 rule over the last four samples, shared with the Simpson part
+        let three = T::from(3.0).unwrap();
+        let rule = y0.scaled_add(three, y1).scaled_add(three, y2).scaled_add(T::one(), y3);
+        sum = sum.scaled_add(dx * T::from(0.375).unwrap(), &rule);
+    }
+    sum
+}
@@ -0,0 +1,119 @@
+#[cfg(test)]
+mod tests {
+    use crate::math::Vector;
+    use crate::numerical_methods::quadrature::*;
+    use approx::assert_relative_eq;
+    use core::f64::consts::PI;
+
+    fn sampled<F: Fn(f64) -> f64>(f: F, a: f64, b: f64, n: usize) -> (Vec<f64>, f64) {
+        let dx = (b - a) / (n - 1) as f64;
+        ((0..n).map(|i| f(a + dx * i as f64)).collect(), dx)
+    }
+
+    #[test]
+    fn test_sample_rules() {
+        // Trapezoid is exact for lines, Simpson for cubics with odd and even counts
+        let (line, dx) = sampled(|x| 3.0 * x - 1.0, 0.0, 2.0, 7);
+        assert_relative_eq!(trapezoid(&line, dx), 4.0, epsilon = 1e-14);
+        for n in [3, 4, 5, 8, 11] {
+            let (cubic, dx) = sampled(|x| x * x * x - x, 0.0, 2.0, n);
+            assert_relative_eq!(simpson(&cubic, dx), 2.0, epsilon = 1e-13);
+        }
+        assert_eq!(trapezoid(&[5.0], 0.1), 0.0);
+        assert_relative_eq!(simpson(&[1.0, 3.0], 0.5), 1.0);
+
+        // Fourth order on a smooth integrand
+        let error = |n: usize| {
+            let (s, dx) = sampled(f64::sin, 0.0, PI, n);
+            (simpson(&s, dx) - 2.0).abs()
+        };
+        assert_relative_eq!((error(21) / error(41)).log2(), 4.0, epsilon = 0.05);
+        assert_relative_eq!((error(20) / error(40)).log2(), 4.0, epsilon = 0.3);
+
+        // Unevenly spaced, vector-valued samples of (x, x^2) on [0, 1]
+        let x = [0.0, 0.1, 0.35, 0.5, 0.8, 1.0];
+        let y: Vec<Vector<f64, 2>> = x.iter().map(|&x| Vector::new([x, x * x])).collect();
+        let integral = trapezoid_nonuniform(&x, &y);
+        assert_relative_eq!(integral[0], 0.5, epsilon = 1e-14);
+        assert_relative_eq!(integral[1], 1.0 / 3.0, epsilon = 1e-2);
+    }
+
+    #[test]
+    fn test_romberg() {
+        let romberg = Romberg::new(1e-13, 1e-13);
+        let estimate = romberg.integrate(f64::exp, 0.0, 1.0).unwrap();
+        assert_relative_eq!(estimate.value, 1.0_f64.exp() - 1.0, epsilon = 1e-13);
+        assert!(estimate.evaluations < 100);
+
+        // Reversed limits flip the sign
+        let estimate = romberg.integrate(f64::exp, 1.0, 0.0).unwrap();
+        assert_relative_eq!(estimate.value, 1.0 - 1.0_f64.exp(), epsilon = 1e-13);
+
+        // Too few levels for sqrt's end-point behaviour
+        let mut shallow = Romberg::new(1e-12, 0.0);
+        shallow.max_levels = 6;
+        match shallow.integrate(f64::sqrt, 0.0, 1.0) {
+            Err(QuadratureError::MaxRefinement { estimate }) => {
+                assert!((estimate.value - 2.0 / 3.0).abs() < 1e-2);
+                assert!(estimate.error > 1e-12);
+            }
+            other => panic!("{other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_gauss_legendre() {
+        let rule = GaussLegendre::<f64, 5>::new();
+        // Abramowitz & Stegun 25.4.30
+        assert_relative_eq!(rule.nodes[0], 0.906179845938664, epsilon = 1e-15);
+        assert_relative_eq!(rule.weights[0], 0.236926885056189, epsilon = 1e-15);
+        assert_relative_eq!(rule.weights[2], 128.0 / 225.0, epsilon = 1e-15);
+        assert_relative_eq!(rule.weights.iter().sum::<f64>(), 2.0, epsilon = 1e-14);
+
+        // Exact up to degree 2N - 1 = 9
+        let exact = rule.integrate(|x: f64| x.powi(9) + x.powi(8), -1.0, 2.0);
+        assert_relative_eq!(exact, (2.0_f64.powi(10) - 1.0) / 10.0 + (2.0_f64.powi(9) + 1.0) / 9.0, epsilon = 1e-11);
+
+        let composite = GaussLegendre::<f64, 6>::new().integrate_composite(f64::cos, 0.0, 10.0, 8);
+        assert_relative_eq!(composite, 10.0_f64.sin(), epsilon = 1e-9);
+
+        // Single precision and vector values
+        let rule = GaussLegendre::<f32, 8>::new();
+        let v = rule.integrate(|x: f32| Vector::new([x.sin(), x.cos()]), 0.0, core::f32::consts::PI);
+        assert_relative_eq!(v[0], 2.0, epsilon = 1e-5);
+        assert_relative_eq!(v[1], 0.0, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_gauss_kronrod() {
+        let quadrature = GaussKronrod::new(1e-12, 1e-12);
+
+        // A single panel is exact well beyond the Gauss error estimate
+        let panel = GaussKronrod::panel(f64::exp, 0.0, 1.0).unwrap();
+        assert_relative_eq!(panel.value, 1.0_f64.exp() - 1.0, epsilon = 1e-15);
+        assert!(panel.error < 1e-12);
+
+        // End-point singularity
+        let estimate = quadrature.integrate(|x: f64| 1.0 / x.sqrt(), 0.0, 1.0).unwrap();
+        assert_relative_eq!(estimate.value, 2.0, epsilon = 1e-10);
+        assert!(estimate.error <= 1e-10);
+
+        // Oscillatory, vector-valued
+        let estimate = quadrature
+            .integrate(|x: f64| Vector::new([x.sin().powi(2), x * x.cos()]), 0.0, 10.0 * PI)
+            .unwrap();
+        assert_relative_eq!(estimate.value[0], 5.0 * PI, epsilon = 1e-10);
+        assert_relative_eq!(estimate.value[1], 0.0, epsilon = 1e-10);
+        assert!(estimate.evaluations > 15);
+
+        // Non-integrable singularity exhausts the refinement
+        let mut limited = GaussKronrod::new(1e-10, 0.0);
+        limited.max_panels = 20;
+        assert!(matches!(limited.integrate(|x: f64| 1.0 / x, 0.0, 1.0), Err(QuadratureError::MaxRefinement { .. })));
+
+        assert!(matches!(
+            quadrature.integrate(|x: f64| if x > 0.5 { f64::NAN } else { x }, 0.0, 1.0),
+            Err(QuadratureError::NonFinite { .. })
+        ));
+    }
+}
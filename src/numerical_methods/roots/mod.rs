@@ -0,0 +1,81 @@
+//! Root finding for scalar equations and square nonlinear systems.
+//!
+//! Bracketing methods ([`bisection`], [`brent`]) need a sign change on the
+//! starting interval and always converge; the open methods ([`newton`],
+//! [`halley`], [`secant`]) converge faster from a good guess but may wander.
+//! Systems `F(x) = 0` with `F: Vector<T, N> -> Vector<T, N>` are solved by
+//! damped [`newton_system`] or by [`levenberg_marquardt`], which also handles
+//! rank-deficient Jacobians and least-squares residuals.
+//!
+//! Every solver stops under the same [`Convergence`] criteria and reports
+//! failure through [`RootError`].
+
+use num_traits::Float;
+
+mod scalar;
+mod systems;
+pub use scalar::{bisection, brent, halley, newton, secant};
+pub use systems::{levenberg_marquardt, newton_system};
+
+/// When an iteration counts as converged.
+///
+/// A solver stops once its last step is below `absolute + relative * |x|`, or
+/// once the residual `|f(x)|` is at most `residual`. For systems both are
+/// Euclidean norms.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Convergence<T> {
+    pub absolute: T,
+    pub relative: T,
+    pub residual: T,
+    pub max_iterations: usize,
+}
+
+impl<T: Float> Convergence<T> {
+    pub fn new(absolute: T, relative: T) -> Self {
+        Self { absolute, relative, residual: T::zero(), max_iterations: 100 }
+    }
+
+    fn step_converged(&self, step: T, x: T) -> bool {
+        step.abs() <= self.absolute + self.relative * x.abs()
+    }
+
+    fn residual_converged(&self, residual: T) -> bool {
+        residual.abs() <= self.residual
+    }
+}
+
+impl<T: Float> Default for Convergence<T> {
+    /// Converges to a few units in the last place
+    fn default() -> Self {
+        Self::new(T::min_positive_value(), T::from(4.0).unwrap() * T::epsilon())
+    }
+}
+
+/// A converged root `x` with the residual `f(x)` there.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Root<X> {
+    pub x: X,
+    pub residual: X,
+    pub iterations: usize,
+    /// Number of function evaluations (Jacobians count once each)
+    pub evaluations: usize,
+}
+
+/// Why a root finder failed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RootError<X> {
+    /// `f(a)` and `f(b)` have the same sign
+    NotBracketed { fa: X, fb: X },
+    /// The derivative (or secant slope) vanished at `x`
+    ZeroDerivative { x: X },
+    /// The Jacobian at `x` could not be factored
+    SingularJacobian { x: X },
+    /// The function returned NaN or infinity at `x`
+    NonFinite { x: X },
+    /// Iteration limit hit; `best` is the last iterate
+    MaxIterations { best: Root<X> },
+}
+
+#[cfg(test)]
+#[path = "tests/mod.rs"]
+mod tests;
@@ -0,0 +1,240 @@
+//! Scalar root finders.
+
+use num_traits::Float;
+
+use super::{Convergence, Root, RootError};
+
+fn finite<T: Float>(x: T, fx: T) -> Result<T, RootError<T>> {
+    if fx.is_finite() { Ok(fx) } else { Err(RootError::NonFinite { x }) }
+}
+
+/// Evaluates the bracket `[a, b]`, returning `Err(root)` if an end point is
+/// already a root.
+fn bracket<T: Float, F: FnMut(T) -> T>(f: &mut F, a: T, b: T) -> Result<(T, T), Result<Root<T>, RootError<T>>> {
+    let fa = finite(a, f(a)).map_err(Err)?;
+    let fb = finite(b, f(b)).map_err(Err)?;
+    let root = |x, residual| Root { x, residual, iterations: 0, evaluations: 2 };
+    if fa == T::zero() {
+        Err(Ok(root(a, fa)))
+    } else if fb == T::zero() {
+        Err(Ok(root(b, fb)))
+    } else if (fa < T::zero()) == (fb < T::zero()) {
+        Err(Err(RootError::NotBracketed { fa, fb }))
+    } else {
+        Ok((fa, fb))
+    }
+}
+
+/// Bisection on a bracket `[a, b]` where `f(a)` and `f(b)` differ in sign.
+///
+/// Gains one bit per iteration; the step criterion applies to the half-width
+/// of the bracket.
+pub fn bisection<T, F>(mut f: F, a: T, b: T, convergence: &Convergence<T>) -> Result<Root<T>, RootError<T>>
+where
+    T: Float,
+    F: FnMut(T) -> T,
+{
+    let (mut fa, _) = match bracket(&mut f, a, b) {
+        Ok(values) => values,
+        Err(done) => return done,
+    };
+    let (mut a, mut b) = (a, b);
+    let half = T::from(0.5).unwrap();
+    let mut best = Root { x: a, residual: fa, iterations: 0, evaluations: 2 };
+    for iteration in 1..=convergence.max_iterations {
+        let mid = a + (b - a) * half;
+        let fm = finite(mid, f(mid))?;
+        best = Root { x: mid, residual: fm, iterations: iteration, evaluations: iteration + 2 };
+        if fm == T::zero()
+            || convergence.residual_converged(fm)
+            || convergence.step_converged((b - a) * half, mid)
+            || mid == a
+            || mid == b
+        {
+            return Ok(best);
+        }
+        if (fm < T::zero()) == (fa < T::zero()) {
+            a = mid;
+            fa = fm;
+        } else {
+            b = mid;
+        }
+    }
+    Err(RootError::MaxIterations { best })
+}
+
+/// Brent's method on a bracket `[a, b]` where `f(a)` and `f(b)` differ in sign.
+///
+/// Inverse quadratic interpolation and secant steps, falling back to bisection
+/// whenever they would leave the bracket or converge too slowly
+/// (Brent, "Algorithms for Minimization without Derivatives", ch. 4).
+pub fn brent<T, F>(mut f: F, a: T, b: T, convergence: &Convergence<T>) -> Result<Root<T>, RootError<T>>
+where
+    T: Float,
+    F: FnMut(T) -> T,
+{
+    let (mut fa, mut fb) = match bracket(&mut f, a, b) {
+        Ok(values) => values,
+        Err(done) => return done,
+    };
+    let (one, two, three) = (T::one(), T::from(2.0).unwrap(), T::from(3.0).unwrap());
+    let half = T::from(0.5).unwrap();
+    let (mut a, mut b) = (a, b);
+    let (mut c, mut fc) = (a, fa);
+    let mut d = b - a;
+    let mut e = d;
+    let mut evaluations = 2;
+
+    for iteration in 1..=convergence.max_iterations {
+        // Keep b the best estimate and c on the other side of the root
+        if (fb < T::zero()) == (fc < T::zero()) {
+            c = a;
+            fc = fa;
+            d = b - a;
+            e = d;
+        }
+        if fc.abs() < fb.abs() {
+            a = b;
+            b = c;
+            c = a;
+            fa = fb;
+            fb = fc;
+            fc = fa;
+        }
+
+        let tolerance = half * (convergence.absolute + convergence.relative * b.abs());
+        let m = half * (c - b);
+        let best = Root { x: b, residual: fb, iterations: iteration - 1, evaluations };
+        if fb == T::zero() || convergence.residual_converged(fb) || m.abs() <= tolerance {
+            return Ok(best);
+        }
+
+        if e.abs() >= tolerance && fa.abs() > fb.abs() {
+            let s = fb / fa;
+            let (mut p, mut q) = if a == c {
+                // Secant
+                (two * m * s, one - s)
+            } else {
+                // Inverse quadratic interpolation
+                let q = fa / fc;
+                let r = fb / fc;
+                (s * (two * m * q * (q - r) - (b - a) * (r - one)), (q - one) * (r - one) * (s - one))
+            };
+            if p > T::zero() {
+                q = -q;
+            } else {
+                p = -p;
+            }
+            if two * p < (three * m * q - (tolerance * q).abs()).min((e * q).abs()) {
+                e = d;
+                d = p / q;
+            } else {
+                d = m;
+                e = m;
+            }
+        } else {
+            d = m;
+            e = m;
+        }
+
+        a = b;
+        fa = fb;
+        b = if d.abs() > tolerance { b + d } else if m > T::zero() { b + tolerance } else { b - tolerance };
+        fb = finite(b, f(b))?;
+        evaluations += 1;
+    }
+    Err(RootError::MaxIterations {
+        best: Root { x: b, residual: fb, iterations: convergence.max_iterations, evaluations },
+    })
+}
+
+/// Newton-Raphson from `x0`; `f` returns `(f(x), f'(x))`.
+pub fn newton<T, F>(mut f: F, x0: T, convergence: &Convergence<T>) -> Result<Root<T>, RootError<T>>
+where
+    T: Float,
+    F: FnMut(T) -> (T, T),
+{
+    open_iteration(x0, convergence, |x| {
+        let (fx, dfx) = f(x);
+        (fx, if dfx == T::zero() { None } else { Some(fx / dfx) })
+    })
+}
+
+/// Halley's method from `x0`, cubically convergent; `f` returns
+/// `(f(x), f'(x), f''(x))`.
+pub fn halley<T, F>(mut f: F, x0: T, convergence: &Convergence<T>) -> Result<Root<T>, RootError<T>>
+where
+    T: Float,
+    F: FnMut(T) -> (T, T, T),
+{
+    let two = T::from(2.0).unwrap();
+    open_iteration(x0, convergence, |x| {
+        let (fx, dfx, d2fx) = f(x);
+        let denominator = two * dfx * dfx - fx * d2fx;
+        (fx, if denominator == T::zero() { None } else { Some(two * fx * dfx / denominator) })
+    })
+}
+
+/// Secant method from the two starting points `x0` and `x1`.
+pub fn secant<T, F>(mut f: F, x0: T, x1: T, convergence: &Convergence<T>) -> Result<Root<T>, RootError<T>>
+where
+    T: Float,
+    F: FnMut(T) -> T,
+{
+    let mut previous = (x0, finite(x0, f(x0))?);
+    let mut evaluations = 1;
+    let mut root = open_iteration(x1, convergence, |x| {
+        let fx = f(x);
+        evaluations += 1;
+        let (xp, fp) = previous;
+        previous = (x, fx);
+        let slope = (fx - fp) / (x - xp);
+        (fx, if slope == T::zero() || !slope.is_finite() { None } else { Some(fx / slope) })
+    });
+    // The first point is one evaluation the iteration did not see
+    match &mut root {
+        Ok(root) => root.evaluations = evaluations,
+        Err(RootError::MaxIterations { best }) => best.evaluations = evaluations,
+        Err(_) => {}
+    }
+    root
+}
+
+/// Shared loop of the open methods: `update(x)` returns `f(x)` and the step to
+/// subtract from `x`, or `None` when the slope vanished.
+fn open_iteration<T, U>(x0: T, convergence: &Convergence<T>, mut update: U) -> Result<Root<T>, RootError<T>>
+where
+    T: Float,
+    U: FnMut(T) -> (T, Option<T>),
+{
+    let mut x = x0;
+    for iteration in 0..convergence.max_iterations {
+        let (fx, step) = update(x);
+        let fx = finite(x, fx)?;
+        let root = Root { x, residual: fx, iterations: iteration, evaluations: iteration + 1 };
+        if fx == T::zero() || convergence.residual_converged(fx) {
+            return Ok(root);
+        }
+        let step = step.ok_or(RootError::ZeroDerivative { x })?;
+        let next = x - step;
+        if !next.is_finite() {
+            return Err(RootError::NonFinite { x: next });
+        }
+        if convergence.step_converged(step, next) {
+            // The step just taken is the better estimate; report its residual
+            let (fnext, _) = update(next);
+            let residual = finite(next, fnext)?;
+            return Ok(Root { x: next, residual, iterations: iteration + 1, evaluations: iteration + 2 });
+        }
+        x = next;
+    }
+    let (fx, _) = update(x);
+    Err(RootError::MaxIterations {
+        best: Root {
+            x,
+            residual: fx,
+            iterations: convergence.max_iterations,
+            evaluations: convergence.max_iterations + 1,
+        },
+    })
+}
@@ -0,0 +1,150 @@
+//! Solvers for square nonlinear systems `F(x) = 0`.
+
+use num_traits::Float;
+
+use super::{Convergence, Root, RootError};
+use crate::math::{Matrix, Vector};
+
+fn is_finite<T: Float, const N: usize>(v: &Vector<T, N>) -> bool {
+    v.data.iter().all(|x| x.is_finite())
+}
+
+/// Newton's method for `F(x) = 0` from `x0`, with `jacobian(x) = dF/dx`.
+///
+/// Each Newton step is halved until `|F|` decreases (at most 30 times), which
+/// keeps the iteration from diverging far from the root. Fails with
+/// [`RootError::SingularJacobian`] if the Jacobian cannot be factored.
+pub fn newton_system<T, F, J, const N: usize>(
+    mut f: F,
+    mut jacobian: J,
+    x0: Vector<T, N>,
+    convergence: &Convergence<T>,
+) -> Result<Root<Vector<T, N>>, RootError<Vector<T, N>>>
+where
+    T: Float + Default,
+    F: FnMut(&Vector<T, N>) -> Vector<T, N>,
+    J: FnMut(&Vector<T, N>) -> Matrix<T, N, N>,
+{
+    let half = T::from(0.5).unwrap();
+    let sufficient = T::from(1e-4).unwrap();
+    let mut x = x0;
+    let mut fx = f(&x);
+    let mut evaluations = 1;
+    if !is_finite(&fx) {
+        return Err(RootError::NonFinite { x });
+    }
+    for iteration in 0..convergence.max_iterations {
+        let norm = fx.norm();
+        if norm == T::zero() || convergence.residual_converged(norm) {
+            return Ok(Root { x, residual: fx, iterations: iteration, evaluations });
+        }
+        let step = jacobian(&x).lu().ok_or(RootError::SingularJacobian { x })?.solve(&-fx);
+        evaluations += 1;
+
+        // Backtrack until the residual norm drops
+        let mut scale = T::one();
+        let (mut trial, mut f_trial) = (x + step, f(&(x + step)));
+        evaluations += 1;
+        for _ in 0..30 {
+            if is_finite(&f_trial) && f_trial.norm() <= (T::one() - sufficient * scale) * norm {
+                break;
+            }
+            scale = scale * half;
+            trial = x + step * scale;
+            f_trial = f(&trial);
+            evaluations += 1;
+        }
+        if !is_finite(&f_trial) {
+            return Err(RootError::NonFinite { x: trial });
+        }
+
+        let converged = convergence.step_converged((step * scale).norm(), trial.norm());
+        x = trial;
+        fx = f_trial;
+        if converged {
+            return Ok(Root { x, residual: fx, iterations: iteration + 1, evaluations });
+        }
+    }
+    Err(RootError::MaxIterations {
+        best: Root { x, residual: fx, iterations: convergence.max_iterations, evaluations },
+    })
+}
+
+/// Levenberg-Marquardt for `F(x) = 0` from `x0`, with `jacobian(x) = dF/dx`.
+///
+/// Solves `(JᵀJ + λI) δ = -JᵀF` with the damping `λ` adapted from the ratio of
+/// actual to predicted decrease of `|F|²` (Nielsen's update, as in Madsen,
+/// Nielsen & Tingleff, "Methods for Non-Linear Least Squares Problems", 3.2).
+/// More robust than [`newton_system`] far from the root and where the Jacobian
+/// is singular; converges to a local minimum of `|F|` when there is no root.
+pub fn levenberg_marquardt<T, F, J, const N: usize>(
+    mut f: F,
+    mut jacobian: J,
+    x0: Vector<T, N>,
+    convergence: &Convergence<T>,
+) -> Result<Root<Vector<T, N>>, RootError<Vector<T, N>>>
+where
+    T: Float + Default,
+    F: FnMut(&Vector<T, N>) -> Vector<T, N>,
+    J: FnMut(&Vector<T, N>) -> Matrix<T, N, N>,
+{
+    let (two, three) = (T::from(2.0).unwrap(), T::from(3.0).unwrap());
+    let mut x = x0;
+    let mut fx = f(&x);
+    if !is_finite(&fx) {
+        return Err(RootError::NonFinite { x });
+    }
+    let mut evaluations = 1;
+    let mut jac = jacobian(&x);
+    evaluations += 1;
+    let mut normal = jac.transpose() * jac;
+    let mut gradient = jac.transpose() * fx;
+    let largest_diagonal = (0..N).fold(T::zero(), |m, i| m.max(normal[(i, i)]));
+    let mut damping = T::from(1e-3).unwrap() * largest_diagonal;
+    if damping == T::zero() {
+        return Err(RootError::SingularJacobian { x });
+    }
+    let mut growth = two;
+
+    for iteration in 0..convergence.max_iterations {
+        let norm = fx.norm();
+        if norm == T::zero() || convergence.residual_converged(norm) {
+            return Ok(Root { x, residual: fx, iterations: iteration, evaluations });
+        }
+
+        let Some(lu) = (normal + Matrix::identity() * damping).lu() else {
+            damping = damping * growth;
+            growth = growth * two;
+            continue;
+        };
+        let step = lu.solve(&-gradient);
+        if convergence.step_converged(step.norm(), x.norm()) {
+            return Ok(Root { x, residual: fx, iterations: iteration, evaluations });
+        }
+
+        let trial = x + step;
+        let f_trial = f(&trial);
+        evaluations += 1;
+        // Gain ratio of actual to predicted decrease of |F|^2 / 2
+        let predicted = step.dot(&(step * damping - gradient)) / two;
+        let actual = (fx.dot(&fx) - f_trial.dot(&f_trial)) / two;
+        let ratio = actual / predicted;
+        if is_finite(&f_trial) && ratio > T::zero() {
+            x = trial;
+            fx = f_trial;
+            jac = jacobian(&x);
+            evaluations += 1;
+            normal = jac.transpose() * jac;
+            gradient = jac.transpose() * fx;
+            let shrink = T::one() - (two * ratio - T::one()).powi(3);
+            damping = damping * shrink.max(T::one() / three);
+            growth = two;
+        } else {
+            damping = damping * growth;
+            growth = growth * two;
+        }
+    }
+    Err(RootError::MaxIterations {
+        best: Root { x, residual: fx, iterations: convergence.max_iterations, evaluations },
+    })
+}
@@ -0,0 +1,133 @@
+#[cfg(test)]
+mod tests {
+    use crate::math::{Matrix, Vector};
+    use crate::numerical_methods::roots::*;
+    use approx::assert_relative_eq;
+
+    // Kepler's equation E - e sin E = M
+    const E: f64 = 0.7;
+    const M: f64 = 1.3;
+
+    fn kepler(x: f64) -> f64 {
+        x - E * x.sin() - M
+    }
+
+    #[test]
+    fn test_bracketing() {
+        let convergence = Convergence::default();
+        let bisected = bisection(kepler, 0.0, 3.0, &convergence).unwrap();
+        let brent_root = brent(kepler, 0.0, 3.0, &convergence).unwrap();
+        assert_relative_eq!(kepler(bisected.x), 0.0, epsilon = 1e-14);
+        assert_relative_eq!(brent_root.x, bisected.x, epsilon = 1e-14);
+        assert_eq!(brent_root.residual, kepler(brent_root.x));
+        assert!(brent_root.evaluations < 12 && bisected.iterations > 40);
+
+        // Either ordering of the bracket, and a root on an end point
+        assert_relative_eq!(bisection(kepler, 3.0, 0.0, &convergence).unwrap().x, bisected.x, epsilon = 1e-14);
+        assert_eq!(bisection(|x: f64| x - 2.0, 0.0, 2.0, &convergence).unwrap().evaluations, 2);
+
+        assert!(matches!(
+            bisection(kepler, 2.5, 3.0, &convergence),
+            Err(RootError::NotBracketed { .. })
+        ));
+
+        // Single precision with a loose tolerance
+        let loose = Convergence::new(1e-3_f32, 0.0);
+        let root = brent(|x: f32| x * x - 2.0, 0.0, 2.0, &loose).unwrap();
+        assert!((root.x - 2.0_f32.sqrt()).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_open_methods() {
+        let convergence = Convergence::default();
+        let reference = brent(kepler, 0.0, 3.0, &convergence).unwrap().x;
+
+        let newton_root = newton(|x: f64| (kepler(x), 1.0 - E * x.cos()), M, &convergence).unwrap();
+        let halley_root = halley(|x: f64| (kepler(x), 1.0 - E * x.cos(), E * x.sin()), M, &convergence).unwrap();
+        let secant_root = secant(kepler, M, M + 0.1, &convergence).unwrap();
+        for root in [newton_root, halley_root, secant_root] {
+            assert_relative_eq!(root.x, reference, epsilon = 1e-14);
+        }
+        // Cubic beats quadratic beats superlinear
+        assert!(halley_root.iterations <= newton_root.iterations);
+        assert!(newton_root.iterations <= secant_root.iterations);
+        assert!(newton_root.iterations < 8);
+        assert_eq!(secant_root.evaluations, secant_root.iterations + 2);
+
+        // On a step criterion the last step is kept: Newton for sqrt(2) stops
+        // after a step of 2e-6 and returns the iterate it led to
+        let root = newton(|x: f64| (x * x - 2.0, 2.0 * x), 1.0, &Convergence::new(1e-3, 0.0)).unwrap();
+        assert!((root.x - 2.0_f64.sqrt()).abs() < 1e-11);
+        assert_eq!(root.residual, root.x * root.x - 2.0);
+        assert_eq!((root.iterations, root.evaluations), (4, 5));
+
+        // A residual criterion stops early
+        let mut coarse = Convergence::new(0.0, 0.0);
+        coarse.residual = 1e-3;
+        let root = newton(|x: f64| (kepler(x), 1.0 - E * x.cos()), M, &coarse).unwrap();
+        assert!(kepler(root.x).abs() <= 1e-3 && root.iterations < newton_root.iterations);
+
+        // x^2 + 1 has no real root
+        assert!(matches!(
+            newton(|x: f64| (x * x + 1.0, 2.0 * x), 0.0, &convergence),
+            Err(RootError::ZeroDerivative { x }) if x == 0.0
+        ));
+        let mut short = Convergence::default();
+        short.max_iterations = 10;
+        match newton(|x: f64| (x * x + 1.0, 2.0 * x), 0.3, &short) {
+            Err(RootError::MaxIterations { best }) => assert_eq!(best.iterations, 10),
+            other => panic!("{other:?}"),
+        }
+        assert!(matches!(
+            newton(|x: f64| (x.ln(), 1.0 / x), 5.0, &convergence),
+            Err(RootError::NonFinite { .. })
+        ));
+    }
+
+    // F(x, y) = (10 (y - x^2), 1 - x), root at (1, 1)
+    fn rosenbrock(v: &Vector<f64, 2>) -> Vector<f64, 2> {
+        Vector::new([10.0 * (v[1] - v[0] * v[0]), 1.0 - v[0]])
+    }
+
+    fn rosenbrock_jacobian(v: &Vector<f64, 2>) -> Matrix<f64, 2, 2> {
+        Matrix::new([[-20.0 * v[0], 10.0], [-1.0, 0.0]])
+    }
+
+    #[test]
+    fn test_systems() {
+        let convergence = Convergence::default();
+        let start = Vector::new([-1.2, 1.0]);
+        for root in [
+            newton_system(rosenbrock, rosenbrock_jacobian, start, &convergence).unwrap(),
+            levenberg_marquardt(rosenbrock, rosenbrock_jacobian, start, &convergence).unwrap(),
+        ] {
+            assert_relative_eq!(root.x[0], 1.0, epsilon = 1e-12);
+            assert_relative_eq!(root.x[1], 1.0, epsilon = 1e-12);
+            assert!(root.residual.norm() < 1e-12);
+        }
+
+        // Circle meets the exponential, from a guess in the wrong quadrant
+        let f = |v: &Vector<f64, 2>| Vector::new([v[0] * v[0] + v[1] * v[1] - 4.0, v[1] - v[0].exp()]);
+        let j = |v: &Vector<f64, 2>| Matrix::new([[2.0 * v[0], 2.0 * v[1]], [-v[0].exp(), 1.0]]);
+        let root = newton_system(f, j, Vector::new([-3.0, 3.0]), &convergence).unwrap();
+        assert!(f(&root.x).norm() < 1e-12);
+        let root = levenberg_marquardt(f, j, Vector::new([1.0, -1.0]), &convergence).unwrap();
+        assert!(f(&root.x).norm() < 1e-12);
+
+        // Singular Jacobian
+        let f = |v: &Vector<f64, 2>| Vector::new([v[0] * v[0] - 1.0, v[1] - 2.0]);
+        let j = |v: &Vector<f64, 2>| Matrix::new([[2.0 * v[0], 0.0], [0.0, 1.0]]);
+        assert!(matches!(
+            newton_system(f, j, Vector::new([0.0, 0.0]), &convergence),
+            Err(RootError::SingularJacobian { .. })
+        ));
+
+        // Without a root, Levenberg-Marquardt settles on the least-squares point
+        let f = |v: &Vector<f64, 2>| Vector::new([v[0] * v[0] + 1.0, v[1] - 3.0]);
+        let j = |v: &Vector<f64, 2>| Matrix::new([[2.0 * v[0], 0.0], [0.0, 1.0]]);
+        let root = levenberg_marquardt(f, j, Vector::new([2.0, 0.0]), &Convergence::new(1e-10, 0.0)).unwrap();
+        assert!(root.x[0].abs() < 1e-4);
+        assert_relative_eq!(root.x[1], 3.0, epsilon = 1e-6);
+        assert_relative_eq!(root.residual[0], 1.0, epsilon = 1e-6);
+    }
+}
@@ -0,0 +1,158 @@
+use num_traits::Float;
+
+use super::{tolerance, Anomaly, KeplerianElements, OrbitError, StateVector};
+use crate::coordinate::Cartesian;
+use crate::math::Vector;
+use crate::utils::wrap_two_pi;
+
+/// Equinoctial elements (Broucke and Cefola, 1972) of an elliptic or
+/// hyperbolic orbit, with `varpi = raan + argp` the longitude of periapsis:
+/// semi-major axis `a`, `h = e sin(varpi)`, `k = e cos(varpi)`,
+/// `p = tan(i/2) sin(raan)`, `q = tan(i/2) cos(raan)` and mean longitude
+/// `lambda = M + varpi`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EquinoctialElements<T: Float> {
+    pub a: T,
+    pub h: T,
+    pub k: T,
+    pub p: T,
+    pub q: T,
+    pub lambda: T,
+}
+
+impl<T: Float> EquinoctialElements<T> {
+    pub fn new(a: T, h: T, k: T, p: T, q: T, lambda: T) -> Self {
+        Self { a, h, k, p, q, lambda }
+    }
+
+    /// Fails for parabolas, which have no finite semi-major axis.
+    pub fn from_keplerian(elements: &KeplerianElements<T>) -> Result<Self, OrbitError> {
+        let e = elements.e();
+        if e == T::one() {
+            return Err(OrbitError::Parabolic);
+        }
+        let varpi = elements.raan() + elements.argp();
+        let (sin_raan, cos_raan) = elements.raan().sin_cos();
+        let tan_half_i = (elements.i() / (T::one() + T::one())).tan();
+        let lambda = elements.mean_anomaly() + varpi;
+        Ok(Self {
+            a: elements.a(),
+            h: e * varpi.sin(),
+            k: e * varpi.cos(),
+            p: tan_half_i * sin_raan,
+            q: tan_half_i * cos_raan,
+            lambda: if e < T::one() { wrap_two_pi(lambda) } else { lambda },
+        })
+    }
+
+    pub fn to_keplerian(&self) -> Result<KeplerianElements<T>, OrbitError> {
+        let two = T::one() + T::one();
+        let varpi = self.h.atan2(self.k);
+        let raan = self.p.atan2(self.q);
+        let i = two * self.p.hypot(self.q).atan();
+        let anomaly = Anomaly::Mean(self.lambda - varpi);
+        KeplerianElements::new(self.a, self.h.hypot(self.k), i, raan, varpi - raan, anomaly)
+    }
+
+    pub fn from_state<F>(state: &StateVector<T, F>, mu: T) -> Result<Self, OrbitError> {
+        Self::from_keplerian(&KeplerianElements::from_state(state, mu)?)
+    }
+
+    pub fn to_state<F>(&self, mu: T) -> Result<StateVector<T, F>, OrbitError> {
+        Ok(self.to_keplerian()?.to_state(mu))
+    }
+}
+
+/// Modified equinoctial elements (Walker et al., 1985), defined for every
+/// conic, with `varpi = raan + argp`: semi-latus rectum `p`,
+/// `f = e cos(varpi)`, `g = e sin(varpi)`, `h = tan(i/2) cos(raan)`,
+/// `k = tan(i/2) sin(raan)` and true longitude `l = varpi + nu`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ModifiedEquinoctialElements<T: Float> {
+    pub p: T,
+    pub f: T,
+    pub g: T,
+    pub h: T,
+    pub k: T,
+    pub l: T,
+}
+
+impl<T: Float> ModifiedEquinoctialElements<T> {
+    pub fn new(p: T, f: T, g: T, h: T, k: T, l: T) -> Self {
+        Self { p, f, g, h, k, l }
+    }
+
+    pub fn from_keplerian(elements: &KeplerianElements<T>) -> Self {
+        let e = elements.e();
+        let varpi = elements.longitude_of_periapsis();
+        let (sin_raan, cos_raan) = elements.raan().sin_cos();
+        let tan_half_i = (elements.i() / (T::one() + T::one())).tan();
+        Self {
+            p: elements.p(),
+            f: e * varpi.cos(),
+            g: e * varpi.sin(),
+            h: tan_half_i * cos_raan,
+            k: tan_half_i * sin_raan,
+            l: elements.true_longitude(),
+        }
+    }
+
+    pub fn to_keplerian(&self) -> Result<KeplerianElements<T>, OrbitError> {
+        let two = T::one() + T::one();
+        let e = self.f.hypot(self.g);
+        let varpi = self.g.atan2(self.f);
+        let raan = self.k.atan2(self.h);
+        let i = two * self.h.hypot(self.k).atan();
+        let anomaly = Anomaly::True(self.l - varpi);
+        if (e - T::one()).abs() <= tolerance() {
+            KeplerianElements::parabolic(self.p, i, raan, varpi - raan, anomaly)
+        } else {
+            KeplerianElements::new(self.p / ((T::one() - e) * (T::one() + e)), e, i, raan, varpi - raan, anomaly)
+        }
+    }
+
+    /// Computed directly, without passing through the singular Keplerian
+    /// elements.
+    pub fn from_state<F>(state: &StateVector<T, F>, mu: T) -> Result<Self, OrbitError> {
+        let (r, v) = (state.position.data, state.velocity.data);
+        let h = r.cross(v);
+        let h_norm = h.norm();
+        if h_norm.is_nan() || h_norm <= tolerance::<T>() * r.norm() * v.norm() {
+            return Err(OrbitError::Rectilinear);
+        }
+        let [wx, wy, wz] = (h / h_norm).data;
+        let (eh, ek) = (-wy / (T::one() + wz), wx / (T::one() + wz));
+        let (f_hat, g_hat) = equinoctial_basis(eh, ek);
+        let e_vec = state.eccentricity_vector(mu).data;
+        Ok(Self {
+            p: h_norm * h_norm / mu,
+            f: e_vec.dot(&f_hat),
+            g: e_vec.dot(&g_hat),
+            h: eh,
+            k: ek,
+            l: wrap_two_pi(r.dot(&g_hat).atan2(r.dot(&f_hat))),
+        })
+    }
+
+    pub fn to_state<F>(&self, mu: T) -> StateVector<T, F> {
+        let (f_hat, g_hat) = equinoctial_basis(self.h, self.k);
+        let (sin_l, cos_l) = self.l.sin_cos();
+        let r = self.p / (T::one() + self.f * cos_l + self.g * sin_l);
+        let speed = (mu / self.p).sqrt();
+        StateVector::new(
+            Cartesian::from_vector(f_hat * (r * cos_l) + g_hat * (r * sin_l)),
+            Cartesian::from_vector(f_hat * (-speed * (self.g + sin_l)) + g_hat * (speed * (self.f + cos_l))),
+        )
+    }
+}
+
+/// In-plane axes of the equinoctial frame for `h = tan(i/2) cos(raan)` and
+/// `k = tan(i/2) sin(raan)`.
+fn equinoctial_basis<T: Float>(h: T, k: T) -> (Vector<T, 3>, Vector<T, 3>) {
+    let (one, two) = (T::one(), T::one() + T::one());
+    let s2 = one + h * h + k * k;
+    (
+        Vector::new([one - k * k + h * h, two * h * k, -two * k]) / s2,
+        Vector::new([two * h * k, one + k * k - h * h, two * h]) / s2,
+    )
+}
@@ -0,0 +1,218 @@
+//! Kepler's equation and conversions between anomalies.
+//!
+//! Near `e = 1` and periapsis, `E - e sin E` and `e sinh H - H` are the
+//! difference of nearly equal terms. They are evaluated as
+//! `(1 - e) sin E + (E - sin E)` and `(e - 1) sinh H + (sinh H - H)` with the
+//! bracketed differences summed as series, which keeps full relative precision
+//! in the mean anomaly. The solvers start from the root of the cubic
+//! approximation there and refine with Halley's method.
+
+use num_traits::Float;
+
+const MAX_ITERATIONS: usize = 50;
+
+/// Below this argument the series are used for `x - sin x` and `sinh x - x`.
+const SERIES_LIMIT: f64 = 1.0;
+
+fn k<T: Float>(x: f64) -> T {
+    T::from(x).unwrap()
+}
+
+fn converged<T: Float>(step: T, x: T) -> bool {
+    step.abs() <= T::epsilon() * k::<T>(8.0) * x.abs()
+}
+
+/// Multiple of `2pi` nearest to `x`, exactly zero in `[-pi, pi)`. Unlike
+/// [`crate::utils::wrap_pi`] this does not round small angles.
+pub(super) fn whole_revolutions<T: Float>(x: T) -> T {
+    let two_pi = k::<T>(core::f64::consts::TAU);
+    two_pi * ((x + k(core::f64::consts::PI)) / two_pi).floor()
+}
+
+/// `x - sin x` (`sign = -1`) or `sinh x - x` (`sign = 1`) by its Taylor series.
+fn cubic_series<T: Float>(x: T, sign: T) -> T {
+    let x2 = x * x;
+    let mut term = x * x2 / k(6.0);
+    let mut sum = term;
+    let mut n = 3.0;
+    while term.abs() > T::epsilon() * sum.abs() {
+        term = term * sign * x2 / k((n + 1.0) * (n + 2.0));
+        sum = sum + term;
+        n += 2.0;
+    }
+    sum
+}
+
+/// Real root of `x^3 + 6 c x - 6 m = 0`, the cubic approximation of Kepler's
+/// equation near periapsis with `c = |1 - e|`.
+fn cubic_root<T: Float>(m: T, c: T) -> T {
+    let s = (k::<T>(9.0) * m * m + k::<T>(8.0) * c * c * c).sqrt();
+    let three_m = k::<T>(3.0) * m;
+    (three_m + s).cbrt() + (three_m - s).cbrt()
+}
+
+/// Mean anomaly `E - e sin E` of an ellipse, accurate near `e = 1`.
+pub fn elliptic_mean_anomaly<T: Float>(big_e: T, e: T) -> T {
+    if big_e.abs() < k(SERIES_LIMIT) {
+        (T::one() - e) * big_e.sin() + cubic_series(big_e, -T::one())
+    } else {
+        big_e - e * big_e.sin()
+    }
+}
+
+/// Mean anomaly `e sinh H - H` of a hyperbola, accurate near `e = 1`.
+pub fn hyperbolic_mean_anomaly<T: Float>(h: T, e: T) -> T {
+    if h.abs() < k(SERIES_LIMIT) {
+        (e - T::one()) * h.sinh() + cubic_series(h, T::one())
+    } else {
+        e * h.sinh() - h
+    }
+}
+
+/// Solves `M = E - e sin E` for the eccentric anomaly, `0 <= e < 1`. Whole
+/// revolutions in `m` are carried over to the result.
+pub fn solve_kepler_elliptic<T: Float>(m: T, e: T) -> T {
+    let revolutions = whole_revolutions(m);
+    let reduced = m - revolutions;
+    let (m, sign) = (reduced.abs(), T::one().copysign(reduced));
+    if m == T::zero() {
+        return revolutions;
+    }
+
+    let mut big_e = if e > k(0.5) && m < k(SERIES_LIMIT) {
+        cubic_root(m, T::one() - e)
+    } else {
+        m + k::<T>(0.85) * e
+    }
+    .min(k(core::f64::consts::PI));
+    for _ in 0..MAX_ITERATIONS {
+        let f = elliptic_mean_anomaly(big_e, e) - m;
+        // 1 - e cos E without cancellation near periapsis
+        let half = (big_e / k(2.0)).sin();
+        let df = (T::one() - e) + k::<T>(2.0) * e * half * half;
+        let step = f / (df - f * e * big_e.sin() / (k::<T>(2.0) * df));
+        big_e = (big_e - step).max(T::zero());
+        if converged(step, big_e) {
+            break;
+        }
+    }
+    sign * big_e + revolutions
+}
+
+/// Solves `M = e sinh H - H` for the hyperbolic anomaly, `e > 1`.
+pub fn solve_kepler_hyperbolic<T: Float>(m: T, e: T) -> T {
+    let (m, sign) = (m.abs(), T::one().copysign(m));
+    if m == T::zero() {
+        return T::zero();
+    }
+    let mut h = if m < k(SERIES_LIMIT) {
+        cubic_root(m, e - T::one()).min((m / (e - T::one())).asinh())
+    } else {
+        (k::<T>(2.0) * m / e + k(1.8)).ln()
+    };
+    for _ in 0..MAX_ITERATIONS {
+        let f = hyperbolic_mean_anomaly(h, e) - m;
+        let half = (h / k(2.0)).sinh();
+        let df = (e - T::one()) + k::<T>(2.0) * e * half * half;
+        let step = f / (df - f * e * h.sinh() / (k::<T>(2.0) * df));
+        h = (h - step).max(T::zero());
+        if converged(step, h) {
+            break;
+        }
+    }
+    sign * h
+}
+
+/// Solves Barker's equation `M = D + D^3/3` for the parabolic anomaly
+/// `D = tan(nu/2)`, in closed form.
+pub fn solve_barker<T: Float>(m: T) -> T {
+    k::<T>(2.0) * ((k::<T>(1.5) * m).asinh() / k(3.0)).sinh()
+}
+
+/// Stumpff functions `c2(z) = (1 - cos sqrt(z))/z` and
+/// `c3(z) = (sqrt(z) - sin sqrt(z))/sqrt(z)^3`, continued to `z <= 0`.
+pub fn stumpff<T: Float>(z: T) -> (T, T) {
+    if z.abs() < k(SERIES_LIMIT) {
+        // c2 = sum (-z)^n / (2n + 2)!, c3 = sum (-z)^n / (2n + 3)!
+        let (mut c2, mut c3) = (T::zero(), T::zero());
+        let (mut t2, mut t3) = (k::<T>(0.5), k::<T>(1.0 / 6.0));
+        let mut n = 0.0;
+        while t2.abs() > T::epsilon() * c2.abs() {
+            c2 = c2 + t2;
+            c3 = c3 + t3;
+            t2 = -t2 * z / k((2.0 * n + 3.0) * (2.0 * n + 4.0));
+            t3 = -t3 * z / k((2.0 * n + 4.0) * (2.0 * n + 5.0));
+            n += 1.0;
+        }
+        (c2, c3)
+    } else if z > T::zero() {
+        let s = z.sqrt();
+        ((T::one() - s.cos()) / z, (s - s.sin()) / (s * z))
+    } else {
+        let s = (-z).sqrt();
+        ((T::one() - s.cosh()) / z, (s.sinh() - s) / (-s * z))
+    }
+}
+
+/// Eccentric anomaly `E` of an ellipse, hyperbolic anomaly `H` of a hyperbola
+/// or parabolic anomaly `D = tan(nu/2)` for true anomaly `nu`.
+pub fn true_to_eccentric<T: Float>(nu: T, e: T) -> T {
+    let one = T::one();
+    let (sin_nu, cos_nu) = nu.sin_cos();
+    if e < one {
+        let big_e = (((one - e) * (one + e)).sqrt() * sin_nu).atan2(e + cos_nu);
+        big_e + whole_revolutions(nu)
+    } else if e > one {
+        (((e - one) * (e + one)).sqrt() * sin_nu / (one + e * cos_nu)).asinh()
+    } else {
+        (nu / k(2.0)).tan()
+    }
+}
+
+/// True anomaly for the eccentric, hyperbolic or parabolic anomaly `x`.
+pub fn eccentric_to_true<T: Float>(x: T, e: T) -> T {
+    let one = T::one();
+    if e < one {
+        // cos E - e and e - cosh H without cancellation near periapsis
+        let half = (x / k(2.0)).sin();
+        let nu = (((one - e) * (one + e)).sqrt() * x.sin()).atan2((one - e) - k::<T>(2.0) * half * half);
+        nu + whole_revolutions(x)
+    } else if e > one {
+        let half = (x / k(2.0)).sinh();
+        (((e - one) * (e + one)).sqrt() * x.sinh()).atan2((e - one) - k::<T>(2.0) * half * half)
+    } else {
+        k::<T>(2.0) * x.atan()
+    }
+}
+
+/// Mean anomaly for the eccentric, hyperbolic or parabolic anomaly `x`.
+pub fn eccentric_to_mean<T: Float>(x: T, e: T) -> T {
+    let one = T::one();
+    if e < one {
+        elliptic_mean_anomaly(x, e)
+    } else if e > one {
+        hyperbolic_mean_anomaly(x, e)
+    } else {
+        x + x * x * x / k(3.0)
+    }
+}
+
+/// Eccentric, hyperbolic or parabolic anomaly for the mean anomaly `m`.
+pub fn mean_to_eccentric<T: Float>(m: T, e: T) -> T {
+    let one = T::one();
+    if e < one {
+        solve_kepler_elliptic(m, e)
+    } else if e > one {
+        solve_kepler_hyperbolic(m, e)
+    } else {
+        solve_barker(m)
+    }
+}
+
+pub fn true_to_mean<T: Float>(nu: T, e: T) -> T {
+    eccentric_to_mean(true_to_eccentric(nu, e), e)
+}
+
+pub fn mean_to_true<T: Float>(m: T, e: T) -> T {
+    eccentric_to_true(mean_to_eccentric(m, e), e)
+}
@@ -0,0 +1,214 @@
+use num_traits::Float;
+
+use super::kepler::{eccentric_to_mean, eccentric_to_true, mean_to_eccentric, true_to_eccentric, whole_revolutions};
+use super::{tolerance, OrbitError, StateVector};
+use crate::coordinate::Cartesian;
+use crate::math::Vector;
+use crate::utils::wrap_two_pi;
+
+/// Position of a body along its orbit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Anomaly<T> {
+    True(T),
+    /// Eccentric anomaly `E` of an ellipse, hyperbolic anomaly `H` of a
+    /// hyperbola or parabolic anomaly `D = tan(nu/2)`
+    Eccentric(T),
+    /// Mean anomaly: `E - e sin E`, `e sinh H - H` or Barker's `D + D^3/3`
+    Mean(T),
+}
+
+/// Classical orbital elements: semi-major axis `a`, eccentricity `e`,
+/// inclination `i`, right ascension of the ascending node, argument of
+/// periapsis and true anomaly.
+///
+/// The orbit size is kept as the semi-latus rectum `p = a (1 - e^2)`, which is
+/// finite for parabolas. The true anomaly lies in `[0, 2pi)` for ellipses and
+/// between the asymptotes for open orbits.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KeplerianElements<T: Float> {
+    p: T,
+    e: T,
+    i: T,
+    raan: T,
+    argp: T,
+    nu: T,
+}
+
+impl<T: Float> KeplerianElements<T> {
+    /// Elements of an ellipse (`0 <= e < 1`, `a > 0`) or a hyperbola (`e > 1`,
+    /// `a < 0`).
+    pub fn new(a: T, e: T, i: T, raan: T, argp: T, anomaly: Anomaly<T>) -> Result<Self, OrbitError> {
+        if e.is_nan() || e < T::zero() {
+            return Err(OrbitError::InvalidEccentricity);
+        }
+        if e == T::one() {
+            return Err(OrbitError::Parabolic);
+        }
+        if (e < T::one()) != (a > T::zero()) || !a.is_finite() {
+            return Err(OrbitError::InvalidSize);
+        }
+        Self::with_anomaly(a * (T::one() - e) * (T::one() + e), e, i, raan, argp, anomaly)
+    }
+
+    /// Elements of a parabola with semi-latus rectum `p`, twice the periapsis
+    /// radius.
+    pub fn parabolic(p: T, i: T, raan: T, argp: T, anomaly: Anomaly<T>) -> Result<Self, OrbitError> {
+        if p.is_nan() || p <= T::zero() {
+            return Err(OrbitError::InvalidSize);
+        }
+        Self::with_anomaly(p, T::one(), i, raan, argp, anomaly)
+    }
+
+    fn with_anomaly(p: T, e: T, i: T, raan: T, argp: T, anomaly: Anomaly<T>) -> Result<Self, OrbitError> {
+        let nu = match anomaly {
+            Anomaly::True(nu) => {
+                if T::one() + e * nu.cos() <= T::zero() {
+                    return Err(OrbitError::InvalidAnomaly);
+                }
+                nu
+            }
+            Anomaly::Eccentric(x) => eccentric_to_true(x, e),
+            Anomaly::Mean(m) => eccentric_to_true(mean_to_eccentric(m, e), e),
+        };
+        let nu = if e < T::one() { wrap_two_pi(nu) } else { nu - whole_revolutions(nu) };
+        Ok(Self { p, e, i, raan: wrap_two_pi(raan), argp: wrap_two_pi(argp), nu })
+    }
+
+    /// Elements of the orbit through `state` about a body with gravitational
+    /// parameter `mu`.
+    pub fn from_state<F>(state: &StateVector<T, F>, mu: T) -> Result<Self, OrbitError> {
+        let tol = tolerance::<T>();
+        let (r, v) = (state.position.data, state.velocity.data);
+        let h = r.cross(v);
+        let h_norm = h.norm();
+        if h_norm.is_nan() || h_norm <= tol * r.norm() * v.norm() {
+            return Err(OrbitError::Rectilinear);
+        }
+        let h_hat = h / h_norm;
+        // In-plane angle from `a` to `b`, positive in the direction of motion
+        let angle = |a: Vector<T, 3>, b: Vector<T, 3>| a.cross(b).dot(&h_hat).atan2(a.dot(&b));
+
+        let [hx, hy, hz] = h.data;
+        let node = (hx * hx + hy * hy).sqrt();
+        let i = node.atan2(hz);
+        let (raan, node_hat) = if node > tol * h_norm {
+            (hx.atan2(-hy), Vector::new([-hy / node, hx / node, T::zero()]))
+        } else {
+            (T::zero(), Vector::new([T::one(), T::zero(), T::zero()]))
+        };
+
+        let e_vec = state.eccentricity_vector(mu).data;
+        let mut e = e_vec.norm();
+        let (argp, nu) = if e > tol {
+            (angle(node_hat, e_vec), angle(e_vec, r))
+        } else {
+            e = T::zero();
+            (T::zero(), angle(node_hat, r))
+        };
+        if (e - T::one()).abs() <= tol {
+            e = T::one();
+        }
+        let nu = if e < T::one() { wrap_two_pi(nu) } else { nu - whole_revolutions(nu) };
+        Ok(Self { p: h_norm * h_norm / mu, e, i, raan: wrap_two_pi(raan), argp: wrap_two_pi(argp), nu })
+    }
+
+    /// Position and velocity in the inertial frame of the elements.
+    pub fn to_state<F>(&self, mu: T) -> StateVector<T, F> {
+        let (sin_raan, cos_raan) = self.raan.sin_cos();
+        let (sin_argp, cos_argp) = self.argp.sin_cos();
+        let (sin_i, cos_i) = self.i.sin_cos();
+        let (sin_nu, cos_nu) = self.nu.sin_cos();
+        // Perifocal axes: towards periapsis and 90 degrees ahead of it
+        let p_hat = Vector::new([
+            cos_raan * cos_argp - sin_raan * sin_argp * cos_i,
+            sin_raan * cos_argp + cos_raan * sin_argp * cos_i,
+            sin_argp * sin_i,
+        ]);
+        let q_hat = Vector::new([
+            -cos_raan * sin_argp - sin_raan * cos_argp * cos_i,
+            -sin_raan * sin_argp + cos_raan * cos_argp * cos_i,
+            cos_argp * sin_i,
+        ]);
+        let r = self.p / (T::one() + self.e * cos_nu);
+        let speed = (mu / self.p).sqrt();
+        StateVector::new(
+            Cartesian::from_vector(p_hat * (r * cos_nu) + q_hat * (r * sin_nu)),
+            Cartesian::from_vector(p_hat * (-speed * sin_nu) + q_hat * (speed * (self.e + cos_nu))),
+        )
+    }
+
+    /// Semi-major axis: negative for hyperbolas and infinite for parabolas.
+    pub fn a(&self) -> T {
+        self.p / ((T::one() - self.e) * (T::one() + self.e))
+    }
+    /// Eccentricity.
+    pub fn e(&self) -> T { self.e }
+    /// Inclination in `[0, pi]`.
+    pub fn i(&self) -> T { self.i }
+    /// Right ascension of the ascending node.
+    pub fn raan(&self) -> T { self.raan }
+    /// Argument of periapsis.
+    pub fn argp(&self) -> T { self.argp }
+    /// Semi-latus rectum.
+    pub fn p(&self) -> T { self.p }
+    pub fn true_anomaly(&self) -> T { self.nu }
+
+    /// Eccentric, hyperbolic or parabolic anomaly.
+    pub fn eccentric_anomaly(&self) -> T {
+        true_to_eccentric(self.nu, self.e)
+    }
+
+    pub fn mean_anomaly(&self) -> T {
+        eccentric_to_mean(self.eccentric_anomaly(), self.e)
+    }
+
+    /// Angle from the ascending node to the body.
+    pub fn argument_of_latitude(&self) -> T {
+        wrap_two_pi(self.argp + self.nu)
+    }
+
+    /// Longitude of periapsis, `raan + argp`.
+    pub fn longitude_of_periapsis(&self) -> T {
+        wrap_two_pi(self.raan + self.argp)
+    }
+
+    /// True longitude, `raan + argp + nu`.
+    pub fn true_longitude(&self) -> T {
+        wrap_two_pi(self.raan + self.argp + self.nu)
+    }
+
+    pub fn periapsis(&self) -> T {
+        self.p / (T::one() + self.e)
+    }
+
+    /// Apoapsis radius, infinite for open orbits.
+    pub fn apoapsis(&self) -> T {
+        if self.e < T::one() { self.p / (T::one() - self.e) } else { T::infinity() }
+    }
+
+    /// Mean motion, the rate of the mean anomaly.
+    pub fn mean_motion(&self, mu: T) -> T {
+        if self.e == T::one() {
+            (T::one() + T::one()) * (mu / self.p.powi(3)).sqrt()
+        } else {
+            (mu / self.a().abs().powi(3)).sqrt()
+        }
+    }
+
+    /// The elements after a time `dt` of unperturbed motion, advancing the
+    /// mean anomaly.
+    pub fn propagate(&self, dt: T, mu: T) -> Self {
+        let m = self.mean_anomaly() + self.mean_motion(mu) * dt;
+        let nu = eccentric_to_true(mean_to_eccentric(m, self.e), self.e);
+        Self { nu: if self.e < T::one() { wrap_two_pi(nu) } else { nu - whole_revolutions(nu) }, ..*self }
+    }
+
+    /// Orbital period, infinite for open orbits.
+    pub fn period(&self, mu: T) -> T {
+        if self.e < T::one() {
+            T::from(core::f64::consts::TAU).unwrap() / self.mean_motion(mu)
+        } else {
+            T::infinity()
+        }
+    }
+}
@@ -0,0 +1,301 @@
+use num_traits::Float;
+use std::vec::Vec;
+
+use super::{Geometry, LambertBranch, LambertError, LambertSolution, TransferDirection};
+use crate::math::Vector;
+
+const MAX_ITERATIONS: usize = 12;
+
+fn k<T: Float>(x: f64) -> T {
+    T::from(x).unwrap()
+}
+
+/// Solves Lambert's problem from `r1` to `r2` in time `tof` about a body with
+/// gravitational parameter `mu` with Gooding's method, returning every
+/// solution up to `max_revolutions` complete revolutions.
+pub fn gooding<T: Float>(
+    r1: &Vector<T, 3>,
+    r2: &Vector<T, 3>,
+    tof: T,
+    mu: T,
+    direction: TransferDirection,
+    max_revolutions: usize,
+) -> Result<Vec<LambertSolution<T>>, LambertError> {
+    let geometry = Geometry::new(r1, r2, tof, direction)?;
+    let q = geometry.lambda;
+    let qsqfm1 = geometry.c / geometry.s;
+    let gms = (mu * geometry.s / k(2.0)).sqrt();
+    let t = k::<T>(4.0) * gms * tof / (geometry.s * geometry.s);
+    let rho = (geometry.r1 - geometry.r2) / geometry.c;
+    let sigma = (T::one() - rho * rho).max(T::zero()).sqrt();
+
+    let mut solutions = Vec::new();
+    for m in 0..=max_revolutions {
+        let roots = xlamb(m, q, qsqfm1, t)?;
+        if roots.is_empty() {
+            break;
+        }
+        for (branch, x) in roots {
+            let TimeOfFlight { dt: qzminx, d2t: qzplx, d3t: zplqx, .. } = tlamb(m, q, qsqfm1, x, -1);
+            let vt = gms * zplqx * sigma;
+            let vr1 = gms * (qzminx - qzplx * rho) / geometry.r1;
+            let vr2 = -gms * (qzminx + qzplx * rho) / geometry.r2;
+            solutions.push(geometry.solution((vr1, vr2), (vt / geometry.r1, vt / geometry.r2), m, branch));
+        }
+    }
+    Ok(solutions)
+}
+
+/// Gooding's non-dimensional time of flight and its first three derivatives
+/// with respect to `x`.
+struct TimeOfFlight<T> {
+    t: T,
+    dt: T,
+    d2t: T,
+    d3t: T,
+}
+
+/// Time of flight at `x` for `m` revolutions with derivatives up to order
+/// `n` (Gooding's `TLAMB`). With `n = -1` the derivative slots hold instead
+/// `q z - x`, `q z + x` and `z + q x`, computed without cancellation, for the
+/// velocities.
+fn tlamb<T: Float>(m: usize, q: T, qsqfm1: T, x: T, n: i32) -> TimeOfFlight<T> {
+    let (zero, one, two) = (T::zero(), T::one(), k::<T>(2.0));
+    let sw = k::<T>(0.4);
+    let velocities = n == -1;
+    let (l1, l2, l3) = (n >= 1, n >= 2, n == 3);
+    let qsq = q * q;
+    let xsq = x * x;
+    let u = (one - x) * (one + x);
+    let mut out = TimeOfFlight { t: zero, dt: zero, d2t: zero, d3t: zero };
+
+    if velocities || m > 0 || x < zero || u.abs() > sw {
+        // Direct computation
+        let y = u.abs().sqrt();
+        let z = (qsqfm1 + qsq * xsq).sqrt();
+        let qx = q * x;
+        let (mut a, mut b, mut aa, mut bb) = (zero, zero, zero, zero);
+        if qx <= zero {
+            a = z - qx;
+            b = q * z - x;
+        }
+        if qx < zero && velocities {
+            aa = qsqfm1 / a;
+            bb = qsqfm1 * (qsq * u - xsq) / b;
+        }
+        if (qx == zero && velocities) || qx > zero {
+            aa = z + qx;
+            bb = q * z + x;
+        }
+        if qx > zero {
+            a = qsqfm1 / aa;
+            b = qsqfm1 * (qsq * u - xsq) / bb;
+        }
+        if velocities {
+            out.dt = b;
+            out.d2t = bb;
+            out.d3t = aa;
+            return out;
+        }
+        let g = if qx * u >= zero { x * z + q * u } else { (xsq - qsq * u) / (x * z - q * u) };
+        let f = a * y;
+        let t = if x <= one {
+            T::from(m).unwrap() * k(core::f64::consts::PI) + f.atan2(g)
+        } else if f > sw {
+            (f + g).ln()
+        } else {
+            // ln((1 + f/(g+1)) / (1 - f/(g+1))) as a series, for small f
+            let fg1 = f / (g + one);
+            let fg1sq = fg1 * fg1;
+            let mut term = two * fg1;
+            let (mut t, mut told) = (term, zero);
+            let mut twoi1 = one;
+            while t != told {
+                twoi1 = twoi1 + two;
+                term = term * fg1sq;
+                told = t;
+                t = t + term / twoi1;
+            }
+            t
+        };
+        out.t = two * (t / y + b) / u;
+        if l1 && z != zero {
+            let qz = q / z;
+            let qz2 = qz * qz;
+            let qz3 = qz * qz2;
+            out.dt = (k::<T>(3.0) * x * out.t - k::<T>(4.0) * (a + qx * qsqfm1) / z) / u;
+            if l2 {
+                out.d2t = (k::<T>(3.0) * out.t + k::<T>(5.0) * x * out.dt + k::<T>(4.0) * qz3 * qsqfm1) / u;
+            }
+            if l3 {
+                out.d3t = (k::<T>(8.0) * out.dt + k::<T>(7.0) * x * out.d2t - k::<T>(12.0) * qz3 * qz2 * x * qsqfm1) / u;
+            }
+        }
+    } else {
+        // Series in u about the parabola, single revolution only
+        let (mut u0i, mut u1i, mut u2i, mut u3i) = (one, one, one, one);
+        let mut term = k::<T>(4.0);
+        let mut tq = q * qsqfm1;
+        let mut tqsum = if q < k(0.5) { one - q * qsq } else { (one / (one + q) + q) * qsqfm1 };
+        let mut ttmold = term / k(3.0);
+        let mut t = ttmold * tqsum;
+        let mut i = 0;
+        loop {
+            i += 1;
+            let p = T::from(i).unwrap();
+            u0i = u0i * u;
+            if l1 && i > 1 {
+                u1i = u1i * u;
+            }
+            if l2 && i > 2 {
+                u2i = u2i * u;
+            }
+            if l3 && i > 3 {
+                u3i = u3i * u;
+            }
+            term = term * (p - k(0.5)) / p;
+            tq = tq * qsq;
+            tqsum = tqsum + tq;
+            let told = t;
+            let tterm = term / (two * p + k(3.0));
+            let tqterm = tterm * tqsum;
+            t = t - u0i * ((k::<T>(1.5) * p + k(0.25)) * tqterm / (p * p - k(0.25)) - ttmold * tq);
+            ttmold = tterm;
+            let tqterm = tqterm * p;
+            if l1 {
+                out.dt = out.dt + tqterm * u1i;
+            }
+            if l2 {
+                out.d2t = out.d2t + tqterm * u2i * (p - one);
+            }
+            if l3 {
+                out.d3t = out.d3t + tqterm * u3i * (p - one) * (p - two);
+            }
+            if i >= n && t == told {
+                break;
+            }
+        }
+        if l3 {
+            out.d3t = k::<T>(8.0) * x * (k::<T>(1.5) * out.d2t - xsq * out.d3t);
+        }
+        if l2 {
+            out.d2t = two * (two * xsq * out.d2t - out.dt);
+        }
+        if l1 {
+            out.dt = -two * x * out.dt;
+        }
+        out.t = t / xsq;
+    }
+    out
+}
+
+/// Eighth root.
+fn d8rt<T: Float>(x: T) -> T {
+    x.sqrt().sqrt().sqrt()
+}
+
+/// The roots `x` of `T(x) = tin` with `m` revolutions, from Gooding's
+/// starters refined by Halley iterations (Gooding's `XLAMB`). Empty when the
+/// time of flight is below the minimum for `m` revolutions.
+fn xlamb<T: Float>(m: usize, q: T, qsqfm1: T, tin: T) -> Result<Vec<(LambertBranch, T)>, LambertError> {
+    let (zero, one, two) = (T::zero(), T::one(), k::<T>(2.0));
+    let pi = k::<T>(core::f64::consts::PI);
+    let (c0, c1, c2, c3, c41, c42) = (k::<T>(1.7), k::<T>(0.5), k::<T>(0.03), k::<T>(0.15), one, k::<T>(0.24));
+    let thr2 = qsqfm1.atan2(two * q) / pi;
+    let mf = T::from(m).unwrap();
+
+    // Bilinear starter from the time of flight at x = 0
+    let from_zero = |t0: T, tdiff: T, c: T| -> T {
+        let mut x = -tdiff / (tdiff + k(4.0));
+        let w = x + c0 * (two * (one - thr2)).sqrt();
+        if w < zero {
+            x = x - d8rt(-w).sqrt() * (x + (tdiff / (tdiff + k::<T>(1.5) * t0)).sqrt());
+        }
+        let w = k::<T>(4.0) / (k::<T>(4.0) + tdiff);
+        x * (one + c * x * (c1 * w - c2 * x * w.sqrt()))
+    };
+
+    if m == 0 {
+        let t0 = tlamb(m, q, qsqfm1, zero, 0).t;
+        let tdiff = tin - t0;
+        let x = if tdiff <= zero { t0 * tdiff / (k::<T>(-4.0) * tin) } else { from_zero(t0, tdiff, one) };
+        return Ok(vec![(LambertBranch::Single, halley(m, q, qsqfm1, tin, x)?)]);
+    }
+
+    // Halley iterations for the minimum time of flight, where dT/dx = 0
+    let mut xm = one / (k::<T>(1.5) * (mf + k(0.5)) * pi);
+    if thr2 < k(0.5) {
+        xm = d8rt(two * thr2) * xm;
+    } else if thr2 > k(0.5) {
+        xm = (two - d8rt(two - two * thr2)) * xm;
+    }
+    let mut minimum = None;
+    for _ in 0..MAX_ITERATIONS {
+        let tof = tlamb(m, q, qsqfm1, xm, 3);
+        if tof.d2t == zero {
+            minimum = Some(tof);
+            break;
+        }
+        let xmold = xm;
+        xm = xm - tof.dt * tof.d2t / (tof.d2t * tof.d2t - tof.dt * tof.d3t / two);
+        if (xmold / xm - one).abs() <= k(3e-7) {
+            minimum = Some(tof);
+            break;
+        }
+    }
+    let TimeOfFlight { t: tmin, mut d2t, .. } = minimum.ok_or(LambertError::NotConverged)?;
+    let tdiffm = tin - tmin;
+    if tdiffm < zero {
+        return Ok(Vec::new());
+    }
+    if tdiffm == zero {
+        return Ok(vec![(LambertBranch::Right, xm)]);
+    }
+    if d2t == zero {
+        d2t = k::<T>(6.0) * mf * pi;
+    }
+    let d2t2 = d2t / two;
+    let mut roots = Vec::with_capacity(2);
+
+    // Starter for the left branch from the time of flight at x = 0
+    let t0 = tlamb(m, q, qsqfm1, zero, 0).t;
+    let tdiff = tin - t0;
+    let x = if tdiff <= zero {
+        xm - (tdiffm / (d2t2 - tdiffm * (d2t2 / (t0 - tmin) - one / (xm * xm)))).sqrt()
+    } else {
+        from_zero(t0, tdiff, (one + mf + c42 * (thr2 - k(0.5))) / (one + c3 * mf))
+    };
+    if x > -one {
+        roots.push((LambertBranch::Left, halley(m, q, qsqfm1, tin, x)?));
+    }
+
+    // Starter for the right branch from the parabola about the minimum
+    let x = (tdiffm / (d2t2 + tdiffm / ((one - xm) * (one - xm)))).sqrt();
+    let w = xm + x;
+    let w = w * k(4.0) / (k::<T>(4.0) + tdiffm) + (one - w) * (one - w);
+    let x = x * (one - (one + mf + c41 * (thr2 - k(0.5))) / (one + c3 * mf) * x * (c1 * w + c2 * x * w.sqrt())) + xm;
+    if x < one {
+        roots.push((LambertBranch::Right, halley(m, q, qsqfm1, tin, x)?));
+    }
+    Ok(roots)
+}
+
+/// Halley iterations on `T(x) = tin`.
+fn halley<T: Float>(m: usize, q: T, qsqfm1: T, tin: T, mut x: T) -> Result<T, LambertError> {
+    for _ in 0..MAX_ITERATIONS {
+        let tof = tlamb(m, q, qsqfm1, x, 2);
+        let t = tin - tof.t;
+        if tof.dt == T::zero() {
+            return Ok(x);
+        }
+        let step = t * tof.dt / (tof.dt * tof.dt + t * tof.d2t / k(2.0));
+        x = x + step;
+        if step.abs() <= k::<T>(1e-13) * (T::one() + x.abs()) {
+            return Ok(x);
+        }
+        if !x.is_finite() {
+            break;
+        }
+    }
+    Err(LambertError::NotConverged)
+}
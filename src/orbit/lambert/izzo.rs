@@ -0,0 +1,180 @@
+use num_traits::Float;
+use std::vec::Vec;
+
+use super::{Geometry, LambertBranch, LambertError, LambertSolution, TransferDirection};
+use crate::math::Vector;
+
+const MAX_ITERATIONS: usize = 15;
+
+fn k<T: Float>(x: f64) -> T {
+    T::from(x).unwrap()
+}
+
+/// Solves Lambert's problem from `r1` to `r2` in time `tof` about a body with
+/// gravitational parameter `mu` with Izzo's algorithm, returning every
+/// solution up to `max_revolutions` complete revolutions.
+pub fn izzo<T: Float>(
+    r1: &Vector<T, 3>,
+    r2: &Vector<T, 3>,
+    tof: T,
+    mu: T,
+    direction: TransferDirection,
+    max_revolutions: usize,
+) -> Result<Vec<LambertSolution<T>>, LambertError> {
+    let geometry = Geometry::new(r1, r2, tof, direction)?;
+    let lambda = geometry.lambda;
+    let t = (k::<T>(2.0) * mu / geometry.s.powi(3)).sqrt() * tof;
+
+    let gamma = (mu * geometry.s / k(2.0)).sqrt();
+    let rho = (geometry.r1 - geometry.r2) / geometry.c;
+    let sigma = (T::one() - rho * rho).max(T::zero()).sqrt();
+    find_x(lambda, t, max_revolutions)?
+        .into_iter()
+        .map(|(revolutions, branch, x)| {
+            let y = (T::one() - lambda * lambda + lambda * lambda * x * x).sqrt();
+            let (ly_minus_x, ly_plus_x) = (lambda * y - x, lambda * y + x);
+            let vr1 = gamma * (ly_minus_x - rho * ly_plus_x) / geometry.r1;
+            let vr2 = -gamma * (ly_minus_x + rho * ly_plus_x) / geometry.r2;
+            let vt = gamma * sigma * (y + lambda * x);
+            Ok(geometry.solution((vr1, vr2), (vt / geometry.r1, vt / geometry.r2), revolutions, branch))
+        })
+        .collect()
+}
+
+/// Every root `x` of `T(x) = t`, tagged with its revolutions and branch.
+fn find_x<T: Float>(lambda: T, t: T, max_revolutions: usize) -> Result<Vec<(usize, LambertBranch, T)>, LambertError> {
+    let pi = k::<T>(core::f64::consts::PI);
+    let one = T::one();
+    let revolutions = |n: usize| T::from(n).unwrap();
+
+    // Largest number of revolutions the time of flight allows
+    let mut m_max = (t / pi).floor().to_usize().unwrap_or(0);
+    let t00 = lambda.acos() + lambda * (one - lambda * lambda).sqrt();
+    let t0 = t00 + revolutions(m_max) * pi;
+    let t1 = k::<T>(2.0 / 3.0) * (one - lambda.powi(3));
+    if t < t0 && m_max > 0 {
+        // Halley iterations for the minimum time of flight with m_max revolutions
+        let (mut x, mut t_min) = (T::zero(), t0);
+        for _ in 0..12 {
+            let (dt, ddt, dddt) = derivatives(lambda, x, t_min);
+            let x_new = if dt == T::zero() { x } else { x - dt * ddt / (ddt * ddt - dt * dddt / k(2.0)) };
+            let step = (x - x_new).abs();
+            x = x_new;
+            t_min = time_of_flight(lambda, x, revolutions(m_max));
+            if step < k(1e-13) {
+                break;
+            }
+        }
+        if t_min > t {
+            m_max -= 1;
+        }
+    }
+    let m_max = m_max.min(max_revolutions);
+
+    let x0 = if t >= t00 {
+        -(t - t00) / (t - t00 + k(4.0))
+    } else if t <= t1 {
+        t1 * (t1 - t) / (k::<T>(0.4) * (one - lambda.powi(5)) * t) + one
+    } else {
+        (t / t00).powf(k::<T>(core::f64::consts::LN_2) / (t1 / t00).ln()) - one
+    };
+    let mut roots = Vec::with_capacity(1 + 2 * m_max);
+    roots.push((0, LambertBranch::Single, householder(lambda, t, x0, 0)?));
+    for n in 1..=m_max {
+        let m = revolutions(n);
+        let left = ((m * pi + pi) / (k::<T>(8.0) * t)).powf(k(2.0 / 3.0));
+        let right = ((k::<T>(8.0) * t) / (m * pi)).powf(k(2.0 / 3.0));
+        roots.push((n, LambertBranch::Left, householder(lambda, t, (left - one) / (left + one), n)?));
+        roots.push((n, LambertBranch::Right, householder(lambda, t, (right - one) / (right + one), n)?));
+    }
+    Ok(roots)
+}
+
+/// Householder's third-order iterations on `T(x) = t`.
+fn householder<T: Float>(lambda: T, t: T, mut x: T, revolutions: usize) -> Result<T, LambertError> {
+    let m = T::from(revolutions).unwrap();
+    for _ in 0..MAX_ITERATIONS {
+        let tof = time_of_flight(lambda, x, m);
+        let (dt, ddt, dddt) = derivatives(lambda, x, tof);
+        let delta = tof - t;
+        let dt2 = dt * dt;
+        let step = delta * (dt2 - delta * ddt / k(2.0)) / (dt * (dt2 - delta * ddt) + dddt * delta * delta / k(6.0));
+        x = x - step;
+        if step.abs() < k(1e-13) {
+            return Ok(x);
+        }
+        if !x.is_finite() {
+            break;
+        }
+    }
+    Err(LambertError::NotConverged)
+}
+
+/// First three derivatives of the time of flight at `x`, given `tof = T(x)`.
+fn derivatives<T: Float>(lambda: T, x: T, tof: T) -> (T, T, T) {
+    let (l2, l3) = (lambda * lambda, lambda.powi(3));
+    let umx2 = T::one() - x * x;
+    let y = (T::one() - l2 * umx2).sqrt();
+    let (y2, y3) = (y * y, y * y * y);
+    let dt = (k::<T>(3.0) * tof * x - k(2.0) + k::<T>(2.0) * l3 * x / y) / umx2;
+    let ddt = (k::<T>(3.0) * tof + k::<T>(5.0) * x * dt + k::<T>(2.0) * (T::one() - l2) * l3 / y3) / umx2;
+    let dddt = (k::<T>(7.0) * x * ddt + k::<T>(8.0) * dt - k::<T>(6.0) * (T::one() - l2) * l2 * l3 * x / y3 / y2) / umx2;
+    (dt, ddt, dddt)
+}
+
+/// Non-dimensional time of flight at `x` with `m` complete revolutions:
+/// Battin's series near the parabola, Lagrange's expression close to it and
+/// Lancaster's elsewhere.
+fn time_of_flight<T: Float>(lambda: T, x: T, m: T) -> T {
+    let one = T::one();
+    let pi = k::<T>(core::f64::consts::PI);
+    let distance = (x - one).abs();
+    if distance < k(0.2) && distance > k(0.01) {
+        return time_of_flight_lagrange(lambda, x, m);
+    }
+    let e = x * x - one;
+    let rho = e.abs();
+    let z = (one + lambda * lambda * e).sqrt();
+    if distance < k(0.01) {
+        let eta = z - lambda * x;
+        let s1 = (one - lambda - x * eta) / k(2.0);
+        let q = k::<T>(4.0 / 3.0) * hypergeometric(s1);
+        (eta.powi(3) * q + k::<T>(4.0) * lambda * eta) / k(2.0) + m * pi / rho.powf(k(1.5))
+    } else {
+        let y = rho.sqrt();
+        let g = x * z - lambda * e;
+        let d = if e < T::zero() {
+            m * pi + g.acos()
+        } else {
+            (y * (z - lambda * x) + g).ln()
+        };
+        (x - lambda * z - d / y) / e
+    }
+}
+
+fn time_of_flight_lagrange<T: Float>(lambda: T, x: T, m: T) -> T {
+    let one = T::one();
+    let two = k::<T>(2.0);
+    let a = one / (one - x * x);
+    if a > T::zero() {
+        let alpha = two * x.acos();
+        let beta = (two * (lambda * lambda / a).sqrt().asin()).copysign(lambda);
+        a * a.sqrt() * ((alpha - alpha.sin()) - (beta - beta.sin()) + two * k::<T>(core::f64::consts::PI) * m) / two
+    } else {
+        let alpha = two * x.acosh();
+        let beta = (two * (-lambda * lambda / a).sqrt().asinh()).copysign(lambda);
+        -a * (-a).sqrt() * ((beta - beta.sinh()) - (alpha - alpha.sinh())) / two
+    }
+}
+
+/// Gauss hypergeometric function `2F1(3, 1; 5/2; z)`.
+fn hypergeometric<T: Float>(z: T) -> T {
+    let (mut sum, mut term) = (T::one(), T::one());
+    let mut j = 0.0;
+    while term.abs() > k(1e-15) {
+        term = term * k::<T>((3.0 + j) * (1.0 + j) / (2.5 + j)) * z / k(j + 1.0);
+        sum = sum + term;
+        j += 1.0;
+    }
+    sum
+}
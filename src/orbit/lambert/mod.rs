@@ -0,0 +1,120 @@
+//! Lambert's problem: the conic arcs joining two positions in a given time.
+//!
+//! Both solvers work in the Lancaster-Blanchard variable `x` and return every
+//! solution up to the requested number of complete revolutions: the single
+//! zero-revolution arc, then for each number of revolutions `N` the left
+//! (`x` below the minimum time of flight) and right branches when the time of
+//! flight allows them.
+//!
+//! - [`izzo`] follows Izzo (2015), with Householder iterations on starters
+//!   from the asymptotic behaviour of the time of flight;
+//! - [`gooding`] follows Gooding (1990), with Halley iterations on his
+//!   bilinear starters. It is independent of the former and serves as a
+//!   cross-check.
+//!
+//! The direction of motion is relative to the `+z` axis of the frame:
+//! prograde transfers have angular momentum with a positive `z` component.
+
+mod gooding;
+mod izzo;
+pub use gooding::gooding;
+pub use izzo::izzo;
+
+use num_traits::Float;
+
+use crate::math::Vector;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferDirection {
+    Prograde,
+    Retrograde,
+}
+
+/// Which solution of a given number of revolutions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LambertBranch {
+    /// The unique zero-revolution arc
+    Single,
+    /// Multi-revolution arc with `x` below that of the minimum time of flight
+    Left,
+    /// Multi-revolution arc with `x` above that of the minimum time of flight
+    Right,
+}
+
+/// Velocities at both ends of a transfer arc.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LambertSolution<T: Float> {
+    pub v1: Vector<T, 3>,
+    pub v2: Vector<T, 3>,
+    pub revolutions: usize,
+    pub branch: LambertBranch,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LambertError {
+    /// The time of flight is not positive
+    InvalidTimeOfFlight,
+    /// The positions are collinear with the central body (or zero), so the
+    /// transfer plane is undefined
+    Collinear,
+    /// The iteration on `x` did not converge
+    NotConverged,
+}
+
+/// Transfer geometry shared by the solvers.
+struct Geometry<T: Float> {
+    r1: T,
+    r2: T,
+    /// Chord and semi-perimeter
+    c: T,
+    s: T,
+    /// `sqrt(1 - c/s)`, negative for transfer angles beyond `pi`
+    lambda: T,
+    /// Radial and transverse unit vectors at both ends
+    ir1: Vector<T, 3>,
+    ir2: Vector<T, 3>,
+    it1: Vector<T, 3>,
+    it2: Vector<T, 3>,
+}
+
+impl<T: Float> Geometry<T> {
+    fn new(r1: &Vector<T, 3>, r2: &Vector<T, 3>, tof: T, direction: TransferDirection) -> Result<Self, LambertError> {
+        if tof.is_nan() || tof <= T::zero() {
+            return Err(LambertError::InvalidTimeOfFlight);
+        }
+        let (r1_norm, r2_norm) = (r1.norm(), r2.norm());
+        let c = (*r2 - *r1).norm();
+        let s = (r1_norm + r2_norm + c) / (T::one() + T::one());
+        let (ir1, ir2) = (*r1 / r1_norm, *r2 / r2_norm);
+        let h = ir1.cross(ir2);
+        let h_norm = h.norm();
+        if h_norm.is_nan() || h_norm <= T::epsilon().sqrt() {
+            return Err(LambertError::Collinear);
+        }
+        let ih = h / h_norm;
+
+        let mut lambda = (T::one() - c / s).max(T::zero()).sqrt();
+        let (mut it1, mut it2) = if ih.data[2] < T::zero() {
+            lambda = -lambda;
+            (ir1.cross(ih), ir2.cross(ih))
+        } else {
+            (ih.cross(ir1), ih.cross(ir2))
+        };
+        if direction == TransferDirection::Retrograde {
+            lambda = -lambda;
+            it1 = -it1;
+            it2 = -it2;
+        }
+        Ok(Self { r1: r1_norm, r2: r2_norm, c, s, lambda, ir1, ir2, it1, it2 })
+    }
+
+    /// Velocities from their radial and transverse components at both ends.
+    fn solution(&self, radial: (T, T), transverse: (T, T), revolutions: usize, branch: LambertBranch) -> LambertSolution<T> {
+        LambertSolution {
+            v1: self.ir1 * radial.0 + self.it1 * transverse.0,
+            v2: self.ir2 * radial.1 + self.it2 * transverse.1,
+            revolutions,
+            branch,
+        }
+    }
+}
@@ -0,0 +1,68 @@
+//! Two-body orbits: Cartesian state vectors and orbital element sets.
+//!
+//! [`KeplerianElements`] are the classical elements. They are singular for
+//! circular and equatorial orbits, where the conversion from a state vector
+//! falls back to the usual conventions:
+//!
+//! - circular: the argument of periapsis is zero and the anomaly is the
+//!   argument of latitude;
+//! - equatorial: the right ascension of the ascending node is zero and the
+//!   argument of periapsis is the longitude of periapsis;
+//! - circular equatorial: both are zero and the anomaly is the true longitude.
+//!
+//! Parabolic orbits are described by their semi-latus rectum.
+//! [`EquinoctialElements`] and [`ModifiedEquinoctialElements`] are nonsingular
+//! for circular and equatorial orbits, and only singular for retrograde
+//! equatorial ones (`i = pi`).
+//!
+//! [`StateVector::propagate`] advances a state analytically with the Lagrange
+//! `f` and `g` coefficients of the universal-variable formulation, and the
+//! [`kepler`] module solves Kepler's equation and converts between anomalies.
+//! The [`lambert`] module finds the transfer arcs between two positions.
+//!
+//! Angles are in radians. Lengths, times and the gravitational parameter `mu`
+//! can be in any consistent units, e.g. km, s and km^3/s^2.
+
+mod equinoctial;
+pub mod kepler;
+mod keplerian;
+pub mod lambert;
+mod state;
+mod two_body;
+pub use equinoctial::{EquinoctialElements, ModifiedEquinoctialElements};
+pub use keplerian::{Anomaly, KeplerianElements};
+pub use state::StateVector;
+pub use two_body::{solve_kepler_universal, LagrangeCoefficients};
+
+use num_traits::Float;
+
+/// Gravitational parameter of the Earth, EGM2008 (m^3/s^2).
+pub const MU_EARTH: f64 = 3.986_004_418e14;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrbitError {
+    /// Negative eccentricity
+    InvalidEccentricity,
+    /// Semi-major axis of the wrong sign for the eccentricity (positive for
+    /// ellipses, negative for hyperbolas), or a non-positive semi-latus rectum
+    InvalidSize,
+    /// A semi-major axis was given for a parabola; use
+    /// [`KeplerianElements::parabolic`]
+    Parabolic,
+    /// True anomaly beyond the asymptotes of a hyperbola
+    InvalidAnomaly,
+    /// Zero angular momentum: a rectilinear trajectory or a zero state
+    Rectilinear,
+    /// An iterative solver did not converge
+    NotConverged,
+}
+
+/// Threshold below which an eccentricity or inclination is treated as zero,
+/// and an eccentricity as one.
+fn tolerance<T: Float>() -> T {
+    T::from(1e-11).unwrap().max(T::epsilon() * T::from(16.0).unwrap())
+}
+
+#[cfg(test)]
+#[path = "tests/mod.rs"]
+mod tests;
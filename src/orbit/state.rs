@@ -0,0 +1,34 @@
+use num_traits::Float;
+
+use crate::coordinate::Cartesian;
+
+/// Position and velocity of a body in an inertial frame `F`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct StateVector<T: Float, F> {
+    pub position: Cartesian<T, F>,
+    pub velocity: Cartesian<T, F>,
+}
+
+impl<T: Float, F> StateVector<T, F> {
+    pub fn new(position: Cartesian<T, F>, velocity: Cartesian<T, F>) -> Self {
+        Self { position, velocity }
+    }
+
+    /// Specific angular momentum `r x v`.
+    pub fn angular_momentum(&self) -> Cartesian<T, F> {
+        self.position.cross(&self.velocity)
+    }
+
+    /// Specific orbital energy `v^2/2 - mu/r`.
+    pub fn energy(&self, mu: T) -> T {
+        let v = self.velocity.norm();
+        v * v / (T::one() + T::one()) - mu / self.position.norm()
+    }
+
+    /// Eccentricity vector, pointing at periapsis.
+    pub fn eccentricity_vector(&self, mu: T) -> Cartesian<T, F> {
+        let (r, v) = (&self.position, &self.velocity);
+        let v2 = v.dot(v);
+        Cartesian::from_vector((r.data * (v2 - mu / r.norm()) - v.data * r.dot(v)) / mu)
+    }
+}
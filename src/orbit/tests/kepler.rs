@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod tests {
+    use crate::coordinate::Cartesian;
+    use crate::orbit::kepler::*;
+    use crate::orbit::*;
+    use crate::reference_frame::ReferenceFrame;
+    use approx::assert_relative_eq;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Default)]
+    struct Inertial;
+    impl ReferenceFrame for Inertial {}
+
+    const MU: f64 = 398_600.441_8;
+
+    fn assert_state(a: &StateVector<f64, Inertial>, b: &StateVector<f64, Inertial>, tolerance: f64) {
+        assert!(a.position.distance_to(&b.position) < tolerance * b.position.norm(), "{:?} vs {:?}", a.position, b.position);
+        assert!(a.velocity.distance_to(&b.velocity) < tolerance * b.velocity.norm(), "{:?} vs {:?}", a.velocity, b.velocity);
+    }
+
+    #[test]
+    fn test_kepler_solvers() {
+        for e in [0.0, 0.3, 0.9, 0.999, 1.0 - 1e-8, 1.0 - 1e-12] {
+            for m in [1e-9, 1e-4, 0.01, 0.5, 2.0, 3.1, std::f64::consts::PI, -1.0, 20.0] {
+                let big_e = solve_kepler_elliptic(m, e);
+                assert_relative_eq!(elliptic_mean_anomaly(big_e, e), m, max_relative = 1e-14);
+            }
+        }
+        for e in [1.0 + 1e-12, 1.0 + 1e-6, 1.2, 3.0, 50.0] {
+            for m in [1e-9, 1e-4, 0.3, 5.0, 1e3, 1e6, -2.0] {
+                let h = solve_kepler_hyperbolic(m, e);
+                assert_relative_eq!(hyperbolic_mean_anomaly(h, e), m, max_relative = 1e-14);
+            }
+        }
+        for m in [-3.0, 1e-9, 0.5, 1e4] {
+            let d = solve_barker(m);
+            assert_relative_eq!(d + d.powi(3) / 3.0, m, max_relative = 1e-14);
+        }
+
+        // The cancellation-free mean anomaly near periapsis of a near parabola
+        let (e, big_e) = (1.0 - 1e-10, 1e-4_f64);
+        let exact = (1.0 - e) * big_e.sin() + big_e.powi(3) / 6.0 - big_e.powi(5) / 120.0;
+        assert_relative_eq!(elliptic_mean_anomaly(big_e, e), exact, max_relative = 1e-15);
+        assert!(((big_e - e * big_e.sin()) - exact).abs() > 1e-9 * exact);
+
+        // Stumpff functions are continuous across the series switch
+        let (c2, c3) = stumpff(0.0);
+        assert_eq!((c2, c3), (0.5, 1.0 / 6.0));
+        for z in [1.0_f64, -1.0] {
+            let (below, above) = (stumpff(z * (1.0 - 1e-12)), stumpff(z * (1.0 + 1e-12)));
+            assert_relative_eq!(below.0, above.0, max_relative = 1e-12);
+            assert_relative_eq!(below.1, above.1, max_relative = 1e-12);
+        }
+        let (c2, c3) = stumpff(4.0);
+        assert_relative_eq!(c2, (1.0 - 2.0_f64.cos()) / 4.0, epsilon = 1e-16);
+        assert_relative_eq!(c3, (2.0 - 2.0_f64.sin()) / 8.0, epsilon = 1e-16);
+    }
+
+    #[test]
+    fn test_anomaly_conversions() {
+        for e in [0.0, 0.2, 0.95, 1.0 - 1e-9, 1.0, 1.0 + 1e-9, 1.5, 8.0] {
+            for nu in [1e-6, 0.3, -1.0, 1.4] {
+                let m = true_to_mean(nu, e);
+                assert_relative_eq!(mean_to_true(m, e), nu, max_relative = 1e-12);
+                let x = true_to_eccentric(nu, e);
+                assert_relative_eq!(eccentric_to_true(x, e), nu, max_relative = 1e-12);
+                assert_relative_eq!(mean_to_eccentric(eccentric_to_mean(x, e), e), x, max_relative = 1e-12);
+            }
+        }
+        // Near e = 1 every regime tends to the parabolic mean motion
+        let nu = 0.5_f64;
+        let (d, q) = ((nu / 2.0).tan(), 1.0);
+        let parabolic = d + d.powi(3) / 3.0;
+        for e in [1.0 - 1e-9, 1.0 + 1e-9] {
+            // Same time from periapsis: M / n = (D + D^3/3) / n_p with
+            // n = sqrt(mu / |a|^3), n_p = 2 sqrt(mu / p^3) and |a| = q / |1 - e|
+            let (a, p) = (q / (1.0_f64 - e).abs(), q * (1.0 + e));
+            let n_ratio = 0.5 * (p / a).powf(1.5);
+            assert_relative_eq!(true_to_mean(nu, e) / n_ratio, parabolic, max_relative = 1e-8);
+        }
+        // Whole revolutions are kept
+        assert_relative_eq!(mean_to_true(4.0 * std::f64::consts::PI + 0.2, 0.0), 4.0 * std::f64::consts::PI + 0.2, epsilon = 1e-13);
+    }
+
+    #[test]
+    fn test_two_body_propagation() {
+        // Vallado, Fundamentals of Astrodynamics and Applications, example 2-4
+        let state: StateVector<f64, Inertial> =
+            StateVector::new(Cartesian::new(1_131.340, -2_282.343, 6_672.423), Cartesian::new(-5.643_05, 4.303_33, 2.428_79));
+        let expected = StateVector::new(
+            Cartesian::new(-4_219.752_7, 4_363.029_2, -3_958.766_6),
+            Cartesian::new(3.689_866, -1.916_735, -6.112_511),
+        );
+        let later = state.propagate(40.0 * 60.0, MU).unwrap();
+        assert_state(&later, &expected, 1e-7);
+        assert_state(&later.propagate(-2_400.0, MU).unwrap(), &state, 1e-12);
+        let lagrange = LagrangeCoefficients::new(&state, 2_400.0, MU).unwrap();
+        assert_relative_eq!(lagrange.f * lagrange.g_dot - lagrange.f_dot * lagrange.g, 1.0, epsilon = 1e-12);
+
+        // Against the propagation of the elements for every kind of conic
+        let ellipse = KeplerianElements::new(26_600.0, 0.74, 1.1, 4.0, 4.8, Anomaly::Mean(0.4)).unwrap();
+        let near_parabola = KeplerianElements::new(-7_000.0 / 1e-9, 1.0 + 1e-9, 0.5, 1.0, 2.0, Anomaly::True(-1.0)).unwrap();
+        let parabola = KeplerianElements::parabolic(14_000.0, 0.5, 1.0, 2.0, Anomaly::True(-1.0)).unwrap();
+        let hyperbola = KeplerianElements::new(-20_000.0, 2.5, 2.0, 0.3, 0.1, Anomaly::True(-1.5)).unwrap();
+        let cases = [
+            (ellipse, 10.3 * ellipse.period(MU)),
+            (ellipse, -1_234.5),
+            (near_parabola, 86_400.0),
+            (parabola, 86_400.0),
+            (parabola, -600.0),
+            (hyperbola, 1e6),
+        ];
+        for (elements, dt) in cases {
+            let start = elements.to_state::<Inertial>(MU);
+            let analytic = elements.propagate(dt, MU).to_state(MU);
+            assert_state(&start.propagate(dt, MU).unwrap(), &analytic, 1e-9);
+            assert_relative_eq!(analytic.energy(MU), start.energy(MU), max_relative = 1e-9, epsilon = 1e-12);
+        }
+        assert_eq!(state.propagate(0.0, MU).unwrap(), state);
+    }
+}
@@ -0,0 +1,127 @@
+#[cfg(test)]
+mod tests {
+    use crate::coordinate::Cartesian;
+    use crate::math::Vector;
+    use crate::orbit::lambert::*;
+    use crate::orbit::StateVector;
+    use crate::reference_frame::ReferenceFrame;
+    use approx::assert_relative_eq;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Default)]
+    struct Inertial;
+    impl ReferenceFrame for Inertial {}
+
+    const MU: f64 = 398_600.441_8;
+
+    fn assert_vector(a: &Vector<f64, 3>, b: &Vector<f64, 3>, tolerance: f64) {
+        assert!((*a - *b).norm() < tolerance * b.norm(), "{:?} vs {:?}", a, b);
+    }
+
+    /// Propagating the departure state by the time of flight reaches `r2`.
+    fn assert_reaches(r1: &Vector<f64, 3>, r2: &Vector<f64, 3>, tof: f64, mu: f64, solution: &LambertSolution<f64>) {
+        let state: StateVector<f64, Inertial> = StateVector::new(Cartesian::from_vector(*r1), Cartesian::from_vector(solution.v1));
+        let arrival = state.propagate(tof, mu).unwrap();
+        assert_vector(&arrival.position.data, r2, 1e-9);
+        assert_vector(&arrival.velocity.data, &solution.v2, 1e-9);
+    }
+
+    #[test]
+    fn test_lambert_published() {
+        // Vallado, Fundamentals of Astrodynamics and Applications, example 7-5
+        let r1 = Vector::new([15_945.34, 0.0, 0.0]);
+        let r2 = Vector::new([12_214.838_99, 10_249.467_31, 0.0]);
+        for solve in [izzo, gooding] {
+            let solutions = solve(&r1, &r2, 76.0 * 60.0, MU, TransferDirection::Prograde, 0).unwrap();
+            assert_eq!(solutions.len(), 1);
+            assert_eq!((solutions[0].revolutions, solutions[0].branch), (0, LambertBranch::Single));
+            assert_vector(&solutions[0].v1, &Vector::new([2.058_913, 2.915_965, 0.0]), 1e-6);
+            assert_vector(&solutions[0].v2, &Vector::new([-3.451_565, 0.910_315, 0.0]), 1e-6);
+        }
+
+        // Curtis, Orbital Mechanics for Engineering Students, example 5.2
+        let r1 = Vector::new([5_000.0, 10_000.0, 2_100.0]);
+        let r2 = Vector::new([-14_600.0, 2_500.0, 7_000.0]);
+        for solve in [izzo, gooding] {
+            let solution = solve(&r1, &r2, 3_600.0, 398_600.0, TransferDirection::Prograde, 0).unwrap()[0];
+            assert_vector(&solution.v1, &Vector::new([-5.992_5, 1.925_4, 3.245_6]), 1e-4);
+            assert_vector(&solution.v2, &Vector::new([-3.312_5, -4.196_6, -0.385_29]), 1e-4);
+        }
+
+        assert_eq!(izzo(&r1, &r2, 0.0, MU, TransferDirection::Prograde, 0), Err(LambertError::InvalidTimeOfFlight));
+        assert_eq!(gooding(&r1, &r1, 3_600.0, MU, TransferDirection::Prograde, 0), Err(LambertError::Collinear));
+        assert_eq!(izzo(&r1, &(r1 * -2.0), 3_600.0, MU, TransferDirection::Prograde, 0), Err(LambertError::Collinear));
+    }
+
+    #[test]
+    fn test_lambert_branches() {
+        let r1 = Vector::new([7_000.0, 0.0, 0.0]);
+        let r2 = Vector::new([-3_000.0, 9_000.0, 2_000.0]);
+        for (tof, expected) in [(600.0, 1), (3_000.0, 1), (12_000.0, 3), (20_000.0, 5), (60_000.0, 11)] {
+            for direction in [TransferDirection::Prograde, TransferDirection::Retrograde] {
+                let from_izzo = izzo(&r1, &r2, tof, MU, direction, 5).unwrap();
+                let from_gooding = gooding(&r1, &r2, tof, MU, direction, 5).unwrap();
+                assert_eq!(from_izzo.len(), from_gooding.len(), "tof {tof} {direction:?}");
+                assert_eq!(from_izzo.len(), expected, "tof {tof}");
+                for (a, b) in from_izzo.iter().zip(&from_gooding) {
+                    assert_eq!((a.revolutions, a.branch), (b.revolutions, b.branch));
+                    assert_vector(&a.v1, &b.v1, 1e-10);
+                    assert_vector(&a.v2, &b.v2, 1e-10);
+                    assert_reaches(&r1, &r2, tof, MU, a);
+                    let h = r1.cross(a.v1).data[2];
+                    assert_eq!(h > 0.0, direction == TransferDirection::Prograde);
+                }
+            }
+        }
+
+        // Near-parabolic and hyperbolic arcs, and a transfer angle beyond pi
+        let r3 = Vector::new([2_000.0, -8_000.0, 500.0]);
+        for tof in [200.0, 1_000.0, 1_500.0, 2_500.0] {
+            for r in [&r2, &r3] {
+                let a = izzo(&r1, r, tof, MU, TransferDirection::Prograde, 0).unwrap()[0];
+                let b = gooding(&r1, r, tof, MU, TransferDirection::Prograde, 0).unwrap()[0];
+                assert_vector(&a.v1, &b.v1, 1e-10);
+                assert_reaches(&r1, r, tof, MU, &a);
+            }
+        }
+
+        // Above the minimum time of flight the left and right branches are
+        // distinct solutions, and every solution lies in the transfer plane
+        let solutions = izzo(&r1, &r2, 60_000.0, MU, TransferDirection::Prograde, 3).unwrap();
+        let (left, right) = (solutions[5], solutions[6]);
+        assert_eq!((left.revolutions, left.branch, right.branch), (3, LambertBranch::Left, LambertBranch::Right));
+        assert!((left.v1 - right.v1).norm() > 1e-3);
+        assert_relative_eq!(solutions[0].v1.dot(&r1.cross(r2)), 0.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_lambert_minimum_time_of_flight() {
+        let r1 = Vector::new([7_000.0, 0.0, 0.0]);
+        let r2 = Vector::new([-3_000.0, 9_000.0, 2_000.0]);
+
+        // Bracket the three-revolution minimum by where its pair of solutions appears
+        let (mut lo, mut hi) = (20_000.0, 60_000.0);
+        for _ in 0..50 {
+            let mid = 0.5 * (lo + hi);
+            match izzo(&r1, &r2, mid, MU, TransferDirection::Prograde, 3) {
+                Ok(solutions) if solutions.len() == 7 => hi = mid,
+                _ => lo = mid,
+            }
+        }
+
+        // The branches close in on each other like the square root of the
+        // excess time of flight, meeting at the minimum
+        for solve in [izzo, gooding] {
+            let separation = |excess: f64| {
+                let tof = hi * (1.0 + excess);
+                let solutions = solve(&r1, &r2, tof, MU, TransferDirection::Prograde, 3).unwrap();
+                let (left, right) = (solutions[5], solutions[6]);
+                assert_eq!((left.branch, right.branch), (LambertBranch::Left, LambertBranch::Right));
+                assert_reaches(&r1, &r2, tof, MU, &left);
+                assert_reaches(&r1, &r2, tof, MU, &right);
+                (left.v1 - right.v1).norm()
+            };
+            let (far, near) = (separation(1e-2), separation(1e-4));
+            assert!(near < 0.15 * far, "{near} vs {far}");
+        }
+    }
+}
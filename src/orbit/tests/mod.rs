@@ -0,0 +1,6 @@
+#[cfg(test)]
+mod orbit;
+#[cfg(test)]
+mod kepler;
+#[cfg(test)]
+mod lambert;
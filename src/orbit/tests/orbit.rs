@@ -0,0 +1,167 @@
+#[cfg(test)]
+mod tests {
+    use crate::coordinate::Cartesian;
+    use crate::orbit::*;
+    use crate::reference_frame::ReferenceFrame;
+    use approx::assert_relative_eq;
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Default)]
+    struct Inertial;
+    impl ReferenceFrame for Inertial {}
+
+    const MU: f64 = 398_600.441_8;
+
+    fn state(r: [f64; 3], v: [f64; 3]) -> StateVector<f64, Inertial> {
+        StateVector::new(Cartesian::new(r[0], r[1], r[2]), Cartesian::new(v[0], v[1], v[2]))
+    }
+
+    fn assert_state(a: &StateVector<f64, Inertial>, b: &StateVector<f64, Inertial>) {
+        let scale = b.position.norm();
+        assert!(a.position.distance_to(&b.position) < 1e-11 * scale, "{:?} vs {:?}", a.position, b.position);
+        assert!(a.velocity.distance_to(&b.velocity) < 1e-11 * b.velocity.norm(), "{:?} vs {:?}", a.velocity, b.velocity);
+    }
+
+    #[test]
+    fn test_vallado_rv2coe() {
+        // Vallado, Fundamentals of Astrodynamics and Applications, example 2-5
+        let s = state([6_524.834, 6_862.875, 6_448.296], [4.901_327, 5.533_756, -1.976_341]);
+        let kep = KeplerianElements::from_state(&s, MU).unwrap();
+        assert_relative_eq!(kep.p(), 11_067.790, max_relative = 1e-6);
+        assert_relative_eq!(kep.a(), 36_127.343, max_relative = 1e-6);
+        assert_relative_eq!(kep.e(), 0.832_853, epsilon = 1e-6);
+        assert_relative_eq!(kep.i().to_degrees(), 87.870, epsilon = 1e-3);
+        assert_relative_eq!(kep.raan().to_degrees(), 227.89, epsilon = 1e-2);
+        assert_relative_eq!(kep.argp().to_degrees(), 53.38, epsilon = 1e-2);
+        assert_relative_eq!(kep.true_anomaly().to_degrees(), 92.335, epsilon = 1e-3);
+        assert_relative_eq!(kep.argument_of_latitude(), kep.argp() + kep.true_anomaly(), epsilon = 1e-15);
+        assert_state(&kep.to_state(MU), &s);
+
+        // The same orbit from its elements, with every kind of anomaly
+        let (a, e, i, raan, argp) = (kep.a(), kep.e(), kep.i(), kep.raan(), kep.argp());
+        for anomaly in [Anomaly::True(kep.true_anomaly()), Anomaly::Eccentric(kep.eccentric_anomaly()), Anomaly::Mean(kep.mean_anomaly())] {
+            let other = KeplerianElements::new(a, e, i, raan, argp, anomaly).unwrap();
+            assert_relative_eq!(other.true_anomaly(), kep.true_anomaly(), epsilon = 1e-12);
+        }
+        let big_e = kep.eccentric_anomaly();
+        assert_relative_eq!(kep.mean_anomaly(), big_e - e * big_e.sin(), epsilon = 1e-15);
+        assert_relative_eq!(kep.period(MU), 2.0 * PI * (a.powi(3) / MU).sqrt(), epsilon = 1e-6);
+        assert_relative_eq!(kep.periapsis() + kep.apoapsis(), 2.0 * a, epsilon = 1e-8);
+        assert_relative_eq!(s.energy(MU), -MU / (2.0 * a), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_singular_orbits() {
+        let r = 7_000.0;
+        let vc = (MU / r).sqrt();
+        let (s, c) = (0.3_f64.sin(), 0.3_f64.cos());
+
+        // Circular inclined: argp = 0, anomaly is the argument of latitude
+        let circular = state([0.0, r * c, r * s], [-vc, 0.0, 0.0]);
+        let kep = KeplerianElements::from_state(&circular, MU).unwrap();
+        assert_eq!((kep.e(), kep.argp()), (0.0, 0.0));
+        assert_relative_eq!(kep.i(), 0.3, epsilon = 1e-12);
+        assert_eq!(kep.raan(), 0.0);
+        assert_relative_eq!(kep.true_anomaly(), FRAC_PI_2, epsilon = 1e-12);
+        assert_state(&kep.to_state(MU), &circular);
+
+        // Circular equatorial: anomaly is the true longitude
+        let equatorial = state([r * c, r * s, 0.0], [-vc * s, vc * c, 0.0]);
+        let kep = KeplerianElements::from_state(&equatorial, MU).unwrap();
+        assert_eq!((kep.i(), kep.raan(), kep.argp(), kep.e()), (0.0, 0.0, 0.0, 0.0));
+        assert_relative_eq!(kep.true_anomaly(), 0.3, epsilon = 1e-12);
+        assert_state(&kep.to_state(MU), &equatorial);
+
+        // Elliptic retrograde equatorial: argp is measured with the motion
+        let retrograde = state([r, 0.0, 0.0], [0.0, -1.2 * vc, 0.0]);
+        let kep = KeplerianElements::from_state(&retrograde, MU).unwrap();
+        assert_relative_eq!(kep.i(), PI, epsilon = 1e-12);
+        assert_eq!(kep.raan(), 0.0);
+        assert_relative_eq!(kep.e(), 0.44, epsilon = 1e-12);
+        assert_relative_eq!(kep.true_anomaly(), 0.0, epsilon = 1e-12);
+        assert_state(&kep.to_state(MU), &retrograde);
+
+        // Parabolic and hyperbolic escape from periapsis
+        let escape = (2.0 * MU / r).sqrt();
+        let parabola = state([r, 0.0, 0.0], [0.0, escape * c, escape * s]);
+        let kep = KeplerianElements::from_state(&parabola, MU).unwrap();
+        assert_eq!(kep.e(), 1.0);
+        assert!(kep.a().is_infinite() && kep.apoapsis().is_infinite());
+        assert_relative_eq!(kep.p(), 2.0 * r, epsilon = 1e-9);
+        let later = KeplerianElements::parabolic(kep.p(), kep.i(), kep.raan(), kep.argp(), Anomaly::True(2.0)).unwrap();
+        let d = 1.0_f64.tan();
+        assert_relative_eq!(later.mean_anomaly(), d + d.powi(3) / 3.0, epsilon = 1e-14);
+        let again = KeplerianElements::parabolic(kep.p(), 0.3, 0.0, 0.0, Anomaly::Mean(later.mean_anomaly())).unwrap();
+        assert_relative_eq!(again.true_anomaly(), 2.0, epsilon = 1e-14);
+
+        let hyperbola = state([r, 0.0, 0.0], [0.0, 1.5 * escape * c, 1.5 * escape * s]);
+        let kep = KeplerianElements::from_state(&hyperbola, MU).unwrap();
+        assert!(kep.e() > 1.0 && kep.a() < 0.0);
+        let outbound = KeplerianElements::new(kep.a(), kep.e(), kep.i(), kep.raan(), kep.argp(), Anomaly::True(1.7)).unwrap();
+        let (a, e) = (outbound.a(), outbound.e());
+        let back = KeplerianElements::new(a, e, kep.i(), 0.0, 0.0, Anomaly::Mean(outbound.mean_anomaly())).unwrap();
+        assert_relative_eq!(back.true_anomaly(), 1.7, epsilon = 1e-12);
+        let departing = outbound.to_state::<Inertial>(MU);
+        assert_relative_eq!(departing.position.norm(), outbound.p() / (1.0 + e * 1.7_f64.cos()), epsilon = 1e-8);
+        assert_relative_eq!(departing.energy(MU), -MU / (2.0 * a), epsilon = 1e-10);
+        assert_state(&KeplerianElements::from_state(&departing, MU).unwrap().to_state(MU), &departing);
+
+        // Invalid element sets
+        let beyond = (-1.0 / e).acos() + 0.01;
+        assert_eq!(KeplerianElements::new(a, e, 0.0, 0.0, 0.0, Anomaly::True(beyond)), Err(OrbitError::InvalidAnomaly));
+        assert_eq!(KeplerianElements::new(-a, e, 0.0, 0.0, 0.0, Anomaly::True(0.0)), Err(OrbitError::InvalidSize));
+        assert_eq!(KeplerianElements::new(r, -0.1, 0.0, 0.0, 0.0, Anomaly::True(0.0)), Err(OrbitError::InvalidEccentricity));
+        assert_eq!(KeplerianElements::new(r, 1.0, 0.0, 0.0, 0.0, Anomaly::True(0.0)), Err(OrbitError::Parabolic));
+        let radial = state([r, 0.0, 0.0], [1.0, 0.0, 0.0]);
+        assert_eq!(KeplerianElements::from_state(&radial, MU), Err(OrbitError::Rectilinear));
+        assert_eq!(ModifiedEquinoctialElements::from_state(&radial, MU), Err(OrbitError::Rectilinear));
+    }
+
+    #[test]
+    fn test_equinoctial_elements() {
+        let kep = KeplerianElements::new(26_600.0, 0.74, 1.1, 4.0, 4.8, Anomaly::Mean(0.4)).unwrap();
+        let s = kep.to_state::<Inertial>(MU);
+
+        let eq = EquinoctialElements::from_keplerian(&kep).unwrap();
+        assert_relative_eq!(eq.a, 26_600.0, epsilon = 1e-9);
+        assert_relative_eq!(eq.h.hypot(eq.k), 0.74, epsilon = 1e-15);
+        assert_relative_eq!(eq.lambda, (0.4 + 4.0 + 4.8) % (2.0 * PI), epsilon = 1e-12);
+        assert_state(&eq.to_state(MU).unwrap(), &s);
+        let from_state = EquinoctialElements::from_state(&s, MU).unwrap();
+        for (x, y) in [(from_state.h, eq.h), (from_state.k, eq.k), (from_state.p, eq.p), (from_state.q, eq.q), (from_state.lambda, eq.lambda)] {
+            assert_relative_eq!(x, y, epsilon = 1e-12);
+        }
+
+        let mee = ModifiedEquinoctialElements::from_keplerian(&kep);
+        let direct = ModifiedEquinoctialElements::from_state(&s, MU).unwrap();
+        for (x, y) in [(direct.f, mee.f), (direct.g, mee.g), (direct.h, mee.h), (direct.k, mee.k), (direct.l, mee.l)] {
+            assert_relative_eq!(x, y, epsilon = 1e-12);
+        }
+        assert_relative_eq!(direct.p, kep.p(), epsilon = 1e-8);
+        assert_state(&mee.to_state(MU), &s);
+        let back = mee.to_keplerian().unwrap();
+        assert_relative_eq!(back.argp(), 4.8, epsilon = 1e-12);
+        assert_relative_eq!(back.mean_anomaly(), 0.4, epsilon = 1e-12);
+
+        // Nonsingular through circular equatorial and parabolic orbits
+        let r = 7_000.0;
+        let vc = (MU / r).sqrt();
+        let circular = state([0.0, r, 0.0], [-vc, 0.0, 0.0]);
+        let mee = ModifiedEquinoctialElements::from_state(&circular, MU).unwrap();
+        for x in [mee.f, mee.g, mee.h, mee.k] {
+            assert_relative_eq!(x, 0.0, epsilon = 1e-15);
+        }
+        assert_relative_eq!(mee.l, FRAC_PI_2, epsilon = 1e-15);
+        assert_state(&mee.to_state(MU), &circular);
+        let eq = EquinoctialElements::from_state(&circular, MU).unwrap();
+        assert_relative_eq!(eq.lambda, FRAC_PI_2, epsilon = 1e-15);
+        assert_state(&eq.to_state(MU).unwrap(), &circular);
+
+        let parabola = state([0.0, 0.0, r], [(2.0 * MU / r).sqrt(), 0.0, 0.0]);
+        let mee = ModifiedEquinoctialElements::from_state(&parabola, MU).unwrap();
+        assert_relative_eq!(mee.f.hypot(mee.g), 1.0, epsilon = 1e-14);
+        assert_state(&mee.to_state(MU), &parabola);
+        assert_eq!(mee.to_keplerian().unwrap().e(), 1.0);
+        assert_eq!(EquinoctialElements::from_state(&parabola, MU), Err(OrbitError::Parabolic));
+    }
+}
@@ -0,0 +1,138 @@
+//! Analytic two-body propagation with universal variables.
+
+use num_traits::Float;
+
+use super::kepler::stumpff;
+use super::{OrbitError, StateVector};
+use crate::coordinate::Cartesian;
+
+const MAX_ITERATIONS: usize = 50;
+
+/// Lagrange coefficients mapping an initial state to the state a time `dt`
+/// later: `r = f r0 + g v0`, `v = f_dot r0 + g_dot v0`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LagrangeCoefficients<T: Float> {
+    pub f: T,
+    pub g: T,
+    pub f_dot: T,
+    pub g_dot: T,
+}
+
+impl<T: Float> LagrangeCoefficients<T> {
+    /// Coefficients for `state` advanced by `dt` (negative to go backwards).
+    pub fn new<F>(state: &StateVector<T, F>, dt: T, mu: T) -> Result<Self, OrbitError> {
+        let r0 = state.position.norm();
+        let sqrt_mu = mu.sqrt();
+        let sigma0 = state.position.dot(&state.velocity) / sqrt_mu;
+        let v0 = state.velocity.norm();
+        let alpha = (T::one() + T::one()) / r0 - v0 * v0 / mu;
+
+        let chi = solve_kepler_universal(r0, sigma0, alpha, sqrt_mu * dt)?;
+        let u = UniversalFunctions::new(chi, alpha);
+        let r = r0 * u.u0 + sigma0 * u.u1 + u.u2;
+        Ok(Self {
+            f: T::one() - u.u2 / r0,
+            g: (r0 * u.u1 + sigma0 * u.u2) / sqrt_mu,
+            f_dot: -sqrt_mu * u.u1 / (r * r0),
+            g_dot: T::one() - u.u2 / r,
+        })
+    }
+
+    pub fn apply<F>(&self, state: &StateVector<T, F>) -> StateVector<T, F> {
+        let (r0, v0) = (state.position.data, state.velocity.data);
+        StateVector::new(
+            Cartesian::from_vector(r0 * self.f + v0 * self.g),
+            Cartesian::from_vector(r0 * self.f_dot + v0 * self.g_dot),
+        )
+    }
+}
+
+/// Universal functions `U_n = chi^n c_n(alpha chi^2)`.
+struct UniversalFunctions<T> {
+    u0: T,
+    u1: T,
+    u2: T,
+    u3: T,
+}
+
+impl<T: Float> UniversalFunctions<T> {
+    fn new(chi: T, alpha: T) -> Self {
+        let chi2 = chi * chi;
+        let z = alpha * chi2;
+        let (c2, c3) = stumpff(z);
+        Self { u0: T::one() - z * c2, u1: chi * (T::one() - z * c3), u2: chi2 * c2, u3: chi2 * chi * c3 }
+    }
+}
+
+/// Solves the universal Kepler equation
+/// `sqrt(mu) dt = r0 U1 + sigma0 U2 + U3` for the universal anomaly `chi`,
+/// given the initial radius `r0`, `sigma0 = r0 . v0 / sqrt(mu)`, the inverse
+/// semi-major axis `alpha = 2/r0 - v0^2/mu` and `tau = sqrt(mu) dt`.
+///
+/// Uses the Laguerre-Conway iteration, which converges from the rough
+/// starters of Vallado for every conic. Elliptic transfers are first reduced
+/// to less than one period.
+pub fn solve_kepler_universal<T: Float>(r0: T, sigma0: T, alpha: T, tau: T) -> Result<T, OrbitError> {
+    let k = |x: f64| -> T { T::from(x).unwrap() };
+    let (one, two) = (T::one(), k(2.0));
+    if tau == T::zero() {
+        return Ok(T::zero());
+    }
+    let small = k(1e-6);
+
+    // Whole revolutions of an ellipse, each a change of 2 pi / sqrt(alpha) in chi
+    let (tau, revolutions) = if alpha * r0 > small {
+        let period = k(core::f64::consts::TAU) / alpha.powf(k(1.5));
+        let n = (tau / period).round();
+        (tau - n * period, n * k(core::f64::consts::TAU) / alpha.sqrt())
+    } else {
+        (tau, T::zero())
+    };
+
+    let mut chi = if alpha * r0 > small {
+        tau * alpha
+    } else if alpha * r0 < -small {
+        let a = one / alpha;
+        let sign = one.copysign(tau);
+        let start = sign
+            * (-a).sqrt()
+            * ((-two * alpha * tau) / (sigma0 + sign * (-a).sqrt() * (one - r0 * alpha))).ln();
+        if start.is_finite() { start } else { tau / r0 }
+    } else {
+        // Nearly parabolic: Barker's equation about the periapsis
+        let p = (r0 * (two - r0 * alpha) - sigma0 * sigma0).max(r0 * small);
+        let s = (one / (k(3.0) * tau / (p * p * p).sqrt())).atan() / two;
+        let w = s.tan().cbrt().atan();
+        p.sqrt() * two / (two * w).tan()
+    };
+
+    let n = k(5.0);
+    let mut last = T::infinity();
+    for _ in 0..MAX_ITERATIONS {
+        let u = UniversalFunctions::new(chi, alpha);
+        let f = r0 * u.u1 + sigma0 * u.u2 + u.u3 - tau;
+        let df = r0 * u.u0 + sigma0 * u.u1 + u.u2;
+        let ddf = sigma0 * u.u0 + (one - alpha * r0) * u.u1;
+        let root = ((n - one) * (n - one) * df * df - n * (n - one) * f * ddf).abs().sqrt();
+        let step = n * f / (df + root.copysign(df));
+        chi = chi - step;
+        // Converged, or stalled on rounding errors in the residual
+        let size = step.abs();
+        if size <= T::epsilon() * k(4.0) * (one + chi.abs()) || (size >= last && size <= k(1e-8) * (one + chi.abs())) {
+            return Ok(chi + revolutions);
+        }
+        if !chi.is_finite() {
+            break;
+        }
+        last = size;
+    }
+    Err(OrbitError::NotConverged)
+}
+
+impl<T: Float, F> StateVector<T, F> {
+    /// The state after a time `dt` of unperturbed motion about a body with
+    /// gravitational parameter `mu`.
+    pub fn propagate(&self, dt: T, mu: T) -> Result<Self, OrbitError> {
+        Ok(LagrangeCoefficients::new(self, dt, mu)?.apply(self))
+    }
+}
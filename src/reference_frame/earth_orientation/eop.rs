@@ -0,0 +1,179 @@
+//! IERS Earth orientation parameters.
+
+use std::vec::Vec;
+
+use super::{Eop, EopError, EopProvider, ARCSEC_TO_RAD};
+use crate::time::{tai_minus_utc, Epoch, TimeScale, Ut1Provider};
+
+/// A daily record of Earth orientation parameters at 0h UTC of `mjd`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EopRecord {
+    pub mjd: f64,
+    pub eop: Eop,
+}
+
+/// Earth orientation parameters tabulated by day and interpolated linearly.
+///
+/// DUT1 is interpolated as UT1 - TAI so that leap seconds between records do
+/// not smear a one-second jump across the day. Outside the table the first or
+/// last record is used; [`EopTable::at`] reports that case instead.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct EopTable {
+    records: Vec<EopRecord>,
+}
+
+impl EopTable {
+    /// Records must be sorted by increasing MJD.
+    pub fn new(records: Vec<EopRecord>) -> Result<Self, EopError> {
+        if records.is_empty() {
+            return Err(EopError::Empty);
+        }
+        if let Some(index) = records.windows(2).position(|w| w[1].mjd <= w[0].mjd) {
+            return Err(EopError::NotIncreasing { index: index + 1 });
+        }
+        Ok(Self { records })
+    }
+
+    pub fn records(&self) -> &[EopRecord] {
+        &self.records
+    }
+
+    /// Reads an IERS `finals2000A` file (`finals2000A.all`, `.data` or
+    /// `.daily`).
+    pub fn load_finals2000a(path: impl AsRef<std::path::Path>) -> Result<Self, EopError> {
+        Self::parse_finals2000a(&read(path.as_ref())?)
+    }
+
+    /// Reads an IERS EOP CSV file such as `finals2000A.all.csv` or the C04
+    /// series.
+    pub fn load_csv(path: impl AsRef<std::path::Path>) -> Result<Self, EopError> {
+        Self::parse_csv(&read(path.as_ref())?)
+    }
+
+    /// Parses the fixed-column `finals2000A` format, preferring the IERS
+    /// Rapid Service values. Rows without polar motion or UT1 (the tail
+    /// beyond the predictions) are skipped; missing LOD and celestial pole
+    /// offsets read as zero.
+    pub fn parse_finals2000a(text: &str) -> Result<Self, EopError> {
+        let mut records = Vec::new();
+        for (index, line) in text.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let error = EopError::Parse { line: index + 1 };
+            let column = |start: usize, end: usize| line.get(start..end.min(line.len())).map(str::trim).unwrap_or("");
+            let number = |text: &str| text.parse::<f64>().map_err(|_| error);
+            let optional = |text: &str| if text.is_empty() { Ok(0.0) } else { number(text) };
+            let (xp, yp, dut1) = (column(18, 27), column(37, 46), column(58, 68));
+            if xp.is_empty() || yp.is_empty() || dut1.is_empty() {
+                continue;
+            }
+            let mas = ARCSEC_TO_RAD * 1e-3;
+            records.push(EopRecord {
+                mjd: number(column(7, 15))?,
+                eop: Eop {
+                    xp: number(xp)? * ARCSEC_TO_RAD,
+                    yp: number(yp)? * ARCSEC_TO_RAD,
+                    dut1: number(dut1)?,
+                    lod: optional(column(79, 86))? * 1e-3,
+                    dx: optional(column(97, 106))? * mas,
+                    dy: optional(column(116, 125))? * mas,
+                },
+            });
+        }
+        Self::new(records)
+    }
+
+    /// Parses `;`- or `,`-separated values with a header row naming the
+    /// columns `MJD`, `x_pole`, `y_pole`, `UT1-UTC` and optionally `LOD`, `dX`
+    /// and `dY` (case-insensitive), in arcseconds, seconds, milliseconds and
+    /// milliarcseconds as published by the IERS. Rows with empty polar motion
+    /// or UT1 are skipped.
+    pub fn parse_csv(text: &str) -> Result<Self, EopError> {
+        let mut lines = text.lines().enumerate().filter(|(_, line)| !line.trim().is_empty());
+        let (_, header) = lines.next().ok_or(EopError::Empty)?;
+        let separator = if header.contains(';') { ';' } else { ',' };
+        let names: Vec<&str> = header.split(separator).map(str::trim).collect();
+        let find = |name: &str| names.iter().position(|n| n.eq_ignore_ascii_case(name));
+        let required = |name: &str| find(name).ok_or(EopError::MissingColumn);
+        let (mjd, xp, yp, dut1) = (required("MJD")?, required("x_pole")?, required("y_pole")?, required("UT1-UTC")?);
+        let (lod, dx, dy) = (find("LOD"), find("dX"), find("dY"));
+
+        let mut records = Vec::new();
+        for (index, line) in lines {
+            let error = EopError::Parse { line: index + 1 };
+            let fields: Vec<&str> = line.split(separator).map(str::trim).collect();
+            let field = |column: usize| fields.get(column).copied().unwrap_or("");
+            let number = |column: usize| field(column).parse::<f64>().map_err(|_| error);
+            let optional = |column: Option<usize>| match column.map(field) {
+                None | Some("") => Ok(0.0),
+                Some(text) => text.parse::<f64>().map_err(|_| error),
+            };
+            if field(xp).is_empty() || field(yp).is_empty() || field(dut1).is_empty() {
+                continue;
+            }
+            let mas = ARCSEC_TO_RAD * 1e-3;
+            records.push(EopRecord {
+                mjd: number(mjd)?,
+                eop: Eop {
+                    xp: number(xp)? * ARCSEC_TO_RAD,
+                    yp: number(yp)? * ARCSEC_TO_RAD,
+                    dut1: number(dut1)?,
+                    lod: optional(lod)? * 1e-3,
+                    dx: optional(dx)? * mas,
+                    dy: optional(dy)? * mas,
+                },
+            });
+        }
+        Self::new(records)
+    }
+
+    /// Parameters interpolated to the UTC epoch `utc`, or `None` outside the
+    /// table.
+    pub fn at(&self, utc: &Epoch) -> Option<Eop> {
+        let mjd = utc.to_scale(TimeScale::UTC).mjd();
+        let (first, last) = (self.records.first()?, self.records.last()?);
+        if mjd < first.mjd || mjd > last.mjd {
+            return None;
+        }
+        Some(self.interpolate(mjd))
+    }
+
+    fn interpolate(&self, mjd: f64) -> Eop {
+        let records = &self.records;
+        if records.len() == 1 {
+            return records[0].eop;
+        }
+        let upper = records.partition_point(|r| r.mjd <= mjd).clamp(1, records.len() - 1);
+        let (a, b) = (&records[upper - 1], &records[upper]);
+        let theta = ((mjd - a.mjd) / (b.mjd - a.mjd)).clamp(0.0, 1.0);
+        let lerp = |x: f64, y: f64| x + (y - x) * theta;
+        // UT1 - TAI is continuous across leap seconds
+        let leap = |m: f64| tai_minus_utc(m.floor() as i64, (m - m.floor()) * 86_400.0);
+        let ut1_tai = lerp(a.eop.dut1 - leap(a.mjd), b.eop.dut1 - leap(b.mjd));
+        Eop {
+            xp: lerp(a.eop.xp, b.eop.xp),
+            yp: lerp(a.eop.yp, b.eop.yp),
+            dut1: ut1_tai + leap(mjd),
+            lod: lerp(a.eop.lod, b.eop.lod),
+            dx: lerp(a.eop.dx, b.eop.dx),
+            dy: lerp(a.eop.dy, b.eop.dy),
+        }
+    }
+}
+
+pub(super) fn read(path: &std::path::Path) -> Result<std::string::String, EopError> {
+    std::fs::read_to_string(path).map_err(|e| EopError::Io(e.kind()))
+}
+
+impl Ut1Provider for EopTable {
+    fn dut1(&self, utc: &Epoch) -> f64 {
+        self.parameters(utc).dut1
+    }
+}
+
+impl EopProvider for EopTable {
+    fn parameters(&self, utc: &Epoch) -> Eop {
+        self.interpolate(utc.to_scale(TimeScale::UTC).mjd())
+    }
+}
@@ -0,0 +1,137 @@
+//! Earth orientation: the rotation from the celestial (GCRF) to the
+//! terrestrial (ITRF) frame.
+//!
+//! Two equinox-based chains are provided, each `W · R3(GAST) · N · P`:
+//!
+//! - [`gcrf_to_itrf`]: IAU 2006 precession ([`FukushimaWilliams`], frame bias
+//!   included) with IAU 2000 nutation, either the built-in [`Iau2000B`] or the
+//!   full IAU 2000A [`NutationSeries`] read from the IERS tables, and
+//!   GMST 2006 from the [`earth_rotation_angle`];
+//! - [`gcrf_to_itrf_1976`]: IAU 1976 precession, [`Iau1980`] nutation and
+//!   GAST 1994. It rotates from the mean equator and equinox of J2000.0 (FK5),
+//!   which differs from the GCRF by the 23 mas frame bias.
+//!
+//! Polar motion, DUT1 and LOD come from an [`EopProvider`]: a constant
+//! [`Eop`] record or an [`EopTable`] loaded from IERS `finals2000A` or CSV
+//! files. Celestial pole offsets `dX, dY` are read but not applied. All angles
+//! are radians and matrices are `DirectionCosineMatrix<f64>` frame rotations.
+
+mod nutation;
+mod precession;
+mod sidereal;
+#[cfg(feature = "std")]
+mod eop;
+pub use nutation::{delaunay_arguments, fundamental_arguments, Iau1980, Iau2000B, Nutation, NutationTheory, SeriesTerm};
+#[cfg(feature = "std")]
+pub use nutation::NutationSeries;
+pub use precession::{
+    mean_obliquity_1980, mean_obliquity_2006, nutation_matrix, precession_1976, FukushimaWilliams,
+};
+pub use sidereal::{
+    earth_rotation_angle, equation_of_equinoxes_1994, equation_of_equinoxes_2006, gast_1994, gmst_1982, gmst_2006,
+    polar_motion, tio_locator,
+};
+#[cfg(feature = "std")]
+pub use eop::{EopRecord, EopTable};
+
+use crate::attitude::DirectionCosineMatrix;
+use crate::time::{Epoch, TimeScale, Ut1Provider};
+
+/// Radians per arcsecond.
+pub const ARCSEC_TO_RAD: f64 = core::f64::consts::PI / 648_000.0;
+const TWO_PI: f64 = core::f64::consts::TAU;
+
+fn rx(angle: f64) -> DirectionCosineMatrix<f64> {
+    DirectionCosineMatrix::rotate_x(angle)
+}
+
+fn rz(angle: f64) -> DirectionCosineMatrix<f64> {
+    DirectionCosineMatrix::rotate_z(angle)
+}
+
+/// Earth orientation parameters: pole coordinates `xp, yp` and celestial pole
+/// offsets `dx, dy` in radians, `dut1 = UT1 - UTC` and excess length of day
+/// `lod` in seconds.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Eop {
+    pub xp: f64,
+    pub yp: f64,
+    pub dut1: f64,
+    pub lod: f64,
+    pub dx: f64,
+    pub dy: f64,
+}
+
+/// Source of Earth orientation parameters at a UTC epoch.
+pub trait EopProvider: Ut1Provider {
+    fn parameters(&self, utc: &Epoch) -> Eop;
+}
+
+/// A constant set of parameters.
+impl Ut1Provider for Eop {
+    fn dut1(&self, _utc: &Epoch) -> f64 {
+        self.dut1
+    }
+}
+
+impl EopProvider for Eop {
+    fn parameters(&self, _utc: &Epoch) -> Eop {
+        *self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EopError {
+    /// The file could not be read
+    #[cfg(feature = "std")]
+    Io(std::io::ErrorKind),
+    /// A malformed number on the given (1-based) line
+    Parse { line: usize },
+    /// A required CSV column is absent from the header
+    MissingColumn,
+    /// No usable records
+    Empty,
+    /// Record `index` does not follow its predecessor in time
+    NotIncreasing { index: usize },
+}
+
+/// GCRF to ITRF rotation at `epoch` with IAU 2006 precession and the given
+/// IAU 2000 nutation theory, scaled by the IAU 2006 adjustments (IERS
+/// Conventions 2010, 5.6.3).
+pub fn gcrf_to_itrf<E, N>(epoch: &Epoch, eop: &E, nutation: &N) -> DirectionCosineMatrix<f64>
+where
+    E: EopProvider + ?Sized,
+    N: NutationTheory + ?Sized,
+{
+    let (tt, ut1, parameters) = epochs(epoch, eop);
+    let t = tt.julian_centuries();
+    let angles = FukushimaWilliams::iau2006(t);
+    let Nutation { dpsi, deps } = nutation.nutation(t);
+    let j2 = -2.7774e-6 * t;
+    let (dpsi, deps) = (dpsi * (1.0 + 0.4697e-6 + j2), deps * (1.0 + j2));
+
+    let gast = gmst_2006(&ut1, &tt) + equation_of_equinoxes_2006(t, dpsi, angles.epsilon);
+    let pole = polar_motion(parameters.xp, parameters.yp, tio_locator(t));
+    pole * rz(gast) * angles.with_nutation(dpsi, deps)
+}
+
+/// Mean J2000.0 (FK5) to ITRF rotation at `epoch` with IAU 1976 precession,
+/// IAU 1980 nutation and GAST 1994.
+pub fn gcrf_to_itrf_1976<E: EopProvider + ?Sized>(epoch: &Epoch, eop: &E) -> DirectionCosineMatrix<f64> {
+    let (tt, ut1, parameters) = epochs(epoch, eop);
+    let t = tt.julian_centuries();
+    let Nutation { dpsi, deps } = Iau1980.nutation(t);
+    let nutation = nutation_matrix(mean_obliquity_1980(t), dpsi, deps);
+    let pole = polar_motion(parameters.xp, parameters.yp, 0.0);
+    pole * rz(gast_1994(&ut1, &tt)) * nutation * precession_1976(t)
+}
+
+/// The epoch in TT and UT1 with the parameters at its UTC equivalent.
+fn epochs<E: EopProvider + ?Sized>(epoch: &Epoch, eop: &E) -> (Epoch, Epoch, Eop) {
+    let utc = epoch.to_scale_with(TimeScale::UTC, eop);
+    (epoch.to_scale_with(TimeScale::TT, eop), utc.to_scale_with(TimeScale::UT1, eop), eop.parameters(&utc))
+}
+
+#[cfg(test)]
+#[path = "tests/mod.rs"]
+mod tests;
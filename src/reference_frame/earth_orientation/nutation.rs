@@ -0,0 +1,384 @@
+//! Nutation series: IAU 2000B and IAU 1980 built in, IAU 2000A from the IERS
+//! coefficient tables.
+
+use super::{ARCSEC_TO_RAD, TWO_PI};
+
+/// Nutation in longitude and obliquity, radians.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Nutation {
+    pub dpsi: f64,
+    pub deps: f64,
+}
+
+/// A nutation theory evaluated at `t` Julian centuries of TT since J2000.0.
+pub trait NutationTheory {
+    fn nutation(&self, t: f64) -> Nutation;
+}
+
+/// `x` arcseconds reduced to one turn, in radians.
+fn turn(x: f64) -> f64 {
+    (x % 1_296_000.0) * ARCSEC_TO_RAD
+}
+
+/// Delaunay arguments `l, l', F, D, Ω` (IERS Conventions 2003, 5.43).
+pub fn delaunay_arguments(t: f64) -> [f64; 5] {
+    [
+        turn(485_868.249_036 + t * (1_717_915_923.217_8 + t * (31.879_2 + t * (0.051_635 + t * -0.000_244_70)))),
+        turn(1_287_104.793_048 + t * (129_596_581.048_1 + t * (-0.553_2 + t * (0.000_136 + t * -0.000_011_49)))),
+        turn(335_779.526_232 + t * (1_739_527_262.847_8 + t * (-12.751_2 + t * (-0.001_037 + t * 0.000_004_17)))),
+        turn(1_072_260.703_692 + t * (1_602_961_601.209_0 + t * (-6.370_6 + t * (0.006_593 + t * -0.000_031_69)))),
+        turn(450_160.398_036 + t * (-6_962_890.543_1 + t * (7.472_2 + t * (0.007_702 + t * -0.000_059_39)))),
+    ]
+}
+
+/// Delaunay arguments followed by the mean longitudes of Mercury to Neptune
+/// and the general precession in longitude, the 14 arguments of the IAU
+/// 2000A series (IERS Conventions 2003, 5.44).
+pub fn fundamental_arguments(t: f64) -> [f64; 14] {
+    let [l, lp, f, d, om] = delaunay_arguments(t);
+    let planet = |l0: f64, rate: f64| (l0 + rate * t) % TWO_PI;
+    [
+        l,
+        lp,
+        f,
+        d,
+        om,
+        planet(4.402_608_842, 2_608.790_314_157_4),
+        planet(3.176_146_697, 1_021.328_554_621_1),
+        planet(1.753_470_314, 628.307_584_999_1),
+        planet(6.203_480_913, 334.061_242_670_0),
+        planet(0.599_546_497, 52.969_096_264_1),
+        planet(0.874_016_757, 21.329_910_496_0),
+        planet(5.481_293_872, 7.478_159_856_7),
+        planet(5.311_886_287, 3.813_303_563_8),
+        (0.024_381_750 + 0.000_005_386_91 * t) * t,
+    ]
+}
+
+fn argument<const K: usize>(multipliers: &[i8; K], arguments: &[f64; K]) -> f64 {
+    multipliers.iter().zip(arguments).map(|(&n, &a)| n as f64 * a).sum::<f64>() % TWO_PI
+}
+
+/// IAU 2000B, 77 luni-solar terms plus fixed planetary offsets; within 1 mas
+/// of IAU 2000A between 1995 and 2050 (McCarthy & Luzum 2003).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Iau2000B;
+
+/// IAU 1980, 106 terms, to be used with IAU 1976 precession.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Iau1980;
+
+impl NutationTheory for Iau2000B {
+    fn nutation(&self, t: f64) -> Nutation {
+        // Linear arguments of the abridged model, not the full polynomials
+        let arguments = [
+            turn(485_868.249_036 + 1_717_915_923.217_8 * t),
+            turn(1_287_104.793_05 + 129_596_581.048_1 * t),
+            turn(335_779.526_232 + 1_739_527_262.847_8 * t),
+            turn(1_072_260.703_69 + 1_602_961_601.209_0 * t),
+            turn(450_160.398_036 - 6_962_890.543_1 * t),
+        ];
+        let (mut dpsi, mut deps) = (0.0, 0.0);
+        for (multipliers, [ps, pst, pc, ec, ect, es]) in IAU2000B_TERMS.iter().rev() {
+            let (s, c) = argument(multipliers, &arguments).sin_cos();
+            dpsi += (ps + pst * t) * s + pc * c;
+            deps += (ec + ect * t) * c + es * s;
+        }
+        // Coefficients in 0.1 μas; planetary terms as constant offsets in mas
+        let unit = ARCSEC_TO_RAD * 1e-7;
+        let mas = ARCSEC_TO_RAD * 1e-3;
+        Nutation { dpsi: dpsi * unit - 0.135 * mas, deps: deps * unit + 0.388 * mas }
+    }
+}
+
+impl NutationTheory for Iau1980 {
+    fn nutation(&self, t: f64) -> Nutation {
+        let revolution = |x: f64, turns: f64| turn(x) + (turns * t % 1.0) * TWO_PI;
+        let arguments = [
+            revolution(485_866.733 + (715_922.633 + (31.310 + 0.064 * t) * t) * t, 1325.0),
+            revolution(1_287_099.804 + (1_292_581.224 + (-0.577 - 0.012 * t) * t) * t, 99.0),
+            revolution(335_778.877 + (295_263.137 + (-13.257 + 0.011 * t) * t) * t, 1342.0),
+            revolution(1_072_261.307 + (1_105_601.328 + (-6.891 + 0.019 * t) * t) * t, 1236.0),
+            revolution(450_160.280 + (-482_890.539 + (7.455 + 0.008 * t) * t) * t, -5.0),
+        ];
+        let (mut dpsi, mut deps) = (0.0, 0.0);
+        for (multipliers, [sp, spt, ce, cet]) in IAU1980_TERMS.iter().rev() {
+            let (s, c) = argument(multipliers, &arguments).sin_cos();
+            dpsi += (sp + spt * t) * s;
+            deps += (ce + cet * t) * c;
+        }
+        // Coefficients in 0.1 mas
+        let unit = ARCSEC_TO_RAD * 1e-4;
+        Nutation { dpsi: dpsi * unit, deps: deps * unit }
+    }
+}
+
+/// One term `t^power (sin · sin(arg) + cos · cos(arg))` of a series over the
+/// 14 [`fundamental_arguments`], coefficients in radians.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SeriesTerm {
+    pub multipliers: [i8; 14],
+    pub power: i32,
+    pub sin: f64,
+    pub cos: f64,
+}
+
+/// A full nutation series such as IAU 2000A (1365 terms), read from the IERS
+/// Conventions coefficient tables.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct NutationSeries {
+    pub longitude: std::vec::Vec<SeriesTerm>,
+    pub obliquity: std::vec::Vec<SeriesTerm>,
+}
+
+#[cfg(feature = "std")]
+impl NutationSeries {
+    /// Reads IERS Conventions tables 5.3a (longitude) and 5.3b (obliquity),
+    /// e.g. `tab5.3a.txt` and `tab5.3b.txt`.
+    pub fn load(
+        longitude: impl AsRef<std::path::Path>,
+        obliquity: impl AsRef<std::path::Path>,
+    ) -> Result<Self, super::EopError> {
+        Ok(Self {
+            longitude: Self::parse_table(&super::eop::read(longitude.as_ref())?)?,
+            obliquity: Self::parse_table(&super::eop::read(obliquity.as_ref())?)?,
+        })
+    }
+
+    /// Parses one IERS table: rows of `i`, the sine and cosine coefficients
+    /// in μas and the 14 argument multipliers, grouped under `j = 0`,
+    /// `j = 1`, ... headers giving the power of `t`. Other lines are ignored.
+    pub fn parse_table(text: &str) -> Result<std::vec::Vec<SeriesTerm>, super::EopError> {
+        let mut terms = std::vec::Vec::new();
+        let mut power = 0;
+        for (index, line) in text.lines().enumerate() {
+            let error = super::EopError::Parse { line: index + 1 };
+            let trimmed = line.trim_start();
+            if let Some(rest) = trimmed.strip_prefix('j').map(str::trim_start).and_then(|r| r.strip_prefix('=')) {
+                let digits = rest.trim_start().split(|c: char| !c.is_ascii_digit()).next().unwrap_or("");
+                power = digits.parse().map_err(|_| error)?;
+                continue;
+            }
+            let fields: std::vec::Vec<&str> = trimmed.split_whitespace().collect();
+            if fields.len() != 17 || fields[0].parse::<u32>().is_err() {
+                continue;
+            }
+            let coefficient = |s: &str| s.parse::<f64>().map(|x| x * ARCSEC_TO_RAD * 1e-6).map_err(|_| error);
+            let mut multipliers = [0; 14];
+            for (m, field) in multipliers.iter_mut().zip(&fields[3..]) {
+                *m = field.parse().map_err(|_| error)?;
+            }
+            terms.push(SeriesTerm { multipliers, power, sin: coefficient(fields[1])?, cos: coefficient(fields[2])? });
+        }
+        Ok(terms)
+    }
+}
+
+#[cfg(feature = "std")]
+impl NutationTheory for NutationSeries {
+    fn nutation(&self, t: f64) -> Nutation {
+        let arguments = fundamental_arguments(t);
+        let sum = |terms: &[SeriesTerm]| {
+            terms.iter().rev().fold(0.0, |total, term| {
+                let (s, c) = argument(&term.multipliers, &arguments).sin_cos();
+                total + t.powi(term.power) * (term.sin * s + term.cos * c)
+            })
+        };
+        Nutation { dpsi: sum(&self.longitude), deps: sum(&self.obliquity) }
+    }
+}
+
+/// IAU 2000B luni-solar terms (McCarthy & Luzum 2003): multipliers of
+/// `l, l', F, D, Ω`, then longitude `sin, sin·t, cos` and obliquity
+/// `cos, cos·t, sin` coefficients in 0.1 μas.
+const IAU2000B_TERMS: [([i8; 5], [f64; 6]); 77] = [
+    ([ 0, 0, 0, 0, 1], [-172064161.0, -174666.0, 33386.0, 92052331.0, 9086.0, 15377.0]),
+    ([ 0, 0, 2,-2, 2], [-13170906.0, -1675.0, -13696.0, 5730336.0, -3015.0, -4587.0]),
+    ([ 0, 0, 2, 0, 2], [-2276413.0, -234.0, 2796.0, 978459.0, -485.0, 1374.0]),
+    ([ 0, 0, 0, 0, 2], [2074554.0, 207.0, -698.0, -897492.0, 470.0, -291.0]),
+    ([ 0, 1, 0, 0, 0], [1475877.0, -3633.0, 11817.0, 73871.0, -184.0, -1924.0]),
+    ([ 0, 1, 2,-2, 2], [-516821.0, 1226.0, -524.0, 224386.0, -677.0, -174.0]),
+    ([ 1, 0, 0, 0, 0], [711159.0, 73.0, -872.0, -6750.0, 0.0, 358.0]),
+    ([ 0, 0, 2, 0, 1], [-387298.0, -367.0, 380.0, 200728.0, 18.0, 318.0]),
+    ([ 1, 0, 2, 0, 2], [-301461.0, -36.0, 816.0, 129025.0, -63.0, 367.0]),
+    ([ 0,-1, 2,-2, 2], [215829.0, -494.0, 111.0, -95929.0, 299.0, 132.0]),
+    ([ 0, 0, 2,-2, 1], [128227.0, 137.0, 181.0, -68982.0, -9.0, 39.0]),
+    ([-1, 0, 2, 0, 2], [123457.0, 11.0, 19.0, -53311.0, 32.0, -4.0]),
+    ([-1, 0, 0, 2, 0], [156994.0, 10.0, -168.0, -1235.0, 0.0, 82.0]),
+    ([ 1, 0, 0, 0, 1], [63110.0, 63.0, 27.0, -33228.0, 0.0, -9.0]),
+    ([-1, 0, 0, 0, 1], [-57976.0, -63.0, -189.0, 31429.0, 0.0, -75.0]),
+    ([-1, 0, 2, 2, 2], [-59641.0, -11.0, 149.0, 25543.0, -11.0, 66.0]),
+    ([ 1, 0, 2, 0, 1], [-51613.0, -42.0, 129.0, 26366.0, 0.0, 78.0]),
+    ([-2, 0, 2, 0, 1], [45893.0, 50.0, 31.0, -24236.0, -10.0, 20.0]),
+    ([ 0, 0, 0, 2, 0], [63384.0, 11.0, -150.0, -1220.0, 0.0, 29.0]),
+    ([ 0, 0, 2, 2, 2], [-38571.0, -1.0, 158.0, 16452.0, -11.0, 68.0]),
+    ([ 0,-2, 2,-2, 2], [32481.0, 0.0, 0.0, -13870.0, 0.0, 0.0]),
+    ([-2, 0, 0, 2, 0], [-47722.0, 0.0, -18.0, 477.0, 0.0, -25.0]),
+    ([ 2, 0, 2, 0, 2], [-31046.0, -1.0, 131.0, 13238.0, -11.0, 59.0]),
+    ([ 1, 0, 2,-2, 2], [28593.0, 0.0, -1.0, -12338.0, 10.0, -3.0]),
+    ([-1, 0, 2, 0, 1], [20441.0, 21.0, 10.0, -10758.0, 0.0, -3.0]),
+    ([ 2, 0, 0, 0, 0], [29243.0, 0.0, -74.0, -609.0, 0.0, 13.0]),
+    ([ 0, 0, 2, 0, 0], [25887.0, 0.0, -66.0, -550.0, 0.0, 11.0]),
+    ([ 0, 1, 0, 0, 1], [-14053.0, -25.0, 79.0, 8551.0, -2.0, -45.0]),
+    ([-1, 0, 0, 2, 1], [15164.0, 10.0, 11.0, -8001.0, 0.0, -1.0]),
+    ([ 0, 2, 2,-2, 2], [-15794.0, 72.0, -16.0, 6850.0, -42.0, -5.0]),
+    ([ 0, 0,-2, 2, 0], [21783.0, 0.0, 13.0, -167.0, 0.0, 13.0]),
+    ([ 1, 0, 0,-2, 1], [-12873.0, -10.0, -37.0, 6953.0, 0.0, -14.0]),
+    ([ 0,-1, 0, 0, 1], [-12654.0, 11.0, 63.0, 6415.0, 0.0, 26.0]),
+    ([-1, 0, 2, 2, 1], [-10204.0, 0.0, 25.0, 5222.0, 0.0, 15.0]),
+    ([ 0, 2, 0, 0, 0], [16707.0, -85.0, -10.0, 168.0, -1.0, 10.0]),
+    ([ 1, 0, 2, 2, 2], [-7691.0, 0.0, 44.0, 3268.0, 0.0, 19.0]),
+    ([-2, 0, 2, 0, 0], [-11024.0, 0.0, -14.0, 104.0, 0.0, 2.0]),
+    ([ 0, 1, 2, 0, 2], [7566.0, -21.0, -11.0, -3250.0, 0.0, -5.0]),
+    ([ 0, 0, 2, 2, 1], [-6637.0, -11.0, 25.0, 3353.0, 0.0, 14.0]),
+    ([ 0,-1, 2, 0, 2], [-7141.0, 21.0, 8.0, 3070.0, 0.0, 4.0]),
+    ([ 0, 0, 0, 2, 1], [-6302.0, -11.0, 2.0, 3272.0, 0.0, 4.0]),
+    ([ 1, 0, 2,-2, 1], [5800.0, 10.0, 2.0, -3045.0, 0.0, -1.0]),
+    ([ 2, 0, 2,-2, 2], [6443.0, 0.0, -7.0, -2768.0, 0.0, -4.0]),
+    ([-2, 0, 0, 2, 1], [-5774.0, -11.0, -15.0, 3041.0, 0.0, -5.0]),
+    ([ 2, 0, 2, 0, 1], [-5350.0, 0.0, 21.0, 2695.0, 0.0, 12.0]),
+    ([ 0,-1, 2,-2, 1], [-4752.0, -11.0, -3.0, 2719.0, 0.0, -3.0]),
+    ([ 0, 0, 0,-2, 1], [-4940.0, -11.0, -21.0, 2720.0, 0.0, -9.0]),
+    ([-1,-1, 0, 2, 0], [7350.0, 0.0, -8.0, -51.0, 0.0, 4.0]),
+    ([ 2, 0, 0,-2, 1], [4065.0, 0.0, 6.0, -2206.0, 0.0, 1.0]),
+    ([ 1, 0, 0, 2, 0], [6579.0, 0.0, -24.0, -199.0, 0.0, 2.0]),
+    ([ 0, 1, 2,-2, 1], [3579.0, 0.0, 5.0, -1900.0, 0.0, 1.0]),
+    ([ 1,-1, 0, 0, 0], [4725.0, 0.0, -6.0, -41.0, 0.0, 3.0]),
+    ([-2, 0, 2, 0, 2], [-3075.0, 0.0, -2.0, 1313.0, 0.0, -1.0]),
+    ([ 3, 0, 2, 0, 2], [-2904.0, 0.0, 15.0, 1233.0, 0.0, 7.0]),
+    ([ 0,-1, 0, 2, 0], [4348.0, 0.0, -10.0, -81.0, 0.0, 2.0]),
+    ([ 1,-1, 2, 0, 2], [-2878.0, 0.0, 8.0, 1232.0, 0.0, 4.0]),
+    ([ 0, 0, 0, 1, 0], [-4230.0, 0.0, 5.0, -20.0, 0.0, -2.0]),
+    ([-1,-1, 2, 2, 2], [-2819.0, 0.0, 7.0, 1207.0, 0.0, 3.0]),
+    ([-1, 0, 2, 0, 0], [-4056.0, 0.0, 5.0, 40.0, 0.0, -2.0]),
+    ([ 0,-1, 2, 2, 2], [-2647.0, 0.0, 11.0, 1129.0, 0.0, 5.0]),
+    ([-2, 0, 0, 0, 1], [-2294.0, 0.0, -10.0, 1266.0, 0.0, -4.0]),
+    ([ 1, 1, 2, 0, 2], [2481.0, 0.0, -7.0, -1062.0, 0.0, -3.0]),
+    ([ 2, 0, 0, 0, 1], [2179.0, 0.0, -2.0, -1129.0, 0.0, -2.0]),
+    ([-1, 1, 0, 1, 0], [3276.0, 0.0, 1.0, -9.0, 0.0, 0.0]),
+    ([ 1, 1, 0, 0, 0], [-3389.0, 0.0, 5.0, 35.0, 0.0, -2.0]),
+    ([ 1, 0, 2, 0, 0], [3339.0, 0.0, -13.0, -107.0, 0.0, 1.0]),
+    ([-1, 0, 2,-2, 1], [-1987.0, 0.0, -6.0, 1073.0, 0.0, -2.0]),
+    ([ 1, 0, 0, 0, 2], [-1981.0, 0.0, 0.0, 854.0, 0.0, 0.0]),
+    ([-1, 0, 0, 1, 0], [4026.0, 0.0, -353.0, -553.0, 0.0, -139.0]),
+    ([ 0, 0, 2, 1, 2], [1660.0, 0.0, -5.0, -710.0, 0.0, -2.0]),
+    ([-1, 0, 2, 4, 2], [-1521.0, 0.0, 9.0, 647.0, 0.0, 4.0]),
+    ([-1, 1, 0, 1, 1], [1314.0, 0.0, 0.0, -700.0, 0.0, 0.0]),
+    ([ 0,-2, 2,-2, 1], [-1283.0, 0.0, 0.0, 672.0, 0.0, 0.0]),
+    ([ 1, 0, 2, 2, 1], [-1331.0, 0.0, 8.0, 663.0, 0.0, 4.0]),
+    ([-2, 0, 2, 2, 2], [1383.0, 0.0, -2.0, -594.0, 0.0, -2.0]),
+    ([-1, 0, 0, 0, 2], [1405.0, 0.0, 4.0, -610.0, 0.0, 2.0]),
+    ([ 1, 1, 2,-2, 2], [1290.0, 0.0, 0.0, -556.0, 0.0, 0.0]),
+];
+
+/// IAU 1980 terms (Seidelmann 1982): multipliers of `l, l', F, D, Ω`, then
+/// longitude `sin, sin·t` and obliquity `cos, cos·t` coefficients in 0.1 mas.
+const IAU1980_TERMS: [([i8; 5], [f64; 4]); 106] = [
+    ([ 0, 0, 0, 0, 1], [-171996.0, -174.2, 92025.0, 8.9]),
+    ([ 0, 0, 0, 0, 2], [2062.0, 0.2, -895.0, 0.5]),
+    ([-2, 0, 2, 0, 1], [46.0, 0.0, -24.0, 0.0]),
+    ([ 2, 0,-2, 0, 0], [11.0, 0.0, 0.0, 0.0]),
+    ([-2, 0, 2, 0, 2], [-3.0, 0.0, 1.0, 0.0]),
+    ([ 1,-1, 0,-1, 0], [-3.0, 0.0, 0.0, 0.0]),
+    ([ 0,-2, 2,-2, 1], [-2.0, 0.0, 1.0, 0.0]),
+    ([ 2, 0,-2, 0, 1], [1.0, 0.0, 0.0, 0.0]),
+    ([ 0, 0, 2,-2, 2], [-13187.0, -1.6, 5736.0, -3.1]),
+    ([ 0, 1, 0, 0, 0], [1426.0, -3.4, 54.0, -0.1]),
+    ([ 0, 1, 2,-2, 2], [-517.0, 1.2, 224.0, -0.6]),
+    ([ 0,-1, 2,-2, 2], [217.0, -0.5, -95.0, 0.3]),
+    ([ 0, 0, 2,-2, 1], [129.0, 0.1, -70.0, 0.0]),
+    ([ 2, 0, 0,-2, 0], [48.0, 0.0, 1.0, 0.0]),
+    ([ 0, 0, 2,-2, 0], [-22.0, 0.0, 0.0, 0.0]),
+    ([ 0, 2, 0, 0, 0], [17.0, -0.1, 0.0, 0.0]),
+    ([ 0, 1, 0, 0, 1], [-15.0, 0.0, 9.0, 0.0]),
+    ([ 0, 2, 2,-2, 2], [-16.0, 0.1, 7.0, 0.0]),
+    ([ 0,-1, 0, 0, 1], [-12.0, 0.0, 6.0, 0.0]),
+    ([-2, 0, 0, 2, 1], [-6.0, 0.0, 3.0, 0.0]),
+    ([ 0,-1, 2,-2, 1], [-5.0, 0.0, 3.0, 0.0]),
+    ([ 2, 0, 0,-2, 1], [4.0, 0.0, -2.0, 0.0]),
+    ([ 0, 1, 2,-2, 1], [4.0, 0.0, -2.0, 0.0]),
+    ([ 1, 0, 0,-1, 0], [-4.0, 0.0, 0.0, 0.0]),
+    ([ 2, 1, 0,-2, 0], [1.0, 0.0, 0.0, 0.0]),
+    ([ 0, 0,-2, 2, 1], [1.0, 0.0, 0.0, 0.0]),
+    ([ 0, 1,-2, 2, 0], [-1.0, 0.0, 0.0, 0.0]),
+    ([ 0, 1, 0, 0, 2], [1.0, 0.0, 0.0, 0.0]),
+    ([-1, 0, 0, 1, 1], [1.0, 0.0, 0.0, 0.0]),
+    ([ 0, 1, 2,-2, 0], [-1.0, 0.0, 0.0, 0.0]),
+    ([ 0, 0, 2, 0, 2], [-2274.0, -0.2, 977.0, -0.5]),
+    ([ 1, 0, 0, 0, 0], [712.0, 0.1, -7.0, 0.0]),
+    ([ 0, 0, 2, 0, 1], [-386.0, -0.4, 200.0, 0.0]),
+    ([ 1, 0, 2, 0, 2], [-301.0, 0.0, 129.0, -0.1]),
+    ([ 1, 0, 0,-2, 0], [-158.0, 0.0, -1.0, 0.0]),
+    ([-1, 0, 2, 0, 2], [123.0, 0.0, -53.0, 0.0]),
+    ([ 0, 0, 0, 2, 0], [63.0, 0.0, -2.0, 0.0]),
+    ([ 1, 0, 0, 0, 1], [63.0, 0.1, -33.0, 0.0]),
+    ([-1, 0, 0, 0, 1], [-58.0, -0.1, 32.0, 0.0]),
+    ([-1, 0, 2, 2, 2], [-59.0, 0.0, 26.0, 0.0]),
+    ([ 1, 0, 2, 0, 1], [-51.0, 0.0, 27.0, 0.0]),
+    ([ 0, 0, 2, 2, 2], [-38.0, 0.0, 16.0, 0.0]),
+    ([ 2, 0, 0, 0, 0], [29.0, 0.0, -1.0, 0.0]),
+    ([ 1, 0, 2,-2, 2], [29.0, 0.0, -12.0, 0.0]),
+    ([ 2, 0, 2, 0, 2], [-31.0, 0.0, 13.0, 0.0]),
+    ([ 0, 0, 2, 0, 0], [26.0, 0.0, -1.0, 0.0]),
+    ([-1, 0, 2, 0, 1], [21.0, 0.0, -10.0, 0.0]),
+    ([-1, 0, 0, 2, 1], [16.0, 0.0, -8.0, 0.0]),
+    ([ 1, 0, 0,-2, 1], [-13.0, 0.0, 7.0, 0.0]),
+    ([-1, 0, 2, 2, 1], [-10.0, 0.0, 5.0, 0.0]),
+    ([ 1, 1, 0,-2, 0], [-7.0, 0.0, 0.0, 0.0]),
+    ([ 0, 1, 2, 0, 2], [7.0, 0.0, -3.0, 0.0]),
+    ([ 0,-1, 2, 0, 2], [-7.0, 0.0, 3.0, 0.0]),
+    ([ 1, 0, 2, 2, 2], [-8.0, 0.0, 3.0, 0.0]),
+    ([ 1, 0, 0, 2, 0], [6.0, 0.0, 0.0, 0.0]),
+    ([ 2, 0, 2,-2, 2], [6.0, 0.0, -3.0, 0.0]),
+    ([ 0, 0, 0, 2, 1], [-6.0, 0.0, 3.0, 0.0]),
+    ([ 0, 0, 2, 2, 1], [-7.0, 0.0, 3.0, 0.0]),
+    ([ 1, 0, 2,-2, 1], [6.0, 0.0, -3.0, 0.0]),
+    ([ 0, 0, 0,-2, 1], [-5.0, 0.0, 3.0, 0.0]),
+    ([ 1,-1, 0, 0, 0], [5.0, 0.0, 0.0, 0.0]),
+    ([ 2, 0, 2, 0, 1], [-5.0, 0.0, 3.0, 0.0]),
+    ([ 0, 1, 0,-2, 0], [-4.0, 0.0, 0.0, 0.0]),
+    ([ 1, 0,-2, 0, 0], [4.0, 0.0, 0.0, 0.0]),
+    ([ 0, 0, 0, 1, 0], [-4.0, 0.0, 0.0, 0.0]),
+    ([ 1, 1, 0, 0, 0], [-3.0, 0.0, 0.0, 0.0]),
+    ([ 1, 0, 2, 0, 0], [3.0, 0.0, 0.0, 0.0]),
+    ([ 1,-1, 2, 0, 2], [-3.0, 0.0, 1.0, 0.0]),
+    ([-1,-1, 2, 2, 2], [-3.0, 0.0, 1.0, 0.0]),
+    ([-2, 0, 0, 0, 1], [-2.0, 0.0, 1.0, 0.0]),
+    ([ 3, 0, 2, 0, 2], [-3.0, 0.0, 1.0, 0.0]),
+    ([ 0,-1, 2, 2, 2], [-3.0, 0.0, 1.0, 0.0]),
+    ([ 1, 1, 2, 0, 2], [2.0, 0.0, -1.0, 0.0]),
+    ([-1, 0, 2,-2, 1], [-2.0, 0.0, 1.0, 0.0]),
+    ([ 2, 0, 0, 0, 1], [2.0, 0.0, -1.0, 0.0]),
+    ([ 1, 0, 0, 0, 2], [-2.0, 0.0, 1.0, 0.0]),
+    ([ 3, 0, 0, 0, 0], [2.0, 0.0, 0.0, 0.0]),
+    ([ 0, 0, 2, 1, 2], [2.0, 0.0, -1.0, 0.0]),
+    ([-1, 0, 0, 0, 2], [1.0, 0.0, -1.0, 0.0]),
+    ([ 1, 0, 0,-4, 0], [-1.0, 0.0, 0.0, 0.0]),
+    ([-2, 0, 2, 2, 2], [1.0, 0.0, -1.0, 0.0]),
+    ([-1, 0, 2, 4, 2], [-2.0, 0.0, 1.0, 0.0]),
+    ([ 2, 0, 0,-4, 0], [-1.0, 0.0, 0.0, 0.0]),
+    ([ 1, 1, 2,-2, 2], [1.0, 0.0, -1.0, 0.0]),
+    ([ 1, 0, 2, 2, 1], [-1.0, 0.0, 1.0, 0.0]),
+    ([-2, 0, 2, 4, 2], [-1.0, 0.0, 1.0, 0.0]),
+    ([-1, 0, 4, 0, 2], [1.0, 0.0, 0.0, 0.0]),
+    ([ 1,-1, 0,-2, 0], [1.0, 0.0, 0.0, 0.0]),
+    ([ 2, 0, 2,-2, 1], [1.0, 0.0, -1.0, 0.0]),
+    ([ 2, 0, 2, 2, 2], [-1.0, 0.0, 0.0, 0.0]),
+    ([ 1, 0, 0, 2, 1], [-1.0, 0.0, 0.0, 0.0]),
+    ([ 0, 0, 4,-2, 2], [1.0, 0.0, 0.0, 0.0]),
+    ([ 3, 0, 2,-2, 2], [1.0, 0.0, 0.0, 0.0]),
+    ([ 1, 0, 2,-2, 0], [-1.0, 0.0, 0.0, 0.0]),
+    ([ 0, 1, 2, 0, 1], [1.0, 0.0, 0.0, 0.0]),
+    ([-1,-1, 0, 2, 1], [1.0, 0.0, 0.0, 0.0]),
+    ([ 0, 0,-2, 0, 1], [-1.0, 0.0, 0.0, 0.0]),
+    ([ 0, 0, 2,-1, 2], [-1.0, 0.0, 0.0, 0.0]),
+    ([ 0, 1, 0, 2, 0], [-1.0, 0.0, 0.0, 0.0]),
+    ([ 1, 0,-2,-2, 0], [-1.0, 0.0, 0.0, 0.0]),
+    ([ 0,-1, 2, 0, 1], [-1.0, 0.0, 0.0, 0.0]),
+    ([ 1, 1, 0,-2, 1], [-1.0, 0.0, 0.0, 0.0]),
+    ([ 1, 0,-2, 2, 0], [-1.0, 0.0, 0.0, 0.0]),
+    ([ 2, 0, 0, 2, 0], [1.0, 0.0, 0.0, 0.0]),
+    ([ 0, 0, 2, 4, 2], [-1.0, 0.0, 0.0, 0.0]),
+    ([ 0, 1, 0, 1, 0], [1.0, 0.0, 0.0, 0.0]),
+];
@@ -0,0 +1,66 @@
+//! Precession of the equator and mean obliquity of the ecliptic.
+
+use super::{rx, rz, ARCSEC_TO_RAD};
+use crate::attitude::DirectionCosineMatrix;
+
+/// Fukushima-Williams bias-precession angles, radians: the ecliptic node
+/// `gamma` and inclination `phi` relative to the GCRS, the precession in
+/// longitude `psi` and the mean obliquity `epsilon`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FukushimaWilliams {
+    pub gamma: f64,
+    pub phi: f64,
+    pub psi: f64,
+    pub epsilon: f64,
+}
+
+impl FukushimaWilliams {
+    /// IAU 2006 angles at `t` Julian centuries of TT since J2000.0
+    /// (Hilton et al. 2006), frame bias included.
+    pub fn iau2006(t: f64) -> Self {
+        let arcsec = |coefficients: [f64; 6]| coefficients.iter().rev().fold(0.0, |s, c| s * t + c) * ARCSEC_TO_RAD;
+        Self {
+            gamma: arcsec([-0.052_928, 10.556_378, 0.493_204_4, -0.000_312_38, -0.000_002_788, 0.000_000_026_0]),
+            phi: arcsec([84_381.412_819, -46.811_016, 0.051_126_8, 0.000_532_89, -0.000_000_440, -0.000_000_017_6]),
+            psi: arcsec([-0.041_775, 5_038.481_484, 1.558_417_5, -0.000_185_22, -0.000_026_452, -0.000_000_014_8]),
+            epsilon: mean_obliquity_2006(t),
+        }
+    }
+
+    /// Bias-precession matrix, GCRS to mean equator and equinox of date.
+    pub fn matrix(&self) -> DirectionCosineMatrix<f64> {
+        rx(-self.epsilon) * rz(-self.psi) * rx(self.phi) * rz(self.gamma)
+    }
+
+    /// Bias-precession-nutation matrix, GCRS to true equator and equinox of
+    /// date.
+    pub fn with_nutation(&self, dpsi: f64, deps: f64) -> DirectionCosineMatrix<f64> {
+        Self { psi: self.psi + dpsi, epsilon: self.epsilon + deps, ..*self }.matrix()
+    }
+}
+
+/// IAU 2006 mean obliquity of the ecliptic, radians.
+pub fn mean_obliquity_2006(t: f64) -> f64 {
+    let coefficients = [84_381.406, -46.836_769, -0.000_183_1, 0.002_003_40, -0.000_000_576, -0.000_000_043_4];
+    coefficients.iter().rev().fold(0.0, |s, c| s * t + c) * ARCSEC_TO_RAD
+}
+
+/// IAU 1980 mean obliquity of the ecliptic, radians.
+pub fn mean_obliquity_1980(t: f64) -> f64 {
+    (84_381.448 + (-46.815_0 + (-0.000_59 + 0.001_813 * t) * t) * t) * ARCSEC_TO_RAD
+}
+
+/// IAU 1976 precession matrix (Lieske et al. 1977), mean equator and equinox
+/// of J2000.0 to those of date.
+pub fn precession_1976(t: f64) -> DirectionCosineMatrix<f64> {
+    let zeta = (2_306.218_1 + (0.301_88 + 0.017_998 * t) * t) * t * ARCSEC_TO_RAD;
+    let z = (2_306.218_1 + (1.094_68 + 0.018_203 * t) * t) * t * ARCSEC_TO_RAD;
+    let theta = (2_004.310_9 + (-0.426_65 - 0.041_833 * t) * t) * t * ARCSEC_TO_RAD;
+    rz(-z) * DirectionCosineMatrix::rotate_y(theta) * rz(-zeta)
+}
+
+/// Nutation matrix, mean to true equator and equinox of date, for mean
+/// obliquity `epsilon`.
+pub fn nutation_matrix(epsilon: f64, dpsi: f64, deps: f64) -> DirectionCosineMatrix<f64> {
+    rx(-(epsilon + deps)) * rz(-dpsi) * rx(epsilon)
+}
@@ -0,0 +1,93 @@
+//! Earth rotation angle, sidereal time and polar motion.
+
+use super::nutation::{delaunay_arguments, Iau1980, NutationTheory};
+use super::precession::mean_obliquity_1980;
+use super::{rx, rz, ARCSEC_TO_RAD, TWO_PI};
+use crate::attitude::DirectionCosineMatrix;
+use crate::time::{Epoch, TimeScale};
+
+fn normalize_angle(angle: f64) -> f64 {
+    angle.rem_euclid(TWO_PI)
+}
+
+/// Days since J2000.0 and fraction of the Julian day (from noon) of a UT1
+/// epoch, kept apart for precision.
+fn ut1_days(ut1: &Epoch) -> (f64, f64) {
+    let ut1 = ut1.to_scale(TimeScale::UT1);
+    let (_, fraction) = ut1.jd_split();
+    (ut1.days_since_j2000(), (fraction + 0.5) % 1.0)
+}
+
+/// Earth rotation angle (IAU 2000), radians in `[0, 2π)`. Epochs not in UT1
+/// are converted with DUT1 = 0; convert with an EOP table first for full
+/// accuracy.
+pub fn earth_rotation_angle(ut1: &Epoch) -> f64 {
+    let (days, fraction) = ut1_days(ut1);
+    normalize_angle(TWO_PI * (fraction + 0.779_057_273_264_0 + 0.002_737_811_911_354_48 * days))
+}
+
+/// Greenwich mean sidereal time consistent with IAU 2006 precession,
+/// radians.
+pub fn gmst_2006(ut1: &Epoch, tt: &Epoch) -> f64 {
+    let t = tt.to_scale(TimeScale::TT).julian_centuries();
+    let coefficients = [0.014_506, 4_612.156_534, 1.391_581_7, -0.000_000_44, -0.000_029_956, -0.000_000_036_8];
+    let polynomial = coefficients.iter().rev().fold(0.0, |s, c| s * t + c);
+    normalize_angle(earth_rotation_angle(ut1) + polynomial * ARCSEC_TO_RAD)
+}
+
+/// Equation of the equinoxes for IAU 2006/2000 given the nutation in
+/// longitude and mean obliquity: `Δψ cos ε` plus the largest complementary
+/// terms (IERS Conventions 2010, table 5.2e), good to about 0.5 μas.
+pub fn equation_of_equinoxes_2006(t: f64, dpsi: f64, epsilon: f64) -> f64 {
+    let [l, lp, f, d, om] = delaunay_arguments(t);
+    // μas sine coefficients and arguments
+    let terms = [
+        (2640.96, om),
+        (63.52, 2.0 * om),
+        (11.75, 2.0 * f - 2.0 * d + 3.0 * om),
+        (11.21, 2.0 * f - 2.0 * d + om),
+        (-4.55, 2.0 * f - 2.0 * d + 2.0 * om),
+        (2.02, 2.0 * f + 3.0 * om),
+        (1.98, 2.0 * f + om),
+        (-1.72, 3.0 * om),
+        (-1.41, lp + om),
+        (-1.26, lp - om),
+        (-0.63, l - om),
+        (-0.63, l + om),
+    ];
+    let complementary = terms.iter().map(|(a, arg)| a * arg.sin()).sum::<f64>() - 0.39 * om.cos() - 0.87 * t * om.sin();
+    dpsi * epsilon.cos() + complementary * 1e-6 * ARCSEC_TO_RAD
+}
+
+/// Greenwich mean sidereal time, IAU 1982 (Aoki et al.), radians.
+pub fn gmst_1982(ut1: &Epoch) -> f64 {
+    let (days, fraction) = ut1_days(ut1);
+    let t = days / 36_525.0;
+    let seconds = 24_110.548_41 - 43_200.0 + (8_640_184.812_866 + (0.093_104 - 6.2e-6 * t) * t) * t;
+    normalize_angle((seconds / 86_400.0 + fraction) * TWO_PI)
+}
+
+/// Equation of the equinoxes, IAU 1994, radians.
+pub fn equation_of_equinoxes_1994(tt: &Epoch) -> f64 {
+    let t = tt.to_scale(TimeScale::TT).julian_centuries();
+    let om = (450_160.280 + (-482_890.539 + (7.455 + 0.008 * t) * t) * t) * ARCSEC_TO_RAD + (-5.0 * t % 1.0) * TWO_PI;
+    let nutation = Iau1980.nutation(t);
+    nutation.dpsi * mean_obliquity_1980(t).cos() + (0.002_64 * om.sin() + 0.000_063 * (2.0 * om).sin()) * ARCSEC_TO_RAD
+}
+
+/// Greenwich apparent sidereal time, IAU 1994: GMST 1982 plus the 1994
+/// equation of the equinoxes, radians.
+pub fn gast_1994(ut1: &Epoch, tt: &Epoch) -> f64 {
+    normalize_angle(gmst_1982(ut1) + equation_of_equinoxes_1994(tt))
+}
+
+/// TIO locator `s'` (IERS Conventions 2003), radians.
+pub fn tio_locator(t: f64) -> f64 {
+    -47e-6 * t * ARCSEC_TO_RAD
+}
+
+/// Polar motion matrix, TIRS to ITRS, for pole coordinates `xp, yp` and TIO
+/// locator `sp` (radians).
+pub fn polar_motion(xp: f64, yp: f64, sp: f64) -> DirectionCosineMatrix<f64> {
+    rx(-yp) * DirectionCosineMatrix::rotate_y(-xp) * rz(sp)
+}
@@ -0,0 +1,185 @@
+#[cfg(test)]
+mod tests {
+    use crate::attitude::DirectionCosineMatrix;
+    use crate::reference_frame::earth_orientation::*;
+    use crate::time::{Epoch, TimeScale};
+    use approx::assert_relative_eq;
+
+    // Reference values from the SOFA test suite (t_sofa_c.c)
+
+    fn centuries(mjd: f64) -> f64 {
+        (mjd - 51_544.5) / 36_525.0
+    }
+
+    fn assert_matrix(m: &DirectionCosineMatrix<f64>, expected: [[f64; 3]; 3], epsilon: f64) {
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_relative_eq!(m.as_matrix()[(i, j)], expected[i][j], epsilon = epsilon);
+            }
+        }
+    }
+
+    #[test]
+    fn test_nutation() {
+        let t = centuries(53_736.0);
+        let b = Iau2000B.nutation(t);
+        assert_relative_eq!(b.dpsi, -0.963_255_229_114_836_278_3e-5, epsilon = 1e-13);
+        assert_relative_eq!(b.deps, 0.406_319_710_662_115_936_7e-4, epsilon = 1e-13);
+        let old = Iau1980.nutation(t);
+        assert_relative_eq!(old.dpsi, -0.964_365_835_322_656_396_6e-5, epsilon = 1e-13);
+        assert_relative_eq!(old.deps, 0.406_005_100_687_971_332_2e-4, epsilon = 1e-13);
+
+        // The two principal terms in IERS table layout, in μas
+        let longitude = "\
+Table 5.3a  Luni-solar and planetary nutation in longitude
+----------------------------------------------------------------
+ j = 0  Number of terms = 2
+    i        A_i             A\"_i     l    l'   F    D   Om L_Me L_Ve  L_E L_Ma  L_J L_Sa  L_U L_Ne  p_A
+    1   -17206424.18        3338.60    0    0    0    0    1    0    0    0    0    0    0    0    0    0
+    2    -1317091.22       -1369.60    0    0    2   -2    2    0    0    0    0    0    0    0    0    0
+ j = 1  Number of terms = 1
+    1      -17418.82           2.88    0    0    0    0    1    0    0    0    0    0    0    0    0    0
+";
+        let terms = NutationSeries::parse_table(longitude).unwrap();
+        assert_eq!(terms.len(), 3);
+        assert_eq!((terms[1].multipliers[2], terms[1].multipliers[3], terms[2].power), (2, -2, 1));
+        let series = NutationSeries { longitude: terms, obliquity: Vec::new() };
+        let [_, _, f, d, om] = delaunay_arguments(t);
+        let micro = ARCSEC_TO_RAD * 1e-6;
+        let expected = (-17_206_424.18 - 17_418.82 * t) * om.sin() + (3338.60 + 2.88 * t) * om.cos()
+            - 1_317_091.22 * (2.0 * f - 2.0 * d + 2.0 * om).sin()
+            - 1369.60 * (2.0 * f - 2.0 * d + 2.0 * om).cos();
+        assert_relative_eq!(series.nutation(t).dpsi, expected * micro, epsilon = 1e-15);
+        assert_eq!(NutationSeries::parse_table(" j = x\n"), Err(EopError::Parse { line: 1 }));
+    }
+
+    #[test]
+    fn test_precession() {
+        let t = centuries(50_123.999_9);
+        let angles = FukushimaWilliams::iau2006(t);
+        assert_relative_eq!(angles.gamma, -0.224_338_767_099_799_569_0e-5, epsilon = 1e-16);
+        assert_relative_eq!(angles.phi, 0.409_101_460_239_131_280_8, epsilon = 1e-12);
+        assert_relative_eq!(angles.psi, -0.950_195_417_801_301_509_2e-3, epsilon = 1e-14);
+        assert_relative_eq!(angles.epsilon, 0.409_101_431_658_736_747_2, epsilon = 1e-12);
+
+        assert_relative_eq!(mean_obliquity_2006(centuries(54_388.0)), 0.409_074_922_938_725_820_4, epsilon = 1e-14);
+        assert_relative_eq!(mean_obliquity_1980(centuries(54_388.0)), 0.409_075_134_764_381_621_8, epsilon = 1e-14);
+
+        let expected = [
+            [0.999_999_550_432_835_073_3, 0.869_663_220_948_096_078_5e-3, 0.377_915_347_495_988_834_5e-3],
+            [-0.869_663_220_948_511_219_2e-3, 0.999_999_621_842_856_061_4, -0.164_328_477_611_188_640_7e-6],
+            [-0.377_915_347_495_033_507_7e-3, -0.164_330_674_614_736_689_6e-6, 0.999_999_928_589_979_011_9],
+        ];
+        assert_matrix(&precession_1976(t), expected, 1e-12);
+    }
+
+    #[test]
+    fn test_sidereal_time_and_polar_motion() {
+        let ut1 = Epoch::from_mjd(54_388.0, TimeScale::UT1);
+        assert_relative_eq!(earth_rotation_angle(&ut1), 0.402_283_724_002_815_810_2, epsilon = 1e-12);
+
+        let (ut1, tt) = (Epoch::from_mjd(53_736.0, TimeScale::UT1), Epoch::from_mjd(53_736.0, TimeScale::TT));
+        assert_relative_eq!(gmst_2006(&ut1, &tt), 1.754_174_971_870_091_203, epsilon = 1e-12);
+        assert_relative_eq!(gmst_1982(&ut1), 1.754_174_981_860_675_096, epsilon = 1e-12);
+        assert_relative_eq!(gast_1994(&ut1, &tt), 1.754_166_136_020_645_203, epsilon = 1e-12);
+        // IAU 2000B against the 2000A value of GAST 2006
+        let t = tt.julian_centuries();
+        let dpsi = Iau2000B.nutation(t).dpsi;
+        let gast = gmst_2006(&ut1, &tt) + equation_of_equinoxes_2006(t, dpsi, mean_obliquity_2006(t));
+        assert_relative_eq!(gast, 1.754_166_137_675_019_159, epsilon = 5e-9);
+
+        assert_relative_eq!(tio_locator(centuries(52_541.0)), -0.621_669_846_998_101_930_9e-11, epsilon = 1e-20);
+        let expected = [
+            [0.999_999_999_999_967_472_1, -0.136_717_458_072_884_698_9e-10, 0.255_060_237_999_997_234_5e-6],
+            [0.141_462_494_795_702_980_1e-10, 0.999_999_999_998_269_531_7, -0.186_035_924_699_886_638_9e-5],
+            [-0.255_060_237_974_121_502_1e-6, 0.186_035_924_700_241_402_1e-5, 0.999_999_999_998_237_003_9],
+        ];
+        let pole = polar_motion(2.550_602_38e-7, 1.860_359_247e-6, -0.136_717_458_072_889_146_0e-10);
+        assert_matrix(&pole, expected, 1e-12);
+    }
+
+    #[test]
+    fn test_gcrf_to_itrf() {
+        // TT = UT1 = MJD 53736 as in the SOFA tests: just before the 2006 leap
+        // second, TAI - UTC = 32 s
+        let tt = Epoch::from_mjd(53_736.0, TimeScale::TT);
+        let eop = Eop { xp: 2.550_602_38e-7, yp: 1.860_359_247e-6, dut1: 32.184 + 32.0, ..Eop::default() };
+        let expected = [
+            [-0.181_033_212_852_868_573_0, 0.983_476_980_689_768_507_1, 0.655_553_563_998_263_444_9e-4],
+            [-0.983_476_813_409_521_125_7, -0.181_033_220_387_102_380_0, 0.574_980_111_612_643_896_2e-3],
+            [0.577_347_401_408_153_946_7e-3, 0.396_183_239_176_864_087_1e-4, 0.999_999_832_550_169_196_9],
+        ];
+        let rotation = gcrf_to_itrf(&tt, &eop, &Iau2000B);
+        assert_matrix(&rotation, expected, 5e-9);
+        // The same instant given in UTC
+        let utc = tt.to_scale(TimeScale::UTC);
+        assert_matrix(&gcrf_to_itrf(&utc, &eop, &Iau2000B), expected, 5e-9);
+
+        // Orthonormal, and inverted by the transpose
+        let identity = *(rotation * rotation.transpose()).as_matrix();
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_relative_eq!(identity[(i, j)], if i == j { 1.0 } else { 0.0 }, epsilon = 1e-15);
+            }
+        }
+
+        // The 1976/1980 chain agrees to within the frame bias and model errors
+        let old = gcrf_to_itrf_1976(&tt, &eop);
+        assert_matrix(&old, expected, 2e-7);
+    }
+
+    #[test]
+    fn test_eop_tables() {
+        // finals2000A rows around the leap second of 2016-12-31
+        let row = |date: &str, mjd: f64, xp: f64, yp: f64, dut1: f64, lod: f64| {
+            format!(
+                "{date} {mjd:8.2} I {xp:9.6}{e:9.6} {yp:9.6}{e:9.6}  I{dut1:10.7}{e:10.7} {lod:7.4}{e:7.4}  I {dx:9.3}{e:9.3} {dy:9.3}{e:9.3}",
+                e = 0.0001,
+                dx = -0.156,
+                dy = -0.291,
+            )
+        };
+        let text = [
+            row("161231", 57_753.0, 0.064_548, 0.263_592, -0.407_444_0, 0.7710),
+            row("17 1 1", 57_754.0, 0.064_960, 0.264_040, 0.592_521_0, 0.6894),
+            "17 1 2 57755.00 P  0.065370 0.004300  0.264480 0.004300".to_string(),
+        ]
+        .join("\n");
+        let table = EopTable::parse_finals2000a(&text).unwrap();
+        assert_eq!(table.records().len(), 2);
+        let first = table.records()[0];
+        assert_eq!(first.mjd, 57_753.0);
+        assert_relative_eq!(first.eop.xp, 0.064_548 * ARCSEC_TO_RAD, epsilon = 1e-18);
+        assert_relative_eq!(first.eop.lod, 0.771e-3, epsilon = 1e-15);
+        assert_relative_eq!(first.eop.dy, -0.291e-3 * ARCSEC_TO_RAD, epsilon = 1e-18);
+
+        // Mid-day before the leap second: UT1 - TAI is interpolated, not DUT1
+        let noon = Epoch::from_mjd(57_753.5, TimeScale::UTC);
+        let eop = table.at(&noon).unwrap();
+        assert_relative_eq!(eop.dut1, (-0.407_444 + 0.592_521 - 1.0) / 2.0, epsilon = 1e-12);
+        assert_relative_eq!(eop.yp, (0.263_592 + 0.264_040) / 2.0 * ARCSEC_TO_RAD, epsilon = 1e-15);
+        assert!(table.at(&Epoch::from_mjd(57_760.0, TimeScale::UTC)).is_none());
+        let ut1 = noon.to_scale_with(TimeScale::UT1, &table);
+        assert_relative_eq!((ut1.seconds_of_day() - noon.seconds_of_day()), eop.dut1, epsilon = 1e-9);
+        assert_relative_eq!(ut1.to_scale_with(TimeScale::UTC, &table).mjd(), 57_753.5, epsilon = 1e-12);
+
+        let csv = "\
+MJD;Year;Month;Day;Type;x_pole;sigma_x_pole;y_pole;sigma_y_pole;x_rate;sigma_x_rate;y_rate;sigma_y_rate;Type;UT1-UTC;sigma_UT1-UTC;LOD;sigma_LOD;Type;dX;sigma_dX;dY;sigma_dY
+57753;2016;12;31;final;0.064548;0.000030;0.263592;0.000030;;;;;final;-0.4074440;0.0000100;0.7710;0.0070;final;-0.156;0.300;-0.291;0.300
+57754;2017;1;1;final;0.064960;0.000030;0.264040;0.000030;;;;;final;0.5925210;0.0000100;0.6894;0.0070;final;-0.156;0.300;-0.291;0.300
+57755;2017;1;2;prediction;0.065370;0.004300;0.264480;0.004300;;;;;prediction;;;;;;;;;
+";
+        assert_eq!(EopTable::parse_csv(csv).unwrap(), table);
+        assert_eq!(EopTable::parse_csv("MJD;x_pole;y_pole\n"), Err(EopError::MissingColumn));
+        assert_eq!(EopTable::parse_csv("MJD,x_pole,y_pole,UT1-UTC\n1,2,3,x"), Err(EopError::Parse { line: 2 }));
+        let unordered = EopTable::new(vec![table.records()[1], table.records()[0]]);
+        assert_eq!(unordered, Err(EopError::NotIncreasing { index: 1 }));
+
+        let dir = std::env::temp_dir().join(format!("matrslab-eop-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("finals2000A.data"), &text).unwrap();
+        assert_eq!(EopTable::load_finals2000a(dir.join("finals2000A.data")).unwrap(), table);
+        assert!(matches!(EopTable::load_csv(dir.join("missing.csv")), Err(EopError::Io(_))));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -9,6 +9,7 @@ mod itrf;
 mod ned;
 mod body;
 mod unknown;
+pub mod earth_orientation;
 
 pub use traits::{ReferenceFrame, FixedFrame, RotatingFrame};
 // pub use icrf::ICRF;
@@ -0,0 +1,173 @@
+//! Gregorian calendar dates and ISO-8601 text.
+
+use super::{TimeError, TimeScale};
+
+/// A proleptic Gregorian date and time of day in some time scale. `second`
+/// reaches 60 only inside a UTC leap second.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Calendar {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: f64,
+}
+
+impl Calendar {
+    pub fn new(year: i32, month: u8, day: u8, hour: u8, minute: u8, second: f64) -> Self {
+        Self { year, month, day, hour, minute, second }
+    }
+
+    /// Midnight starting the given date.
+    pub fn date(year: i32, month: u8, day: u8) -> Self {
+        Self::new(year, month, day, 0, 0, 0.0)
+    }
+}
+
+pub(crate) fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+pub(crate) fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Modified Julian day number of a Gregorian date (Fliegel & Van Flandern,
+/// valid for years after -4800).
+pub(crate) const fn mjd_from_date(year: i32, month: u8, day: u8) -> i64 {
+    let a = (14 - month as i64) / 12;
+    let y = year as i64 + 4800 - a;
+    let m = month as i64 + 12 * a - 3;
+    day as i64 + (153 * m + 2) / 5 + 365 * y + y / 4 - y / 100 + y / 400 - 32_045 - 2_400_001
+}
+
+/// Gregorian date of a modified Julian day number, inverse of
+/// [`mjd_from_date`].
+pub(crate) fn date_from_mjd(mjd: i64) -> (i32, u8, u8) {
+    let a = mjd + 2_400_001 + 32_044;
+    let b = (4 * a + 3) / 146_097;
+    let c = a - 146_097 * b / 4;
+    let d = (4 * c + 3) / 1461;
+    let e = c - 1461 * d / 4;
+    let m = (5 * e + 2) / 153;
+    let day = e - (153 * m + 2) / 5 + 1;
+    let month = m + 3 - 12 * (m / 10);
+    let year = 100 * b + d - 4800 + m / 10;
+    (year as i32, month as u8, day as u8)
+}
+
+/// A parsed ISO-8601 timestamp: the calendar fields as written, the UTC
+/// offset of a `±hh:mm` zone in seconds, and the scale named after it.
+pub(crate) struct Timestamp {
+    pub calendar: Calendar,
+    pub offset: f64,
+    pub scale: Option<TimeScale>,
+}
+
+struct Cursor<'a> {
+    text: &'a str,
+}
+
+impl<'a> Cursor<'a> {
+    fn peek(&self) -> Option<char> {
+        self.text.chars().next()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let matched = self.peek() == Some(c);
+        if matched {
+            self.text = &self.text[c.len_utf8()..];
+        }
+        matched
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), TimeError> {
+        if self.eat(c) { Ok(()) } else { Err(TimeError::Syntax) }
+    }
+
+    /// The next `count` ASCII digits, or all of at least `count` if `more`.
+    fn digits(&mut self, count: usize, more: bool) -> Result<&'a str, TimeError> {
+        let available = self.text.find(|c: char| !c.is_ascii_digit()).unwrap_or(self.text.len());
+        if available < count {
+            return Err(TimeError::Syntax);
+        }
+        let end = if more { available } else { count };
+        let (digits, rest) = self.text.split_at(end);
+        self.text = rest;
+        Ok(digits)
+    }
+
+    fn number(&mut self, count: usize) -> Result<u8, TimeError> {
+        self.digits(count, false)?.parse().map_err(|_| TimeError::Syntax)
+    }
+}
+
+/// Parses `[±]YYYY-MM-DD[(T| )hh:mm[:ss[.fff]]][Z|±hh[:mm]][ SCALE]`. Field
+/// ranges are checked later, against the scale.
+pub(crate) fn parse(text: &str) -> Result<Timestamp, TimeError> {
+    let mut cursor = Cursor { text: text.trim() };
+    let negative = cursor.eat('-');
+    if !negative {
+        cursor.eat('+');
+    }
+    let year: i32 = cursor.digits(4, true)?.parse().map_err(|_| TimeError::Syntax)?;
+    let year = if negative { -year } else { year };
+    cursor.expect('-')?;
+    let month = cursor.number(2)?;
+    cursor.expect('-')?;
+    let day = cursor.number(2)?;
+    let mut calendar = Calendar::date(year, month, day);
+
+    let with_time = cursor.text.len() > 1
+        && (cursor.eat('T') || (cursor.text.as_bytes()[1].is_ascii_digit() && cursor.eat(' ')));
+    if with_time {
+        calendar.hour = cursor.number(2)?;
+        cursor.expect(':')?;
+        calendar.minute = cursor.number(2)?;
+        if cursor.eat(':') {
+            let start = cursor.text;
+            cursor.digits(2, false)?;
+            if cursor.eat('.') {
+                cursor.digits(1, true)?;
+            }
+            let seconds = &start[..start.len() - cursor.text.len()];
+            calendar.second = seconds.parse().map_err(|_| TimeError::Syntax)?;
+        }
+    }
+
+    let mut offset = 0.0;
+    let mut zoned = cursor.eat('Z');
+    if let Some(sign) = cursor.peek().filter(|&c| with_time && (c == '+' || c == '-')) {
+        cursor.eat(sign);
+        let hours = cursor.number(2)?;
+        let minutes = if cursor.eat(':') || cursor.peek().is_some_and(|c| c.is_ascii_digit()) {
+            cursor.number(2)?
+        } else {
+            0
+        };
+        if hours > 23 || minutes > 59 {
+            return Err(TimeError::Syntax);
+        }
+        offset = (hours as f64 * 60.0 + minutes as f64) * 60.0;
+        if sign == '-' {
+            offset = -offset;
+        }
+        zoned = true;
+    }
+
+    let scale = match cursor.text.trim_start() {
+        "" => None,
+        name if name.len() < cursor.text.len() => Some(name.parse()?),
+        _ => return Err(TimeError::Syntax),
+    };
+    if zoned && scale.is_some_and(|scale| scale != TimeScale::UTC) {
+        return Err(TimeError::Syntax);
+    }
+    Ok(Timestamp { calendar, offset, scale })
+}
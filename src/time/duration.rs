@@ -0,0 +1,134 @@
+//! Spans of time.
+
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+use super::SECONDS_PER_DAY;
+
+/// A signed span of SI seconds, kept as whole days plus seconds in
+/// `[0, 86400)` so that long spans keep sub-microsecond resolution.
+///
+/// Normalization makes the derived ordering chronological.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Duration {
+    days: i64,
+    seconds: f64,
+}
+
+impl Duration {
+    pub const ZERO: Duration = Duration { days: 0, seconds: 0.0 };
+
+    /// `days * 86400 + seconds`, normalized.
+    pub fn new(days: i64, seconds: f64) -> Self {
+        let (carry, seconds) = split_seconds(seconds);
+        Self { days: days + carry, seconds }
+    }
+
+    pub fn from_seconds(seconds: f64) -> Self {
+        Self::new(0, seconds)
+    }
+
+    pub fn from_minutes(minutes: f64) -> Self {
+        Self::from_seconds(minutes * 60.0)
+    }
+
+    pub fn from_hours(hours: f64) -> Self {
+        Self::from_seconds(hours * 3600.0)
+    }
+
+    pub fn from_days(days: f64) -> Self {
+        let whole = days.floor();
+        Self::new(whole as i64, (days - whole) * SECONDS_PER_DAY)
+    }
+
+    /// Whole days, rounded towards negative infinity
+    pub fn days(&self) -> i64 {
+        self.days
+    }
+
+    /// Seconds beyond [`days`](Self::days), in `[0, 86400)`
+    pub fn seconds(&self) -> f64 {
+        self.seconds
+    }
+
+    pub fn as_seconds(&self) -> f64 {
+        self.days as f64 * SECONDS_PER_DAY + self.seconds
+    }
+
+    pub fn as_days(&self) -> f64 {
+        self.days as f64 + self.seconds / SECONDS_PER_DAY
+    }
+
+    pub fn abs(self) -> Self {
+        if self.days < 0 { -self } else { self }
+    }
+}
+
+/// Splits seconds into whole days and the remainder in `[0, 86400)`.
+pub(crate) fn split_seconds(seconds: f64) -> (i64, f64) {
+    let days = (seconds / SECONDS_PER_DAY).floor();
+    let rest = seconds - days * SECONDS_PER_DAY;
+    // Rounding can leave the remainder a hair outside the interval
+    if rest >= SECONDS_PER_DAY {
+        (days as i64 + 1, rest - SECONDS_PER_DAY)
+    } else if rest < 0.0 {
+        (days as i64 - 1, rest + SECONDS_PER_DAY)
+    } else {
+        (days as i64, rest)
+    }
+}
+
+impl Add for Duration {
+    type Output = Duration;
+    fn add(self, rhs: Duration) -> Duration {
+        Duration::new(self.days + rhs.days, self.seconds + rhs.seconds)
+    }
+}
+
+impl Sub for Duration {
+    type Output = Duration;
+    fn sub(self, rhs: Duration) -> Duration {
+        Duration::new(self.days - rhs.days, self.seconds - rhs.seconds)
+    }
+}
+
+impl Neg for Duration {
+    type Output = Duration;
+    fn neg(self) -> Duration {
+        Duration::new(-self.days, -self.seconds)
+    }
+}
+
+impl AddAssign for Duration {
+    fn add_assign(&mut self, rhs: Duration) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Duration {
+    fn sub_assign(&mut self, rhs: Duration) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<f64> for Duration {
+    type Output = Duration;
+    fn mul(self, rhs: f64) -> Duration {
+        Duration::from_days(self.days as f64 * rhs) + Duration::from_seconds(self.seconds * rhs)
+    }
+}
+
+impl Div<f64> for Duration {
+    type Output = Duration;
+    fn div(self, rhs: f64) -> Duration {
+        let days = self.days as f64 / rhs;
+        Duration::from_days(days) + Duration::from_seconds(self.seconds / rhs)
+    }
+}
+
+/// The ratio of two spans
+impl Div for Duration {
+    type Output = f64;
+    fn div(self, rhs: Duration) -> f64 {
+        self.as_seconds() / rhs.as_seconds()
+    }
+}
@@ -0,0 +1,360 @@
+//! Instants in a time scale.
+
+use core::fmt;
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+use core::str::FromStr;
+
+use super::calendar::{self, date_from_mjd, days_in_month, mjd_from_date};
+use super::duration::split_seconds;
+use super::{
+    day_length, tai_minus_utc, Calendar, Duration, TimeError, TimeScale, Ut1Provider, DAYS_PER_CENTURY,
+    JD_MJD_OFFSET, SECONDS_PER_DAY, TAI_MINUS_GPS, TT_MINUS_TAI,
+};
+
+/// Whole-day part of [`MJD_J2000`](super::MJD_J2000).
+const MJD_J2000_DAY: i64 = 51_544;
+/// Modified Julian date of the GPS week 0 epoch, 1980-01-06.
+const MJD_GPS_EPOCH: i64 = 44_244;
+/// Modified Julian date of the Unix epoch, 1970-01-01.
+#[cfg(feature = "std")]
+const MJD_UNIX_EPOCH: i64 = 40_587;
+
+/// An instant labelled in a [`TimeScale`]: a modified Julian day number and
+/// the seconds elapsed in that day.
+///
+/// Seconds lie in `[0, 86400)`, or up to 86401 on a UTC leap-second day.
+/// Equality is structural: the same instant in two scales compares unequal,
+/// subtract epochs to compare them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Epoch {
+    mjd: i64,
+    seconds: f64,
+    scale: TimeScale,
+}
+
+impl Epoch {
+    /// `seconds` into the day `mjd`; seconds outside the day roll over into
+    /// neighbouring days.
+    pub fn new(mjd: i64, seconds: f64, scale: TimeScale) -> Self {
+        if scale == TimeScale::UTC {
+            if (0.0..day_length(mjd)).contains(&seconds) {
+                return Self { mjd, seconds, scale };
+            }
+            if seconds >= day_length(mjd) && seconds < day_length(mjd) + SECONDS_PER_DAY {
+                return Self { mjd: mjd + 1, seconds: seconds - day_length(mjd), scale };
+            }
+            if seconds < 0.0 && seconds >= -day_length(mjd - 1) {
+                return Self { mjd: mjd - 1, seconds: seconds + day_length(mjd - 1), scale };
+            }
+        }
+        let (carry, seconds) = split_seconds(seconds);
+        Self { mjd: mjd + carry, seconds, scale }
+    }
+
+    /// J2000.0, 2000-01-01T12:00:00 TT.
+    pub fn j2000() -> Self {
+        Self { mjd: MJD_J2000_DAY, seconds: SECONDS_PER_DAY / 2.0, scale: TimeScale::TT }
+    }
+
+    /// From a modified Julian date. UTC dates count fractions of the actual
+    /// day length, as in SOFA.
+    pub fn from_mjd(mjd: f64, scale: TimeScale) -> Self {
+        Self::from_mjd_split(mjd, 0.0, scale)
+    }
+
+    pub fn from_jd(jd: f64, scale: TimeScale) -> Self {
+        Self::from_jd_split(jd, 0.0, scale)
+    }
+
+    /// From a two-part Julian date `jd1 + jd2`, e.g. a day number and a
+    /// fraction, without rounding their sum.
+    pub fn from_jd_split(jd1: f64, jd2: f64, scale: TimeScale) -> Self {
+        Self::from_mjd_split(jd1 - JD_MJD_OFFSET, jd2, scale)
+    }
+
+    fn from_mjd_split(mjd1: f64, mjd2: f64, scale: TimeScale) -> Self {
+        let (w1, w2) = (mjd1.floor(), mjd2.floor());
+        let fraction = (mjd1 - w1) + (mjd2 - w2);
+        let (carry, fraction) = if fraction >= 1.0 { (1, fraction - 1.0) } else { (0, fraction) };
+        let mjd = w1 as i64 + w2 as i64 + carry;
+        let length = if scale == TimeScale::UTC { day_length(mjd) } else { SECONDS_PER_DAY };
+        Self::new(mjd, fraction * length, scale)
+    }
+
+    /// From a calendar date and time of day in `scale`.
+    pub fn from_calendar(calendar: Calendar, scale: TimeScale) -> Result<Self, TimeError> {
+        let Calendar { year, month, day, hour, minute, second } = calendar;
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return Err(TimeError::InvalidDate);
+        }
+        let mjd = mjd_from_date(year, month, day);
+        let last_minute = scale == TimeScale::UTC && hour == 23 && minute == 59;
+        let minute_length = if last_minute { 60.0 + day_length(mjd) - SECONDS_PER_DAY } else { 60.0 };
+        if hour > 23 || minute > 59 || !(0.0..minute_length).contains(&second) {
+            return Err(TimeError::InvalidTime);
+        }
+        let seconds = (hour as f64 * 60.0 + minute as f64) * 60.0 + second;
+        Ok(Self { mjd, seconds, scale })
+    }
+
+    /// From a GPS week number and seconds into the week.
+    pub fn from_gps_week(week: i64, seconds: f64) -> Self {
+        Self::new(MJD_GPS_EPOCH + 7 * week, seconds, TimeScale::GPS)
+    }
+
+    /// The system clock as UTC (assumes it does not smear leap seconds).
+    #[cfg(feature = "std")]
+    pub fn now() -> Self {
+        let unix = match std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH) {
+            Ok(elapsed) => elapsed.as_secs_f64(),
+            Err(before) => -before.duration().as_secs_f64(),
+        };
+        let (days, seconds) = split_seconds(unix);
+        Self { mjd: MJD_UNIX_EPOCH + days, seconds, scale: TimeScale::UTC }
+    }
+
+    pub fn scale(&self) -> TimeScale {
+        self.scale
+    }
+
+    /// Modified Julian day number
+    pub fn day(&self) -> i64 {
+        self.mjd
+    }
+
+    pub fn seconds_of_day(&self) -> f64 {
+        self.seconds
+    }
+
+    fn day_fraction(&self) -> f64 {
+        match self.scale {
+            TimeScale::UTC => self.seconds / day_length(self.mjd),
+            _ => self.seconds / SECONDS_PER_DAY,
+        }
+    }
+
+    /// Modified Julian date
+    pub fn mjd(&self) -> f64 {
+        self.mjd as f64 + self.day_fraction()
+    }
+
+    /// Julian date
+    pub fn jd(&self) -> f64 {
+        let (jd1, jd2) = self.jd_split();
+        jd1 + jd2
+    }
+
+    /// Julian date as the start of the day plus its elapsed fraction, the
+    /// two-part form SOFA-style routines take for full precision.
+    pub fn jd_split(&self) -> (f64, f64) {
+        (self.mjd as f64 + JD_MJD_OFFSET, self.day_fraction())
+    }
+
+    /// Days since J2000.0, counted in this epoch's own scale
+    pub fn days_since_j2000(&self) -> f64 {
+        (self.mjd - MJD_J2000_DAY) as f64 + (self.day_fraction() - 0.5)
+    }
+
+    /// Seconds since J2000.0, counted in this epoch's own scale
+    pub fn seconds_since_j2000(&self) -> f64 {
+        (self.mjd - MJD_J2000_DAY) as f64 * SECONDS_PER_DAY + (self.seconds - SECONDS_PER_DAY / 2.0)
+    }
+
+    /// Julian centuries since J2000.0, counted in this epoch's own scale; the
+    /// argument of most precession-nutation and ephemeris series
+    pub fn julian_centuries(&self) -> f64 {
+        self.days_since_j2000() / DAYS_PER_CENTURY
+    }
+
+    /// Calendar date and time of day in this epoch's scale.
+    pub fn calendar(&self) -> Calendar {
+        let (year, month, day) = date_from_mjd(self.mjd);
+        if self.seconds >= SECONDS_PER_DAY {
+            return Calendar::new(year, month, day, 23, 59, self.seconds - (SECONDS_PER_DAY - 60.0));
+        }
+        let hour = (self.seconds / 3600.0).floor();
+        let minute = ((self.seconds - hour * 3600.0) / 60.0).floor();
+        let second = self.seconds - hour * 3600.0 - minute * 60.0;
+        Calendar::new(year, month, day, hour as u8, minute as u8, second)
+    }
+
+    /// GPS week number and seconds into the week.
+    pub fn gps_week(&self) -> (i64, f64) {
+        let gps = self.to_scale(TimeScale::GPS);
+        let days = gps.mjd - MJD_GPS_EPOCH;
+        (days.div_euclid(7), days.rem_euclid(7) as f64 * SECONDS_PER_DAY + gps.seconds)
+    }
+
+    /// The same instant labelled in `scale`, taking UT1 = UTC.
+    pub fn to_scale(&self, scale: TimeScale) -> Self {
+        self.to_scale_with(scale, &0.0)
+    }
+
+    /// The same instant labelled in `scale`, with DUT1 from `ut1` for
+    /// conversions to or from UT1.
+    pub fn to_scale_with<U: Ut1Provider + ?Sized>(&self, scale: TimeScale, ut1: &U) -> Self {
+        if scale == self.scale {
+            return *self;
+        }
+        let (mjd, seconds) = self.tai(ut1);
+        Self::from_tai(mjd, seconds, scale, ut1)
+    }
+
+    /// Day and (unnormalized) seconds in TAI.
+    fn tai<U: Ut1Provider + ?Sized>(&self, ut1: &U) -> (i64, f64) {
+        let Self { mjd, seconds, .. } = *self;
+        match self.scale {
+            TimeScale::TAI => (mjd, seconds),
+            TimeScale::TT => (mjd, seconds - TT_MINUS_TAI),
+            TimeScale::GPS => (mjd, seconds + TAI_MINUS_GPS),
+            TimeScale::TDB => (mjd, seconds - tdb_minus_tt(self) - TT_MINUS_TAI),
+            TimeScale::UTC => (mjd, seconds + tai_minus_utc(mjd, seconds)),
+            TimeScale::UT1 => {
+                // DUT1 is tabulated against UTC; one refinement suffices
+                let mut utc = Self::new(mjd, seconds, TimeScale::UTC);
+                for _ in 0..2 {
+                    utc = Self::new(mjd, seconds - ut1.dut1(&utc), TimeScale::UTC);
+                }
+                utc.tai(ut1)
+            }
+        }
+    }
+
+    fn from_tai<U: Ut1Provider + ?Sized>(mjd: i64, seconds: f64, scale: TimeScale, ut1: &U) -> Self {
+        let tai = Self::new(mjd, seconds, TimeScale::TAI);
+        let (mjd, seconds) = (tai.mjd, tai.seconds);
+        match scale {
+            TimeScale::TAI => tai,
+            TimeScale::TT => Self::new(mjd, seconds + TT_MINUS_TAI, scale),
+            TimeScale::GPS => Self::new(mjd, seconds - TAI_MINUS_GPS, scale),
+            TimeScale::TDB => {
+                let tt = Self::new(mjd, seconds + TT_MINUS_TAI, TimeScale::TT);
+                Self::new(tt.mjd, tt.seconds + tdb_minus_tt(&tt), scale)
+            }
+            TimeScale::UTC => {
+                // The UTC day is the TAI day or the one before
+                let mut day = mjd;
+                for _ in 0..4 {
+                    let elapsed = (mjd - day) as f64 * SECONDS_PER_DAY + seconds;
+                    let mut utc = elapsed - tai_minus_utc(day, elapsed);
+                    utc = elapsed - tai_minus_utc(day, utc);
+                    if utc < 0.0 {
+                        day -= 1;
+                    } else if utc >= day_length(day) {
+                        day += 1;
+                    } else {
+                        return Self { mjd: day, seconds: utc, scale };
+                    }
+                }
+                Self::new(mjd, seconds - tai_minus_utc(mjd, seconds), scale)
+            }
+            TimeScale::UT1 => {
+                let utc = Self::from_tai(mjd, seconds, TimeScale::UTC, ut1);
+                Self::new(utc.mjd, utc.seconds + ut1.dut1(&utc), scale)
+            }
+        }
+    }
+
+    /// The epoch rounded to `digits` decimals of a second.
+    fn rounded(&self, digits: usize) -> Self {
+        let factor = 10f64.powi(digits as i32);
+        Self::new(self.mjd, (self.seconds * factor).round() / factor, self.scale)
+    }
+}
+
+/// TDB - TT in seconds at the TT (or, to within 2 ns, TDB) epoch `tt`, after
+/// USNO Circular 179 eq. 2.6.
+fn tdb_minus_tt(tt: &Epoch) -> f64 {
+    let t = tt.julian_centuries();
+    0.001_657 * (628.3076 * t + 6.2401).sin()
+        + 0.000_022 * (575.3385 * t + 4.2970).sin()
+        + 0.000_014 * (1256.6152 * t + 6.1969).sin()
+        + 0.000_005 * (606.9777 * t + 4.0212).sin()
+        + 0.000_005 * (52.9691 * t + 0.4444).sin()
+        + 0.000_002 * (21.3299 * t + 5.5431).sin()
+        + 0.000_010 * t * (628.3076 * t + 4.2490).sin()
+}
+
+/// Steps by elapsed SI seconds; UTC epochs step through TAI, so leap seconds
+/// are counted.
+impl Add<Duration> for Epoch {
+    type Output = Epoch;
+    fn add(self, rhs: Duration) -> Epoch {
+        if self.scale == TimeScale::UTC {
+            return (self.to_scale(TimeScale::TAI) + rhs).to_scale(TimeScale::UTC);
+        }
+        Epoch::new(self.mjd + rhs.days(), self.seconds + rhs.seconds(), self.scale)
+    }
+}
+
+impl Sub<Duration> for Epoch {
+    type Output = Epoch;
+    fn sub(self, rhs: Duration) -> Epoch {
+        self + -rhs
+    }
+}
+
+impl AddAssign<Duration> for Epoch {
+    fn add_assign(&mut self, rhs: Duration) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign<Duration> for Epoch {
+    fn sub_assign(&mut self, rhs: Duration) {
+        *self = *self - rhs;
+    }
+}
+
+/// Elapsed time from `rhs` to `self`, measured in `self`'s scale (TAI for
+/// UTC epochs).
+impl Sub for Epoch {
+    type Output = Duration;
+    fn sub(self, rhs: Epoch) -> Duration {
+        let scale = if self.scale == TimeScale::UTC { TimeScale::TAI } else { self.scale };
+        let (a, b) = (self.to_scale(scale), rhs.to_scale(scale));
+        Duration::new(a.mjd - b.mjd, a.seconds - b.seconds)
+    }
+}
+
+/// ISO-8601 with the scale appended, `2000-01-01T12:00:00.000 TT`; the
+/// precision sets the decimals of the second (default 3).
+impl fmt::Display for Epoch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = f.precision().unwrap_or(3).min(9);
+        let width = if digits == 0 { 2 } else { digits + 3 };
+        let c = self.rounded(digits).calendar();
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:0width$.digits$} {}",
+            c.year, c.month, c.day, c.hour, c.minute, c.second, self.scale
+        )
+    }
+}
+
+/// Parses ISO-8601 calendar dates and times: `2024-03-01`,
+/// `2024-03-01T12:34:56.789Z`, `2024-03-01 12:34+02:00` or
+/// `2000-01-01T12:00:00 TDB`. Without a scale name the time is UTC; zone
+/// offsets are only accepted for UTC.
+impl FromStr for Epoch {
+    type Err = TimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let timestamp = calendar::parse(s)?;
+        let scale = timestamp.scale.unwrap_or(TimeScale::UTC);
+        let mut local = timestamp.calendar;
+        if timestamp.offset == 0.0 {
+            return Epoch::from_calendar(local, scale);
+        }
+        if !(1..=12).contains(&local.month) || local.day == 0 || local.day > days_in_month(local.year, local.month) {
+            return Err(TimeError::InvalidDate);
+        }
+        // Shift the wall-clock labels so a leap second stays in the last minute
+        let minutes = local.hour as i64 * 60 + local.minute as i64 - (timestamp.offset / 60.0) as i64;
+        let mjd = mjd_from_date(local.year, local.month, local.day) + minutes.div_euclid(1440);
+        let minutes = minutes.rem_euclid(1440);
+        (local.year, local.month, local.day) = date_from_mjd(mjd);
+        (local.hour, local.minute) = ((minutes / 60) as u8, (minutes % 60) as u8);
+        Epoch::from_calendar(local, scale)
+    }
+}
@@ -0,0 +1,82 @@
+//! TAI - UTC from the IERS leap-second history.
+
+use super::calendar::mjd_from_date;
+use super::SECONDS_PER_DAY;
+
+/// A change of TAI - UTC from the first of a month: before 1972 the offset
+/// drifted linearly, `offset + (MJD - reference) * rate` seconds.
+struct Offset {
+    mjd: i64,
+    offset: f64,
+    reference: f64,
+    rate: f64,
+}
+
+const fn offset(year: i32, month: u8, offset: f64, reference: f64, rate: f64) -> Offset {
+    Offset { mjd: mjd_from_date(year, month, 1), offset, reference, rate }
+}
+
+const fn step(year: i32, month: u8, seconds: f64) -> Offset {
+    offset(year, month, seconds, 0.0, 0.0)
+}
+
+/// IERS Bulletin C up to the leap second of 2016-12-31.
+const TABLE: [Offset; 42] = [
+    offset(1960, 1, 1.417_818_0, 37_300.0, 0.001_296_0),
+    offset(1961, 1, 1.422_818_0, 37_300.0, 0.001_296_0),
+    offset(1961, 8, 1.372_818_0, 37_300.0, 0.001_296_0),
+    offset(1962, 1, 1.845_858_0, 37_665.0, 0.001_123_2),
+    offset(1963, 11, 1.945_858_0, 37_665.0, 0.001_123_2),
+    offset(1964, 1, 3.240_130_0, 38_761.0, 0.001_296_0),
+    offset(1964, 4, 3.340_130_0, 38_761.0, 0.001_296_0),
+    offset(1964, 9, 3.440_130_0, 38_761.0, 0.001_296_0),
+    offset(1965, 1, 3.540_130_0, 38_761.0, 0.001_296_0),
+    offset(1965, 3, 3.640_130_0, 38_761.0, 0.001_296_0),
+    offset(1965, 7, 3.740_130_0, 38_761.0, 0.001_296_0),
+    offset(1965, 9, 3.840_130_0, 38_761.0, 0.001_296_0),
+    offset(1966, 1, 4.313_170_0, 39_126.0, 0.002_592_0),
+    offset(1968, 2, 4.213_170_0, 39_126.0, 0.002_592_0),
+    step(1972, 1, 10.0),
+    step(1972, 7, 11.0),
+    step(1973, 1, 12.0),
+    step(1974, 1, 13.0),
+    step(1975, 1, 14.0),
+    step(1976, 1, 15.0),
+    step(1977, 1, 16.0),
+    step(1978, 1, 17.0),
+    step(1979, 1, 18.0),
+    step(1980, 1, 19.0),
+    step(1981, 7, 20.0),
+    step(1982, 7, 21.0),
+    step(1983, 7, 22.0),
+    step(1985, 7, 23.0),
+    step(1988, 1, 24.0),
+    step(1990, 1, 25.0),
+    step(1991, 1, 26.0),
+    step(1992, 7, 27.0),
+    step(1993, 7, 28.0),
+    step(1994, 7, 29.0),
+    step(1996, 1, 30.0),
+    step(1997, 7, 31.0),
+    step(1999, 1, 32.0),
+    step(2006, 1, 33.0),
+    step(2009, 1, 34.0),
+    step(2012, 7, 35.0),
+    step(2015, 7, 36.0),
+    step(2017, 1, 37.0),
+];
+
+/// TAI - UTC in seconds at `seconds` into the UTC day `mjd`.
+///
+/// Dates before 1960 use the first table entry and dates after the last leap
+/// second keep its offset.
+pub fn tai_minus_utc(mjd: i64, seconds: f64) -> f64 {
+    let entry = TABLE.iter().rev().find(|entry| entry.mjd <= mjd).unwrap_or(&TABLE[0]);
+    entry.offset + (mjd as f64 + seconds / SECONDS_PER_DAY - entry.reference) * entry.rate
+}
+
+/// Length in SI seconds of the UTC day `mjd`: 86401 on the last day before a
+/// leap second, and slightly off 86400 across the pre-1972 steps.
+pub fn day_length(mjd: i64) -> f64 {
+    SECONDS_PER_DAY + tai_minus_utc(mjd + 1, 0.0) - tai_minus_utc(mjd, SECONDS_PER_DAY)
+}
@@ -0,0 +1,122 @@
+//! Time scales, epochs and durations.
+//!
+//! An [`Epoch`] is an instant labelled in one [`TimeScale`], stored as a whole
+//! modified Julian day plus seconds into that day so that sub-microsecond
+//! resolution survives over centuries. Epochs convert between
+//!
+//! - UTC, with the built-in leap-second table (including the 1961-1971 drift
+//!   offsets) and 86401-second days where a leap second is inserted;
+//! - TAI, the uniform atomic scale everything is converted through;
+//! - TT = TAI + 32.184 s and GPS = TAI - 19 s;
+//! - TDB, from TT by the periodic series of USNO Circular 179 (eq. 2.6,
+//!   about 10 μs);
+//! - UT1 = UTC + DUT1, with DUT1 from a [`Ut1Provider`] (zero unless given).
+//!
+//! Calendar dates are proleptic Gregorian. [`Epoch`] parses and prints
+//! ISO-8601 strings such as `2024-03-01T12:34:56.789Z` or
+//! `2000-01-01T12:00:00 TT`. A [`Duration`] is a span of SI seconds; adding
+//! one to a UTC epoch steps across leap seconds.
+
+mod calendar;
+mod duration;
+mod epoch;
+mod leap_seconds;
+pub use calendar::Calendar;
+pub use duration::Duration;
+pub use epoch::Epoch;
+pub use leap_seconds::{day_length, tai_minus_utc};
+
+use core::fmt;
+use core::str::FromStr;
+
+/// Seconds per day in every scale except on UTC leap-second days.
+pub const SECONDS_PER_DAY: f64 = 86_400.0;
+/// Modified Julian date of J2000.0, 2000-01-01T12:00:00 TT.
+pub const MJD_J2000: f64 = 51_544.5;
+/// Julian date minus modified Julian date.
+pub const JD_MJD_OFFSET: f64 = 2_400_000.5;
+/// Days per Julian century.
+pub const DAYS_PER_CENTURY: f64 = 36_525.0;
+
+/// TT - TAI in seconds.
+const TT_MINUS_TAI: f64 = 32.184;
+/// TAI - GPS in seconds.
+const TAI_MINUS_GPS: f64 = 19.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeScale {
+    /// Coordinated Universal Time
+    UTC,
+    /// International Atomic Time
+    TAI,
+    /// Terrestrial Time
+    TT,
+    /// Barycentric Dynamical Time
+    TDB,
+    /// GPS system time
+    GPS,
+    /// Universal Time, Earth rotation angle as a time
+    UT1,
+}
+
+impl TimeScale {
+    pub fn name(self) -> &'static str {
+        match self {
+            TimeScale::UTC => "UTC",
+            TimeScale::TAI => "TAI",
+            TimeScale::TT => "TT",
+            TimeScale::TDB => "TDB",
+            TimeScale::GPS => "GPS",
+            TimeScale::UT1 => "UT1",
+        }
+    }
+}
+
+impl fmt::Display for TimeScale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for TimeScale {
+    type Err = TimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [TimeScale::UTC, TimeScale::TAI, TimeScale::TT, TimeScale::TDB, TimeScale::GPS, TimeScale::UT1]
+            .into_iter()
+            .find(|scale| scale.name().eq_ignore_ascii_case(s))
+            .ok_or(TimeError::UnknownScale)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeError {
+    /// Month or day of month out of range
+    InvalidDate,
+    /// Hour, minute or second out of range; second 60 is only valid in the
+    /// last minute of a UTC leap-second day
+    InvalidTime,
+    /// Malformed ISO-8601 string
+    Syntax,
+    /// Time scale name not recognised
+    UnknownScale,
+}
+
+/// Source of `DUT1 = UT1 - UTC` in seconds.
+///
+/// A bare `f64` is a constant DUT1; tables of Earth orientation parameters
+/// implement this to interpolate the observed values.
+pub trait Ut1Provider {
+    /// DUT1 at the UTC epoch `utc`
+    fn dut1(&self, utc: &Epoch) -> f64;
+}
+
+impl Ut1Provider for f64 {
+    fn dut1(&self, _utc: &Epoch) -> f64 {
+        *self
+    }
+}
+
+#[cfg(test)]
+#[path = "tests/mod.rs"]
+mod tests;
@@ -0,0 +1,155 @@
+#[cfg(test)]
+mod tests {
+    use crate::time::*;
+    use approx::assert_relative_eq;
+
+    fn utc(text: &str) -> Epoch {
+        text.parse().expect(text)
+    }
+
+    #[test]
+    fn test_calendar_and_julian_dates() {
+        let j2000 = Epoch::j2000();
+        assert_eq!(j2000.jd(), 2_451_545.0);
+        assert_eq!(j2000.mjd(), MJD_J2000);
+        assert_eq!(j2000.julian_centuries(), 0.0);
+        assert_eq!(j2000.calendar(), Calendar::new(2000, 1, 1, 12, 0, 0.0));
+
+        let dates = [(1858, 11, 17, 0), (1972, 1, 1, 41_317), (2000, 2, 29, 51_603), (2017, 1, 1, 57_754), (1600, 3, 1, -94_493)];
+        for (year, month, day, mjd) in dates {
+            let epoch = Epoch::from_calendar(Calendar::date(year, month, day), TimeScale::TT).unwrap();
+            assert_eq!(epoch.day(), mjd);
+            assert_eq!(epoch.calendar(), Calendar::date(year, month, day));
+        }
+        for mjd in (-200_000..200_000).step_by(997) {
+            let epoch = Epoch::new(mjd, 0.0, TimeScale::TAI);
+            assert_eq!(Epoch::from_calendar(epoch.calendar(), TimeScale::TAI).unwrap(), epoch);
+        }
+
+        // Split Julian dates keep full resolution
+        let epoch = Epoch::from_jd_split(2_460_000.5, 0.123_456_789_012, TimeScale::TT);
+        assert_eq!(epoch.day(), 60_000);
+        assert_relative_eq!(epoch.seconds_of_day(), 0.123_456_789_012 * 86_400.0, epsilon = 1e-9);
+        assert_relative_eq!(Epoch::from_mjd(epoch.mjd(), TimeScale::TT).seconds_of_day(), epoch.seconds_of_day(), epsilon = 1e-5);
+
+        let invalid = [Calendar::date(2023, 2, 29), Calendar::date(2024, 13, 1), Calendar::date(2024, 4, 0)];
+        for calendar in invalid {
+            assert_eq!(Epoch::from_calendar(calendar, TimeScale::UTC), Err(TimeError::InvalidDate));
+        }
+        let late = Calendar::new(2024, 1, 1, 24, 0, 0.0);
+        assert_eq!(Epoch::from_calendar(late, TimeScale::UTC), Err(TimeError::InvalidTime));
+    }
+
+    #[test]
+    fn test_leap_seconds() {
+        assert_eq!(tai_minus_utc(57_753, 0.0), 36.0);
+        assert_eq!(tai_minus_utc(57_754, 0.0), 37.0);
+        assert_eq!(day_length(57_753), 86_401.0);
+        assert_eq!(day_length(57_754), 86_400.0);
+        // Drift era: 1.4228180 + (MJD - 37300) * 0.001296 s
+        assert_relative_eq!(tai_minus_utc(37_400, 43_200.0), 1.422_818 + 100.5 * 0.001_296, epsilon = 1e-12);
+
+        // 23:59:60 exists only at the end of a leap-second day
+        let leap = Epoch::from_calendar(Calendar::new(2016, 12, 31, 23, 59, 60.5), TimeScale::UTC).unwrap();
+        assert_eq!(leap.seconds_of_day(), 86_400.5);
+        let bad = Calendar::new(2016, 12, 30, 23, 59, 60.0);
+        assert_eq!(Epoch::from_calendar(bad, TimeScale::UTC), Err(TimeError::InvalidTime));
+        assert_eq!(Epoch::from_calendar(bad, TimeScale::TAI), Err(TimeError::InvalidTime));
+
+        let tai = leap.to_scale(TimeScale::TAI);
+        assert_eq!(tai.calendar(), Calendar::new(2017, 1, 1, 0, 0, 36.5));
+        assert_eq!(tai.to_scale(TimeScale::UTC), leap);
+
+        // Elapsed time across the leap second counts it
+        let before = utc("2016-12-31T23:59:59Z");
+        let after = utc("2017-01-01T00:00:00Z");
+        assert_eq!((after - before).as_seconds(), 2.0);
+        assert_eq!(before + Duration::from_seconds(1.0), Epoch::new(57_753, 86_400.0, TimeScale::UTC));
+        assert_eq!(before + Duration::from_seconds(2.0), after);
+        assert_eq!(after - Duration::from_seconds(0.5), leap);
+        assert_eq!(format!("{}", leap), "2016-12-31T23:59:60.500 UTC");
+        assert_eq!(format!("{:.0}", Epoch::new(57_753, 86_400.6, TimeScale::UTC)), "2017-01-01T00:00:00 UTC");
+    }
+
+    #[test]
+    fn test_scale_conversions() {
+        // SOFA t_sofa_c: UTC 2006-01-15 21:24:37.5
+        let epoch = Epoch::from_calendar(Calendar::new(2006, 1, 15, 21, 24, 37.5), TimeScale::UTC).unwrap();
+        let tai = epoch.to_scale(TimeScale::TAI);
+        assert_relative_eq!(tai.jd_split().1, (77_077.5 + 33.0) / 86_400.0, epsilon = 1e-15);
+        let tt = epoch.to_scale(TimeScale::TT);
+        assert_relative_eq!(tt.jd_split().1, (77_077.5 + 33.0 + 32.184) / 86_400.0, epsilon = 1e-15);
+        assert_eq!(epoch.to_scale(TimeScale::GPS).calendar(), Calendar::new(2006, 1, 15, 21, 24, 51.5));
+
+        let tdb = tt.to_scale(TimeScale::TDB);
+        let offset = (tdb.seconds_of_day() - tt.seconds_of_day()).abs();
+        assert!(offset > 1e-5 && offset < 1.7e-3, "{offset}");
+        assert_relative_eq!(tdb.to_scale(TimeScale::UTC).seconds_of_day(), epoch.seconds_of_day(), epsilon = 1e-9);
+
+        // UT1 with a constant DUT1, and through the trait object
+        let dut1 = -0.387_845;
+        let ut1 = epoch.to_scale_with(TimeScale::UT1, &dut1);
+        assert_relative_eq!(ut1.seconds_of_day(), epoch.seconds_of_day() + dut1, epsilon = 1e-9);
+        let provider: &dyn Ut1Provider = &dut1;
+        let back = ut1.to_scale_with(TimeScale::TT, provider);
+        assert_relative_eq!(back.seconds_of_day(), tt.seconds_of_day(), epsilon = 1e-9);
+        assert_eq!(epoch.to_scale(TimeScale::UT1).seconds_of_day(), epoch.seconds_of_day());
+
+        // GPS weeks
+        assert_eq!(utc("1980-01-06T00:00:00Z").gps_week(), (0, 0.0));
+        let gps = Epoch::from_gps_week(2_300, 345_600.25);
+        assert_eq!(gps.gps_week(), (2_300, 345_600.25));
+        assert_eq!((gps - gps.to_scale(TimeScale::UTC)).as_seconds(), 0.0);
+        assert_eq!(gps.to_scale(TimeScale::UTC).calendar().second, 0.25 - 18.0 + 60.0);
+    }
+
+    #[test]
+    fn test_iso_8601_and_durations() {
+        let epoch = utc("2024-03-01T12:34:56.789Z");
+        assert_eq!(epoch.calendar(), Calendar::new(2024, 3, 1, 12, 34, epoch.calendar().second));
+        assert_relative_eq!(epoch.calendar().second, 56.789, epsilon = 1e-9);
+        assert_eq!(format!("{}", epoch), "2024-03-01T12:34:56.789 UTC");
+        assert_eq!(format!("{:.6}", epoch), "2024-03-01T12:34:56.789000 UTC");
+        assert_eq!(utc(&format!("{:.9}", epoch)), epoch);
+
+        assert_eq!(utc("2024-03-01"), utc("2024-03-01T00:00:00Z"));
+        assert_eq!(utc("2024-03-01 12:34"), utc("2024-03-01T12:34:00"));
+        assert_eq!(utc("2024-03-01T01:30+02:00"), utc("2024-02-29T23:30Z"));
+        assert_eq!(utc("2024-03-01T22:00:00-0300"), utc("2024-03-02T01:00:00 UTC"));
+        // A zone offset keeps a leap second in the last minute of the UTC day
+        assert_eq!(utc("2017-01-01T00:59:60.5+01:00").seconds_of_day(), 86_400.5);
+        let tt = utc("2000-01-01T12:00:00 TT");
+        assert_eq!(tt, Epoch::j2000());
+        assert_eq!(format!("{:.0}", tt), "2000-01-01T12:00:00 TT");
+        assert_eq!("2000-01-01T11:59:27.816 tai".parse::<Epoch>().unwrap().to_scale(TimeScale::TT), tt);
+
+        let failures = [
+            ("2024-3-01", TimeError::Syntax),
+            ("2024-03-01T12", TimeError::Syntax),
+            ("2024-03-01T12:00:00 TT+01:00", TimeError::UnknownScale),
+            ("2024-03-01T12:00:00+01:00 TT", TimeError::Syntax),
+            ("2024-03-01T12:00:00 XYZ", TimeError::UnknownScale),
+            ("2024-02-30", TimeError::InvalidDate),
+            ("2024-03-01T12:60", TimeError::InvalidTime),
+        ];
+        for (text, error) in failures {
+            assert_eq!(text.parse::<Epoch>(), Err(error), "{text}");
+        }
+
+        let week = Duration::from_days(7.0);
+        assert_eq!((week.days(), week.seconds()), (7, 0.0));
+        assert_eq!(Duration::from_hours(-1.5), Duration::new(-1, 86_400.0 - 5_400.0));
+        assert_eq!((week / 7.0).as_seconds(), 86_400.0);
+        assert_eq!(week * 0.5 + Duration::from_minutes(30.0), Duration::new(3, 45_000.0));
+        assert_eq!(week / Duration::from_days(2.0), 3.5);
+        assert!(Duration::from_seconds(-1.0) < Duration::ZERO);
+        assert_eq!(Duration::from_seconds(-1.0).abs(), Duration::from_seconds(1.0));
+
+        // A century of seconds stays exact to well under a microsecond
+        let start = Epoch::j2000();
+        let end = start + Duration::from_days(36_525.0) + Duration::from_seconds(1e-7);
+        assert_relative_eq!(end.julian_centuries(), 1.0, epsilon = 1e-15);
+        assert_relative_eq!((end - start).seconds(), 1e-7, epsilon = 1e-12);
+        assert_eq!((end - start).days(), 36_525);
+    }
+}
@@ -61,3 +61,17 @@ where
         self.map(|x| x.to_degrees())
     }
 }
+
+/// Wraps an angle in radians into `(-pi, pi]`.
+pub fn wrap_pi<T: Float>(angle: T) -> T {
+    let pi = T::from(core::f64::consts::PI).unwrap();
+    let wrapped = wrap_two_pi(angle + pi) - pi;
+    if wrapped == -pi { pi } else { wrapped }
+}
+
+/// Wraps an angle in radians into `[0, 2pi)`.
+pub fn wrap_two_pi<T: Float>(angle: T) -> T {
+    let two_pi = T::from(core::f64::consts::TAU).unwrap();
+    let wrapped = angle - two_pi * (angle / two_pi).floor();
+    if wrapped >= two_pi { T::zero() } else { wrapped }
+}